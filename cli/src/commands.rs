@@ -0,0 +1,757 @@
+// Subcomandos do adr-admin e a transação que cada um monta
+//
+// `plan` só deriva contas e monta instruções: não acessa a rede, o que permite testar
+// cada subcomando sem um validador. As views (`status`, `collection-info`, ...) são
+// simuladas direto pelo main.
+
+use std::path::PathBuf;
+
+use adr_token_mint_client::{instructions, pda, PaymentMode, PriceTier, PricingMode, PROGRAM_ID};
+use anchor_lang::prelude::Pubkey;
+use anchor_lang::solana_program::instruction::Instruction;
+use clap::{Parser, Subcommand, ValueEnum};
+use serde_json::{json, Value};
+use solana_keypair::Keypair;
+use solana_signer::Signer;
+
+use crate::deploy_config::DeployConfig;
+
+#[derive(Parser)]
+#[command(name = "adr-admin", version, about = "Administração do programa adr_token_mint")]
+pub struct Cli {
+    /// URL do RPC (padrão: ANCHOR_PROVIDER_URL ou devnet)
+    #[arg(long, env = "ANCHOR_PROVIDER_URL", default_value = "https://api.devnet.solana.com")]
+    pub url: String,
+
+    /// Wallet do admin (pagadora e signatária)
+    #[arg(long, default_value = "./wallet-dev.json")]
+    pub keypair: PathBuf,
+
+    /// Arquivo de configuração do deploy
+    #[arg(long, default_value = "config/deploy-config.json")]
+    pub config_file: PathBuf,
+
+    /// Metadados da coleção (padrão: collectionMetadata do arquivo de configuração)
+    #[arg(long, global = true)]
+    pub collection: Option<Pubkey>,
+
+    /// Apenas simula a transação; não envia nem altera o arquivo de configuração
+    #[arg(long)]
+    pub dry_run: bool,
+
+    /// Saída em JSON
+    #[arg(long)]
+    pub json: bool,
+
+    #[command(subcommand)]
+    pub command: Command,
+}
+
+#[derive(Subcommand)]
+pub enum Command {
+    /// Inicializa a coleção, o contador de NFTs, a sequência de eventos e a conta de configuração
+    Init {
+        #[arg(long)]
+        name: String,
+        #[arg(long)]
+        symbol: String,
+        #[arg(long)]
+        uri: String,
+    },
+    /// Migra a conta de configuração keypair da devnet para a PDA e cria a sequência de
+    /// eventos se necessário (migrate_config)
+    MigrateConfig,
+    /// Converte uma conta do programa do layout v1 para a versão atual (migrate_account)
+    MigrateAccount { account: Pubkey },
+    /// Cria a sequência de eventos em implantações anteriores a ela (initialize_event_sequence)
+    InitEventSequence,
+    /// Define o token de pagamento (set_payment_token)
+    SetPaymentToken {
+        mint: Pubkey,
+        /// Marca o token como token da pump.fun no arquivo de configuração
+        #[arg(long)]
+        pumpfun: bool,
+    },
+    /// Define uma conta de reserva de recompensas customizada (set_reward_reserve)
+    SetRewardReserve { account: Pubkey },
+    /// Cria a reserva de recompensas do token de pagamento (initialize_reward_reserve)
+    InitRewardReserve,
+    /// Deposita tokens da wallet do admin na reserva (deposit_reward_reserve)
+    DepositRewards {
+        /// Quantidade em unidades mínimas do token
+        amount: u64,
+    },
+    /// Ativa/desativa o staking e define a taxa de recompensa em basis points
+    ConfigureStaking {
+        #[arg(long, action = clap::ArgAction::Set)]
+        enabled: bool,
+        #[arg(long)]
+        reward_rate: u64,
+    },
+    /// Define o bônus de holder (bps) sobre a recompensa de stake com NFT travado
+    SetNftHolderBoost { boost_bps: u64 },
+    /// Pausa o programa em emergência
+    Pause {
+        #[arg(long)]
+        reason: String,
+    },
+    /// Retoma o programa após uma pausa
+    Unpause {
+        #[arg(long)]
+        reason: String,
+    },
+    /// Define supply, janela de mint, preço base e limite por carteira (configure_collection)
+    ConfigureCollection {
+        /// 0 = sem limite
+        #[arg(long, default_value_t = 0)]
+        max_supply: u64,
+        /// Unix timestamp; 0 = sem início
+        #[arg(long, default_value_t = 0)]
+        mint_start: i64,
+        /// Unix timestamp; 0 = sem fim
+        #[arg(long, default_value_t = 0)]
+        mint_end: i64,
+        /// Preço do modo Fixed e base das curvas; 0 no modo Fixed exige --allow-free-mint
+        /// em set-pricing-curve
+        #[arg(long, default_value_t = 0)]
+        mint_price: u64,
+        /// 0 = sem limite
+        #[arg(long, default_value_t = 0)]
+        max_per_wallet: u64,
+    },
+    /// Define a raiz de Merkle e o preço da allowlist (set_allowlist)
+    SetAllowlist {
+        /// Raiz em hexadecimal (32 bytes)
+        #[arg(value_parser = parse_hash)]
+        merkle_root: [u8; 32],
+        #[arg(long)]
+        price: u64,
+    },
+    /// Define a curva de preço da coleção (set_pricing_curve)
+    SetPricingCurve {
+        #[arg(long, value_enum)]
+        mode: PricingModeArg,
+        /// Acréscimo por mint (linear) ou em bps (exponencial)
+        #[arg(long, default_value_t = 0)]
+        increment: u64,
+        /// Faixa no formato INICIO:PRECO (repetir para cada faixa)
+        #[arg(long = "tier", value_parser = parse_tier)]
+        tiers: Vec<PriceTier>,
+        /// Libera o mint gratuito (mint_price = 0 no modo Fixed)
+        #[arg(long)]
+        allow_free_mint: bool,
+    },
+    /// Divide o pagamento do mint entre queima, tesouraria e reserva (set_payment_split)
+    SetPaymentSplit {
+        #[arg(long)]
+        burn_bps: u16,
+        #[arg(long, default_value_t = 0)]
+        treasury_bps: u16,
+        #[arg(long, default_value_t = 0)]
+        reserve_bps: u16,
+        /// Conta de token da tesouraria (obrigatória com --treasury-bps)
+        #[arg(long)]
+        treasury: Option<Pubkey>,
+    },
+    /// Define a forma de pagamento aceita pela coleção (set_payment_mode)
+    SetPaymentMode {
+        #[arg(value_enum)]
+        mode: PaymentModeArg,
+    },
+    /// Saca lamports da tesouraria SOL da coleção (withdraw_sol_treasury)
+    WithdrawSolTreasury {
+        amount: u64,
+        /// Padrão: a wallet do admin
+        #[arg(long)]
+        destination: Option<Pubkey>,
+    },
+    /// Define quantos tokens a queima de um NFT devolve (configure_redemption)
+    ConfigureRedemption { amount: u64 },
+    /// Deposita tokens de pagamento na pool de resgate da coleção (deposit_redemption_pool)
+    DepositRedemptionPool { amount: u64 },
+    /// Deposita tokens de pagamento na pool de recompensas de NFTs (deposit_nft_reward_pool)
+    DepositNftRewardPool { amount: u64 },
+    /// Define a URI exibida pelos NFTs antes do reveal (set_reveal_placeholder)
+    SetRevealPlaceholder { uri: String },
+    /// Fixa a URI base e o hash de proveniência do reveal (commit_reveal)
+    CommitReveal {
+        #[arg(long)]
+        base_uri: String,
+        /// Hash em hexadecimal (32 bytes)
+        #[arg(long, value_parser = parse_hash)]
+        provenance_hash: [u8; 32],
+    },
+    /// Revela os NFTs informados, gravando a URI final nos metadados (reveal_nfts; os bloqueados são ignorados)
+    RevealNfts {
+        #[arg(required = true)]
+        nft_mints: Vec<Pubkey>,
+    },
+    /// Torna os NFTs da coleção intransferíveis (set_soulbound)
+    SetSoulbound {
+        #[arg(long, action = clap::ArgAction::Set)]
+        enabled: bool,
+    },
+    /// Define a recompensa por segundo de cada NFT em stake (set_nft_reward_rate)
+    SetNftRewardRate { rate: u64 },
+    /// Mostra o resumo da configuração on-chain (get_config_summary)
+    Status,
+    /// Mostra supply e janela de mint da coleção (get_collection_info)
+    CollectionInfo,
+    /// Mostra o preço atual e dos próximos mints (get_mint_quote)
+    MintQuote {
+        #[arg(long, default_value_t = 5)]
+        count: u64,
+    },
+    /// Mostra os mints de uma carteira na coleção (get_wallet_mint_count)
+    WalletMintCount {
+        /// Padrão: a wallet do admin
+        wallet: Option<Pubkey>,
+    },
+    /// Mostra a posição de stake de uma carteira no token de pagamento (get_stake_summary)
+    StakeSummary {
+        /// Padrão: a wallet do admin
+        staker: Option<Pubkey>,
+    },
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+pub enum PricingModeArg {
+    Fixed,
+    Linear,
+    Exponential,
+    Tiered,
+}
+
+impl From<PricingModeArg> for PricingMode {
+    fn from(mode: PricingModeArg) -> Self {
+        match mode {
+            PricingModeArg::Fixed => PricingMode::Fixed,
+            PricingModeArg::Linear => PricingMode::Linear,
+            PricingModeArg::Exponential => PricingMode::Exponential,
+            PricingModeArg::Tiered => PricingMode::Tiered,
+        }
+    }
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+pub enum PaymentModeArg {
+    SplToken,
+    Sol,
+}
+
+impl From<PaymentModeArg> for PaymentMode {
+    fn from(mode: PaymentModeArg) -> Self {
+        match mode {
+            PaymentModeArg::SplToken => PaymentMode::SplToken,
+            PaymentModeArg::Sol => PaymentMode::Sol,
+        }
+    }
+}
+
+// Transação montada por um subcomando e as chaves a gravar após o envio
+pub struct Plan {
+    pub instructions: Vec<Instruction>,
+    pub signers: Vec<Keypair>,
+    pub updates: Vec<(&'static str, Value)>,
+    pub timestamp_key: &'static str,
+}
+
+pub type CliResult<T> = Result<T, String>;
+
+impl Command {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Command::Init { .. } => "init",
+            Command::MigrateConfig => "migrate-config",
+            Command::MigrateAccount { .. } => "migrate-account",
+            Command::InitEventSequence => "init-event-sequence",
+            Command::SetPaymentToken { .. } => "set-payment-token",
+            Command::SetRewardReserve { .. } => "set-reward-reserve",
+            Command::InitRewardReserve => "init-reward-reserve",
+            Command::DepositRewards { .. } => "deposit-rewards",
+            Command::ConfigureStaking { .. } => "configure-staking",
+            Command::SetNftHolderBoost { .. } => "set-nft-holder-boost",
+            Command::Pause { .. } => "pause",
+            Command::Unpause { .. } => "unpause",
+            Command::ConfigureCollection { .. } => "configure-collection",
+            Command::SetAllowlist { .. } => "set-allowlist",
+            Command::SetPricingCurve { .. } => "set-pricing-curve",
+            Command::SetPaymentSplit { .. } => "set-payment-split",
+            Command::SetPaymentMode { .. } => "set-payment-mode",
+            Command::WithdrawSolTreasury { .. } => "withdraw-sol-treasury",
+            Command::ConfigureRedemption { .. } => "configure-redemption",
+            Command::DepositRedemptionPool { .. } => "deposit-redemption-pool",
+            Command::DepositNftRewardPool { .. } => "deposit-nft-reward-pool",
+            Command::SetRevealPlaceholder { .. } => "set-reveal-placeholder",
+            Command::CommitReveal { .. } => "commit-reveal",
+            Command::RevealNfts { .. } => "reveal-nfts",
+            Command::SetSoulbound { .. } => "set-soulbound",
+            Command::SetNftRewardRate { .. } => "set-nft-reward-rate",
+            Command::Status => "status",
+            Command::CollectionInfo => "collection-info",
+            Command::MintQuote { .. } => "mint-quote",
+            Command::WalletMintCount { .. } => "wallet-mint-count",
+            Command::StakeSummary { .. } => "stake-summary",
+        }
+    }
+
+    // Subcomandos de leitura: simulam uma view e não enviam transação
+    pub fn is_view(&self) -> bool {
+        matches!(
+            self,
+            Command::Status
+                | Command::CollectionInfo
+                | Command::MintQuote { .. }
+                | Command::WalletMintCount { .. }
+                | Command::StakeSummary { .. }
+        )
+    }
+}
+
+// Coleção informada por --collection ou gravada pelo `init`
+pub fn collection_metadata(collection: Option<Pubkey>, config: &DeployConfig) -> CliResult<Pubkey> {
+    match collection {
+        Some(collection) => Ok(collection),
+        None => config.pubkey("collectionMetadata"),
+    }
+}
+
+pub fn plan(
+    command: &Command,
+    admin: Pubkey,
+    collection_override: Option<Pubkey>,
+    config: &DeployConfig,
+) -> CliResult<Plan> {
+    let collection = || collection_metadata(collection_override, config);
+
+    let plan = match command {
+        Command::Init { name, symbol, uri } => {
+            // Contas keypair novas que assinam junto com o admin
+            let collection_mint = Keypair::new();
+            let collection_metadata = Keypair::new();
+
+            let accounts = instructions::initialize_collection_accounts(
+                admin,
+                collection_mint.pubkey(),
+                collection_metadata.pubkey(),
+            );
+            let updates = vec![
+                ("programId", PROGRAM_ID.to_string().into()),
+                ("collectionMint", collection_mint.pubkey().to_string().into()),
+                ("collectionMetadata", collection_metadata.pubkey().to_string().into()),
+                ("collectionTokenAccount", accounts.collection_token_account.to_string().into()),
+                ("nftCounter", accounts.nft_counter.to_string().into()),
+                ("configAccount", accounts.config.to_string().into()),
+                ("eventSequence", accounts.event_sequence.to_string().into()),
+            ];
+            Plan {
+                instructions: vec![instructions::initialize_collection(
+                    accounts,
+                    name.clone(),
+                    symbol.clone(),
+                    uri.clone(),
+                )],
+                signers: vec![collection_mint, collection_metadata],
+                updates,
+                timestamp_key: "initTimestamp",
+            }
+        }
+        Command::MigrateConfig => {
+            let accounts = instructions::migrate_config_accounts(admin);
+            let updates = vec![
+                ("legacyConfigAccount", accounts.legacy_config.to_string().into()),
+                ("configAccount", accounts.config.to_string().into()),
+                ("eventSequence", accounts.event_sequence.to_string().into()),
+            ];
+            Plan {
+                instructions: vec![instructions::migrate_config(accounts)],
+                signers: Vec::new(),
+                updates,
+                timestamp_key: "configMigrationTime",
+            }
+        }
+        Command::MigrateAccount { account } => {
+            let accounts = instructions::migrate_account_accounts(admin, *account);
+            Plan {
+                instructions: vec![instructions::migrate_account(accounts)],
+                signers: Vec::new(),
+                updates: Vec::new(),
+                timestamp_key: "accountMigrationTime",
+            }
+        }
+        Command::InitEventSequence => {
+            let accounts = instructions::initialize_event_sequence_accounts(admin);
+            let event_sequence = accounts.event_sequence;
+            Plan {
+                instructions: vec![instructions::initialize_event_sequence(accounts)],
+                signers: Vec::new(),
+                updates: vec![("eventSequence", event_sequence.to_string().into())],
+                timestamp_key: "eventSequenceInitTime",
+            }
+        }
+        Command::SetPaymentToken { mint, pumpfun } => {
+            let accounts = adr_token_mint::accounts::SetPaymentToken {
+                admin,
+                config: pda::find_config_pda().0,
+                event_sequence: pda::find_event_sequence_pda().0,
+            };
+            Plan {
+                instructions: vec![instructions::set_payment_token(accounts, *mint)],
+                signers: Vec::new(),
+                updates: vec![
+                    ("paymentTokenMint", mint.to_string().into()),
+                    ("isPumpfunToken", (*pumpfun).into()),
+                ],
+                timestamp_key: "paymentTokenConfigTimestamp",
+            }
+        }
+        Command::SetRewardReserve { account } => {
+            let accounts = adr_token_mint::accounts::SetRewardReserve {
+                admin,
+                config: pda::find_config_pda().0,
+                event_sequence: pda::find_event_sequence_pda().0,
+            };
+            Plan {
+                instructions: vec![instructions::set_reward_reserve(accounts, *account)],
+                signers: Vec::new(),
+                updates: vec![("rewardReserveAccount", account.to_string().into())],
+                timestamp_key: "rewardReserveConfigTimestamp",
+            }
+        }
+        Command::InitRewardReserve => {
+            let accounts =
+                instructions::initialize_reward_reserve_accounts(admin, config.pubkey("paymentTokenMint")?);
+            let reserve = accounts.reward_reserve_account;
+            Plan {
+                instructions: vec![instructions::initialize_reward_reserve(accounts)],
+                signers: Vec::new(),
+                updates: vec![
+                    ("rewardReserveAccount", reserve.to_string().into()),
+                    ("rewardReserveInitialized", true.into()),
+                ],
+                timestamp_key: "rewardReserveInitTime",
+            }
+        }
+        Command::DepositRewards { amount } => {
+            let token_mint = config.pubkey("paymentTokenMint")?;
+            let mut accounts = instructions::deposit_reward_reserve_accounts(admin, token_mint);
+            // Reserva customizada gravada por set-reward-reserve tem precedência
+            if config.get("rewardReserveAccount").is_some() {
+                accounts.reward_reserve_account = config.pubkey("rewardReserveAccount")?;
+            }
+            Plan {
+                instructions: vec![instructions::deposit_reward_reserve(accounts, *amount)],
+                signers: Vec::new(),
+                updates: vec![
+                    ("lastDepositAmount", (*amount).into()),
+                    ("totalDeposited", accumulate(config, "totalDeposited", *amount)?.into()),
+                ],
+                timestamp_key: "lastDepositTime",
+            }
+        }
+        Command::ConfigureStaking { enabled, reward_rate } => {
+            let accounts = adr_token_mint::accounts::ConfigureStaking {
+                admin,
+                config: pda::find_config_pda().0,
+                event_sequence: pda::find_event_sequence_pda().0,
+            };
+            Plan {
+                instructions: vec![instructions::configure_staking(accounts, *enabled, *reward_rate)],
+                signers: Vec::new(),
+                updates: vec![
+                    ("stakingEnabled", (*enabled).into()),
+                    ("stakingRewardRate", (*reward_rate).into()),
+                ],
+                timestamp_key: "stakingConfigTime",
+            }
+        }
+        Command::SetNftHolderBoost { boost_bps } => {
+            let accounts = adr_token_mint::accounts::ConfigureStaking {
+                admin,
+                config: pda::find_config_pda().0,
+                event_sequence: pda::find_event_sequence_pda().0,
+            };
+            Plan {
+                instructions: vec![instructions::set_nft_holder_boost(accounts, *boost_bps)],
+                signers: Vec::new(),
+                updates: vec![("nftHolderBoostBps", (*boost_bps).into())],
+                timestamp_key: "stakingConfigTime",
+            }
+        }
+        Command::Pause { reason } | Command::Unpause { reason } => {
+            let paused = matches!(command, Command::Pause { .. });
+            let accounts = adr_token_mint::accounts::EmergencyPause {
+                admin,
+                config: pda::find_config_pda().0,
+                event_sequence: pda::find_event_sequence_pda().0,
+            };
+            Plan {
+                instructions: vec![instructions::set_emergency_pause(accounts, paused, reason.clone())],
+                signers: Vec::new(),
+                updates: vec![
+                    ("emergencyPaused", paused.into()),
+                    ("emergencyPauseReason", reason.clone().into()),
+                ],
+                timestamp_key: "emergencyPauseTime",
+            }
+        }
+        Command::ConfigureCollection { max_supply, mint_start, mint_end, mint_price, max_per_wallet } => {
+            let accounts = instructions::configure_collection_accounts(admin, collection()?);
+            Plan {
+                instructions: vec![instructions::configure_collection(
+                    accounts,
+                    *max_supply,
+                    *mint_start,
+                    *mint_end,
+                    *mint_price,
+                    *max_per_wallet,
+                )],
+                signers: Vec::new(),
+                updates: vec![
+                    ("maxSupply", (*max_supply).into()),
+                    ("mintStart", (*mint_start).into()),
+                    ("mintEnd", (*mint_end).into()),
+                    ("mintPrice", (*mint_price).into()),
+                    ("maxPerWallet", (*max_per_wallet).into()),
+                ],
+                timestamp_key: "collectionConfigTime",
+            }
+        }
+        Command::SetAllowlist { merkle_root, price } => {
+            let accounts = instructions::set_allowlist_accounts(admin, collection()?);
+            Plan {
+                instructions: vec![instructions::set_allowlist(accounts, *merkle_root, *price)],
+                signers: Vec::new(),
+                updates: vec![
+                    ("allowlistMerkleRoot", hex(merkle_root).into()),
+                    ("allowlistPrice", (*price).into()),
+                ],
+                timestamp_key: "allowlistConfigTime",
+            }
+        }
+        Command::SetPricingCurve { mode, increment, tiers, allow_free_mint } => {
+            let accounts = instructions::set_pricing_curve_accounts(admin, collection()?);
+            let tier_values: Vec<Value> = tiers
+                .iter()
+                .map(|tier| json!({ "startCount": tier.start_count, "price": tier.price }))
+                .collect();
+            Plan {
+                instructions: vec![instructions::set_pricing_curve(
+                    accounts,
+                    (*mode).into(),
+                    *increment,
+                    tiers.clone(),
+                    *allow_free_mint,
+                )],
+                signers: Vec::new(),
+                updates: vec![
+                    ("pricingMode", enum_name(*mode).into()),
+                    ("priceIncrement", (*increment).into()),
+                    ("priceTiers", tier_values.into()),
+                    ("allowFreeMint", (*allow_free_mint).into()),
+                ],
+                timestamp_key: "pricingConfigTime",
+            }
+        }
+        Command::SetPaymentSplit { burn_bps, treasury_bps, reserve_bps, treasury } => {
+            let accounts = instructions::set_payment_split_accounts(admin, collection()?, *treasury);
+            Plan {
+                instructions: vec![instructions::set_payment_split(
+                    accounts,
+                    *burn_bps,
+                    *treasury_bps,
+                    *reserve_bps,
+                )],
+                signers: Vec::new(),
+                updates: vec![
+                    ("burnBps", (*burn_bps).into()),
+                    ("treasuryBps", (*treasury_bps).into()),
+                    ("reserveBps", (*reserve_bps).into()),
+                    ("treasuryAccount", treasury.unwrap_or_default().to_string().into()),
+                ],
+                timestamp_key: "paymentSplitConfigTime",
+            }
+        }
+        Command::SetPaymentMode { mode } => {
+            let accounts = instructions::set_payment_mode_accounts(admin, collection()?);
+            Plan {
+                instructions: vec![instructions::set_payment_mode(accounts, (*mode).into())],
+                signers: Vec::new(),
+                updates: vec![("paymentMode", enum_name(*mode).into())],
+                timestamp_key: "paymentModeConfigTime",
+            }
+        }
+        Command::WithdrawSolTreasury { amount, destination } => {
+            let destination = destination.unwrap_or(admin);
+            let accounts = instructions::withdraw_sol_treasury_accounts(admin, collection()?, destination);
+            let sol_treasury = accounts.sol_treasury;
+            Plan {
+                instructions: vec![instructions::withdraw_sol_treasury(accounts, *amount)],
+                signers: Vec::new(),
+                updates: vec![
+                    ("solTreasury", sol_treasury.to_string().into()),
+                    ("lastSolWithdrawAmount", (*amount).into()),
+                    ("lastSolWithdrawDestination", destination.to_string().into()),
+                    ("totalSolWithdrawn", accumulate(config, "totalSolWithdrawn", *amount)?.into()),
+                ],
+                timestamp_key: "lastSolWithdrawTime",
+            }
+        }
+        Command::ConfigureRedemption { amount } => {
+            let accounts = instructions::configure_redemption_accounts(admin, collection()?);
+            Plan {
+                instructions: vec![instructions::configure_redemption(accounts, *amount)],
+                signers: Vec::new(),
+                updates: vec![("redemptionAmount", (*amount).into())],
+                timestamp_key: "redemptionConfigTime",
+            }
+        }
+        Command::DepositRedemptionPool { amount } => {
+            let accounts = instructions::deposit_redemption_pool_accounts(
+                admin,
+                collection()?,
+                config.pubkey("paymentTokenMint")?,
+            );
+            let pool = accounts.redemption_pool;
+            Plan {
+                instructions: vec![instructions::deposit_redemption_pool(accounts, *amount)],
+                signers: Vec::new(),
+                updates: vec![
+                    ("redemptionPool", pool.to_string().into()),
+                    ("lastRedemptionDepositAmount", (*amount).into()),
+                    (
+                        "totalRedemptionDeposited",
+                        accumulate(config, "totalRedemptionDeposited", *amount)?.into(),
+                    ),
+                ],
+                timestamp_key: "lastRedemptionDepositTime",
+            }
+        }
+        Command::DepositNftRewardPool { amount } => {
+            let accounts = instructions::deposit_nft_reward_pool_accounts(
+                admin,
+                collection()?,
+                config.pubkey("paymentTokenMint")?,
+            );
+            let pool = accounts.nft_reward_pool;
+            Plan {
+                instructions: vec![instructions::deposit_nft_reward_pool(accounts, *amount)],
+                signers: Vec::new(),
+                updates: vec![
+                    ("nftRewardPool", pool.to_string().into()),
+                    ("lastNftRewardDepositAmount", (*amount).into()),
+                    (
+                        "totalNftRewardDeposited",
+                        accumulate(config, "totalNftRewardDeposited", *amount)?.into(),
+                    ),
+                ],
+                timestamp_key: "lastNftRewardDepositTime",
+            }
+        }
+        Command::SetRevealPlaceholder { uri } => {
+            let accounts = instructions::configure_reveal_accounts(admin, collection()?);
+            Plan {
+                instructions: vec![instructions::set_reveal_placeholder(accounts, uri.clone())],
+                signers: Vec::new(),
+                updates: vec![("revealPlaceholderUri", uri.clone().into())],
+                timestamp_key: "revealConfigTime",
+            }
+        }
+        Command::CommitReveal { base_uri, provenance_hash } => {
+            let accounts = instructions::configure_reveal_accounts(admin, collection()?);
+            Plan {
+                instructions: vec![instructions::commit_reveal(
+                    accounts,
+                    base_uri.clone(),
+                    *provenance_hash,
+                )],
+                signers: Vec::new(),
+                updates: vec![
+                    ("revealBaseUri", base_uri.clone().into()),
+                    ("revealProvenanceHash", hex(provenance_hash).into()),
+                ],
+                timestamp_key: "revealCommitTime",
+            }
+        }
+        Command::RevealNfts { nft_mints } => {
+            let accounts = instructions::reveal_nfts_accounts(admin, collection()?);
+            let remaining_accounts = instructions::reveal_nft_metadata_accounts(nft_mints);
+            let count = nft_mints.len() as u64;
+            Plan {
+                instructions: vec![instructions::reveal_nfts(accounts, remaining_accounts)],
+                signers: Vec::new(),
+                updates: vec![("revealedNfts", accumulate(config, "revealedNfts", count)?.into())],
+                timestamp_key: "lastRevealTime",
+            }
+        }
+        Command::SetSoulbound { enabled } => {
+            let accounts = instructions::set_soulbound_accounts(admin, collection()?);
+            Plan {
+                instructions: vec![instructions::set_soulbound(accounts, *enabled)],
+                signers: Vec::new(),
+                updates: vec![("soulbound", (*enabled).into())],
+                timestamp_key: "soulboundConfigTime",
+            }
+        }
+        Command::SetNftRewardRate { rate } => {
+            let accounts = instructions::set_nft_reward_rate_accounts(admin, collection()?);
+            Plan {
+                instructions: vec![instructions::set_nft_reward_rate(accounts, *rate)],
+                signers: Vec::new(),
+                updates: vec![("nftRewardRate", (*rate).into())],
+                timestamp_key: "nftRewardRateConfigTime",
+            }
+        }
+        Command::Status
+        | Command::CollectionInfo
+        | Command::MintQuote { .. }
+        | Command::WalletMintCount { .. }
+        | Command::StakeSummary { .. } => unreachable!("views não enviam transação"),
+    };
+    Ok(plan)
+}
+
+// Soma `amount` ao total acumulado gravado em `key`
+fn accumulate(config: &DeployConfig, key: &str, amount: u64) -> CliResult<u64> {
+    config
+        .get(key)
+        .and_then(Value::as_u64)
+        .unwrap_or(0)
+        .checked_add(amount)
+        .ok_or_else(|| format!("{} excede u64", key))
+}
+
+// Nome do valor como aceito na linha de comando (ex.: "spl-token")
+fn enum_name(value: impl ValueEnum) -> String {
+    value
+        .to_possible_value()
+        .map(|value| value.get_name().to_string())
+        .unwrap_or_default()
+}
+
+fn hex(bytes: &[u8; 32]) -> String {
+    bytes.iter().map(|byte| format!("{:02x}", byte)).collect()
+}
+
+pub fn parse_hash(value: &str) -> Result<[u8; 32], String> {
+    let value = value.strip_prefix("0x").unwrap_or(value);
+    if value.len() != 64 || !value.is_ascii() {
+        return Err(format!("esperados 64 dígitos hexadecimais, veio {}", value.len()));
+    }
+    let mut hash = [0u8; 32];
+    for (index, byte) in hash.iter_mut().enumerate() {
+        *byte = u8::from_str_radix(&value[index * 2..index * 2 + 2], 16)
+            .map_err(|err| format!("hexadecimal inválido: {}", err))?;
+    }
+    Ok(hash)
+}
+
+pub fn parse_tier(value: &str) -> Result<PriceTier, String> {
+    let (start_count, price) = value
+        .split_once(':')
+        .ok_or_else(|| format!("faixa {} fora do formato INICIO:PRECO", value))?;
+    Ok(PriceTier {
+        start_count: start_count.parse().map_err(|err| format!("início inválido: {}", err))?,
+        price: price.parse().map_err(|err| format!("preço inválido: {}", err))?,
+    })
+}
@@ -0,0 +1,70 @@
+// Leitura e escrita do config/deploy-config.json usado pelos scripts JS
+//
+// O arquivo é mantido como um objeto JSON genérico para preservar as chaves
+// que a CLI não conhece; a ordem das chaves também é preservada.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+use anchor_lang::prelude::Pubkey;
+use chrono::{SecondsFormat, Utc};
+use serde_json::{Map, Value};
+
+pub struct DeployConfig {
+    path: PathBuf,
+    values: Map<String, Value>,
+}
+
+impl DeployConfig {
+    // Carrega o arquivo; se ele não existir começa vazio (caso do `init`)
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let values = if path.exists() {
+            let contents = fs::read_to_string(path)
+                .map_err(|err| format!("falha ao ler {}: {}", path.display(), err))?;
+            serde_json::from_str(&contents)
+                .map_err(|err| format!("{} não é um objeto JSON válido: {}", path.display(), err))?
+        } else {
+            Map::new()
+        };
+        Ok(Self {
+            path: path.to_path_buf(),
+            values,
+        })
+    }
+
+    pub fn get(&self, key: &str) -> Option<&Value> {
+        self.values.get(key)
+    }
+
+    // Lê uma chave obrigatória com um pubkey em base58
+    pub fn pubkey(&self, key: &str) -> Result<Pubkey, String> {
+        let value = self
+            .values
+            .get(key)
+            .and_then(Value::as_str)
+            .ok_or_else(|| format!("{} ausente em {}", key, self.path.display()))?;
+        Pubkey::from_str(value).map_err(|err| format!("{} inválido ({}): {}", key, value, err))
+    }
+
+    pub fn set(&mut self, key: &str, value: impl Into<Value>) {
+        self.values.insert(key.to_string(), value.into());
+    }
+
+    // Mesmo formato de `new Date().toISOString()` dos scripts
+    pub fn set_now(&mut self, key: &str) {
+        self.set(key, Utc::now().to_rfc3339_opts(SecondsFormat::Millis, true));
+    }
+
+    // Grava com indentação de 2 espaços, como `JSON.stringify(config, null, 2)`
+    pub fn save(&self) -> Result<(), String> {
+        if let Some(dir) = self.path.parent().filter(|dir| !dir.as_os_str().is_empty()) {
+            fs::create_dir_all(dir)
+                .map_err(|err| format!("falha ao criar {}: {}", dir.display(), err))?;
+        }
+        let contents = serde_json::to_string_pretty(&self.values)
+            .map_err(|err| format!("falha ao serializar configuração: {}", err))?;
+        fs::write(&self.path, contents)
+            .map_err(|err| format!("falha ao gravar {}: {}", self.path.display(), err))
+    }
+}
@@ -0,0 +1,9 @@
+// Administração do programa adr_token_mint (binário adr-admin)
+//
+// - `commands`: subcomandos e a transação que cada um monta
+// - `deploy_config`: leitura e escrita do config/deploy-config.json
+// - `rpc`: implementação de `ProgramRpc` sobre JSON-RPC HTTP
+
+pub mod commands;
+pub mod deploy_config;
+pub mod rpc;
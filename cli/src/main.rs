@@ -0,0 +1,230 @@
+// adr-admin: CLI de administração do programa adr_token_mint
+//
+// Substitui os scripts JS de administração (initialize-collection, configure-payment-token,
+// initialize-reward-reserve, deposit-rewards, configure-staking, ...). Cada subcomando
+// monta a instrução com o `adr_token_mint_client`, envia (ou simula com `--dry-run`) e
+// atualiza o config/deploy-config.json com as mesmas chaves que os scripts gravavam.
+
+use std::process::ExitCode;
+
+use adr_token_mint_cli::commands::{self, Cli, CliResult, Command};
+use adr_token_mint_cli::deploy_config::DeployConfig;
+use adr_token_mint_cli::rpc::RpcConnection;
+use adr_token_mint_client::{pda, views, ProgramRpc};
+use anchor_spl::associated_token::get_associated_token_address;
+use clap::Parser;
+use serde::Serialize;
+use serde_json::{json, Map, Value};
+use solana_keypair::{read_keypair_file, Keypair};
+
+#[derive(Serialize)]
+struct Report {
+    command: &'static str,
+    dry_run: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    signature: Option<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    logs: Vec<String>,
+    updated: Map<String, Value>,
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    let json = cli.json;
+    match run(cli) {
+        Ok(output) => {
+            println!("{}", output);
+            ExitCode::SUCCESS
+        }
+        Err(err) => {
+            if json {
+                println!("{}", json!({ "error": err }));
+            } else {
+                eprintln!("Erro: {}", err);
+            }
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn run(cli: Cli) -> CliResult<String> {
+    let payer = read_keypair_file(&cli.keypair)
+        .map_err(|err| format!("falha ao ler a wallet {}: {}", cli.keypair.display(), err))?;
+    let rpc = RpcConnection::new(cli.url.clone(), payer);
+    let mut config = DeployConfig::load(&cli.config_file)?;
+
+    if cli.command.is_view() {
+        return view(&cli, &rpc, &config);
+    }
+
+    let command = cli.command.name();
+    let plan = commands::plan(&cli.command, rpc.payer(), cli.collection, &config)?;
+
+    let mut report = Report {
+        command,
+        dry_run: cli.dry_run,
+        signature: None,
+        logs: Vec::new(),
+        updated: Map::new(),
+    };
+
+    if cli.dry_run {
+        let simulation = rpc.simulate_instructions(&plan.instructions)?;
+        report.logs = simulation.logs;
+    } else {
+        let signers: Vec<&Keypair> = plan.signers.iter().collect();
+        report.signature = Some(rpc.send_with_signers(&plan.instructions, &signers)?);
+
+        for (key, value) in plan.updates {
+            config.set(key, value.clone());
+            report.updated.insert(key.to_string(), value);
+        }
+        config.set_now(plan.timestamp_key);
+        if let Some(timestamp) = config.get(plan.timestamp_key) {
+            report.updated.insert(plan.timestamp_key.to_string(), timestamp.clone());
+        }
+        config.save()?;
+    }
+
+    if cli.json {
+        serde_json::to_string_pretty(&report).map_err(|err| err.to_string())
+    } else {
+        Ok(render(&report))
+    }
+}
+
+// Subcomandos de leitura
+fn view(cli: &Cli, rpc: &RpcConnection, config: &DeployConfig) -> CliResult<String> {
+    if let Command::Status = cli.command {
+        return status(rpc, cli.json);
+    }
+
+    let collection = || commands::collection_metadata(cli.collection, config);
+    let value = match &cli.command {
+        Command::CollectionInfo => {
+            let collection = collection()?;
+            let info = views::get_collection_info(rpc, collection).map_err(|err| err.to_string())?;
+            json!({
+                "collection": collection.to_string(),
+                "name": info.name,
+                "symbol": info.symbol,
+                "uri": info.uri,
+                "count": info.count,
+                "minted": info.minted,
+                "liveSupply": info.live_supply,
+                "maxSupply": info.max_supply,
+                "mintStart": info.mint_start,
+                "mintEnd": info.mint_end,
+            })
+        }
+        Command::MintQuote { count } => {
+            let quote = views::get_mint_quote(rpc, collection()?, *count).map_err(|err| err.to_string())?;
+            json!({
+                "currentPrice": quote.current_price,
+                "nextPrices": quote.next_prices,
+            })
+        }
+        Command::WalletMintCount { wallet } => {
+            let wallet = wallet.unwrap_or(rpc.payer());
+            let count = views::get_wallet_mint_count(rpc, wallet, collection()?)
+                .map_err(|err| err.to_string())?;
+            json!({
+                "wallet": wallet.to_string(),
+                "count": count.count,
+                // u64::MAX quando a coleção não limita mints por carteira
+                "remaining": (count.remaining != u64::MAX).then_some(count.remaining),
+            })
+        }
+        Command::StakeSummary { staker } => {
+            let staker = staker.unwrap_or(rpc.payer());
+            let token_mint = config.pubkey("paymentTokenMint")?;
+            let summary = views::get_stake_summary(rpc, staker, token_mint).map_err(|err| err.to_string())?;
+            json!({
+                "staker": staker.to_string(),
+                "isStaking": summary.is_staking,
+                "amount": summary.amount,
+                "startTime": summary.start_time,
+                "unlockTime": summary.unlock_time,
+                "canUnstake": summary.can_unstake,
+                "timeRemaining": summary.time_remaining,
+                "estimatedReward": summary.estimated_reward,
+            })
+        }
+        _ => unreachable!("{} não é uma view", cli.command.name()),
+    };
+
+    if cli.json {
+        return serde_json::to_string_pretty(&value).map_err(|err| err.to_string());
+    }
+    let lines: Vec<String> = value
+        .as_object()
+        .into_iter()
+        .flatten()
+        .map(|(key, value)| match value {
+            Value::String(text) => format!("{}: {}", key, text),
+            Value::Null => format!("{}: -", key),
+            other => format!("{}: {}", key, other),
+        })
+        .collect();
+    Ok(lines.join("\n"))
+}
+
+fn status(rpc: &RpcConnection, json: bool) -> CliResult<String> {
+    let config_account = pda::find_config_pda().0;
+    let summary = views::get_config_summary(rpc).map_err(|err| err.to_string())?;
+    let reserve = get_associated_token_address(&pda::find_stake_authority_pda().0, &summary.payment_token_mint);
+
+    if json {
+        let value = json!({
+            "configAccount": config_account.to_string(),
+            "admin": summary.admin.to_string(),
+            "paymentTokenMint": summary.payment_token_mint.to_string(),
+            "defaultRewardReserve": reserve.to_string(),
+            "stakingEnabled": summary.staking_enabled,
+            "stakingRewardRate": summary.staking_reward_rate,
+            "maxStakeAmount": summary.max_stake_amount,
+            "nftHolderBoostBps": summary.nft_holder_boost_bps,
+            "totalStaked": summary.total_staked,
+            "activeStakes": summary.active_stakes,
+            "emergencyPaused": summary.emergency_paused,
+            "configVersion": summary.config_version,
+            "eventSeq": summary.event_seq,
+        });
+        return serde_json::to_string_pretty(&value).map_err(|err| err.to_string());
+    }
+
+    Ok(format!(
+        "Config: {} (versão {})\nAdmin: {}\nToken de pagamento: {}\nReserva padrão: {}\nStaking: {} (taxa {} bps, máximo {}, bônus de holder {} bps)\nEm stake: {} em {} posições\nPausado: {}\nÚltimo evento: #{}",
+        config_account,
+        summary.config_version,
+        summary.admin,
+        summary.payment_token_mint,
+        reserve,
+        if summary.staking_enabled { "ativado" } else { "desativado" },
+        summary.staking_reward_rate,
+        summary.max_stake_amount,
+        summary.nft_holder_boost_bps,
+        summary.total_staked,
+        summary.active_stakes,
+        if summary.emergency_paused { "sim" } else { "não" },
+        summary.event_seq,
+    ))
+}
+
+fn render(report: &Report) -> String {
+    let mut lines = Vec::new();
+    if report.dry_run {
+        lines.push(format!("Simulação de {} concluída", report.command));
+        lines.extend(report.logs.iter().map(|log| format!("  {}", log)));
+    } else {
+        lines.push(format!("{} concluído", report.command));
+        if let Some(signature) = &report.signature {
+            lines.push(format!("Transação: {}", signature));
+        }
+        for (key, value) in &report.updated {
+            lines.push(format!("  {} = {}", key, value));
+        }
+    }
+    lines.join("\n")
+}
+
@@ -0,0 +1,183 @@
+// Implementação de `ProgramRpc` sobre JSON-RPC HTTP
+//
+// As transações são assinadas pela wallet do admin (pagadora) e, quando a instrução
+// cria contas keypair, pelos signers extras passados em `send_with_signers`.
+
+use std::str::FromStr;
+use std::thread;
+use std::time::Duration;
+
+use adr_token_mint_client::{ProgramRpc, SimulationResult};
+use anchor_lang::prelude::Pubkey;
+use anchor_lang::solana_program::hash::Hash;
+use anchor_lang::solana_program::instruction::Instruction;
+use base64::engine::general_purpose::STANDARD as BASE64;
+use base64::Engine;
+use serde_json::{json, Value};
+use solana_keypair::Keypair;
+use solana_signer::Signer;
+use solana_transaction::Transaction;
+
+// Tentativas de confirmação (intervalo de 500ms)
+const CONFIRM_ATTEMPTS: u32 = 60;
+
+pub struct RpcConnection {
+    url: String,
+    agent: ureq::Agent,
+    payer: Keypair,
+}
+
+impl RpcConnection {
+    pub fn new(url: String, payer: Keypair) -> Self {
+        Self {
+            url,
+            agent: ureq::AgentBuilder::new().timeout(Duration::from_secs(30)).build(),
+            payer,
+        }
+    }
+
+    pub fn payer(&self) -> Pubkey {
+        self.payer.pubkey()
+    }
+
+    fn call(&self, method: &str, params: Value) -> Result<Value, String> {
+        let request = json!({ "jsonrpc": "2.0", "id": 1, "method": method, "params": params });
+        let response: Value = self
+            .agent
+            .post(&self.url)
+            .send_json(request)
+            .map_err(|err| format!("{}: {}", method, err))?
+            .into_json()
+            .map_err(|err| format!("{}: resposta inválida: {}", method, err))?;
+
+        if let Some(error) = response.get("error") {
+            return Err(format!("{}: {}", method, error));
+        }
+        Ok(response["result"].clone())
+    }
+
+    fn latest_blockhash(&self) -> Result<Hash, String> {
+        let result = self.call("getLatestBlockhash", json!([{ "commitment": "confirmed" }]))?;
+        let blockhash = result["value"]["blockhash"]
+            .as_str()
+            .ok_or("getLatestBlockhash: blockhash ausente")?;
+        Hash::from_str(blockhash).map_err(|err| format!("blockhash inválido: {}", err))
+    }
+
+    fn encode(transaction: &Transaction) -> Result<String, String> {
+        let bytes = bincode::serialize(transaction)
+            .map_err(|err| format!("falha ao serializar transação: {}", err))?;
+        Ok(BASE64.encode(bytes))
+    }
+
+    fn confirm(&self, signature: &str) -> Result<(), String> {
+        for _ in 0..CONFIRM_ATTEMPTS {
+            let result = self.call("getSignatureStatuses", json!([[signature]]))?;
+            let status = &result["value"][0];
+            if !status.is_null() {
+                if !status["err"].is_null() {
+                    return Err(format!("transação {} falhou: {}", signature, status["err"]));
+                }
+                if matches!(
+                    status["confirmationStatus"].as_str(),
+                    Some("confirmed") | Some("finalized")
+                ) {
+                    return Ok(());
+                }
+            }
+            thread::sleep(Duration::from_millis(500));
+        }
+        Err(format!("transação {} não confirmada a tempo", signature))
+    }
+
+    // Envia e confirma uma transação assinada pelo admin e pelos signers extras
+    pub fn send_with_signers(
+        &self,
+        instructions: &[Instruction],
+        extra_signers: &[&Keypair],
+    ) -> Result<String, String> {
+        let mut signers: Vec<&Keypair> = vec![&self.payer];
+        signers.extend_from_slice(extra_signers);
+
+        let blockhash = self.latest_blockhash()?;
+        let transaction = Transaction::new_signed_with_payer(
+            instructions,
+            Some(&self.payer.pubkey()),
+            &signers,
+            blockhash,
+        );
+        let result = self.call(
+            "sendTransaction",
+            json!([Self::encode(&transaction)?, { "encoding": "base64", "preflightCommitment": "confirmed" }]),
+        )?;
+        let signature = result
+            .as_str()
+            .ok_or("sendTransaction: assinatura ausente")?
+            .to_string();
+
+        self.confirm(&signature)?;
+        Ok(signature)
+    }
+}
+
+impl ProgramRpc for RpcConnection {
+    type Error = String;
+
+    fn get_account_data(&self, address: &Pubkey) -> Result<Option<Vec<u8>>, String> {
+        let result = self.call(
+            "getAccountInfo",
+            json!([address.to_string(), { "encoding": "base64", "commitment": "confirmed" }]),
+        )?;
+        let account = &result["value"];
+        if account.is_null() {
+            return Ok(None);
+        }
+        let data = account["data"][0]
+            .as_str()
+            .ok_or("getAccountInfo: dados ausentes")?;
+        BASE64
+            .decode(data)
+            .map(Some)
+            .map_err(|err| format!("getAccountInfo: base64 inválido: {}", err))
+    }
+
+    fn send_instructions(&self, instructions: &[Instruction]) -> Result<String, String> {
+        self.send_with_signers(instructions, &[])
+    }
+
+    // Simulação sem verificação de assinaturas: contas keypair novas não precisam assinar
+    fn simulate_instructions(&self, instructions: &[Instruction]) -> Result<SimulationResult, String> {
+        let transaction = Transaction::new_with_payer(instructions, Some(&self.payer.pubkey()));
+        let result = self.call(
+            "simulateTransaction",
+            json!([
+                Self::encode(&transaction)?,
+                {
+                    "encoding": "base64",
+                    "sigVerify": false,
+                    "replaceRecentBlockhash": true,
+                    "commitment": "confirmed"
+                }
+            ]),
+        )?;
+        let value = &result["value"];
+
+        let logs: Vec<String> = value["logs"]
+            .as_array()
+            .map(|logs| logs.iter().filter_map(|log| log.as_str().map(String::from)).collect())
+            .unwrap_or_default();
+        if !value["err"].is_null() {
+            return Err(format!("simulação falhou: {}\n{}", value["err"], logs.join("\n")));
+        }
+
+        let return_data = match value["returnData"]["data"][0].as_str() {
+            Some(data) => Some(
+                BASE64
+                    .decode(data)
+                    .map_err(|err| format!("returnData inválido: {}", err))?,
+            ),
+            None => None,
+        };
+        Ok(SimulationResult { logs, return_data })
+    }
+}
@@ -0,0 +1,334 @@
+use std::fs;
+use std::path::PathBuf;
+
+use adr_token_mint_cli::commands::{parse_hash, plan, Cli, Command};
+use adr_token_mint_cli::deploy_config::DeployConfig;
+use adr_token_mint_client::{instructions, pda, PaymentMode, PriceTier, PricingMode};
+use anchor_lang::prelude::Pubkey;
+use anchor_lang::solana_program::instruction::AccountMeta;
+use anchor_spl::associated_token::get_associated_token_address;
+use clap::Parser;
+use serde_json::{json, Value};
+
+// Arquivo de configuração temporário, removido ao sair do escopo
+struct TempConfig(PathBuf);
+
+impl TempConfig {
+    fn new(name: &str, contents: Option<Value>) -> Self {
+        let path = std::env::temp_dir()
+            .join(format!("adr-admin-{}-{}", std::process::id(), name))
+            .join("deploy-config.json");
+        if let Some(contents) = contents {
+            fs::create_dir_all(path.parent().unwrap()).unwrap();
+            fs::write(&path, serde_json::to_string_pretty(&contents).unwrap()).unwrap();
+        }
+        Self(path)
+    }
+
+    fn load(&self) -> DeployConfig {
+        DeployConfig::load(&self.0).unwrap()
+    }
+}
+
+impl Drop for TempConfig {
+    fn drop(&mut self) {
+        let _ = fs::remove_dir_all(self.0.parent().unwrap());
+    }
+}
+
+fn parse(args: &[&str]) -> Cli {
+    Cli::try_parse_from(std::iter::once("adr-admin").chain(args.iter().copied())).unwrap()
+}
+
+fn config_with_collection(collection: Pubkey, payment_token_mint: Pubkey) -> DeployConfig {
+    let mut config = DeployConfig::load(&std::env::temp_dir().join("adr-admin-inexistente.json")).unwrap();
+    config.set("collectionMetadata", collection.to_string());
+    config.set("paymentTokenMint", payment_token_mint.to_string());
+    config
+}
+
+#[test]
+fn deploy_config_round_trip_preserves_unknown_keys_and_order() {
+    let file = TempConfig::new(
+        "round-trip",
+        Some(json!({
+            "programId": "65zQjC4UYf4zJdDyfScpZjgaBbiMRpmFhNJkFSp39GZF",
+            "deployTimestamp": "2025-05-25T01:05:56.511Z",
+            "chaveDosScripts": { "aninhada": [1, 2, 3] },
+            "stakingRewardRate": 500,
+        })),
+    );
+
+    let mut config = file.load();
+    let mint = Pubkey::new_unique();
+    config.set("stakingRewardRate", 800);
+    config.set("paymentTokenMint", mint.to_string());
+    config.set_now("stakingConfigTime");
+    config.save().unwrap();
+
+    let contents = fs::read_to_string(&file.0).unwrap();
+    // Mesma indentação de `JSON.stringify(config, null, 2)`
+    assert!(contents.starts_with("{\n  \"programId\""));
+
+    let reloaded = file.load();
+    assert_eq!(reloaded.pubkey("paymentTokenMint").unwrap(), mint);
+    assert_eq!(reloaded.get("stakingRewardRate"), Some(&json!(800)));
+    assert_eq!(reloaded.get("chaveDosScripts"), Some(&json!({ "aninhada": [1, 2, 3] })));
+    let timestamp = reloaded.get("stakingConfigTime").and_then(Value::as_str).unwrap();
+    assert!(timestamp.ends_with('Z') && timestamp.len() == "2025-05-25T01:05:56.511Z".len());
+
+    // Chaves existentes mantêm a posição; as novas vão para o fim
+    let value: serde_json::Map<String, Value> = serde_json::from_str(&contents).unwrap();
+    let keys: Vec<&str> = value.keys().map(String::as_str).collect();
+    assert_eq!(
+        keys,
+        [
+            "programId",
+            "deployTimestamp",
+            "chaveDosScripts",
+            "stakingRewardRate",
+            "paymentTokenMint",
+            "stakingConfigTime",
+        ]
+    );
+}
+
+#[test]
+fn deploy_config_starts_empty_and_creates_the_directory_on_save() {
+    let file = TempConfig::new("novo", None);
+    let mut config = file.load();
+    let error = config.pubkey("collectionMetadata").unwrap_err();
+    assert!(error.contains("collectionMetadata ausente"), "{}", error);
+
+    config.set("collectionMetadata", "não é pubkey");
+    assert!(config.pubkey("collectionMetadata").unwrap_err().contains("inválido"));
+
+    config.save().unwrap();
+    assert!(file.0.exists());
+}
+
+#[test]
+fn collection_commands_use_the_configured_collection_unless_overridden() {
+    let admin = Pubkey::new_unique();
+    let configured = Pubkey::new_unique();
+    let other = Pubkey::new_unique();
+    let config = config_with_collection(configured, Pubkey::new_unique());
+
+    let cli = parse(&["set-soulbound", "--enabled", "true"]);
+    let planned = plan(&cli.command, admin, cli.collection, &config).unwrap();
+    let expected = instructions::set_soulbound(instructions::set_soulbound_accounts(admin, configured), true);
+    assert_eq!(planned.instructions, [expected]);
+    assert_eq!(planned.updates, [("soulbound", json!(true))]);
+
+    // --collection é global e pode vir depois do subcomando
+    let cli = parse(&["set-nft-reward-rate", "1000", "--collection", &other.to_string()]);
+    let planned = plan(&cli.command, admin, cli.collection, &config).unwrap();
+    let expected =
+        instructions::set_nft_reward_rate(instructions::set_nft_reward_rate_accounts(admin, other), 1000);
+    assert_eq!(planned.instructions, [expected]);
+
+    // Sem coleção no arquivo nem na linha de comando
+    let empty = TempConfig::new("sem-colecao", None).load();
+    let cli = parse(&["configure-redemption", "5"]);
+    let error = plan(&cli.command, admin, cli.collection, &empty).err().unwrap();
+    assert!(error.contains("collectionMetadata"), "{}", error);
+}
+
+#[test]
+fn configure_collection_and_pricing_curve_encode_their_arguments() {
+    let admin = Pubkey::new_unique();
+    let collection = Pubkey::new_unique();
+    let config = config_with_collection(collection, Pubkey::new_unique());
+
+    let cli = parse(&["configure-collection", "--max-supply", "100", "--mint-price", "25"]);
+    let planned = plan(&cli.command, admin, None, &config).unwrap();
+    let expected = instructions::configure_collection(
+        instructions::configure_collection_accounts(admin, collection),
+        100,
+        0,
+        0,
+        25,
+        0,
+    );
+    assert_eq!(planned.instructions, [expected]);
+    assert_eq!(planned.timestamp_key, "collectionConfigTime");
+
+    let cli = parse(&[
+        "set-pricing-curve",
+        "--mode",
+        "tiered",
+        "--tier",
+        "0:100",
+        "--tier",
+        "10:150",
+    ]);
+    let planned = plan(&cli.command, admin, None, &config).unwrap();
+    let tiers = vec![
+        PriceTier { start_count: 0, price: 100 },
+        PriceTier { start_count: 10, price: 150 },
+    ];
+    let expected = instructions::set_pricing_curve(
+        instructions::set_pricing_curve_accounts(admin, collection),
+        PricingMode::Tiered,
+        0,
+        tiers,
+        false,
+    );
+    assert_eq!(planned.instructions, [expected]);
+    assert_eq!(planned.updates[0], ("pricingMode", json!("tiered")));
+    assert_eq!(planned.updates[2].1, json!([
+        { "startCount": 0, "price": 100 },
+        { "startCount": 10, "price": 150 },
+    ]));
+
+    assert_eq!(planned.updates[3], ("allowFreeMint", json!(false)));
+
+    // Preço fixo zero só minta com o mint gratuito liberado
+    let cli = parse(&["set-pricing-curve", "--mode", "fixed", "--allow-free-mint"]);
+    let planned = plan(&cli.command, admin, None, &config).unwrap();
+    let expected = instructions::set_pricing_curve(
+        instructions::set_pricing_curve_accounts(admin, collection),
+        PricingMode::Fixed,
+        0,
+        Vec::new(),
+        true,
+    );
+    assert_eq!(planned.instructions, [expected]);
+    assert_eq!(planned.updates[3], ("allowFreeMint", json!(true)));
+
+    assert!(Cli::try_parse_from(["adr-admin", "set-pricing-curve", "--mode", "tiered", "--tier", "10"]).is_err());
+}
+
+#[test]
+fn payment_commands_pass_treasury_and_payment_mode() {
+    let admin = Pubkey::new_unique();
+    let collection = Pubkey::new_unique();
+    let treasury = Pubkey::new_unique();
+    let config = config_with_collection(collection, Pubkey::new_unique());
+
+    let cli = parse(&[
+        "set-payment-split",
+        "--burn-bps",
+        "5000",
+        "--treasury-bps",
+        "5000",
+        "--treasury",
+        &treasury.to_string(),
+    ]);
+    let planned = plan(&cli.command, admin, None, &config).unwrap();
+    let expected = instructions::set_payment_split(
+        instructions::set_payment_split_accounts(admin, collection, Some(treasury)),
+        5000,
+        5000,
+        0,
+    );
+    assert_eq!(planned.instructions, [expected]);
+
+    // Sem tesouraria, a conta opcional vai como o ID do programa
+    let cli = parse(&["set-payment-split", "--burn-bps", "10000"]);
+    let planned = plan(&cli.command, admin, None, &config).unwrap();
+    assert_eq!(planned.instructions[0].accounts.last().unwrap().pubkey, adr_token_mint::ID);
+
+    let cli = parse(&["set-payment-mode", "sol"]);
+    let planned = plan(&cli.command, admin, None, &config).unwrap();
+    let expected =
+        instructions::set_payment_mode(instructions::set_payment_mode_accounts(admin, collection), PaymentMode::Sol);
+    assert_eq!(planned.instructions, [expected]);
+    assert_eq!(planned.updates, [("paymentMode", json!("sol"))]);
+}
+
+#[test]
+fn withdraw_sol_treasury_defaults_to_the_admin_and_accumulates_the_total() {
+    let admin = Pubkey::new_unique();
+    let collection = Pubkey::new_unique();
+    let mut config = config_with_collection(collection, Pubkey::new_unique());
+    config.set("totalSolWithdrawn", 1_000);
+
+    let cli = parse(&["withdraw-sol-treasury", "500"]);
+    let planned = plan(&cli.command, admin, None, &config).unwrap();
+    let expected = instructions::withdraw_sol_treasury(
+        instructions::withdraw_sol_treasury_accounts(admin, collection, admin),
+        500,
+    );
+    assert_eq!(planned.instructions, [expected]);
+    let updates: serde_json::Map<String, Value> =
+        planned.updates.into_iter().map(|(key, value)| (key.to_string(), value)).collect();
+    assert_eq!(updates["solTreasury"], json!(pda::find_sol_treasury_pda(&collection).0.to_string()));
+    assert_eq!(updates["lastSolWithdrawDestination"], json!(admin.to_string()));
+    assert_eq!(updates["totalSolWithdrawn"], json!(1_500));
+}
+
+#[test]
+fn pool_deposits_use_the_configured_payment_token() {
+    let admin = Pubkey::new_unique();
+    let collection = Pubkey::new_unique();
+    let payment_token_mint = Pubkey::new_unique();
+    let config = config_with_collection(collection, payment_token_mint);
+
+    let cli = parse(&["deposit-redemption-pool", "700"]);
+    let planned = plan(&cli.command, admin, None, &config).unwrap();
+    let accounts = instructions::deposit_redemption_pool_accounts(admin, collection, payment_token_mint);
+    let pool = accounts.redemption_pool;
+    assert_eq!(planned.instructions, [instructions::deposit_redemption_pool(accounts, 700)]);
+    assert_eq!(planned.updates[0], ("redemptionPool", json!(pool.to_string())));
+
+    let cli = parse(&["deposit-nft-reward-pool", "300"]);
+    let planned = plan(&cli.command, admin, None, &config).unwrap();
+    let authority = pda::find_nft_reward_authority_pda(&collection).0;
+    let pool = get_associated_token_address(&authority, &payment_token_mint);
+    assert_eq!(planned.updates[0], ("nftRewardPool", json!(pool.to_string())));
+    assert_eq!(planned.updates[2], ("totalNftRewardDeposited", json!(300)));
+}
+
+#[test]
+fn reveal_commands_parse_hashes_and_pass_nft_metadata_accounts() {
+    let admin = Pubkey::new_unique();
+    let collection = Pubkey::new_unique();
+    let config = config_with_collection(collection, Pubkey::new_unique());
+    let hash = "ab".repeat(32);
+
+    let cli = parse(&["commit-reveal", "--base-uri", "https://arweave.net/base/", "--provenance-hash", &hash]);
+    let planned = plan(&cli.command, admin, None, &config).unwrap();
+    let expected = instructions::commit_reveal(
+        instructions::configure_reveal_accounts(admin, collection),
+        "https://arweave.net/base/".to_string(),
+        [0xab; 32],
+    );
+    assert_eq!(planned.instructions, [expected]);
+    assert_eq!(planned.updates[1], ("revealProvenanceHash", json!(hash)));
+
+    assert!(parse_hash(&format!("0x{}", hash)).is_ok());
+    assert!(parse_hash("abcd").is_err());
+    assert!(parse_hash(&"zz".repeat(32)).is_err());
+
+    let nft_mints = [Pubkey::new_unique(), Pubkey::new_unique()];
+    let cli = parse(&["reveal-nfts", &nft_mints[0].to_string(), &nft_mints[1].to_string()]);
+    let planned = plan(&cli.command, admin, None, &config).unwrap();
+    let metadata: Vec<AccountMeta> = nft_mints
+        .iter()
+        .map(|mint| AccountMeta::new(pda::find_nft_metadata_pda(mint).0, false))
+        .collect();
+    let accounts = &planned.instructions[0].accounts;
+    assert_eq!(accounts[accounts.len() - 2..], metadata[..]);
+    assert_eq!(planned.updates, [("revealedNfts", json!(2))]);
+
+    // Sem NFTs não há o que revelar
+    assert!(Cli::try_parse_from(["adr-admin", "reveal-nfts"]).is_err());
+}
+
+#[test]
+fn views_are_not_planned_as_transactions() {
+    for args in [
+        vec!["status"],
+        vec!["collection-info"],
+        vec!["mint-quote", "--count", "3"],
+        vec!["wallet-mint-count"],
+        vec!["stake-summary"],
+    ] {
+        let cli = parse(&args);
+        assert!(cli.command.is_view(), "{}", cli.command.name());
+    }
+    let cli = parse(&["set-allowlist", &"00".repeat(32), "--price", "10"]);
+    assert!(!cli.command.is_view());
+    assert!(matches!(cli.command, Command::SetAllowlist { price: 10, .. }));
+}
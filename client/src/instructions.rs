@@ -0,0 +1,836 @@
+// Builders tipados para cada instrução do programa
+//
+// Cada builder recebe a struct de contas gerada pelo Anchor (`adr_token_mint::accounts`)
+// e os argumentos da instrução. As funções `*_accounts` preenchem as contas derivadas
+// (PDAs, ATAs e programas) dos fluxos mais usados.
+
+use anchor_lang::prelude::Pubkey;
+use anchor_lang::solana_program::instruction::{AccountMeta, Instruction};
+use anchor_lang::{system_program, InstructionData, ToAccountMetas};
+use anchor_spl::associated_token::{
+    self, get_associated_token_address, get_associated_token_address_with_program_id,
+};
+use anchor_spl::token;
+
+use adr_token_mint::{accounts, instruction, LEGACY_CONFIG_ACCOUNT};
+
+use crate::{pda, NftBumps, PaymentMode, PriceTier, PricingMode, StakingPeriod, PROGRAM_ID};
+
+fn build(accounts: impl ToAccountMetas, data: impl InstructionData) -> Instruction {
+    Instruction {
+        program_id: PROGRAM_ID,
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
+
+fn build_with_remaining(
+    accounts: impl ToAccountMetas,
+    data: impl InstructionData,
+    remaining_accounts: Vec<AccountMeta>,
+) -> Instruction {
+    let mut instruction = build(accounts, data);
+    instruction.accounts.extend(remaining_accounts);
+    instruction
+}
+
+// Coleção e mint
+
+pub fn initialize_collection(
+    accounts: accounts::InitializeCollection,
+    name: String,
+    symbol: String,
+    uri: String,
+) -> Instruction {
+    build(accounts, instruction::InitializeCollection { name, symbol, uri })
+}
+
+pub fn initialize_event_sequence(accounts: accounts::InitializeEventSequence) -> Instruction {
+    build(accounts, instruction::InitializeEventSequence {})
+}
+
+pub fn migrate_config(accounts: accounts::MigrateConfig) -> Instruction {
+    build(accounts, instruction::MigrateConfig {})
+}
+
+pub fn migrate_account(accounts: accounts::MigrateAccount) -> Instruction {
+    build(accounts, instruction::MigrateAccount {})
+}
+
+// max_price: maior preço que o comprador aceita pagar; o valor cobrado é o da curva da
+// coleção (o antigo argumento `amount` era o valor pago)
+pub fn mint_nft_with_payment(
+    accounts: accounts::MintNFTWithPayment,
+    name: String,
+    symbol: String,
+    uri: String,
+    max_price: u64,
+) -> Instruction {
+    build(
+        accounts,
+        instruction::MintNftWithPayment { name, symbol, uri, max_price },
+    )
+}
+
+// nfts: remaining_accounts e bumps de cada NFT do lote, como em `mint_batch_nft_accounts`
+pub fn mint_batch(
+    accounts: accounts::MintBatch,
+    quantity: u8,
+    name: String,
+    symbol: String,
+    uri: String,
+    max_total_price: u64,
+    nfts: (Vec<AccountMeta>, Vec<NftBumps>),
+) -> Instruction {
+    let (remaining_accounts, bumps) = nfts;
+    build_with_remaining(
+        accounts,
+        instruction::MintBatch { quantity, name, symbol, uri, max_total_price, bumps },
+        remaining_accounts,
+    )
+}
+
+pub fn mint_nft_with_sol(
+    accounts: accounts::MintNFTWithSol,
+    name: String,
+    symbol: String,
+    uri: String,
+    max_price: u64,
+) -> Instruction {
+    build(
+        accounts,
+        instruction::MintNftWithSol { name, symbol, uri, max_price },
+    )
+}
+
+pub fn mint_allowlisted(
+    accounts: accounts::MintAllowlisted,
+    name: String,
+    symbol: String,
+    uri: String,
+    allocation: u64,
+    proof: Vec<[u8; 32]>,
+) -> Instruction {
+    build(
+        accounts,
+        instruction::MintAllowlisted { name, symbol, uri, allocation, proof },
+    )
+}
+
+pub fn burn_nft(accounts: accounts::BurnNft) -> Instruction {
+    build(accounts, instruction::BurnNft {})
+}
+
+// Configuração da coleção
+
+pub fn configure_collection(
+    accounts: accounts::ConfigureCollection,
+    max_supply: u64,
+    mint_start: i64,
+    mint_end: i64,
+    mint_price: u64,
+    max_per_wallet: u64,
+) -> Instruction {
+    build(
+        accounts,
+        instruction::ConfigureCollection {
+            max_supply,
+            mint_start,
+            mint_end,
+            mint_price,
+            max_per_wallet,
+        },
+    )
+}
+
+pub fn set_payment_mode(accounts: accounts::SetPaymentMode, payment_mode: PaymentMode) -> Instruction {
+    build(accounts, instruction::SetPaymentMode { payment_mode })
+}
+
+pub fn set_soulbound(accounts: accounts::SetSoulbound, soulbound: bool) -> Instruction {
+    build(accounts, instruction::SetSoulbound { soulbound })
+}
+
+pub fn set_nft_reward_rate(accounts: accounts::SetNftRewardRate, nft_reward_rate: u64) -> Instruction {
+    build(accounts, instruction::SetNftRewardRate { nft_reward_rate })
+}
+
+pub fn withdraw_sol_treasury(accounts: accounts::WithdrawSolTreasury, amount: u64) -> Instruction {
+    build(accounts, instruction::WithdrawSolTreasury { amount })
+}
+
+// allow_free_mint: libera o mint com mint_price = 0 no modo Fixed
+pub fn set_pricing_curve(
+    accounts: accounts::SetPricingCurve,
+    pricing_mode: PricingMode,
+    price_increment: u64,
+    tiers: Vec<PriceTier>,
+    allow_free_mint: bool,
+) -> Instruction {
+    build(
+        accounts,
+        instruction::SetPricingCurve { pricing_mode, price_increment, tiers, allow_free_mint },
+    )
+}
+
+pub fn configure_redemption(accounts: accounts::ConfigureRedemption, redemption_amount: u64) -> Instruction {
+    build(accounts, instruction::ConfigureRedemption { redemption_amount })
+}
+
+pub fn deposit_redemption_pool(accounts: accounts::DepositRedemptionPool, amount: u64) -> Instruction {
+    build(accounts, instruction::DepositRedemptionPool { amount })
+}
+
+pub fn set_payment_split(
+    accounts: accounts::SetPaymentSplit,
+    burn_bps: u16,
+    treasury_bps: u16,
+    reserve_bps: u16,
+) -> Instruction {
+    build(
+        accounts,
+        instruction::SetPaymentSplit { burn_bps, treasury_bps, reserve_bps },
+    )
+}
+
+pub fn set_allowlist(
+    accounts: accounts::SetAllowlist,
+    merkle_root: [u8; 32],
+    allowlist_price: u64,
+) -> Instruction {
+    build(accounts, instruction::SetAllowlist { merkle_root, allowlist_price })
+}
+
+// Metadados e reveal
+
+pub fn update_nft_metadata(
+    accounts: accounts::UpdateNftMetadata,
+    name: Option<String>,
+    symbol: Option<String>,
+    uri: Option<String>,
+) -> Instruction {
+    build(accounts, instruction::UpdateNftMetadata { name, symbol, uri })
+}
+
+pub fn lock_metadata(accounts: accounts::UpdateNftMetadata) -> Instruction {
+    build(accounts, instruction::LockMetadata {})
+}
+
+pub fn set_reveal_placeholder(accounts: accounts::ConfigureReveal, placeholder_uri: String) -> Instruction {
+    build(accounts, instruction::SetRevealPlaceholder { placeholder_uri })
+}
+
+pub fn commit_reveal(
+    accounts: accounts::ConfigureReveal,
+    base_uri: String,
+    provenance_hash: [u8; 32],
+) -> Instruction {
+    build(accounts, instruction::CommitReveal { base_uri, provenance_hash })
+}
+
+// remaining_accounts: contas NFTMetadata graváveis a revelar
+pub fn reveal_nfts(accounts: accounts::RevealNfts, remaining_accounts: Vec<AccountMeta>) -> Instruction {
+    build_with_remaining(accounts, instruction::RevealNfts {}, remaining_accounts)
+}
+
+// Administração
+
+pub fn approve_delegate(accounts: accounts::ApproveDelegate, amount: u64) -> Instruction {
+    build(accounts, instruction::ApproveDelegate { amount })
+}
+
+pub fn set_payment_token(accounts: accounts::SetPaymentToken, payment_token_mint: Pubkey) -> Instruction {
+    build(accounts, instruction::SetPaymentToken { payment_token_mint })
+}
+
+pub fn set_reward_reserve(accounts: accounts::SetRewardReserve, reward_reserve: Pubkey) -> Instruction {
+    build(accounts, instruction::SetRewardReserve { reward_reserve })
+}
+
+pub fn initialize_reward_reserve(accounts: accounts::InitializeRewardReserve) -> Instruction {
+    build(accounts, instruction::InitializeRewardReserve {})
+}
+
+pub fn deposit_reward_reserve(accounts: accounts::DepositRewardReserve, amount: u64) -> Instruction {
+    build(accounts, instruction::DepositRewardReserve { amount })
+}
+
+pub fn configure_staking(accounts: accounts::ConfigureStaking, enabled: bool, reward_rate: u64) -> Instruction {
+    build(accounts, instruction::ConfigureStaking { enabled, reward_rate })
+}
+
+pub fn set_nft_holder_boost(accounts: accounts::ConfigureStaking, boost_bps: u64) -> Instruction {
+    build(accounts, instruction::SetNftHolderBoost { boost_bps })
+}
+
+pub fn set_emergency_pause(accounts: accounts::EmergencyPause, paused: bool, reason: String) -> Instruction {
+    build(accounts, instruction::SetEmergencyPause { paused, reason })
+}
+
+// Staking
+
+// `receipt_uri` exige as contas de recibo (veja `with_stake_receipt`)
+pub fn stake_tokens(
+    accounts: accounts::StakeTokens,
+    amount: u64,
+    period: StakingPeriod,
+    receipt_uri: Option<String>,
+) -> Instruction {
+    build(accounts, instruction::StakeTokens { amount, period, receipt_uri })
+}
+
+pub fn unstake_tokens(accounts: accounts::UnstakeTokens) -> Instruction {
+    build(accounts, instruction::UnstakeTokens {})
+}
+
+pub fn close_stake(accounts: accounts::CloseStake) -> Instruction {
+    build(accounts, instruction::CloseStake {})
+}
+
+pub fn stake_nft(accounts: accounts::StakeNft) -> Instruction {
+    build(accounts, instruction::StakeNft {})
+}
+
+pub fn unstake_nft(accounts: accounts::UnstakeNft) -> Instruction {
+    build(accounts, instruction::UnstakeNft {})
+}
+
+pub fn deposit_nft_reward_pool(accounts: accounts::DepositNftRewardPool, amount: u64) -> Instruction {
+    build(accounts, instruction::DepositNftRewardPool { amount })
+}
+
+pub fn lock_boost_nft(accounts: accounts::LockBoostNft) -> Instruction {
+    build(accounts, instruction::LockBoostNft {})
+}
+
+pub fn unlock_boost_nft(accounts: accounts::UnlockBoostNft) -> Instruction {
+    build(accounts, instruction::UnlockBoostNft {})
+}
+
+// Leitura (use com ProgramRpc::simulate_instructions, veja `views`)
+
+pub fn get_stake_summary(accounts: accounts::GetStakeSummary) -> Instruction {
+    build(accounts, instruction::GetStakeSummary {})
+}
+
+pub fn get_config_summary(accounts: accounts::GetConfigSummary) -> Instruction {
+    build(accounts, instruction::GetConfigSummary {})
+}
+
+pub fn get_collection_info(accounts: accounts::GetCollectionInfo) -> Instruction {
+    build(accounts, instruction::GetCollectionInfo {})
+}
+
+pub fn get_wallet_mint_count(accounts: accounts::GetWalletMintCount) -> Instruction {
+    build(accounts, instruction::GetWalletMintCount {})
+}
+
+// Usa as mesmas contas de get_collection_info
+pub fn get_mint_quote(accounts: accounts::GetCollectionInfo, count: u64) -> Instruction {
+    build(accounts, instruction::GetMintQuote { count })
+}
+
+// Contas derivadas dos fluxos mais usados
+
+// collection_mint e collection_metadata são contas keypair novas, assinadas junto com o payer
+pub fn initialize_collection_accounts(
+    payer: Pubkey,
+    collection_mint: Pubkey,
+    collection_metadata: Pubkey,
+) -> accounts::InitializeCollection {
+    accounts::InitializeCollection {
+        payer,
+        collection_mint,
+        collection_metadata,
+        collection_token_account: get_associated_token_address(&payer, &collection_mint),
+        config: pda::find_config_pda().0,
+        event_sequence: pda::find_event_sequence_pda().0,
+        nft_counter: pda::find_nft_counter_pda().0,
+        collection_config: pda::find_collection_config_pda(&collection_metadata).0,
+        token_program: token::ID,
+        associated_token_program: associated_token::ID,
+        system_program: system_program::ID,
+        rent: anchor_lang::solana_program::sysvar::rent::ID,
+    }
+}
+
+// Implantações anteriores à sequência de eventos
+pub fn initialize_event_sequence_accounts(admin: Pubkey) -> accounts::InitializeEventSequence {
+    accounts::InitializeEventSequence {
+        admin,
+        config: pda::find_config_pda().0,
+        event_sequence: pda::find_event_sequence_pda().0,
+        system_program: system_program::ID,
+    }
+}
+
+// Migração da ConfigAccount keypair da devnet para a PDA
+pub fn migrate_config_accounts(admin: Pubkey) -> accounts::MigrateConfig {
+    accounts::MigrateConfig {
+        admin,
+        legacy_config: LEGACY_CONFIG_ACCOUNT,
+        config: pda::find_config_pda().0,
+        event_sequence: pda::find_event_sequence_pda().0,
+        system_program: system_program::ID,
+    }
+}
+
+// Conversão de qualquer conta do programa ainda no layout v1
+pub fn migrate_account_accounts(payer: Pubkey, account: Pubkey) -> accounts::MigrateAccount {
+    accounts::MigrateAccount {
+        payer,
+        account,
+        system_program: system_program::ID,
+    }
+}
+
+pub fn initialize_reward_reserve_accounts(
+    admin: Pubkey,
+    token_mint: Pubkey,
+) -> accounts::InitializeRewardReserve {
+    let stake_authority = pda::find_stake_authority_pda().0;
+    accounts::InitializeRewardReserve {
+        admin,
+        reward_reserve_account: get_associated_token_address(&stake_authority, &token_mint),
+        token_mint,
+        stake_authority,
+        config: pda::find_config_pda().0,
+        event_sequence: pda::find_event_sequence_pda().0,
+        token_program: token::ID,
+        associated_token_program: associated_token::ID,
+        system_program: system_program::ID,
+        rent: anchor_lang::solana_program::sysvar::rent::ID,
+    }
+}
+
+pub fn deposit_reward_reserve_accounts(
+    admin: Pubkey,
+    token_mint: Pubkey,
+) -> accounts::DepositRewardReserve {
+    let stake_authority = pda::find_stake_authority_pda().0;
+    accounts::DepositRewardReserve {
+        admin,
+        admin_token_account: get_associated_token_address(&admin, &token_mint),
+        reward_reserve_account: get_associated_token_address(&stake_authority, &token_mint),
+        token_mint,
+        stake_authority,
+        config: pda::find_config_pda().0,
+        event_sequence: pda::find_event_sequence_pda().0,
+        token_program: token::ID,
+    }
+}
+
+// Configuração da coleção (admin)
+
+pub fn configure_collection_accounts(admin: Pubkey, collection_metadata: Pubkey) -> accounts::ConfigureCollection {
+    accounts::ConfigureCollection {
+        admin,
+        config: pda::find_config_pda().0,
+        collection_metadata,
+        nft_counter: pda::find_nft_counter_pda().0,
+        collection_config: pda::find_collection_config_pda(&collection_metadata).0,
+        event_sequence: pda::find_event_sequence_pda().0,
+        system_program: system_program::ID,
+    }
+}
+
+pub fn set_allowlist_accounts(admin: Pubkey, collection_metadata: Pubkey) -> accounts::SetAllowlist {
+    accounts::SetAllowlist {
+        admin,
+        config: pda::find_config_pda().0,
+        collection_metadata,
+        collection_config: pda::find_collection_config_pda(&collection_metadata).0,
+        event_sequence: pda::find_event_sequence_pda().0,
+    }
+}
+
+pub fn set_pricing_curve_accounts(admin: Pubkey, collection_metadata: Pubkey) -> accounts::SetPricingCurve {
+    accounts::SetPricingCurve {
+        admin,
+        config: pda::find_config_pda().0,
+        collection_metadata,
+        nft_counter: pda::find_nft_counter_pda().0,
+        collection_config: pda::find_collection_config_pda(&collection_metadata).0,
+        event_sequence: pda::find_event_sequence_pda().0,
+    }
+}
+
+// treasury: conta de token que recebe a parte da tesouraria; None quando treasury_bps é 0
+pub fn set_payment_split_accounts(
+    admin: Pubkey,
+    collection_metadata: Pubkey,
+    treasury: Option<Pubkey>,
+) -> accounts::SetPaymentSplit {
+    accounts::SetPaymentSplit {
+        admin,
+        config: pda::find_config_pda().0,
+        collection_metadata,
+        collection_config: pda::find_collection_config_pda(&collection_metadata).0,
+        event_sequence: pda::find_event_sequence_pda().0,
+        treasury_token_account: treasury,
+    }
+}
+
+pub fn set_payment_mode_accounts(admin: Pubkey, collection_metadata: Pubkey) -> accounts::SetPaymentMode {
+    accounts::SetPaymentMode {
+        admin,
+        config: pda::find_config_pda().0,
+        collection_metadata,
+        collection_config: pda::find_collection_config_pda(&collection_metadata).0,
+        event_sequence: pda::find_event_sequence_pda().0,
+    }
+}
+
+pub fn withdraw_sol_treasury_accounts(
+    admin: Pubkey,
+    collection_metadata: Pubkey,
+    destination: Pubkey,
+) -> accounts::WithdrawSolTreasury {
+    accounts::WithdrawSolTreasury {
+        admin,
+        config: pda::find_config_pda().0,
+        collection_metadata,
+        sol_treasury: pda::find_sol_treasury_pda(&collection_metadata).0,
+        destination,
+        event_sequence: pda::find_event_sequence_pda().0,
+        system_program: system_program::ID,
+    }
+}
+
+pub fn configure_redemption_accounts(admin: Pubkey, collection_metadata: Pubkey) -> accounts::ConfigureRedemption {
+    accounts::ConfigureRedemption {
+        admin,
+        config: pda::find_config_pda().0,
+        collection_metadata,
+        collection_config: pda::find_collection_config_pda(&collection_metadata).0,
+        event_sequence: pda::find_event_sequence_pda().0,
+    }
+}
+
+// Usadas por set_reveal_placeholder e commit_reveal
+pub fn configure_reveal_accounts(admin: Pubkey, collection_metadata: Pubkey) -> accounts::ConfigureReveal {
+    accounts::ConfigureReveal {
+        admin,
+        config: pda::find_config_pda().0,
+        collection_metadata,
+        collection_config: pda::find_collection_config_pda(&collection_metadata).0,
+        event_sequence: pda::find_event_sequence_pda().0,
+    }
+}
+
+pub fn reveal_nfts_accounts(admin: Pubkey, collection_metadata: Pubkey) -> accounts::RevealNfts {
+    accounts::RevealNfts {
+        admin,
+        config: pda::find_config_pda().0,
+        collection_metadata,
+        collection_config: pda::find_collection_config_pda(&collection_metadata).0,
+        event_sequence: pda::find_event_sequence_pda().0,
+    }
+}
+
+// remaining_accounts do reveal_nfts: os metadados graváveis de cada NFT
+pub fn reveal_nft_metadata_accounts(nft_mints: &[Pubkey]) -> Vec<AccountMeta> {
+    nft_mints
+        .iter()
+        .map(|nft_mint| AccountMeta::new(pda::find_nft_metadata_pda(nft_mint).0, false))
+        .collect()
+}
+
+pub fn set_soulbound_accounts(admin: Pubkey, collection_metadata: Pubkey) -> accounts::SetSoulbound {
+    accounts::SetSoulbound {
+        admin,
+        config: pda::find_config_pda().0,
+        collection_metadata,
+        collection_config: pda::find_collection_config_pda(&collection_metadata).0,
+        event_sequence: pda::find_event_sequence_pda().0,
+    }
+}
+
+pub fn set_nft_reward_rate_accounts(admin: Pubkey, collection_metadata: Pubkey) -> accounts::SetNftRewardRate {
+    accounts::SetNftRewardRate {
+        admin,
+        config: pda::find_config_pda().0,
+        collection_metadata,
+        collection_config: pda::find_collection_config_pda(&collection_metadata).0,
+        event_sequence: pda::find_event_sequence_pda().0,
+    }
+}
+
+pub fn deposit_redemption_pool_accounts(
+    admin: Pubkey,
+    collection_metadata: Pubkey,
+    payment_token_mint: Pubkey,
+) -> accounts::DepositRedemptionPool {
+    let redemption_authority = pda::find_redemption_authority_pda(&collection_metadata).0;
+    accounts::DepositRedemptionPool {
+        admin,
+        config: pda::find_config_pda().0,
+        collection_metadata,
+        redemption_authority,
+        redemption_pool: get_associated_token_address(&redemption_authority, &payment_token_mint),
+        admin_token_account: get_associated_token_address(&admin, &payment_token_mint),
+        payment_token_mint,
+        event_sequence: pda::find_event_sequence_pda().0,
+        token_program: token::ID,
+        associated_token_program: associated_token::ID,
+        system_program: system_program::ID,
+    }
+}
+
+pub fn deposit_nft_reward_pool_accounts(
+    admin: Pubkey,
+    collection_metadata: Pubkey,
+    payment_token_mint: Pubkey,
+) -> accounts::DepositNftRewardPool {
+    let nft_reward_authority = pda::find_nft_reward_authority_pda(&collection_metadata).0;
+    accounts::DepositNftRewardPool {
+        admin,
+        config: pda::find_config_pda().0,
+        collection_metadata,
+        nft_reward_authority,
+        nft_reward_pool: get_associated_token_address(&nft_reward_authority, &payment_token_mint),
+        admin_token_account: get_associated_token_address(&admin, &payment_token_mint),
+        payment_token_mint,
+        event_sequence: pda::find_event_sequence_pda().0,
+        token_program: token::ID,
+        associated_token_program: associated_token::ID,
+        system_program: system_program::ID,
+    }
+}
+
+pub fn stake_tokens_accounts(
+    staker: Pubkey,
+    token_mint: Pubkey,
+) -> accounts::StakeTokens {
+    let stake_authority = pda::find_stake_authority_pda().0;
+    accounts::StakeTokens {
+        staker,
+        token_mint,
+        staker_token_account: get_associated_token_address(&staker, &token_mint),
+        stake_account: pda::find_stake_account_pda(&staker, &token_mint).0,
+        stake_token_account: get_associated_token_address(&stake_authority, &token_mint),
+        stake_authority,
+        stake_receipt: None,
+        receipt_mint: None,
+        receipt_metadata: None,
+        receipt_token_account: None,
+        config: pda::find_config_pda().0,
+        event_sequence: pda::find_event_sequence_pda().0,
+        token_program: token::ID,
+        associated_token_program: associated_token::ID,
+        system_program: system_program::ID,
+        rent: anchor_lang::solana_program::sysvar::rent::ID,
+    }
+}
+
+// Contas do recibo para um stake_tokens com `receipt_uri`; `receipt_mint` é um keypair
+// novo, que também assina a transação
+pub fn with_stake_receipt(mut accounts: accounts::StakeTokens, receipt_mint: Pubkey) -> accounts::StakeTokens {
+    accounts.stake_receipt = Some(pda::find_stake_receipt_pda(&accounts.stake_account).0);
+    accounts.receipt_mint = Some(receipt_mint);
+    accounts.receipt_metadata = Some(pda::find_nft_metadata_pda(&receipt_mint).0);
+    accounts.receipt_token_account = Some(get_associated_token_address(&accounts.staker, &receipt_mint));
+    accounts
+}
+
+// Posição sem recibo; informe o bônus quando houver NFT travado
+pub fn unstake_tokens_accounts(
+    staker: Pubkey,
+    token_mint: Pubkey,
+    reward_reserve_account: Pubkey,
+    with_boost: bool,
+) -> accounts::UnstakeTokens {
+    let stake_authority = pda::find_stake_authority_pda().0;
+    let stake_account = pda::find_stake_account_pda(&staker, &token_mint).0;
+    accounts::UnstakeTokens {
+        staker,
+        token_mint,
+        staker_token_account: get_associated_token_address(&staker, &token_mint),
+        stake_token_account: get_associated_token_address(&stake_authority, &token_mint),
+        reward_reserve_account,
+        stake_authority,
+        stake_account,
+        stake_receipt: None,
+        receipt_mint: None,
+        receipt_token_account: None,
+        receipt_metadata: None,
+        rent_recipient: None,
+        stake_boost: with_boost.then(|| pda::find_stake_boost_pda(&stake_account).0),
+        config: pda::find_config_pda().0,
+        event_sequence: pda::find_event_sequence_pda().0,
+        token_program: token::ID,
+        system_program: system_program::ID,
+    }
+}
+
+// Posição com recibo resgatada pelo `holder` do NFT; `staker` abriu a posição e recebe o
+// aluguel de volta. Recibos migrados do v1 não gravaram o staker: o aluguel vai para
+// `StakeReceipt::rent_recipient(holder)`, que deve substituir `rent_recipient`
+pub fn unstake_tokens_with_receipt_accounts(
+    holder: Pubkey,
+    staker: Pubkey,
+    token_mint: Pubkey,
+    receipt_mint: Pubkey,
+    reward_reserve_account: Pubkey,
+    with_boost: bool,
+) -> accounts::UnstakeTokens {
+    let mut accounts = unstake_tokens_accounts(staker, token_mint, reward_reserve_account, with_boost);
+    accounts.staker = holder;
+    accounts.staker_token_account = get_associated_token_address(&holder, &token_mint);
+    accounts.stake_receipt = Some(pda::find_stake_receipt_pda(&accounts.stake_account).0);
+    accounts.receipt_mint = Some(receipt_mint);
+    accounts.receipt_token_account = Some(get_associated_token_address(&holder, &receipt_mint));
+    accounts.receipt_metadata = Some(pda::find_nft_metadata_pda(&receipt_mint).0);
+    accounts.rent_recipient = Some(staker);
+    accounts
+}
+
+// Posição já resgatada antes de o unstake fechar a conta
+pub fn close_stake_accounts(staker: Pubkey, token_mint: Pubkey) -> accounts::CloseStake {
+    accounts::CloseStake {
+        staker,
+        token_mint,
+        stake_account: pda::find_stake_account_pda(&staker, &token_mint).0,
+        event_sequence: pda::find_event_sequence_pda().0,
+    }
+}
+
+// Mint do NFT de índice `count` (valor atual do NftCounter), sem tesouraria nem reserva
+// `nft_token_program`: Token, ou Token-2022 em coleções soulbound
+pub fn mint_nft_with_payment_accounts(
+    payer: Pubkey,
+    collection_metadata: Pubkey,
+    count: u64,
+    payment_token_mint: Pubkey,
+    nft_token_program: Pubkey,
+) -> accounts::MintNFTWithPayment {
+    let nft_mint = pda::find_nft_mint_pda(&collection_metadata, count).0;
+    accounts::MintNFTWithPayment {
+        payer,
+        nft_counter: pda::find_nft_counter_pda().0,
+        nft_mint,
+        nft_metadata: pda::find_nft_metadata_pda(&nft_mint).0,
+        nft_token_account: get_associated_token_address_with_program_id(
+            &payer,
+            &nft_mint,
+            &nft_token_program,
+        ),
+        collection_metadata,
+        collection_config: pda::find_collection_config_pda(&collection_metadata).0,
+        mint_record: pda::find_mint_record_pda(&collection_metadata, &payer).0,
+        payment_token_mint,
+        payer_payment_token_account: get_associated_token_address(&payer, &payment_token_mint),
+        treasury_token_account: None,
+        reward_reserve_account: None,
+        config: pda::find_config_pda().0,
+        event_sequence: pda::find_event_sequence_pda().0,
+        token_program: token::ID,
+        nft_token_program,
+        associated_token_program: associated_token::ID,
+        system_program: system_program::ID,
+        rent: anchor_lang::solana_program::sysvar::rent::ID,
+    }
+}
+
+// Lote a partir do índice `count` (valor atual do NftCounter), sem tesouraria nem reserva
+pub fn mint_batch_accounts(
+    payer: Pubkey,
+    collection_metadata: Pubkey,
+    payment_token_mint: Pubkey,
+) -> accounts::MintBatch {
+    accounts::MintBatch {
+        payer,
+        nft_counter: pda::find_nft_counter_pda().0,
+        collection_metadata,
+        collection_config: pda::find_collection_config_pda(&collection_metadata).0,
+        mint_record: pda::find_mint_record_pda(&collection_metadata, &payer).0,
+        payment_token_mint,
+        payer_payment_token_account: get_associated_token_address(&payer, &payment_token_mint),
+        treasury_token_account: None,
+        reward_reserve_account: None,
+        config: pda::find_config_pda().0,
+        event_sequence: pda::find_event_sequence_pda().0,
+        token_program: token::ID,
+        associated_token_program: associated_token::ID,
+        system_program: system_program::ID,
+    }
+}
+
+// remaining_accounts ([nft_mint, nft_metadata, nft_token_account]) e bumps dos NFTs de
+// índices `first_index..first_index + quantity`
+pub fn mint_batch_nft_accounts(
+    payer: Pubkey,
+    collection_metadata: Pubkey,
+    first_index: u64,
+    quantity: u8,
+) -> (Vec<AccountMeta>, Vec<NftBumps>) {
+    let mut remaining_accounts = Vec::with_capacity(quantity as usize * 3);
+    let mut bumps = Vec::with_capacity(quantity as usize);
+    for index in first_index..first_index + quantity as u64 {
+        let (nft_mint, nft_mint_bump) = pda::find_nft_mint_pda(&collection_metadata, index);
+        let (nft_metadata, nft_metadata_bump) = pda::find_nft_metadata_pda(&nft_mint);
+        remaining_accounts.push(AccountMeta::new(nft_mint, false));
+        remaining_accounts.push(AccountMeta::new(nft_metadata, false));
+        remaining_accounts.push(AccountMeta::new(get_associated_token_address(&payer, &nft_mint), false));
+        bumps.push(NftBumps { nft_mint: nft_mint_bump, nft_metadata: nft_metadata_bump });
+    }
+    (remaining_accounts, bumps)
+}
+
+// collection: coleção do NFT (`NFTMetadata.collection`); None para os metadados da própria coleção
+pub fn update_nft_metadata_accounts(
+    authority: Pubkey,
+    nft_metadata: Pubkey,
+    collection: Option<Pubkey>,
+) -> accounts::UpdateNftMetadata {
+    accounts::UpdateNftMetadata {
+        authority,
+        nft_metadata,
+        collection_config: pda::find_collection_config_pda(&collection.unwrap_or_default()).0,
+        event_sequence: pda::find_event_sequence_pda().0,
+    }
+}
+
+pub fn get_stake_summary_accounts(
+    staker: Pubkey,
+    token_mint: Pubkey,
+    stake_exists: bool,
+    with_boost: bool,
+) -> accounts::GetStakeSummary {
+    let stake_account = pda::find_stake_account_pda(&staker, &token_mint).0;
+    accounts::GetStakeSummary {
+        staker,
+        token_mint,
+        stake_account: stake_exists.then_some(stake_account),
+        stake_boost: (stake_exists && with_boost).then(|| pda::find_stake_boost_pda(&stake_account).0),
+        config: pda::find_config_pda().0,
+    }
+}
+
+pub fn get_config_summary_accounts() -> accounts::GetConfigSummary {
+    accounts::GetConfigSummary {
+        config: pda::find_config_pda().0,
+        event_sequence: pda::find_event_sequence_pda().0,
+    }
+}
+
+pub fn get_collection_info_accounts(collection_metadata: Pubkey) -> accounts::GetCollectionInfo {
+    accounts::GetCollectionInfo {
+        collection_metadata,
+        nft_counter: pda::find_nft_counter_pda().0,
+        collection_config: pda::find_collection_config_pda(&collection_metadata).0,
+    }
+}
+
+pub fn get_wallet_mint_count_accounts(
+    wallet: Pubkey,
+    collection_metadata: Pubkey,
+    record_exists: bool,
+) -> accounts::GetWalletMintCount {
+    accounts::GetWalletMintCount {
+        wallet,
+        collection_metadata,
+        collection_config: pda::find_collection_config_pda(&collection_metadata).0,
+        mint_record: record_exists.then(|| pda::find_mint_record_pda(&collection_metadata, &wallet).0),
+    }
+}
@@ -0,0 +1,83 @@
+// Cliente off-chain do programa adr_token_mint
+//
+// - `instructions`: builders tipados para cada instrução do programa
+// - `state`: leitura e decodificação das contas do programa
+// - `views`: simulação das instruções de leitura (get_*) e decodificação do return data
+// - `pda`: as mesmas funções find_*_pda usadas pelo programa
+//
+// O acesso à rede fica atrás do trait `ProgramRpc`, que pode ser implementado sobre
+// o RpcClient de um validador local, um banco em processo ou um mock em memória.
+
+use std::fmt;
+
+use anchor_lang::prelude::Pubkey;
+use anchor_lang::solana_program::instruction::Instruction;
+
+pub mod instructions;
+pub mod state;
+pub mod views;
+
+// Derivação de PDAs compartilhada com o programa
+pub mod pda {
+    pub use adr_token_mint::utils::{
+        find_allowlist_claim_pda, find_collection_config_pda, find_config_pda,
+        find_event_sequence_pda, find_mint_record_pda, find_nft_counter_pda,
+        find_nft_metadata_pda, find_nft_mint_pda, find_nft_reward_authority_pda,
+        find_nft_stake_pda, find_redemption_authority_pda, find_sol_treasury_pda, find_stake_account_pda,
+        find_stake_authority_pda, find_stake_boost_pda, find_stake_receipt_pda,
+    };
+}
+
+// Tipos do programa usados como argumentos e resultados
+pub use adr_token_mint::{
+    self as program, NftBumps, PaymentMode, PriceTier, PricingMode, StakingPeriod,
+    ID as PROGRAM_ID,
+};
+
+// Resultado de uma simulação de transação
+#[derive(Debug, Clone, Default)]
+pub struct SimulationResult {
+    pub logs: Vec<String>,
+    pub return_data: Option<Vec<u8>>,
+}
+
+// Acesso à rede independente do cliente RPC usado
+// A assinatura das transações é responsabilidade de quem implementa o trait
+pub trait ProgramRpc {
+    type Error: fmt::Display;
+
+    // Dados da conta, ou None se ela não existir
+    fn get_account_data(&self, address: &Pubkey) -> Result<Option<Vec<u8>>, Self::Error>;
+
+    // Envia as instruções em uma transação e retorna a assinatura
+    fn send_instructions(&self, instructions: &[Instruction]) -> Result<String, Self::Error>;
+
+    // Simula as instruções sem alterar estado
+    fn simulate_instructions(
+        &self,
+        instructions: &[Instruction],
+    ) -> Result<SimulationResult, Self::Error>;
+}
+
+#[derive(Debug)]
+pub enum ClientError<E> {
+    Rpc(E),
+    AccountNotFound(Pubkey),
+    Decode(anchor_lang::error::Error),
+    MissingReturnData,
+}
+
+impl<E: fmt::Display> fmt::Display for ClientError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ClientError::Rpc(err) => write!(f, "erro de RPC: {}", err),
+            ClientError::AccountNotFound(address) => write!(f, "conta {} não encontrada", address),
+            ClientError::Decode(err) => write!(f, "falha ao decodificar: {}", err),
+            ClientError::MissingReturnData => write!(f, "simulação não retornou dados"),
+        }
+    }
+}
+
+impl<E: fmt::Debug + fmt::Display> std::error::Error for ClientError<E> {}
+
+pub type ClientResult<T, E> = Result<T, ClientError<E>>;
@@ -0,0 +1,106 @@
+// Leitura e decodificação das contas do programa
+
+use anchor_lang::prelude::Pubkey;
+use anchor_lang::AccountDeserialize;
+
+use adr_token_mint::{
+    AllowlistClaim, CollectionConfig, ConfigAccount, EventSequence, MintRecord, NFTMetadata,
+    NftCounter, NftStakeAccount, StakeAccount, StakeBoost, StakeReceipt,
+};
+
+use crate::{pda, ClientError, ClientResult, ProgramRpc};
+
+// Decodifica uma conta Anchor verificando o discriminator
+pub fn decode_account<T: AccountDeserialize>(data: &[u8]) -> anchor_lang::Result<T> {
+    T::try_deserialize(&mut &data[..])
+}
+
+// Busca e decodifica uma conta; None se ela não existir
+pub fn fetch_account<T: AccountDeserialize, R: ProgramRpc>(
+    rpc: &R,
+    address: &Pubkey,
+) -> ClientResult<Option<T>, R::Error> {
+    let Some(data) = rpc.get_account_data(address).map_err(ClientError::Rpc)? else {
+        return Ok(None);
+    };
+    decode_account(&data).map(Some).map_err(ClientError::Decode)
+}
+
+// Busca uma conta que precisa existir
+pub fn fetch_required<T: AccountDeserialize, R: ProgramRpc>(
+    rpc: &R,
+    address: &Pubkey,
+) -> ClientResult<T, R::Error> {
+    fetch_account(rpc, address)?.ok_or(ClientError::AccountNotFound(*address))
+}
+
+pub fn fetch_config<R: ProgramRpc>(rpc: &R) -> ClientResult<ConfigAccount, R::Error> {
+    fetch_required(rpc, &pda::find_config_pda().0)
+}
+
+pub fn fetch_nft_counter<R: ProgramRpc>(rpc: &R) -> ClientResult<NftCounter, R::Error> {
+    fetch_required(rpc, &pda::find_nft_counter_pda().0)
+}
+
+pub fn fetch_event_sequence<R: ProgramRpc>(rpc: &R) -> ClientResult<EventSequence, R::Error> {
+    fetch_required(rpc, &pda::find_event_sequence_pda().0)
+}
+
+pub fn fetch_collection_config<R: ProgramRpc>(
+    rpc: &R,
+    collection: &Pubkey,
+) -> ClientResult<CollectionConfig, R::Error> {
+    fetch_required(rpc, &pda::find_collection_config_pda(collection).0)
+}
+
+pub fn fetch_nft_metadata<R: ProgramRpc>(
+    rpc: &R,
+    nft_mint: &Pubkey,
+) -> ClientResult<NFTMetadata, R::Error> {
+    fetch_required(rpc, &pda::find_nft_metadata_pda(nft_mint).0)
+}
+
+pub fn fetch_stake_account<R: ProgramRpc>(
+    rpc: &R,
+    staker: &Pubkey,
+    token_mint: &Pubkey,
+) -> ClientResult<Option<StakeAccount>, R::Error> {
+    fetch_account(rpc, &pda::find_stake_account_pda(staker, token_mint).0)
+}
+
+pub fn fetch_stake_boost<R: ProgramRpc>(
+    rpc: &R,
+    stake_account: &Pubkey,
+) -> ClientResult<Option<StakeBoost>, R::Error> {
+    fetch_account(rpc, &pda::find_stake_boost_pda(stake_account).0)
+}
+
+pub fn fetch_stake_receipt<R: ProgramRpc>(
+    rpc: &R,
+    stake_account: &Pubkey,
+) -> ClientResult<Option<StakeReceipt>, R::Error> {
+    fetch_account(rpc, &pda::find_stake_receipt_pda(stake_account).0)
+}
+
+pub fn fetch_nft_stake<R: ProgramRpc>(
+    rpc: &R,
+    nft_mint: &Pubkey,
+) -> ClientResult<Option<NftStakeAccount>, R::Error> {
+    fetch_account(rpc, &pda::find_nft_stake_pda(nft_mint).0)
+}
+
+pub fn fetch_mint_record<R: ProgramRpc>(
+    rpc: &R,
+    collection: &Pubkey,
+    wallet: &Pubkey,
+) -> ClientResult<Option<MintRecord>, R::Error> {
+    fetch_account(rpc, &pda::find_mint_record_pda(collection, wallet).0)
+}
+
+pub fn fetch_allowlist_claim<R: ProgramRpc>(
+    rpc: &R,
+    collection: &Pubkey,
+    wallet: &Pubkey,
+) -> ClientResult<Option<AllowlistClaim>, R::Error> {
+    fetch_account(rpc, &pda::find_allowlist_claim_pda(collection, wallet).0)
+}
@@ -0,0 +1,83 @@
+// Simulação das instruções de leitura e decodificação do return data
+
+use anchor_lang::prelude::Pubkey;
+use anchor_lang::solana_program::instruction::Instruction;
+use anchor_lang::AnchorDeserialize;
+
+use adr_token_mint::views::{CollectionInfo, ConfigSummary, MintQuote, StakingSummary, WalletMintCount};
+
+use crate::{instructions, state, ClientError, ClientResult, ProgramRpc};
+
+// Simula uma instrução de leitura e decodifica o valor retornado
+pub fn simulate_view<T: AnchorDeserialize, R: ProgramRpc>(
+    rpc: &R,
+    instruction: Instruction,
+) -> ClientResult<T, R::Error> {
+    let simulation = rpc
+        .simulate_instructions(&[instruction])
+        .map_err(ClientError::Rpc)?;
+    let data = simulation.return_data.ok_or(ClientError::MissingReturnData)?;
+    T::try_from_slice(&data).map_err(|err| ClientError::Decode(err.into()))
+}
+
+pub fn get_stake_summary<R: ProgramRpc>(
+    rpc: &R,
+    staker: Pubkey,
+    token_mint: Pubkey,
+) -> ClientResult<StakingSummary, R::Error> {
+    // Contas opcionais só são passadas quando existem
+    let stake_account = state::fetch_stake_account(rpc, &staker, &token_mint)?;
+    let stake_boost = match stake_account {
+        Some(_) => {
+            let address = crate::pda::find_stake_account_pda(&staker, &token_mint).0;
+            state::fetch_stake_boost(rpc, &address)?
+        }
+        None => None,
+    };
+    let accounts = instructions::get_stake_summary_accounts(
+        staker,
+        token_mint,
+        stake_account.is_some(),
+        stake_boost.is_some(),
+    );
+    simulate_view(rpc, instructions::get_stake_summary(accounts))
+}
+
+pub fn get_config_summary<R: ProgramRpc>(rpc: &R) -> ClientResult<ConfigSummary, R::Error> {
+    let accounts = instructions::get_config_summary_accounts();
+    simulate_view(rpc, instructions::get_config_summary(accounts))
+}
+
+pub fn get_collection_info<R: ProgramRpc>(
+    rpc: &R,
+    collection_metadata: Pubkey,
+) -> ClientResult<CollectionInfo, R::Error> {
+    let accounts = instructions::get_collection_info_accounts(collection_metadata);
+    simulate_view(rpc, instructions::get_collection_info(accounts))
+}
+
+// Preço atual e dos próximos `count` mints da coleção
+pub fn get_mint_quote<R: ProgramRpc>(
+    rpc: &R,
+    collection_metadata: Pubkey,
+    count: u64,
+) -> ClientResult<MintQuote, R::Error> {
+    let accounts = instructions::get_collection_info_accounts(collection_metadata);
+    simulate_view(rpc, instructions::get_mint_quote(accounts, count))
+}
+
+// Mints públicos da carteira na coleção e quantos ainda restam pelo limite por carteira
+pub fn get_wallet_mint_count<R: ProgramRpc>(
+    rpc: &R,
+    wallet: Pubkey,
+    collection_metadata: Pubkey,
+) -> ClientResult<WalletMintCount, R::Error> {
+    // O registro só é passado quando a carteira já mintou
+    let mint_record = state::fetch_mint_record(rpc, &collection_metadata, &wallet)?;
+    let accounts = instructions::get_wallet_mint_count_accounts(
+        wallet,
+        collection_metadata,
+        mint_record.is_some(),
+    );
+    simulate_view(rpc, instructions::get_wallet_mint_count(accounts))
+}
@@ -0,0 +1,401 @@
+use std::cell::RefCell;
+use std::collections::HashMap;
+
+use adr_token_mint::views::{MintQuote, StakingSummary, WalletMintCount};
+use adr_token_mint::{ConfigAccount, MintRecord, StakeAccount, ACCOUNT_VERSION};
+use adr_token_mint_client::{
+    instructions, pda, state, views, ClientError, ProgramRpc, SimulationResult, StakingPeriod,
+    PROGRAM_ID,
+};
+use anchor_lang::prelude::Pubkey;
+use anchor_lang::solana_program::instruction::Instruction;
+use anchor_lang::{AccountSerialize, AnchorSerialize, Discriminator};
+use anchor_spl::associated_token::get_associated_token_address;
+
+// RPC em memória: contas fixas e return data programado para simulações
+#[derive(Default)]
+struct MemoryRpc {
+    accounts: HashMap<Pubkey, Vec<u8>>,
+    return_data: Option<Vec<u8>>,
+    simulated: RefCell<Vec<Instruction>>,
+}
+
+impl MemoryRpc {
+    fn insert<T: AccountSerialize>(&mut self, address: Pubkey, account: &T) {
+        let mut data = Vec::new();
+        account.try_serialize(&mut data).unwrap();
+        self.accounts.insert(address, data);
+    }
+}
+
+impl ProgramRpc for MemoryRpc {
+    type Error = String;
+
+    fn get_account_data(&self, address: &Pubkey) -> Result<Option<Vec<u8>>, String> {
+        Ok(self.accounts.get(address).cloned())
+    }
+
+    fn send_instructions(&self, _instructions: &[Instruction]) -> Result<String, String> {
+        Err("envio não suportado".to_string())
+    }
+
+    fn simulate_instructions(&self, instructions: &[Instruction]) -> Result<SimulationResult, String> {
+        self.simulated.borrow_mut().extend_from_slice(instructions);
+        Ok(SimulationResult {
+            logs: Vec::new(),
+            return_data: self.return_data.clone(),
+        })
+    }
+}
+
+fn config_account(admin: Pubkey, payment_token_mint: Pubkey) -> ConfigAccount {
+    ConfigAccount {
+        version: ACCOUNT_VERSION,
+        payment_token_mint,
+        admin,
+        staking_enabled: true,
+        staking_reward_rate: 1000,
+        max_stake_amount: 1_000_000,
+        emergency_paused: false,
+        reward_reserve: Pubkey::new_unique(),
+        config_version: 3,
+        nft_holder_boost_bps: 1000,
+        total_staked: 0,
+        active_stakes: 0,
+        reserved: [0; 40],
+    }
+}
+
+#[test]
+fn stake_tokens_builder_encodes_discriminator_and_derived_accounts() {
+    let staker = Pubkey::new_unique();
+    let token_mint = Pubkey::new_unique();
+
+    let accounts = instructions::stake_tokens_accounts(staker, token_mint);
+    let ix = instructions::stake_tokens(accounts, 500, StakingPeriod::Minutes5, None);
+
+    assert_eq!(ix.program_id, PROGRAM_ID);
+    assert_eq!(&ix.data[..8], adr_token_mint::instruction::StakeTokens::DISCRIMINATOR);
+    assert_eq!(&ix.data[8..16], &500u64.to_le_bytes());
+
+    // staker assina e a posição é o PDA derivado pelo programa
+    assert_eq!(ix.accounts[0].pubkey, staker);
+    assert!(ix.accounts[0].is_signer);
+    let stake_account = pda::find_stake_account_pda(&staker, &token_mint).0;
+    assert!(ix.accounts.iter().any(|meta| meta.pubkey == stake_account && meta.is_writable));
+
+    // A configuração é sempre a PDA canônica
+    assert!(ix.accounts.iter().any(|meta| meta.pubkey == pda::find_config_pda().0));
+}
+
+#[test]
+fn stake_receipt_accounts_follow_the_receipt_mint() {
+    let staker = Pubkey::new_unique();
+    let holder = Pubkey::new_unique();
+    let token_mint = Pubkey::new_unique();
+    let receipt_mint = Pubkey::new_unique();
+    let stake_account = pda::find_stake_account_pda(&staker, &token_mint).0;
+    let stake_receipt = pda::find_stake_receipt_pda(&stake_account).0;
+    let receipt_metadata = pda::find_nft_metadata_pda(&receipt_mint).0;
+
+    let accounts = instructions::with_stake_receipt(
+        instructions::stake_tokens_accounts(staker, token_mint),
+        receipt_mint,
+    );
+    let ix = instructions::stake_tokens(
+        accounts,
+        500,
+        StakingPeriod::Minutes5,
+        Some("https://example.com/receipt.json".to_string()),
+    );
+    // O mint do recibo é um keypair novo e assina junto com o staker
+    assert!(ix.accounts.iter().any(|meta| meta.pubkey == receipt_mint && meta.is_signer));
+    assert!(ix.accounts.iter().any(|meta| meta.pubkey == stake_receipt));
+    assert!(ix.accounts.iter().any(|meta| meta.pubkey == receipt_metadata));
+    assert!(ix.accounts.iter().any(
+        |meta| meta.pubkey == get_associated_token_address(&staker, &receipt_mint)
+    ));
+    assert_eq!(ix.data[17], 1);
+
+    // No resgate o holder assina com a própria ATA do recibo e o aluguel volta ao staker
+    let accounts = instructions::unstake_tokens_with_receipt_accounts(
+        holder,
+        staker,
+        token_mint,
+        receipt_mint,
+        Pubkey::new_unique(),
+        false,
+    );
+    assert_eq!(accounts.stake_account, stake_account);
+    assert_eq!(accounts.stake_receipt, Some(stake_receipt));
+    assert_eq!(accounts.receipt_metadata, Some(receipt_metadata));
+    assert_eq!(
+        accounts.receipt_token_account,
+        Some(get_associated_token_address(&holder, &receipt_mint))
+    );
+    assert_eq!(accounts.rent_recipient, Some(staker));
+    let ix = instructions::unstake_tokens(accounts);
+    assert_eq!(ix.accounts[0].pubkey, holder);
+    assert!(ix.accounts[0].is_signer);
+}
+
+#[test]
+fn close_stake_targets_the_stakers_position_and_refunds_the_signer() {
+    let staker = Pubkey::new_unique();
+    let token_mint = Pubkey::new_unique();
+
+    let ix = instructions::close_stake(instructions::close_stake_accounts(staker, token_mint));
+    assert_eq!(&ix.data[..8], adr_token_mint::instruction::CloseStake::DISCRIMINATOR);
+
+    // O aluguel volta para quem assina, que também é a seed da posição
+    assert_eq!(ix.accounts[0].pubkey, staker);
+    assert!(ix.accounts[0].is_signer && ix.accounts[0].is_writable);
+    let stake_account = pda::find_stake_account_pda(&staker, &token_mint).0;
+    assert_eq!(ix.accounts[2].pubkey, stake_account);
+    assert!(ix.accounts[2].is_writable);
+}
+
+#[test]
+fn mint_batch_passes_each_nft_with_its_canonical_bumps() {
+    let payer = Pubkey::new_unique();
+    let collection = Pubkey::new_unique();
+    let payment_token_mint = Pubkey::new_unique();
+
+    let nfts = instructions::mint_batch_nft_accounts(payer, collection, 7, 2);
+    let ix = instructions::mint_batch(
+        instructions::mint_batch_accounts(payer, collection, payment_token_mint),
+        2,
+        "NFT".to_string(),
+        "NFT".to_string(),
+        "https://example.com".to_string(),
+        100,
+        nfts,
+    );
+    assert_eq!(&ix.data[..8], adr_token_mint::instruction::MintBatch::DISCRIMINATOR);
+
+    // As contas de cada NFT vêm depois das contas fixas, na ordem dos índices
+    let remaining = &ix.accounts[ix.accounts.len() - 6..];
+    for (offset, accounts) in remaining.chunks(3).enumerate() {
+        let (nft_mint, mint_bump) = pda::find_nft_mint_pda(&collection, 7 + offset as u64);
+        let (nft_metadata, metadata_bump) = pda::find_nft_metadata_pda(&nft_mint);
+        assert_eq!(accounts[0].pubkey, nft_mint);
+        assert_eq!(accounts[1].pubkey, nft_metadata);
+        assert!(accounts.iter().all(|meta| meta.is_writable && !meta.is_signer));
+
+        // Os bumps ficam no fim dos dados, dois bytes por NFT
+        let bumps_offset = ix.data.len() - 4 + offset * 2;
+        assert_eq!(ix.data[bumps_offset..bumps_offset + 2], [mint_bump, metadata_bump]);
+    }
+}
+
+#[test]
+fn update_nft_metadata_reads_the_collection_config_of_the_nfts_collection() {
+    let authority = Pubkey::new_unique();
+    let nft_metadata = Pubkey::new_unique();
+    let collection = Pubkey::new_unique();
+
+    let ix = instructions::update_nft_metadata(
+        instructions::update_nft_metadata_accounts(authority, nft_metadata, Some(collection)),
+        None,
+        None,
+        Some("https://new-uri.com".to_string()),
+    );
+    assert_eq!(&ix.data[..8], adr_token_mint::instruction::UpdateNftMetadata::DISCRIMINATOR);
+    // Somente leitura: serve para conferir se o reveal da coleção está pendente
+    assert_eq!(ix.accounts[2].pubkey, pda::find_collection_config_pda(&collection).0);
+    assert!(!ix.accounts[2].is_writable);
+
+    // Metadados da própria coleção apontam para a PDA da chave padrão, que não existe
+    let ix = instructions::lock_metadata(
+        instructions::update_nft_metadata_accounts(authority, collection, None),
+    );
+    assert_eq!(ix.accounts[2].pubkey, pda::find_collection_config_pda(&Pubkey::default()).0);
+}
+
+#[test]
+fn redemption_pool_is_the_payment_ata_of_the_collection_authority() {
+    let admin = Pubkey::new_unique();
+    let collection = Pubkey::new_unique();
+    let payment_token_mint = Pubkey::new_unique();
+
+    let accounts = instructions::deposit_redemption_pool_accounts(admin, collection, payment_token_mint);
+    let redemption_authority = pda::find_redemption_authority_pda(&collection).0;
+    assert_eq!(accounts.redemption_authority, redemption_authority);
+    assert_eq!(
+        accounts.redemption_pool,
+        get_associated_token_address(&redemption_authority, &payment_token_mint)
+    );
+    assert_eq!(accounts.admin_token_account, get_associated_token_address(&admin, &payment_token_mint));
+
+    let ix = instructions::deposit_redemption_pool(accounts, 500);
+    assert_eq!(&ix.data[..8], adr_token_mint::instruction::DepositRedemptionPool::DISCRIMINATOR);
+    assert_eq!(u64::from_le_bytes(ix.data[8..16].try_into().unwrap()), 500);
+}
+
+#[test]
+fn nft_reward_pool_is_separate_from_the_reward_reserve() {
+    let admin = Pubkey::new_unique();
+    let collection = Pubkey::new_unique();
+    let payment_token_mint = Pubkey::new_unique();
+
+    let accounts = instructions::deposit_nft_reward_pool_accounts(admin, collection, payment_token_mint);
+    let nft_reward_authority = pda::find_nft_reward_authority_pda(&collection).0;
+    assert_eq!(accounts.nft_reward_authority, nft_reward_authority);
+    assert_eq!(
+        accounts.nft_reward_pool,
+        get_associated_token_address(&nft_reward_authority, &payment_token_mint)
+    );
+    // A reserva de stake_tokens guarda o principal dos stakers; a pool é outra conta
+    assert_ne!(
+        accounts.nft_reward_pool,
+        get_associated_token_address(&pda::find_stake_authority_pda().0, &payment_token_mint)
+    );
+
+    let ix = instructions::deposit_nft_reward_pool(accounts, 700);
+    assert_eq!(&ix.data[..8], adr_token_mint::instruction::DepositNftRewardPool::DISCRIMINATOR);
+    assert_eq!(u64::from_le_bytes(ix.data[8..16].try_into().unwrap()), 700);
+}
+
+#[test]
+fn optional_accounts_are_encoded_as_program_id() {
+    let staker = Pubkey::new_unique();
+    let accounts = instructions::unstake_tokens_accounts(
+        staker,
+        Pubkey::new_unique(),
+        Pubkey::new_unique(),
+        false,
+    );
+    let ix = instructions::unstake_tokens(accounts);
+
+    // Contas opcionais ausentes viram o ID do programa, como no cliente TS
+    let placeholders = ix.accounts.iter().filter(|meta| meta.pubkey == PROGRAM_ID).count();
+    assert_eq!(placeholders, 6);
+}
+
+#[test]
+fn fetch_decodes_accounts_and_reports_missing_ones() {
+    let mut rpc = MemoryRpc::default();
+    let config = pda::find_config_pda().0;
+    match state::fetch_config(&rpc) {
+        Err(ClientError::AccountNotFound(address)) => assert_eq!(address, config),
+        other => panic!("esperado AccountNotFound, veio {:?}", other.map(|c| c.admin)),
+    }
+
+    let admin = Pubkey::new_unique();
+    rpc.insert(config, &config_account(admin, Pubkey::new_unique()));
+    let fetched = state::fetch_config(&rpc).unwrap();
+    assert_eq!(fetched.admin, admin);
+    assert_eq!(fetched.staking_reward_rate, 1000);
+
+    // Dados de outra conta falham na verificação do discriminator
+    rpc.accounts.insert(config, vec![0u8; 64]);
+    assert!(matches!(state::fetch_config(&rpc), Err(ClientError::Decode(_))));
+}
+
+#[test]
+fn stake_summary_view_passes_existing_position_and_decodes_return_data() {
+    let staker = Pubkey::new_unique();
+    let token_mint = Pubkey::new_unique();
+
+    let summary = StakingSummary {
+        is_staking: true,
+        amount: 500,
+        start_time: 10,
+        unlock_time: 310,
+        period: StakingPeriod::Minutes5,
+        claimed: false,
+        can_unstake: false,
+        estimated_reward: 60,
+        time_remaining: 300,
+    };
+    let mut rpc = MemoryRpc {
+        return_data: Some(summary.try_to_vec().unwrap()),
+        ..Default::default()
+    };
+    let stake_account = pda::find_stake_account_pda(&staker, &token_mint).0;
+    rpc.insert(
+        stake_account,
+        &StakeAccount {
+            version: ACCOUNT_VERSION,
+            owner: staker,
+            amount: 500,
+            start_time: 10,
+            unlock_time: 310,
+            period: StakingPeriod::Minutes5,
+            claimed: false,
+            reserved: [0; 32],
+        },
+    );
+
+    let decoded = views::get_stake_summary(&rpc, staker, token_mint).unwrap();
+    assert_eq!(decoded.amount, 500);
+    assert_eq!(decoded.estimated_reward, 60);
+
+    // A posição existe e o bônus não, então só a posição é passada
+    let simulated = rpc.simulated.borrow();
+    let metas = &simulated[0].accounts;
+    assert_eq!(metas[2].pubkey, stake_account);
+    assert_eq!(metas[3].pubkey, PROGRAM_ID);
+}
+
+#[test]
+fn mint_quote_view_reuses_collection_info_accounts() {
+    let collection = Pubkey::new_unique();
+    let quote = MintQuote {
+        current_price: 1_000,
+        next_prices: vec![1_000, 1_100],
+    };
+    let rpc = MemoryRpc {
+        return_data: Some(quote.try_to_vec().unwrap()),
+        ..Default::default()
+    };
+
+    let decoded = views::get_mint_quote(&rpc, collection, 2).unwrap();
+    assert_eq!(decoded.current_price, 1_000);
+    assert_eq!(decoded.next_prices, vec![1_000, 1_100]);
+
+    let simulated = rpc.simulated.borrow();
+    assert_eq!(&simulated[0].data[..8], adr_token_mint::instruction::GetMintQuote::DISCRIMINATOR);
+    assert_eq!(u64::from_le_bytes(simulated[0].data[8..16].try_into().unwrap()), 2);
+    assert_eq!(simulated[0].accounts[2].pubkey, pda::find_collection_config_pda(&collection).0);
+}
+
+#[test]
+fn wallet_mint_count_view_passes_the_record_only_when_it_exists() {
+    let wallet = Pubkey::new_unique();
+    let collection = Pubkey::new_unique();
+    let mint_record = pda::find_mint_record_pda(&collection, &wallet).0;
+    let mut rpc = MemoryRpc {
+        return_data: Some(WalletMintCount { count: 0, remaining: u64::MAX }.try_to_vec().unwrap()),
+        ..Default::default()
+    };
+
+    // Carteira que ainda não mintou: o registro opcional vai como o ID do programa
+    let decoded = views::get_wallet_mint_count(&rpc, wallet, collection).unwrap();
+    assert_eq!(decoded.remaining, u64::MAX);
+    assert_eq!(rpc.simulated.borrow()[0].accounts[3].pubkey, PROGRAM_ID);
+
+    rpc.insert(
+        mint_record,
+        &MintRecord {
+            version: ACCOUNT_VERSION,
+            collection,
+            wallet,
+            count: 2,
+            reserved: [0; 16],
+        },
+    );
+    views::get_wallet_mint_count(&rpc, wallet, collection).unwrap();
+    let simulated = rpc.simulated.borrow();
+    assert_eq!(&simulated[1].data[..8], adr_token_mint::instruction::GetWalletMintCount::DISCRIMINATOR);
+    assert_eq!(simulated[1].accounts[3].pubkey, mint_record);
+}
+
+#[test]
+fn view_without_return_data_is_an_error() {
+    let rpc = MemoryRpc::default();
+    assert!(matches!(
+        views::get_config_summary(&rpc),
+        Err(ClientError::MissingReturnData)
+    ));
+}
@@ -0,0 +1,634 @@
+// Decodificação dos eventos Anchor a partir dos logs de uma transação
+//
+// `emit!` grava cada evento em uma linha "Program data: <base64>", onde os 8 primeiros
+// bytes são o discriminator do evento. Só são considerados os logs emitidos enquanto o
+// programa adr_token_mint está no topo da pilha de invocações, para ignorar dados de
+// outros programas chamados via CPI.
+//
+// Todo evento do programa começa com `event_seq: u64`, então o número na sequência global
+// é lido logo após o discriminator, inclusive de eventos que o indexador não decodifica
+// (os de versões mais novas do programa).
+
+use adr_token_mint::{
+    BoostNftLockedEvent, BoostNftUnlockedEvent, CollectionField, CollectionInitializedEvent,
+    CollectionUpdateEvent, ConfigField, ConfigMigratedEvent, ConfigUpdateEvent, DelegateApprovedEvent,
+    EmergencyPauseEvent, NftBurnEvent, NftMetadataLockEvent, NftMetadataUpdateEvent,
+    NftRewardPoolDepositEvent, NftStakingEvent, NftUnstakingEvent, NftsRevealedEvent, PaymentMode,
+    PriceTier, PricingMode, RedemptionPoolDepositEvent, RevealCommitEvent, RewardReserveDepositEvent,
+    SolTreasuryWithdrawEvent, StakeClosedEvent, StakeReceiptIssuedEvent, StakeTopUpEvent, StakingEvent,
+    StakingPeriod, TokenBurnEvent, UnstakingEvent,
+};
+use anchor_lang::prelude::Pubkey;
+use anchor_lang::{AnchorDeserialize, Discriminator};
+use base64::engine::general_purpose::STANDARD as BASE64;
+use base64::Engine;
+use serde_json::{json, Value};
+
+const PROGRAM_DATA_PREFIX: &str = "Program data: ";
+
+pub enum ProgramEvent {
+    TokenBurn(TokenBurnEvent),
+    Staking(StakingEvent),
+    Unstaking(UnstakingEvent),
+    ConfigUpdate(ConfigUpdateEvent),
+    EmergencyPause(EmergencyPauseEvent),
+    CollectionInitialized(CollectionInitializedEvent),
+    RewardReserveDeposit(RewardReserveDepositEvent),
+    DelegateApproved(DelegateApprovedEvent),
+    StakeTopUp(StakeTopUpEvent),
+    StakeClosed(StakeClosedEvent),
+    ConfigMigrated(ConfigMigratedEvent),
+    CollectionUpdate(CollectionUpdateEvent),
+    SolTreasuryWithdraw(SolTreasuryWithdrawEvent),
+    NftBurn(NftBurnEvent),
+    RedemptionPoolDeposit(RedemptionPoolDepositEvent),
+    NftRewardPoolDeposit(NftRewardPoolDepositEvent),
+    NftMetadataUpdate(NftMetadataUpdateEvent),
+    NftMetadataLock(NftMetadataLockEvent),
+    RevealCommit(RevealCommitEvent),
+    NftsRevealed(NftsRevealedEvent),
+    NftStaking(NftStakingEvent),
+    NftUnstaking(NftUnstakingEvent),
+    BoostNftLocked(BoostNftLockedEvent),
+    BoostNftUnlocked(BoostNftUnlockedEvent),
+    StakeReceiptIssued(StakeReceiptIssuedEvent),
+}
+
+impl ProgramEvent {
+    // Decodifica o payload de um "Program data:"; None para eventos desconhecidos
+    pub fn decode(data: &[u8]) -> Option<Self> {
+        if data.len() < 8 {
+            return None;
+        }
+        let (discriminator, mut payload) = data.split_at(8);
+        let event = match discriminator {
+            d if d == TokenBurnEvent::DISCRIMINATOR => {
+                ProgramEvent::TokenBurn(TokenBurnEvent::deserialize(&mut payload).ok()?)
+            }
+            d if d == StakingEvent::DISCRIMINATOR => {
+                ProgramEvent::Staking(StakingEvent::deserialize(&mut payload).ok()?)
+            }
+            d if d == UnstakingEvent::DISCRIMINATOR => {
+                ProgramEvent::Unstaking(UnstakingEvent::deserialize(&mut payload).ok()?)
+            }
+            d if d == ConfigUpdateEvent::DISCRIMINATOR => {
+                ProgramEvent::ConfigUpdate(ConfigUpdateEvent::deserialize(&mut payload).ok()?)
+            }
+            d if d == EmergencyPauseEvent::DISCRIMINATOR => {
+                ProgramEvent::EmergencyPause(EmergencyPauseEvent::deserialize(&mut payload).ok()?)
+            }
+            d if d == CollectionInitializedEvent::DISCRIMINATOR => ProgramEvent::CollectionInitialized(
+                CollectionInitializedEvent::deserialize(&mut payload).ok()?,
+            ),
+            d if d == RewardReserveDepositEvent::DISCRIMINATOR => ProgramEvent::RewardReserveDeposit(
+                RewardReserveDepositEvent::deserialize(&mut payload).ok()?,
+            ),
+            d if d == DelegateApprovedEvent::DISCRIMINATOR => {
+                ProgramEvent::DelegateApproved(DelegateApprovedEvent::deserialize(&mut payload).ok()?)
+            }
+            d if d == StakeTopUpEvent::DISCRIMINATOR => {
+                ProgramEvent::StakeTopUp(StakeTopUpEvent::deserialize(&mut payload).ok()?)
+            }
+            d if d == StakeClosedEvent::DISCRIMINATOR => {
+                ProgramEvent::StakeClosed(StakeClosedEvent::deserialize(&mut payload).ok()?)
+            }
+            d if d == ConfigMigratedEvent::DISCRIMINATOR => {
+                ProgramEvent::ConfigMigrated(ConfigMigratedEvent::deserialize(&mut payload).ok()?)
+            }
+            d if d == CollectionUpdateEvent::DISCRIMINATOR => ProgramEvent::CollectionUpdate(
+                CollectionUpdateEvent::deserialize(&mut payload).ok()?,
+            ),
+            d if d == SolTreasuryWithdrawEvent::DISCRIMINATOR => ProgramEvent::SolTreasuryWithdraw(
+                SolTreasuryWithdrawEvent::deserialize(&mut payload).ok()?,
+            ),
+            d if d == NftBurnEvent::DISCRIMINATOR => {
+                ProgramEvent::NftBurn(NftBurnEvent::deserialize(&mut payload).ok()?)
+            }
+            d if d == RedemptionPoolDepositEvent::DISCRIMINATOR => ProgramEvent::RedemptionPoolDeposit(
+                RedemptionPoolDepositEvent::deserialize(&mut payload).ok()?,
+            ),
+            d if d == NftRewardPoolDepositEvent::DISCRIMINATOR => ProgramEvent::NftRewardPoolDeposit(
+                NftRewardPoolDepositEvent::deserialize(&mut payload).ok()?,
+            ),
+            d if d == NftMetadataUpdateEvent::DISCRIMINATOR => ProgramEvent::NftMetadataUpdate(
+                NftMetadataUpdateEvent::deserialize(&mut payload).ok()?,
+            ),
+            d if d == NftMetadataLockEvent::DISCRIMINATOR => {
+                ProgramEvent::NftMetadataLock(NftMetadataLockEvent::deserialize(&mut payload).ok()?)
+            }
+            d if d == RevealCommitEvent::DISCRIMINATOR => {
+                ProgramEvent::RevealCommit(RevealCommitEvent::deserialize(&mut payload).ok()?)
+            }
+            d if d == NftsRevealedEvent::DISCRIMINATOR => {
+                ProgramEvent::NftsRevealed(NftsRevealedEvent::deserialize(&mut payload).ok()?)
+            }
+            d if d == NftStakingEvent::DISCRIMINATOR => {
+                ProgramEvent::NftStaking(NftStakingEvent::deserialize(&mut payload).ok()?)
+            }
+            d if d == NftUnstakingEvent::DISCRIMINATOR => {
+                ProgramEvent::NftUnstaking(NftUnstakingEvent::deserialize(&mut payload).ok()?)
+            }
+            d if d == BoostNftLockedEvent::DISCRIMINATOR => {
+                ProgramEvent::BoostNftLocked(BoostNftLockedEvent::deserialize(&mut payload).ok()?)
+            }
+            d if d == BoostNftUnlockedEvent::DISCRIMINATOR => ProgramEvent::BoostNftUnlocked(
+                BoostNftUnlockedEvent::deserialize(&mut payload).ok()?,
+            ),
+            d if d == StakeReceiptIssuedEvent::DISCRIMINATOR => ProgramEvent::StakeReceiptIssued(
+                StakeReceiptIssuedEvent::deserialize(&mut payload).ok()?,
+            ),
+            _ => return None,
+        };
+        Some(event)
+    }
+
+    pub fn event_seq(&self) -> u64 {
+        match self {
+            ProgramEvent::TokenBurn(event) => event.event_seq,
+            ProgramEvent::Staking(event) => event.event_seq,
+            ProgramEvent::Unstaking(event) => event.event_seq,
+            ProgramEvent::ConfigUpdate(event) => event.event_seq,
+            ProgramEvent::EmergencyPause(event) => event.event_seq,
+            ProgramEvent::CollectionInitialized(event) => event.event_seq,
+            ProgramEvent::RewardReserveDeposit(event) => event.event_seq,
+            ProgramEvent::DelegateApproved(event) => event.event_seq,
+            ProgramEvent::StakeTopUp(event) => event.event_seq,
+            ProgramEvent::StakeClosed(event) => event.event_seq,
+            ProgramEvent::ConfigMigrated(event) => event.event_seq,
+            ProgramEvent::CollectionUpdate(event) => event.event_seq,
+            ProgramEvent::SolTreasuryWithdraw(event) => event.event_seq,
+            ProgramEvent::NftBurn(event) => event.event_seq,
+            ProgramEvent::RedemptionPoolDeposit(event) => event.event_seq,
+            ProgramEvent::NftRewardPoolDeposit(event) => event.event_seq,
+            ProgramEvent::NftMetadataUpdate(event) => event.event_seq,
+            ProgramEvent::NftMetadataLock(event) => event.event_seq,
+            ProgramEvent::RevealCommit(event) => event.event_seq,
+            ProgramEvent::NftsRevealed(event) => event.event_seq,
+            ProgramEvent::NftStaking(event) => event.event_seq,
+            ProgramEvent::NftUnstaking(event) => event.event_seq,
+            ProgramEvent::BoostNftLocked(event) => event.event_seq,
+            ProgramEvent::BoostNftUnlocked(event) => event.event_seq,
+            ProgramEvent::StakeReceiptIssued(event) => event.event_seq,
+        }
+    }
+
+    pub fn kind(&self) -> &'static str {
+        match self {
+            ProgramEvent::TokenBurn(_) => "TokenBurnEvent",
+            ProgramEvent::Staking(_) => "StakingEvent",
+            ProgramEvent::Unstaking(_) => "UnstakingEvent",
+            ProgramEvent::ConfigUpdate(_) => "ConfigUpdateEvent",
+            ProgramEvent::EmergencyPause(_) => "EmergencyPauseEvent",
+            ProgramEvent::CollectionInitialized(_) => "CollectionInitializedEvent",
+            ProgramEvent::RewardReserveDeposit(_) => "RewardReserveDepositEvent",
+            ProgramEvent::DelegateApproved(_) => "DelegateApprovedEvent",
+            ProgramEvent::StakeTopUp(_) => "StakeTopUpEvent",
+            ProgramEvent::StakeClosed(_) => "StakeClosedEvent",
+            ProgramEvent::ConfigMigrated(_) => "ConfigMigratedEvent",
+            ProgramEvent::CollectionUpdate(_) => "CollectionUpdateEvent",
+            ProgramEvent::SolTreasuryWithdraw(_) => "SolTreasuryWithdrawEvent",
+            ProgramEvent::NftBurn(_) => "NftBurnEvent",
+            ProgramEvent::RedemptionPoolDeposit(_) => "RedemptionPoolDepositEvent",
+            ProgramEvent::NftRewardPoolDeposit(_) => "NftRewardPoolDepositEvent",
+            ProgramEvent::NftMetadataUpdate(_) => "NftMetadataUpdateEvent",
+            ProgramEvent::NftMetadataLock(_) => "NftMetadataLockEvent",
+            ProgramEvent::RevealCommit(_) => "RevealCommitEvent",
+            ProgramEvent::NftsRevealed(_) => "NftsRevealedEvent",
+            ProgramEvent::NftStaking(_) => "NftStakingEvent",
+            ProgramEvent::NftUnstaking(_) => "NftUnstakingEvent",
+            ProgramEvent::BoostNftLocked(_) => "BoostNftLockedEvent",
+            ProgramEvent::BoostNftUnlocked(_) => "BoostNftUnlockedEvent",
+            ProgramEvent::StakeReceiptIssued(_) => "StakeReceiptIssuedEvent",
+        }
+    }
+
+    // Carteira que originou o evento (staker, pagador, dono ou admin)
+    pub fn wallet(&self) -> Pubkey {
+        match self {
+            ProgramEvent::TokenBurn(event) => event.payer,
+            ProgramEvent::Staking(event) => event.staker,
+            ProgramEvent::Unstaking(event) => event.staker,
+            ProgramEvent::ConfigUpdate(event) => event.admin,
+            ProgramEvent::EmergencyPause(event) => event.admin,
+            ProgramEvent::CollectionInitialized(event) => event.admin,
+            ProgramEvent::RewardReserveDeposit(event) => event.admin,
+            ProgramEvent::DelegateApproved(event) => event.owner,
+            ProgramEvent::StakeTopUp(event) => event.staker,
+            ProgramEvent::StakeClosed(event) => event.staker,
+            ProgramEvent::ConfigMigrated(event) => event.admin,
+            ProgramEvent::CollectionUpdate(event) => event.admin,
+            ProgramEvent::SolTreasuryWithdraw(event) => event.admin,
+            ProgramEvent::NftBurn(event) => event.holder,
+            ProgramEvent::RedemptionPoolDeposit(event) => event.admin,
+            ProgramEvent::NftRewardPoolDeposit(event) => event.admin,
+            ProgramEvent::NftMetadataUpdate(event) => event.authority,
+            ProgramEvent::NftMetadataLock(event) => event.authority,
+            ProgramEvent::RevealCommit(event) => event.admin,
+            ProgramEvent::NftsRevealed(event) => event.admin,
+            ProgramEvent::NftStaking(event) => event.staker,
+            ProgramEvent::NftUnstaking(event) => event.staker,
+            ProgramEvent::BoostNftLocked(event) => event.staker,
+            ProgramEvent::BoostNftUnlocked(event) => event.staker,
+            ProgramEvent::StakeReceiptIssued(event) => event.staker,
+        }
+    }
+
+    pub fn nft_mint(&self) -> Option<Pubkey> {
+        match self {
+            ProgramEvent::TokenBurn(event) => Some(event.nft_mint),
+            ProgramEvent::NftBurn(event) => Some(event.nft_mint),
+            ProgramEvent::NftStaking(event) => Some(event.nft_mint),
+            ProgramEvent::NftUnstaking(event) => Some(event.nft_mint),
+            ProgramEvent::BoostNftLocked(event) => Some(event.nft_mint),
+            ProgramEvent::BoostNftUnlocked(event) => Some(event.nft_mint),
+            ProgramEvent::StakeReceiptIssued(event) => Some(event.receipt_mint),
+            _ => None,
+        }
+    }
+
+    // StakingEvent não tem timestamp próprio; o início do stake faz esse papel
+    pub fn timestamp(&self) -> i64 {
+        match self {
+            ProgramEvent::TokenBurn(event) => event.timestamp,
+            ProgramEvent::Staking(event) => event.start_time,
+            ProgramEvent::Unstaking(event) => event.timestamp,
+            ProgramEvent::ConfigUpdate(event) => event.timestamp,
+            ProgramEvent::EmergencyPause(event) => event.timestamp,
+            ProgramEvent::CollectionInitialized(event) => event.timestamp,
+            ProgramEvent::RewardReserveDeposit(event) => event.timestamp,
+            ProgramEvent::DelegateApproved(event) => event.timestamp,
+            ProgramEvent::StakeTopUp(event) => event.timestamp,
+            ProgramEvent::StakeClosed(event) => event.timestamp,
+            ProgramEvent::ConfigMigrated(event) => event.timestamp,
+            ProgramEvent::CollectionUpdate(event) => event.timestamp,
+            ProgramEvent::SolTreasuryWithdraw(event) => event.timestamp,
+            ProgramEvent::NftBurn(event) => event.timestamp,
+            ProgramEvent::RedemptionPoolDeposit(event) => event.timestamp,
+            ProgramEvent::NftRewardPoolDeposit(event) => event.timestamp,
+            ProgramEvent::NftMetadataUpdate(event) => event.timestamp,
+            ProgramEvent::NftMetadataLock(event) => event.timestamp,
+            ProgramEvent::RevealCommit(event) => event.timestamp,
+            ProgramEvent::NftsRevealed(event) => event.timestamp,
+            ProgramEvent::NftStaking(event) => event.timestamp,
+            ProgramEvent::NftUnstaking(event) => event.timestamp,
+            ProgramEvent::BoostNftLocked(event) => event.timestamp,
+            ProgramEvent::BoostNftUnlocked(event) => event.timestamp,
+            ProgramEvent::StakeReceiptIssued(event) => event.timestamp,
+        }
+    }
+
+    // Campos do evento em JSON (pubkeys em base58)
+    pub fn to_json(&self) -> Value {
+        match self {
+            ProgramEvent::TokenBurn(event) => json!({
+                "payer": event.payer.to_string(),
+                "token_mint": event.token_mint.to_string(),
+                "amount": event.amount,
+                "nft_mint": event.nft_mint.to_string(),
+                "timestamp": event.timestamp,
+                "price": event.price,
+                "treasury_amount": event.treasury_amount,
+                "reserve_amount": event.reserve_amount,
+                "payment_type": payment_mode_name(event.payment_type),
+                "burn_amount": event.burn_amount,
+            }),
+            ProgramEvent::Staking(event) => json!({
+                "staker": event.staker.to_string(),
+                "amount": event.amount,
+                "period": period_name(event.period),
+                "start_time": event.start_time,
+                "unlock_time": event.unlock_time,
+                "stake_account": event.stake_account.to_string(),
+            }),
+            ProgramEvent::Unstaking(event) => json!({
+                "staker": event.staker.to_string(),
+                "stake_account": event.stake_account.to_string(),
+                "original_amount": event.original_amount,
+                "reward_amount": event.reward_amount,
+                "total_amount": event.total_amount,
+                "timestamp": event.timestamp,
+            }),
+            ProgramEvent::ConfigUpdate(event) => {
+                let (field, old, new) = config_change(&event.change);
+                json!({
+                    "admin": event.admin.to_string(),
+                    "field": field,
+                    "old": old,
+                    "new": new,
+                    "config_version": event.config_version,
+                    "timestamp": event.timestamp,
+                })
+            }
+            ProgramEvent::EmergencyPause(event) => json!({
+                "admin": event.admin.to_string(),
+                "paused": event.paused,
+                "reason": event.reason,
+                "timestamp": event.timestamp,
+            }),
+            ProgramEvent::CollectionInitialized(event) => json!({
+                "admin": event.admin.to_string(),
+                "collection": event.collection.to_string(),
+                "collection_mint": event.collection_mint.to_string(),
+                "config": event.config.to_string(),
+                "name": event.name,
+                "symbol": event.symbol,
+                "uri": event.uri,
+                "timestamp": event.timestamp,
+            }),
+            ProgramEvent::RewardReserveDeposit(event) => json!({
+                "admin": event.admin.to_string(),
+                "reward_reserve": event.reward_reserve.to_string(),
+                "amount": event.amount,
+                "old_balance": event.old_balance,
+                "new_balance": event.new_balance,
+                "timestamp": event.timestamp,
+            }),
+            ProgramEvent::DelegateApproved(event) => json!({
+                "owner": event.owner.to_string(),
+                "token_account": event.token_account.to_string(),
+                "old_delegate": event.old_delegate.map(|delegate| delegate.to_string()),
+                "old_delegated_amount": event.old_delegated_amount,
+                "new_delegate": event.new_delegate.to_string(),
+                "new_delegated_amount": event.new_delegated_amount,
+                "timestamp": event.timestamp,
+            }),
+            ProgramEvent::StakeTopUp(event) => json!({
+                "staker": event.staker.to_string(),
+                "stake_account": event.stake_account.to_string(),
+                "old_amount": event.old_amount,
+                "added_amount": event.added_amount,
+                "new_amount": event.new_amount,
+                "old_period": period_name(event.old_period),
+                "new_period": period_name(event.new_period),
+                "old_unlock_time": event.old_unlock_time,
+                "new_unlock_time": event.new_unlock_time,
+                "timestamp": event.timestamp,
+            }),
+            ProgramEvent::StakeClosed(event) => json!({
+                "staker": event.staker.to_string(),
+                "stake_account": event.stake_account.to_string(),
+                "timestamp": event.timestamp,
+            }),
+            ProgramEvent::ConfigMigrated(event) => json!({
+                "admin": event.admin.to_string(),
+                "legacy_config": event.legacy_config.to_string(),
+                "config": event.config.to_string(),
+                "config_version": event.config_version,
+                "timestamp": event.timestamp,
+            }),
+            ProgramEvent::CollectionUpdate(event) => {
+                let (field, old, new) = collection_change(&event.change);
+                json!({
+                    "admin": event.admin.to_string(),
+                    "collection": event.collection.to_string(),
+                    "field": field,
+                    "old": old,
+                    "new": new,
+                    "timestamp": event.timestamp,
+                })
+            }
+            ProgramEvent::SolTreasuryWithdraw(event) => json!({
+                "admin": event.admin.to_string(),
+                "collection": event.collection.to_string(),
+                "sol_treasury": event.sol_treasury.to_string(),
+                "destination": event.destination.to_string(),
+                "amount": event.amount,
+                "old_balance": event.old_balance,
+                "new_balance": event.new_balance,
+                "timestamp": event.timestamp,
+            }),
+            ProgramEvent::NftBurn(event) => json!({
+                "holder": event.holder.to_string(),
+                "nft_mint": event.nft_mint.to_string(),
+                "collection": event.collection.to_string(),
+                "refund_amount": event.refund_amount,
+                "live_supply": event.live_supply,
+                "timestamp": event.timestamp,
+            }),
+            ProgramEvent::RedemptionPoolDeposit(event) => json!({
+                "admin": event.admin.to_string(),
+                "collection": event.collection.to_string(),
+                "redemption_pool": event.redemption_pool.to_string(),
+                "amount": event.amount,
+                "new_balance": event.new_balance,
+                "timestamp": event.timestamp,
+            }),
+            ProgramEvent::NftRewardPoolDeposit(event) => json!({
+                "admin": event.admin.to_string(),
+                "collection": event.collection.to_string(),
+                "nft_reward_pool": event.nft_reward_pool.to_string(),
+                "amount": event.amount,
+                "new_balance": event.new_balance,
+                "timestamp": event.timestamp,
+            }),
+            ProgramEvent::NftMetadataUpdate(event) => json!({
+                "nft_metadata": event.nft_metadata.to_string(),
+                "authority": event.authority.to_string(),
+                "name": event.name,
+                "symbol": event.symbol,
+                "uri": event.uri,
+                "timestamp": event.timestamp,
+            }),
+            ProgramEvent::NftMetadataLock(event) => json!({
+                "nft_metadata": event.nft_metadata.to_string(),
+                "authority": event.authority.to_string(),
+                "timestamp": event.timestamp,
+            }),
+            ProgramEvent::RevealCommit(event) => json!({
+                "admin": event.admin.to_string(),
+                "collection": event.collection.to_string(),
+                "base_uri": event.base_uri,
+                "provenance_hash": hex(&event.provenance_hash),
+                "timestamp": event.timestamp,
+            }),
+            ProgramEvent::NftsRevealed(event) => json!({
+                "admin": event.admin.to_string(),
+                "collection": event.collection.to_string(),
+                "revealed": event.revealed,
+                "skipped": event.skipped.iter().map(|key| key.to_string()).collect::<Vec<_>>(),
+                "timestamp": event.timestamp,
+            }),
+            ProgramEvent::NftStaking(event) => json!({
+                "staker": event.staker.to_string(),
+                "nft_mint": event.nft_mint.to_string(),
+                "collection": event.collection.to_string(),
+                "nft_stake": event.nft_stake.to_string(),
+                "timestamp": event.timestamp,
+            }),
+            ProgramEvent::NftUnstaking(event) => json!({
+                "staker": event.staker.to_string(),
+                "nft_mint": event.nft_mint.to_string(),
+                "collection": event.collection.to_string(),
+                "reward_amount": event.reward_amount,
+                "unpaid_reward": event.unpaid_reward,
+                "staked_seconds": event.staked_seconds,
+                "timestamp": event.timestamp,
+            }),
+            ProgramEvent::BoostNftLocked(event) => json!({
+                "staker": event.staker.to_string(),
+                "stake_account": event.stake_account.to_string(),
+                "nft_mint": event.nft_mint.to_string(),
+                "collection": event.collection.to_string(),
+                "timestamp": event.timestamp,
+            }),
+            ProgramEvent::BoostNftUnlocked(event) => json!({
+                "staker": event.staker.to_string(),
+                "stake_account": event.stake_account.to_string(),
+                "nft_mint": event.nft_mint.to_string(),
+                "collection": event.collection.to_string(),
+                "timestamp": event.timestamp,
+            }),
+            ProgramEvent::StakeReceiptIssued(event) => json!({
+                "staker": event.staker.to_string(),
+                "stake_account": event.stake_account.to_string(),
+                "receipt_mint": event.receipt_mint.to_string(),
+                "timestamp": event.timestamp,
+            }),
+        }
+    }
+}
+
+// Nome do campo e valores antigo/novo de uma alteração de configuração
+fn config_change(change: &ConfigField) -> (&'static str, Value, Value) {
+    match *change {
+        ConfigField::PaymentTokenMint { old, new } => {
+            ("payment_token_mint", json!(old.to_string()), json!(new.to_string()))
+        }
+        ConfigField::Admin { old, new } => ("admin", json!(old.to_string()), json!(new.to_string())),
+        ConfigField::StakingEnabled { old, new } => ("staking_enabled", json!(old), json!(new)),
+        ConfigField::StakingRewardRate { old, new } => ("staking_reward_rate", json!(old), json!(new)),
+        ConfigField::MaxStakeAmount { old, new } => ("max_stake_amount", json!(old), json!(new)),
+        ConfigField::EmergencyPaused { old, new } => ("emergency_paused", json!(old), json!(new)),
+        ConfigField::NftHolderBoostBps { old, new } => ("nft_holder_boost_bps", json!(old), json!(new)),
+        ConfigField::RewardReserve { old, new } => {
+            ("reward_reserve", json!(old.to_string()), json!(new.to_string()))
+        }
+    }
+}
+
+// Nome do campo e valores antigo/novo de uma alteração na configuração de uma coleção
+fn collection_change(change: &CollectionField) -> (&'static str, Value, Value) {
+    match change {
+        CollectionField::MaxSupply { old, new } => ("max_supply", json!(old), json!(new)),
+        CollectionField::MintStart { old, new } => ("mint_start", json!(old), json!(new)),
+        CollectionField::MintEnd { old, new } => ("mint_end", json!(old), json!(new)),
+        CollectionField::MintPrice { old, new } => ("mint_price", json!(old), json!(new)),
+        CollectionField::MaxPerWallet { old, new } => ("max_per_wallet", json!(old), json!(new)),
+        CollectionField::MerkleRoot { old, new } => ("merkle_root", json!(hex(old)), json!(hex(new))),
+        CollectionField::AllowlistPrice { old, new } => ("allowlist_price", json!(old), json!(new)),
+        CollectionField::PricingMode { old, new } => {
+            ("pricing_mode", json!(pricing_mode_name(*old)), json!(pricing_mode_name(*new)))
+        }
+        CollectionField::PriceIncrement { old, new } => ("price_increment", json!(old), json!(new)),
+        CollectionField::PriceTiers { old, new } => ("price_tiers", price_tiers(old), price_tiers(new)),
+        CollectionField::BurnBps { old, new } => ("burn_bps", json!(old), json!(new)),
+        CollectionField::TreasuryBps { old, new } => ("treasury_bps", json!(old), json!(new)),
+        CollectionField::ReserveBps { old, new } => ("reserve_bps", json!(old), json!(new)),
+        CollectionField::Treasury { old, new } => ("treasury", json!(old.to_string()), json!(new.to_string())),
+        CollectionField::PaymentMode { old, new } => {
+            ("payment_mode", json!(payment_mode_name(*old)), json!(payment_mode_name(*new)))
+        }
+        CollectionField::RedemptionAmount { old, new } => ("redemption_amount", json!(old), json!(new)),
+        CollectionField::PlaceholderUri { old, new } => ("placeholder_uri", json!(old), json!(new)),
+        CollectionField::Soulbound { old, new } => ("soulbound", json!(old), json!(new)),
+        CollectionField::NftRewardRate { old, new } => ("nft_reward_rate", json!(old), json!(new)),
+        CollectionField::AllowFreeMint { old, new } => ("allow_free_mint", json!(old), json!(new)),
+    }
+}
+
+fn price_tiers(tiers: &[PriceTier]) -> Value {
+    tiers
+        .iter()
+        .map(|tier| json!({ "start_count": tier.start_count, "price": tier.price }))
+        .collect()
+}
+
+fn pricing_mode_name(mode: PricingMode) -> &'static str {
+    match mode {
+        PricingMode::Fixed => "Fixed",
+        PricingMode::Linear => "Linear",
+        PricingMode::Exponential => "Exponential",
+        PricingMode::Tiered => "Tiered",
+    }
+}
+
+fn payment_mode_name(mode: PaymentMode) -> &'static str {
+    match mode {
+        PaymentMode::SplToken => "SplToken",
+        PaymentMode::Sol => "Sol",
+    }
+}
+
+fn hex(bytes: &[u8; 32]) -> String {
+    bytes.iter().map(|byte| format!("{:02x}", byte)).collect()
+}
+
+fn period_name(period: StakingPeriod) -> &'static str {
+    match period {
+        StakingPeriod::Minutes1 => "Minutes1",
+        StakingPeriod::Minutes2 => "Minutes2",
+        StakingPeriod::Minutes5 => "Minutes5",
+        StakingPeriod::Minutes10 => "Minutes10",
+        StakingPeriod::Minutes30 => "Minutes30",
+    }
+}
+
+// Número na sequência global de qualquer evento do programa (discriminator + event_seq)
+pub fn event_seq(data: &[u8]) -> Option<u64> {
+    let bytes = data.get(8..16)?;
+    Some(u64::from_le_bytes(bytes.try_into().ok()?))
+}
+
+// Extrai os eventos do programa dos logs de uma transação, na ordem de emissão
+pub fn decode_logs(program_id: &Pubkey, logs: &[String]) -> Vec<ProgramEvent> {
+    program_data(program_id, logs)
+        .iter()
+        .filter_map(|data| ProgramEvent::decode(data))
+        .collect()
+}
+
+// Números de sequência de todos os eventos do programa na transação, indexados ou não
+pub fn decode_sequences(program_id: &Pubkey, logs: &[String]) -> Vec<u64> {
+    program_data(program_id, logs)
+        .iter()
+        .filter_map(|data| event_seq(data))
+        .collect()
+}
+
+// Payloads dos "Program data:" emitidos com o programa no topo da pilha de invocações
+fn program_data(program_id: &Pubkey, logs: &[String]) -> Vec<Vec<u8>> {
+    let program = program_id.to_string();
+    let mut stack: Vec<&str> = Vec::new();
+    let mut payloads = Vec::new();
+
+    for log in logs {
+        if let Some(data) = log.strip_prefix(PROGRAM_DATA_PREFIX) {
+            if stack.last() == Some(&program.as_str()) {
+                if let Ok(bytes) = BASE64.decode(data) {
+                    payloads.push(bytes);
+                }
+            }
+            continue;
+        }
+        // "Program <id> invoke [n]" empilha; "Program <id> success|failed: ..." desempilha
+        let mut parts = log.split_whitespace();
+        if parts.next() != Some("Program") {
+            continue;
+        }
+        // "Program log: ..." e "Program return: ..." não mudam a pilha
+        let (Some(id), Some(action)) = (parts.next(), parts.next()) else {
+            continue;
+        };
+        if id.ends_with(':') {
+            continue;
+        }
+        match action {
+            "invoke" => stack.push(id),
+            "success" | "failed:" => {
+                stack.pop();
+            }
+            _ => {}
+        }
+    }
+    payloads
+}
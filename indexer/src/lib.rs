@@ -0,0 +1,12 @@
+// Indexador dos eventos do programa adr_token_mint
+//
+// - `decode`: extrai os eventos Anchor ("Program data:") dos logs de uma transação
+// - `source`: transações vindas de um endpoint JSON-RPC ou de um arquivo salvo
+// - `store`: gravação em SQLite, consultas por staker, NFT, carteira e tipo de evento e
+//   lacunas na sequência global de eventos
+
+pub mod decode;
+pub mod source;
+pub mod store;
+
+pub use adr_token_mint::ID as PROGRAM_ID;
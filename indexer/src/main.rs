@@ -0,0 +1,141 @@
+// adr-indexer: indexa os eventos do programa adr_token_mint em um banco SQLite
+//
+// `sync` busca as transações novas do programa via JSON-RPC a partir da última assinatura
+// indexada; `import` lê um arquivo de transações salvas. As consultas imprimem um evento
+// JSON por linha; `gaps` lista os intervalos de event_seq que faltam no banco.
+
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+use adr_token_mint_indexer::source::{self, RpcSource, SavedTransaction};
+use adr_token_mint_indexer::store::{SequenceGap, Store, StoredEvent};
+use adr_token_mint_indexer::PROGRAM_ID;
+use anchor_lang::prelude::Pubkey;
+use clap::{Parser, Subcommand};
+use serde_json::json;
+
+#[derive(Parser)]
+#[command(name = "adr-indexer", version, about = "Indexador de eventos do programa adr_token_mint")]
+struct Cli {
+    /// Banco SQLite (criado se não existir)
+    #[arg(long, default_value = "indexer.db")]
+    db: PathBuf,
+
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Indexa as transações novas do programa a partir de um endpoint JSON-RPC
+    Sync {
+        #[arg(long, env = "ANCHOR_PROVIDER_URL", default_value = "https://api.devnet.solana.com")]
+        url: String,
+    },
+    /// Indexa um arquivo de respostas de getTransaction (array JSON ou JSON Lines)
+    Import { file: PathBuf },
+    /// Histórico de stakes, aportes e unstakes de um staker
+    Staker { staker: Pubkey },
+    /// Eventos de um NFT
+    Nft { nft_mint: Pubkey },
+    /// Todos os eventos de uma carteira (staker, pagador ou admin)
+    Wallet { wallet: Pubkey },
+    /// Eventos de um tipo (ex.: ConfigUpdateEvent, EmergencyPauseEvent)
+    Kind { kind: String },
+    /// Intervalos de event_seq ausentes, com as transações vizinhas para buscar de novo
+    Gaps,
+}
+
+fn main() -> ExitCode {
+    match run(Cli::parse()) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("Erro: {}", err);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn run(cli: Cli) -> Result<(), String> {
+    let mut store = Store::open(&cli.db).map_err(|err| format!("falha ao abrir {}: {}", cli.db.display(), err))?;
+
+    let events = match cli.command {
+        Command::Sync { url } => {
+            let rpc = RpcSource::new(url);
+            let cursor = store.last_signature().map_err(|err| err.to_string())?;
+            let signatures = rpc.signatures_since(&PROGRAM_ID, cursor.as_deref())?;
+            // Uma transação por vez, da mais antiga para a mais nova, para o cursor
+            // avançar mesmo se uma busca falhar no meio
+            let mut indexed = 0;
+            for signature in &signatures {
+                let transaction = rpc.fetch_transaction(signature)?;
+                indexed += insert(&mut store, &transaction)?;
+            }
+            report(signatures.len(), indexed);
+            return Ok(());
+        }
+        Command::Import { file } => {
+            let transactions = source::load_file(&file)?;
+            return index(&mut store, &transactions);
+        }
+        Command::Staker { staker } => store.events_by_staker(&staker),
+        Command::Nft { nft_mint } => store.events_by_nft(&nft_mint),
+        Command::Wallet { wallet } => store.events_by_wallet(&wallet),
+        Command::Kind { kind } => store.events_by_kind(&kind),
+        Command::Gaps => {
+            for gap in store.sequence_gaps().map_err(|err| err.to_string())? {
+                println!("{}", render_gap(&gap));
+            }
+            return Ok(());
+        }
+    }
+    .map_err(|err| err.to_string())?;
+
+    for event in events {
+        println!("{}", render(&event));
+    }
+    Ok(())
+}
+
+fn index(store: &mut Store, transactions: &[SavedTransaction]) -> Result<(), String> {
+    let mut indexed = 0;
+    for transaction in transactions {
+        indexed += insert(store, transaction)?;
+    }
+    report(transactions.len(), indexed);
+    Ok(())
+}
+
+fn insert(store: &mut Store, transaction: &SavedTransaction) -> Result<usize, String> {
+    store
+        .insert_transaction(&PROGRAM_ID, transaction)
+        .map_err(|err| format!("{}: {}", transaction.signature, err))
+}
+
+fn report(transactions: usize, events: usize) {
+    println!("{} transações processadas, {} eventos indexados", transactions, events);
+}
+
+fn render(event: &StoredEvent) -> String {
+    json!({
+        "signature": event.signature,
+        "slot": event.slot,
+        "event_seq": event.event_seq,
+        "kind": event.kind,
+        "wallet": event.wallet,
+        "nft_mint": event.nft_mint,
+        "timestamp": event.timestamp,
+        "data": event.data,
+    })
+    .to_string()
+}
+
+fn render_gap(gap: &SequenceGap) -> String {
+    json!({
+        "first_missing": gap.first_missing,
+        "last_missing": gap.last_missing,
+        "after_signature": gap.after_signature,
+        "before_signature": gap.before_signature,
+    })
+    .to_string()
+}
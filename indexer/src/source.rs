@@ -0,0 +1,144 @@
+// Origem das transações: endpoint JSON-RPC ou arquivo de transações salvas
+//
+// Os dois formatos usam a resposta de `getTransaction` (encoding "json"): o arquivo
+// pode ser um array JSON dessas respostas ou uma resposta por linha (JSON Lines).
+
+use std::fs;
+use std::path::Path;
+use std::time::Duration;
+
+use anchor_lang::prelude::Pubkey;
+use serde_json::{json, Value};
+
+// Limite de assinaturas por chamada de getSignaturesForAddress
+const SIGNATURES_PAGE: usize = 1000;
+
+pub struct SavedTransaction {
+    pub signature: String,
+    pub slot: u64,
+    pub block_time: Option<i64>,
+    pub failed: bool,
+    pub logs: Vec<String>,
+}
+
+impl SavedTransaction {
+    // Converte uma resposta de getTransaction
+    pub fn from_rpc_json(value: &Value) -> Result<Self, String> {
+        let signature = value["transaction"]["signatures"][0]
+            .as_str()
+            .ok_or("transação sem assinatura")?
+            .to_string();
+        let slot = value["slot"]
+            .as_u64()
+            .ok_or_else(|| format!("{}: slot ausente", signature))?;
+        let meta = &value["meta"];
+        let logs = meta["logMessages"]
+            .as_array()
+            .map(|logs| logs.iter().filter_map(|log| log.as_str().map(String::from)).collect())
+            .unwrap_or_default();
+
+        Ok(Self {
+            signature,
+            slot,
+            block_time: value["blockTime"].as_i64(),
+            failed: !meta["err"].is_null(),
+            logs,
+        })
+    }
+}
+
+// Lê um arquivo de transações salvas (array JSON ou JSON Lines)
+pub fn load_file(path: &Path) -> Result<Vec<SavedTransaction>, String> {
+    let contents = fs::read_to_string(path)
+        .map_err(|err| format!("falha ao ler {}: {}", path.display(), err))?;
+
+    let values: Vec<Value> = match serde_json::from_str::<Value>(&contents) {
+        Ok(Value::Array(values)) => values,
+        Ok(value) => vec![value],
+        Err(_) => contents
+            .lines()
+            .filter(|line| !line.trim().is_empty())
+            .map(serde_json::from_str)
+            .collect::<Result<_, _>>()
+            .map_err(|err| format!("{} não é JSON nem JSON Lines: {}", path.display(), err))?,
+    };
+    values.iter().map(SavedTransaction::from_rpc_json).collect()
+}
+
+pub struct RpcSource {
+    url: String,
+    agent: ureq::Agent,
+}
+
+impl RpcSource {
+    pub fn new(url: String) -> Self {
+        Self {
+            url,
+            agent: ureq::AgentBuilder::new().timeout(Duration::from_secs(30)).build(),
+        }
+    }
+
+    fn call(&self, method: &str, params: Value) -> Result<Value, String> {
+        let request = json!({ "jsonrpc": "2.0", "id": 1, "method": method, "params": params });
+        let response: Value = self
+            .agent
+            .post(&self.url)
+            .send_json(request)
+            .map_err(|err| format!("{}: {}", method, err))?
+            .into_json()
+            .map_err(|err| format!("{}: resposta inválida: {}", method, err))?;
+
+        if let Some(error) = response.get("error") {
+            return Err(format!("{}: {}", method, error));
+        }
+        Ok(response["result"].clone())
+    }
+
+    // Assinaturas do programa mais novas que `until`, da mais antiga para a mais nova
+    pub fn signatures_since(&self, program_id: &Pubkey, until: Option<&str>) -> Result<Vec<String>, String> {
+        let mut signatures = Vec::new();
+        let mut before: Option<String> = None;
+
+        loop {
+            let mut options = json!({ "limit": SIGNATURES_PAGE, "commitment": "confirmed" });
+            if let Some(until) = until {
+                options["until"] = json!(until);
+            }
+            if let Some(before) = &before {
+                options["before"] = json!(before);
+            }
+            let page = self.call("getSignaturesForAddress", json!([program_id.to_string(), options]))?;
+            let page = page.as_array().ok_or("getSignaturesForAddress: resposta inválida")?;
+
+            let page: Vec<String> = page
+                .iter()
+                .filter_map(|entry| entry["signature"].as_str().map(String::from))
+                .collect();
+            let done = page.len() < SIGNATURES_PAGE;
+            before = page.last().cloned();
+            signatures.extend(page);
+            if done {
+                break;
+            }
+        }
+
+        // A RPC devolve da mais nova para a mais antiga
+        signatures.reverse();
+        Ok(signatures)
+    }
+
+    pub fn fetch_transaction(&self, signature: &str) -> Result<SavedTransaction, String> {
+        let value = self.call(
+            "getTransaction",
+            json!([signature, {
+                "encoding": "json",
+                "commitment": "confirmed",
+                "maxSupportedTransactionVersion": 0
+            }]),
+        )?;
+        if value.is_null() {
+            return Err(format!("transação {} não encontrada", signature));
+        }
+        SavedTransaction::from_rpc_json(&value)
+    }
+}
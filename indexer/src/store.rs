@@ -0,0 +1,245 @@
+// Armazenamento dos eventos em SQLite
+//
+// `transactions` guarda cada assinatura já processada (com ou sem eventos), o que torna a
+// indexação idempotente e serve de cursor para a próxima execução. `events` guarda um
+// registro por evento, com a carteira e o NFT extraídos para as consultas indexadas.
+// `event_sequence` guarda o `event_seq` de todo evento visto, inclusive dos tipos que não
+// são decodificados, para apontar lacunas na sequência global do programa.
+
+use std::path::Path;
+
+use anchor_lang::prelude::Pubkey;
+use rusqlite::{params, Connection, OptionalExtension};
+use serde_json::Value;
+
+use crate::decode::{decode_logs, decode_sequences, ProgramEvent};
+use crate::source::SavedTransaction;
+
+const SCHEMA: &str = "
+CREATE TABLE IF NOT EXISTS transactions (
+    signature TEXT PRIMARY KEY,
+    slot INTEGER NOT NULL,
+    block_time INTEGER,
+    failed INTEGER NOT NULL
+);
+CREATE TABLE IF NOT EXISTS events (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    signature TEXT NOT NULL REFERENCES transactions(signature),
+    slot INTEGER NOT NULL,
+    event_index INTEGER NOT NULL,
+    event_seq INTEGER NOT NULL,
+    kind TEXT NOT NULL,
+    wallet TEXT NOT NULL,
+    nft_mint TEXT,
+    timestamp INTEGER NOT NULL,
+    data TEXT NOT NULL,
+    UNIQUE (signature, event_index)
+);
+CREATE TABLE IF NOT EXISTS event_sequence (
+    seq INTEGER PRIMARY KEY,
+    signature TEXT NOT NULL REFERENCES transactions(signature)
+);
+CREATE INDEX IF NOT EXISTS events_wallet ON events (wallet, slot);
+CREATE INDEX IF NOT EXISTS events_nft_mint ON events (nft_mint, slot);
+CREATE INDEX IF NOT EXISTS events_kind ON events (kind, slot);
+";
+
+const EVENT_COLUMNS: &str = "signature, slot, event_seq, kind, wallet, nft_mint, timestamp, data";
+
+pub struct StoredEvent {
+    pub signature: String,
+    pub slot: u64,
+    pub event_seq: u64,
+    pub kind: String,
+    pub wallet: String,
+    pub nft_mint: Option<String>,
+    pub timestamp: i64,
+    pub data: Value,
+}
+
+// Intervalo de event_seq sem eventos no banco, entre as transações vizinhas conhecidas
+pub struct SequenceGap {
+    pub first_missing: u64,
+    pub last_missing: u64,
+    pub after_signature: Option<String>,
+    pub before_signature: String,
+}
+
+pub struct Store {
+    conn: Connection,
+}
+
+impl Store {
+    pub fn open(path: &Path) -> rusqlite::Result<Self> {
+        Self::init(Connection::open(path)?)
+    }
+
+    pub fn open_in_memory() -> rusqlite::Result<Self> {
+        Self::init(Connection::open_in_memory()?)
+    }
+
+    fn init(conn: Connection) -> rusqlite::Result<Self> {
+        conn.execute_batch(SCHEMA)?;
+        Ok(Self { conn })
+    }
+
+    pub fn contains(&self, signature: &str) -> rusqlite::Result<bool> {
+        self.conn
+            .query_row(
+                "SELECT 1 FROM transactions WHERE signature = ?1",
+                [signature],
+                |_| Ok(()),
+            )
+            .optional()
+            .map(|found| found.is_some())
+    }
+
+    // Assinatura mais recente já indexada (cursor para a RPC)
+    pub fn last_signature(&self) -> rusqlite::Result<Option<String>> {
+        self.conn
+            .query_row(
+                "SELECT signature FROM transactions ORDER BY slot DESC, rowid DESC LIMIT 1",
+                [],
+                |row| row.get(0),
+            )
+            .optional()
+    }
+
+    // Indexa uma transação; devolve quantos eventos foram gravados
+    // Transações já vistas são ignoradas e as que falharam não geram eventos
+    pub fn insert_transaction(
+        &mut self,
+        program_id: &Pubkey,
+        transaction: &SavedTransaction,
+    ) -> rusqlite::Result<usize> {
+        if self.contains(&transaction.signature)? {
+            return Ok(0);
+        }
+        let (events, sequences) = if transaction.failed {
+            (Vec::new(), Vec::new())
+        } else {
+            (
+                decode_logs(program_id, &transaction.logs),
+                decode_sequences(program_id, &transaction.logs),
+            )
+        };
+
+        let tx = self.conn.transaction()?;
+        tx.execute(
+            "INSERT INTO transactions (signature, slot, block_time, failed) VALUES (?1, ?2, ?3, ?4)",
+            params![
+                transaction.signature,
+                transaction.slot as i64,
+                transaction.block_time,
+                transaction.failed
+            ],
+        )?;
+        for (index, event) in events.iter().enumerate() {
+            insert_event(&tx, transaction, index, event)?;
+        }
+        for seq in sequences {
+            tx.execute(
+                "INSERT OR IGNORE INTO event_sequence (seq, signature) VALUES (?1, ?2)",
+                params![seq as i64, transaction.signature],
+            )?;
+        }
+        tx.commit()?;
+        Ok(events.len())
+    }
+
+    // Histórico de stakes, aportes e unstakes de uma carteira
+    pub fn events_by_staker(&self, staker: &Pubkey) -> rusqlite::Result<Vec<StoredEvent>> {
+        self.query(
+            "WHERE wallet = ?1 AND kind IN ('StakingEvent', 'StakeTopUpEvent', 'UnstakingEvent', \
+             'StakeClosedEvent', 'StakeReceiptIssuedEvent', 'BoostNftLockedEvent', \
+             'BoostNftUnlockedEvent', 'NftStakingEvent', 'NftUnstakingEvent')",
+            &staker.to_string(),
+        )
+    }
+
+    // Eventos ligados a um NFT (mint, queima, stake, bônus e recibo de stake)
+    pub fn events_by_nft(&self, nft_mint: &Pubkey) -> rusqlite::Result<Vec<StoredEvent>> {
+        self.query("WHERE nft_mint = ?1", &nft_mint.to_string())
+    }
+
+    pub fn events_by_wallet(&self, wallet: &Pubkey) -> rusqlite::Result<Vec<StoredEvent>> {
+        self.query("WHERE wallet = ?1", &wallet.to_string())
+    }
+
+    pub fn events_by_kind(&self, kind: &str) -> rusqlite::Result<Vec<StoredEvent>> {
+        self.query("WHERE kind = ?1", kind)
+    }
+
+    // Lacunas na sequência global desde o evento 1, em ordem crescente
+    pub fn sequence_gaps(&self) -> rusqlite::Result<Vec<SequenceGap>> {
+        let mut statement = self
+            .conn
+            .prepare("SELECT seq, signature FROM event_sequence ORDER BY seq")?;
+        let rows = statement.query_map([], |row| {
+            Ok((row.get::<_, i64>(0)? as u64, row.get::<_, String>(1)?))
+        })?;
+
+        let mut gaps = Vec::new();
+        let mut previous: Option<(u64, String)> = None;
+        for row in rows {
+            let (seq, signature) = row?;
+            let expected = previous.as_ref().map_or(1, |(last, _)| last + 1);
+            if seq > expected {
+                gaps.push(SequenceGap {
+                    first_missing: expected,
+                    last_missing: seq - 1,
+                    after_signature: previous.as_ref().map(|(_, signature)| signature.clone()),
+                    before_signature: signature.clone(),
+                });
+            }
+            previous = Some((seq, signature));
+        }
+        Ok(gaps)
+    }
+
+    fn query(&self, filter: &str, value: &str) -> rusqlite::Result<Vec<StoredEvent>> {
+        let sql = format!(
+            "SELECT {} FROM events {} ORDER BY slot, id",
+            EVENT_COLUMNS, filter
+        );
+        let mut statement = self.conn.prepare(&sql)?;
+        let rows = statement.query_map([value], |row| {
+            let data: String = row.get(7)?;
+            Ok(StoredEvent {
+                signature: row.get(0)?,
+                slot: row.get::<_, i64>(1)? as u64,
+                event_seq: row.get::<_, i64>(2)? as u64,
+                kind: row.get(3)?,
+                wallet: row.get(4)?,
+                nft_mint: row.get(5)?,
+                timestamp: row.get(6)?,
+                data: serde_json::from_str(&data).unwrap_or(Value::Null),
+            })
+        })?;
+        rows.collect()
+    }
+}
+
+fn insert_event(
+    tx: &rusqlite::Transaction<'_>,
+    transaction: &SavedTransaction,
+    index: usize,
+    event: &ProgramEvent,
+) -> rusqlite::Result<()> {
+    tx.execute(
+        "INSERT INTO events (signature, slot, event_index, event_seq, kind, wallet, nft_mint, timestamp, data)
+         VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9)",
+        params![
+            transaction.signature,
+            transaction.slot as i64,
+            index as i64,
+            event.event_seq() as i64,
+            event.kind(),
+            event.wallet().to_string(),
+            event.nft_mint().map(|mint| mint.to_string()),
+            event.timestamp(),
+            event.to_json().to_string(),
+        ],
+    )?;
+    Ok(())
+}
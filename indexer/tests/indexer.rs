@@ -0,0 +1,428 @@
+use std::fs;
+
+use adr_token_mint::{
+    BoostNftLockedEvent, CollectionField, CollectionUpdateEvent, ConfigField, ConfigMigratedEvent, ConfigUpdateEvent,
+    EmergencyPauseEvent, NftBurnEvent, NftRewardPoolDepositEvent, NftStakingEvent, PaymentMode, PriceTier,
+    RedemptionPoolDepositEvent, SolTreasuryWithdrawEvent, StakeTopUpEvent, StakingEvent, StakingPeriod,
+    TokenBurnEvent, UnstakingEvent,
+};
+use adr_token_mint_indexer::decode::{decode_logs, ProgramEvent};
+use adr_token_mint_indexer::source::{load_file, SavedTransaction};
+use adr_token_mint_indexer::store::Store;
+use adr_token_mint_indexer::PROGRAM_ID;
+use anchor_lang::prelude::Pubkey;
+use anchor_lang::Event;
+use base64::engine::general_purpose::STANDARD as BASE64;
+use base64::Engine;
+use serde_json::{json, Value};
+
+// Logs no formato do validador para uma instrução do programa que emite `events`
+fn program_logs(events: &[Vec<u8>]) -> Vec<String> {
+    let mut logs = vec![
+        format!("Program {} invoke [1]", PROGRAM_ID),
+        "Program log: Instruction: Teste".to_string(),
+    ];
+    logs.extend(events.iter().map(|data| format!("Program data: {}", BASE64.encode(data))));
+    logs.push(format!("Program {} consumed 5000 of 200000 compute units", PROGRAM_ID));
+    logs.push(format!("Program {} success", PROGRAM_ID));
+    logs
+}
+
+// Resposta de getTransaction reduzida aos campos usados pelo indexador
+fn rpc_transaction(signature: &str, slot: u64, failed: bool, logs: Vec<String>) -> Value {
+    json!({
+        "slot": slot,
+        "blockTime": 1_700_000_000 + slot as i64,
+        "transaction": { "signatures": [signature] },
+        "meta": {
+            "err": if failed { json!({ "InstructionError": [0, { "Custom": 6000 }] }) } else { Value::Null },
+            "logMessages": logs,
+        },
+    })
+}
+
+fn staking_event(event_seq: u64, staker: Pubkey, amount: u64) -> StakingEvent {
+    StakingEvent {
+        event_seq,
+        staker,
+        amount,
+        period: StakingPeriod::Minutes5,
+        start_time: 100,
+        unlock_time: 400,
+        stake_account: Pubkey::new_unique(),
+    }
+}
+
+fn unstaking_event(event_seq: u64, staker: Pubkey) -> UnstakingEvent {
+    UnstakingEvent {
+        event_seq,
+        staker,
+        stake_account: Pubkey::new_unique(),
+        original_amount: 500,
+        reward_amount: 60,
+        total_amount: 560,
+        timestamp: 500,
+    }
+}
+
+fn burn_event(event_seq: u64, payer: Pubkey, nft_mint: Pubkey) -> TokenBurnEvent {
+    TokenBurnEvent {
+        event_seq,
+        payer,
+        token_mint: Pubkey::new_unique(),
+        amount: 1_000,
+        nft_mint,
+        timestamp: 50,
+        price: 1_000,
+        treasury_amount: 0,
+        reserve_amount: 0,
+        payment_type: PaymentMode::SplToken,
+        burn_amount: 1_000,
+    }
+}
+
+#[test]
+fn decode_ignores_program_data_from_other_programs() {
+    let staker = Pubkey::new_unique();
+    let other_program = Pubkey::new_unique();
+    let foreign = staking_event(1, Pubkey::new_unique(), 1).data();
+
+    let logs = vec![
+        format!("Program {} invoke [1]", PROGRAM_ID),
+        // CPI para outro programa que também escreve "Program data:"
+        format!("Program {} invoke [2]", other_program),
+        format!("Program data: {}", BASE64.encode(&foreign)),
+        format!("Program {} success", other_program),
+        format!("Program data: {}", BASE64.encode(staking_event(2, staker, 500).data())),
+        format!("Program {} success", PROGRAM_ID),
+        // Fora de qualquer invocação do programa
+        format!("Program data: {}", BASE64.encode(&foreign)),
+    ];
+
+    let events = decode_logs(&PROGRAM_ID, &logs);
+    assert_eq!(events.len(), 1);
+    match &events[0] {
+        ProgramEvent::Staking(event) => {
+            assert_eq!(event.staker, staker);
+            assert_eq!(event.amount, 500);
+        }
+        other => panic!("esperado StakingEvent, veio {}", other.kind()),
+    }
+}
+
+#[test]
+fn import_indexes_events_and_answers_staker_and_nft_queries() {
+    let staker = Pubkey::new_unique();
+    let admin = Pubkey::new_unique();
+    let nft_mint = Pubkey::new_unique();
+
+    let pause = EmergencyPauseEvent {
+        event_seq: 5,
+        admin,
+        paused: true,
+        reason: "manutenção".to_string(),
+        timestamp: 600,
+    };
+    let top_up = StakeTopUpEvent {
+        event_seq: 3,
+        staker,
+        stake_account: Pubkey::new_unique(),
+        old_amount: 500,
+        added_amount: 100,
+        new_amount: 600,
+        old_period: StakingPeriod::Minutes5,
+        new_period: StakingPeriod::Minutes10,
+        old_unlock_time: 400,
+        new_unlock_time: 800,
+        timestamp: 200,
+    };
+    let transactions = [
+        rpc_transaction("mint", 10, false, program_logs(&[burn_event(1, staker, nft_mint).data()])),
+        rpc_transaction(
+            "stake",
+            11,
+            false,
+            program_logs(&[staking_event(2, staker, 500).data(), top_up.data()]),
+        ),
+        // Transação com erro não gera eventos, mesmo com logs
+        rpc_transaction("falhou", 12, true, program_logs(&[staking_event(99, staker, 9).data()])),
+        rpc_transaction("unstake", 13, false, program_logs(&[unstaking_event(4, staker).data()])),
+        rpc_transaction("pausa", 14, false, program_logs(&[pause.data()])),
+    ];
+
+    // Arquivo em JSON Lines, como salvo a partir de um validador local
+    let path = std::env::temp_dir().join(format!("adr-indexer-{}.jsonl", std::process::id()));
+    let contents: Vec<String> = transactions.iter().map(Value::to_string).collect();
+    fs::write(&path, contents.join("\n")).unwrap();
+    let loaded = load_file(&path).unwrap();
+    fs::remove_file(&path).unwrap();
+    assert_eq!(loaded.len(), 5);
+
+    let mut store = Store::open_in_memory().unwrap();
+    let indexed: usize = loaded
+        .iter()
+        .map(|transaction| store.insert_transaction(&PROGRAM_ID, transaction).unwrap())
+        .sum();
+    assert_eq!(indexed, 5);
+
+    let history = store.events_by_staker(&staker).unwrap();
+    let kinds: Vec<&str> = history.iter().map(|event| event.kind.as_str()).collect();
+    assert_eq!(kinds, ["StakingEvent", "StakeTopUpEvent", "UnstakingEvent"]);
+    assert_eq!(history[0].signature, "stake");
+    assert_eq!(history[1].event_seq, 3);
+    assert_eq!(history[0].data["amount"], 500);
+    assert_eq!(history[1].data["new_period"], "Minutes10");
+    assert_eq!(history[2].data["reward_amount"], 60);
+
+    let nft = store.events_by_nft(&nft_mint).unwrap();
+    assert_eq!(nft.len(), 1);
+    assert_eq!(nft[0].kind, "TokenBurnEvent");
+    assert_eq!(nft[0].wallet, staker.to_string());
+
+    let pauses = store.events_by_kind("EmergencyPauseEvent").unwrap();
+    assert_eq!(pauses[0].data["reason"], "manutenção");
+    assert_eq!(store.last_signature().unwrap().as_deref(), Some("pausa"));
+    // A transação que falhou não conta na sequência
+    assert!(store.sequence_gaps().unwrap().is_empty());
+
+    // Reimportar é idempotente
+    let again: usize = loaded
+        .iter()
+        .map(|transaction| store.insert_transaction(&PROGRAM_ID, transaction).unwrap())
+        .sum();
+    assert_eq!(again, 0);
+    assert_eq!(store.events_by_wallet(&staker).unwrap().len(), 4);
+}
+
+#[test]
+fn config_update_is_stored_with_typed_field_and_version() {
+    let admin = Pubkey::new_unique();
+    let update = ConfigUpdateEvent {
+        event_seq: 1,
+        admin,
+        change: ConfigField::StakingRewardRate { old: 500, new: 800 },
+        config_version: 7,
+        timestamp: 900,
+    };
+    let value = rpc_transaction("config", 20, false, program_logs(&[update.data()]));
+
+    let mut store = Store::open_in_memory().unwrap();
+    let transaction = SavedTransaction::from_rpc_json(&value).unwrap();
+    assert_eq!(store.insert_transaction(&PROGRAM_ID, &transaction).unwrap(), 1);
+
+    let stored = store.events_by_kind("ConfigUpdateEvent").unwrap();
+    assert_eq!(stored[0].wallet, admin.to_string());
+    assert_eq!(stored[0].data["field"], "staking_reward_rate");
+    assert_eq!(stored[0].data["old"], 500);
+    assert_eq!(stored[0].data["new"], 800);
+    assert_eq!(stored[0].data["config_version"], 7);
+}
+
+#[test]
+fn sequence_gaps_include_events_that_are_not_decoded() {
+    let staker = Pubkey::new_unique();
+    // Evento de uma versão mais nova do programa: não é decodificado, mas o event_seq dele
+    // fecha a sequência
+    let mut unknown = vec![0xAA; 8];
+    unknown.extend_from_slice(&2u64.to_le_bytes());
+    let transactions = [
+        rpc_transaction("um", 30, false, program_logs(&[staking_event(1, staker, 10).data()])),
+        rpc_transaction("dois", 31, false, program_logs(&[unknown])),
+        // Eventos 3 a 5 perdidos
+        rpc_transaction("seis", 35, false, program_logs(&[unstaking_event(6, staker).data()])),
+        rpc_transaction("oito", 37, false, program_logs(&[burn_event(8, staker, Pubkey::new_unique()).data()])),
+    ];
+
+    let mut store = Store::open_in_memory().unwrap();
+    for value in &transactions {
+        let transaction = SavedTransaction::from_rpc_json(value).unwrap();
+        store.insert_transaction(&PROGRAM_ID, &transaction).unwrap();
+    }
+
+    let gaps = store.sequence_gaps().unwrap();
+    let ranges: Vec<(u64, u64)> = gaps.iter().map(|gap| (gap.first_missing, gap.last_missing)).collect();
+    assert_eq!(ranges, [(3, 5), (7, 7)]);
+    assert_eq!(gaps[0].after_signature.as_deref(), Some("dois"));
+    assert_eq!(gaps[0].before_signature, "seis");
+}
+
+#[test]
+fn collection_update_and_treasury_withdraw_are_stored_with_typed_fields() {
+    let admin = Pubkey::new_unique();
+    let collection = Pubkey::new_unique();
+    let tiers = CollectionUpdateEvent {
+        event_seq: 1,
+        admin,
+        collection,
+        change: CollectionField::PriceTiers {
+            old: Vec::new(),
+            new: vec![PriceTier { start_count: 0, price: 100 }, PriceTier { start_count: 10, price: 150 }],
+        },
+        timestamp: 700,
+    };
+    let payment_mode = CollectionUpdateEvent {
+        event_seq: 2,
+        admin,
+        collection,
+        change: CollectionField::PaymentMode { old: PaymentMode::SplToken, new: PaymentMode::Sol },
+        timestamp: 700,
+    };
+    let withdraw = SolTreasuryWithdrawEvent {
+        event_seq: 3,
+        admin,
+        collection,
+        sol_treasury: Pubkey::new_unique(),
+        destination: admin,
+        amount: 2_000,
+        old_balance: 5_000,
+        new_balance: 3_000,
+        timestamp: 710,
+    };
+    let value = rpc_transaction(
+        "colecao",
+        40,
+        false,
+        program_logs(&[tiers.data(), payment_mode.data(), withdraw.data()]),
+    );
+
+    let mut store = Store::open_in_memory().unwrap();
+    let transaction = SavedTransaction::from_rpc_json(&value).unwrap();
+    assert_eq!(store.insert_transaction(&PROGRAM_ID, &transaction).unwrap(), 3);
+
+    let updates = store.events_by_kind("CollectionUpdateEvent").unwrap();
+    assert_eq!(updates.len(), 2);
+    assert_eq!(updates[0].wallet, admin.to_string());
+    assert_eq!(updates[0].data["collection"], collection.to_string());
+    assert_eq!(updates[0].data["field"], "price_tiers");
+    assert_eq!(updates[0].data["old"], json!([]));
+    assert_eq!(updates[0].data["new"][1], json!({ "start_count": 10, "price": 150 }));
+    assert_eq!(updates[1].data["field"], "payment_mode");
+    assert_eq!(updates[1].data["old"], "SplToken");
+    assert_eq!(updates[1].data["new"], "Sol");
+
+    let withdrawals = store.events_by_kind("SolTreasuryWithdrawEvent").unwrap();
+    assert_eq!(withdrawals[0].data["amount"], 2_000);
+    assert_eq!(withdrawals[0].data["new_balance"], 3_000);
+}
+
+#[test]
+fn nft_burn_stake_and_boost_events_are_found_by_nft_mint() {
+    let holder = Pubkey::new_unique();
+    let admin = Pubkey::new_unique();
+    let collection = Pubkey::new_unique();
+    let nft_mint = Pubkey::new_unique();
+
+    let nft_stake = NftStakingEvent {
+        event_seq: 1,
+        staker: holder,
+        nft_mint,
+        collection,
+        nft_stake: Pubkey::new_unique(),
+        timestamp: 100,
+    };
+    let boost = BoostNftLockedEvent {
+        event_seq: 2,
+        staker: holder,
+        stake_account: Pubkey::new_unique(),
+        nft_mint,
+        collection,
+        timestamp: 200,
+    };
+    let redemption_deposit = RedemptionPoolDepositEvent {
+        event_seq: 3,
+        admin,
+        collection,
+        redemption_pool: Pubkey::new_unique(),
+        amount: 1_000,
+        new_balance: 1_000,
+        timestamp: 250,
+    };
+    let reward_deposit = NftRewardPoolDepositEvent {
+        event_seq: 4,
+        admin,
+        collection,
+        nft_reward_pool: Pubkey::new_unique(),
+        amount: 500,
+        new_balance: 800,
+        timestamp: 260,
+    };
+    let nft_burn = NftBurnEvent {
+        event_seq: 5,
+        holder,
+        nft_mint,
+        collection,
+        refund_amount: 1_000,
+        live_supply: 9,
+        timestamp: 300,
+    };
+    let transactions = [
+        rpc_transaction("stake", 50, false, program_logs(&[nft_stake.data()])),
+        rpc_transaction("bonus", 51, false, program_logs(&[boost.data()])),
+        rpc_transaction(
+            "depositos",
+            52,
+            false,
+            program_logs(&[redemption_deposit.data(), reward_deposit.data()]),
+        ),
+        rpc_transaction("queima", 53, false, program_logs(&[nft_burn.data()])),
+    ];
+
+    let mut store = Store::open_in_memory().unwrap();
+    for value in &transactions {
+        let transaction = SavedTransaction::from_rpc_json(value).unwrap();
+        store.insert_transaction(&PROGRAM_ID, &transaction).unwrap();
+    }
+
+    let nft = store.events_by_nft(&nft_mint).unwrap();
+    let kinds: Vec<&str> = nft.iter().map(|event| event.kind.as_str()).collect();
+    assert_eq!(kinds, ["NftStakingEvent", "BoostNftLockedEvent", "NftBurnEvent"]);
+    assert_eq!(nft[2].wallet, holder.to_string());
+    assert_eq!(nft[2].data["refund_amount"], 1_000);
+    assert_eq!(store.events_by_staker(&holder).unwrap().len(), 2);
+
+    let deposits = store.events_by_wallet(&admin).unwrap();
+    let kinds: Vec<&str> = deposits.iter().map(|event| event.kind.as_str()).collect();
+    assert_eq!(kinds, ["RedemptionPoolDepositEvent", "NftRewardPoolDepositEvent"]);
+    assert_eq!(deposits[1].data["new_balance"], 800);
+    assert!(store.sequence_gaps().unwrap().is_empty());
+}
+
+#[test]
+fn config_migration_is_indexed_in_the_global_sequence() {
+    let admin = Pubkey::new_unique();
+    // migrate_config cria a EventSequence e o evento recebe o primeiro número
+    let migrated = ConfigMigratedEvent {
+        event_seq: 1,
+        admin,
+        legacy_config: Pubkey::new_unique(),
+        config: Pubkey::new_unique(),
+        config_version: 3,
+        timestamp: 10,
+    };
+    let transactions = [
+        rpc_transaction("migracao", 1, false, program_logs(&[migrated.data()])),
+        rpc_transaction("dois", 2, false, program_logs(&[staking_event(2, admin, 10).data()])),
+    ];
+
+    let mut store = Store::open_in_memory().unwrap();
+    for value in &transactions {
+        let transaction = SavedTransaction::from_rpc_json(value).unwrap();
+        store.insert_transaction(&PROGRAM_ID, &transaction).unwrap();
+    }
+
+    let migrations = store.events_by_kind("ConfigMigratedEvent").unwrap();
+    assert_eq!(migrations[0].event_seq, 1);
+    assert_eq!(migrations[0].data["config_version"], 3);
+    assert!(store.sequence_gaps().unwrap().is_empty());
+}
+
+#[test]
+fn saved_transaction_reads_rpc_fields() {
+    let value = rpc_transaction("assinatura", 42, false, vec!["Program log: oi".to_string()]);
+    let transaction = SavedTransaction::from_rpc_json(&value).unwrap();
+    assert_eq!(transaction.signature, "assinatura");
+    assert_eq!(transaction.slot, 42);
+    assert_eq!(transaction.block_time, Some(1_700_000_042));
+    assert!(!transaction.failed);
+    assert_eq!(transaction.logs, ["Program log: oi"]);
+}
@@ -1,7 +1,17 @@
 use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+
+pub mod migration;
+pub mod utils;
+pub mod views;
+
+use anchor_lang::solana_program::program_pack::Pack;
+use anchor_spl::token::spl_token::instruction::AuthorityType;
 use anchor_spl::{
-    associated_token::{AssociatedToken}, 
-    token::{Mint, Token, TokenAccount, mint_to, MintTo, approve, Approve, burn, Burn, transfer, Transfer},
+    associated_token::{self, AssociatedToken, get_associated_token_address, get_associated_token_address_with_program_id}, 
+    token::{self, Mint, Token, TokenAccount, approve, Approve, burn, Burn, transfer, Transfer, close_account, CloseAccount, InitializeMint2},
+    token_2022::{self, spl_token_2022::extension::ExtensionType},
+    token_interface::{self, TokenInterface},
 };
 
 declare_id!("65zQjC4UYf4zJdDyfScpZjgaBbiMRpmFhNJkFSp39GZF");
@@ -9,15 +19,21 @@ declare_id!("65zQjC4UYf4zJdDyfScpZjgaBbiMRpmFhNJkFSp39GZF");
 // Definir evento para registrar informações de queima de tokens
 #[event]
 pub struct TokenBurnEvent {
+    pub event_seq: u64,  // Número na sequência global (primeiro campo de todos os eventos)
     pub payer: Pubkey,
     pub token_mint: Pubkey,
-    pub amount: u64,
+    pub amount: u64,  // Total pago pelo NFT (no modo SOL, inclui o complemento de aluguel da tesouraria)
     pub nft_mint: Pubkey,
     pub timestamp: i64,
+    pub price: u64,  // Preço da curva para este mint (nos mints da allowlist, amount é o allowlist_price)
+    pub treasury_amount: u64,  // Parte do pagamento enviada à tesouraria
+    pub reserve_amount: u64,   // Parte do pagamento enviada à reserva de recompensas
+    pub payment_type: PaymentMode,  // Token SPL ou SOL (valores em lamports)
+    pub burn_amount: u64,      // Parte do pagamento queimada
 }
 
 // Enumeração para os diferentes períodos de staking
-#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
 pub enum StakingPeriod {
     Minutes1 = 1,    // 1 minuto
     Minutes2 = 2,    // 2 minutos
@@ -48,6 +64,7 @@ impl StakingPeriod {
 // Eventos para monitoramento
 #[event]
 pub struct StakingEvent {
+    pub event_seq: u64,
     pub staker: Pubkey,
     pub amount: u64,
     pub period: StakingPeriod,
@@ -58,6 +75,7 @@ pub struct StakingEvent {
 
 #[event]
 pub struct UnstakingEvent {
+    pub event_seq: u64,
     pub staker: Pubkey,
     pub stake_account: Pubkey,
     pub original_amount: u64,
@@ -66,31 +84,313 @@ pub struct UnstakingEvent {
     pub timestamp: i64,
 }
 
+// Posição resgatada antes do fechamento no unstake, fechada por close_stake
+#[event]
+pub struct StakeClosedEvent {
+    pub event_seq: u64,
+    pub staker: Pubkey,
+    pub stake_account: Pubkey,
+    pub timestamp: i64,
+}
+
+// Campo da ConfigAccount alterado, com os valores antigo e novo
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum ConfigField {
+    PaymentTokenMint { old: Pubkey, new: Pubkey },
+    Admin { old: Pubkey, new: Pubkey },
+    StakingEnabled { old: bool, new: bool },
+    StakingRewardRate { old: u64, new: u64 },
+    MaxStakeAmount { old: u64, new: u64 },
+    EmergencyPaused { old: bool, new: bool },
+    RewardReserve { old: Pubkey, new: Pubkey },
+    NftHolderBoostBps { old: u64, new: u64 },
+}
+
 #[event]
 pub struct ConfigUpdateEvent {
+    pub event_seq: u64,
+    pub admin: Pubkey,
+    pub change: ConfigField,
+    pub config_version: u64,  // Versão da configuração após esta alteração
+    pub timestamp: i64,
+}
+
+// ConfigAccount keypair antiga copiada para a PDA. migrate_config cria a EventSequence se
+// ela ainda não existir, então o evento entra na sequência global como os demais
+#[event]
+pub struct ConfigMigratedEvent {
+    pub event_seq: u64,
+    pub admin: Pubkey,
+    pub legacy_config: Pubkey,
+    pub config: Pubkey,
+    pub config_version: u64,
+    pub timestamp: i64,
+}
+
+// Campo da CollectionConfig alterado pelo admin, com os valores antigo e novo
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq)]
+pub enum CollectionField {
+    MaxSupply { old: u64, new: u64 },
+    MintStart { old: i64, new: i64 },
+    MintEnd { old: i64, new: i64 },
+    MintPrice { old: u64, new: u64 },
+    MaxPerWallet { old: u64, new: u64 },
+    MerkleRoot { old: [u8; 32], new: [u8; 32] },
+    AllowlistPrice { old: u64, new: u64 },
+    PricingMode { old: PricingMode, new: PricingMode },
+    PriceIncrement { old: u64, new: u64 },
+    PriceTiers { old: Vec<PriceTier>, new: Vec<PriceTier> },  // Só as faixas em uso
+    BurnBps { old: u16, new: u16 },
+    TreasuryBps { old: u16, new: u16 },
+    ReserveBps { old: u16, new: u16 },
+    Treasury { old: Pubkey, new: Pubkey },
+    PaymentMode { old: PaymentMode, new: PaymentMode },
+    RedemptionAmount { old: u64, new: u64 },
+    PlaceholderUri { old: String, new: String },
+    Soulbound { old: bool, new: bool },
+    NftRewardRate { old: u64, new: u64 },
+    AllowFreeMint { old: bool, new: bool },
+}
+
+#[event]
+pub struct CollectionUpdateEvent {
+    pub event_seq: u64,
+    pub admin: Pubkey,
+    pub collection: Pubkey,
+    pub change: CollectionField,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct SolTreasuryWithdrawEvent {
+    pub event_seq: u64,
+    pub admin: Pubkey,
+    pub collection: Pubkey,
+    pub sol_treasury: Pubkey,
+    pub destination: Pubkey,
+    pub amount: u64,
+    pub old_balance: u64,  // Lamports da tesouraria antes do saque
+    pub new_balance: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct NftBurnEvent {
+    pub event_seq: u64,
+    pub holder: Pubkey,
+    pub nft_mint: Pubkey,
+    pub collection: Pubkey,
+    pub refund_amount: u64,  // Tokens devolvidos pela pool de resgate
+    pub live_supply: u64,    // NFTs da coleção ainda em circulação
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct RedemptionPoolDepositEvent {
+    pub event_seq: u64,
+    pub admin: Pubkey,
+    pub collection: Pubkey,
+    pub redemption_pool: Pubkey,
+    pub amount: u64,
+    pub new_balance: u64,  // Saldo da pool após o depósito
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct NftRewardPoolDepositEvent {
+    pub event_seq: u64,
+    pub admin: Pubkey,
+    pub collection: Pubkey,
+    pub nft_reward_pool: Pubkey,
+    pub amount: u64,
+    pub new_balance: u64,  // Saldo da pool após o depósito
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct NftMetadataUpdateEvent {
+    pub event_seq: u64,
+    pub nft_metadata: Pubkey,
+    pub authority: Pubkey,
+    pub name: String,
+    pub symbol: String,
+    pub uri: String,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct NftMetadataLockEvent {
+    pub event_seq: u64,
+    pub nft_metadata: Pubkey,
+    pub authority: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct RevealCommitEvent {
+    pub event_seq: u64,
+    pub admin: Pubkey,
+    pub collection: Pubkey,
+    pub base_uri: String,
+    pub provenance_hash: [u8; 32],
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct NftsRevealedEvent {
+    pub event_seq: u64,
     pub admin: Pubkey,
-    pub field: String,
-    pub old_value: String,
-    pub new_value: String,
+    pub collection: Pubkey,
+    pub revealed: u64,  // Quantidade de NFTs revelados nesta chamada
+    pub skipped: Vec<Pubkey>,  // Metadados bloqueados, mantidos com a URI atual
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct NftStakingEvent {
+    pub event_seq: u64,
+    pub staker: Pubkey,
+    pub nft_mint: Pubkey,
+    pub collection: Pubkey,
+    pub nft_stake: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct NftUnstakingEvent {
+    pub event_seq: u64,
+    pub staker: Pubkey,
+    pub nft_mint: Pubkey,
+    pub collection: Pubkey,
+    pub reward_amount: u64,
+    pub unpaid_reward: u64,  // Parte acumulada que a pool de recompensas não cobriu
+    pub staked_seconds: i64,
+    pub timestamp: i64,
+}
+
+// NFT da coleção travado junto a uma posição de stake_tokens (lock_boost_nft)
+#[event]
+pub struct BoostNftLockedEvent {
+    pub event_seq: u64,
+    pub staker: Pubkey,
+    pub stake_account: Pubkey,
+    pub nft_mint: Pubkey,
+    pub collection: Pubkey,
+    pub timestamp: i64,
+}
+
+// NFT de bônus devolvido ao dono (unlock_boost_nft)
+#[event]
+pub struct BoostNftUnlockedEvent {
+    pub event_seq: u64,
+    pub staker: Pubkey,
+    pub stake_account: Pubkey,
+    pub nft_mint: Pubkey,
+    pub collection: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct StakeReceiptIssuedEvent {
+    pub event_seq: u64,
+    pub staker: Pubkey,
+    pub stake_account: Pubkey,
+    pub receipt_mint: Pubkey,
     pub timestamp: i64,
 }
 
 #[event]
 pub struct EmergencyPauseEvent {
+    pub event_seq: u64,
     pub admin: Pubkey,
     pub paused: bool,
     pub reason: String,
     pub timestamp: i64,
 }
 
+#[event]
+pub struct CollectionInitializedEvent {
+    pub event_seq: u64,
+    pub admin: Pubkey,
+    pub collection: Pubkey,
+    pub collection_mint: Pubkey,
+    pub config: Pubkey,
+    pub name: String,
+    pub symbol: String,
+    pub uri: String,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct RewardReserveDepositEvent {
+    pub event_seq: u64,
+    pub admin: Pubkey,
+    pub reward_reserve: Pubkey,
+    pub amount: u64,
+    pub old_balance: u64,  // Saldo da reserva antes do depósito
+    pub new_balance: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct DelegateApprovedEvent {
+    pub event_seq: u64,
+    pub owner: Pubkey,
+    pub token_account: Pubkey,
+    pub old_delegate: Option<Pubkey>,  // Delegado substituído, se havia um
+    pub old_delegated_amount: u64,
+    pub new_delegate: Pubkey,
+    pub new_delegated_amount: u64,
+    pub timestamp: i64,
+}
+
+// Aporte em uma posição ativa: o valor é somado e o período reiniciado
+#[event]
+pub struct StakeTopUpEvent {
+    pub event_seq: u64,
+    pub staker: Pubkey,
+    pub stake_account: Pubkey,
+    pub old_amount: u64,
+    pub added_amount: u64,
+    pub new_amount: u64,
+    pub old_period: StakingPeriod,
+    pub new_period: StakingPeriod,
+    pub old_unlock_time: i64,
+    pub new_unlock_time: i64,
+    pub timestamp: i64,
+}
+
 // Adicionar nova estrutura para o contador
 #[account]
+#[derive(InitSpace)]
 pub struct NftCounter {
+    pub version: u8,
     pub count: u64,  // Contador global de NFTs
+    pub reserved: [u8; 16],
 }
 
+// Sequência global dos eventos do programa: cada evento emitido leva o próximo número no
+// campo `event_seq`, sempre o primeiro do payload, o que permite a consumidores off-chain
+// detectar lacunas e buscar as transações perdidas sem conhecer o layout de cada evento
 #[account]
+#[derive(InitSpace)]
+pub struct EventSequence {
+    pub version: u8,
+    pub seq: u64,  // Número do último evento emitido
+    pub reserved: [u8; 16],
+}
+
+impl EventSequence {
+    pub fn next_seq(&mut self) -> Result<u64> {
+        self.seq = self.seq.checked_add(1).ok_or(ErrorCode::MathOverflow)?;
+        Ok(self.seq)
+    }
+}
+
+#[account]
+#[derive(InitSpace)]
 pub struct ConfigAccount {
+    pub version: u8,
     pub payment_token_mint: Pubkey,
     pub admin: Pubkey,
     pub staking_enabled: bool,
@@ -98,756 +398,4828 @@ pub struct ConfigAccount {
     pub max_stake_amount: u64,    // Valor máximo que pode ser colocado em stake
     pub emergency_paused: bool,   // Flag para pausar o contrato em caso de emergência
     pub reward_reserve: Pubkey,   // Conta que armazena tokens de recompensa
+    pub config_version: u64,      // Incrementada a cada alteração de campo
+    pub nft_holder_boost_bps: u64, // Bônus de stake_tokens com NFT da coleção travado (bps)
+    pub total_staked: u64,        // Principal das posições abertas de stake_tokens
+    pub active_stakes: u64,       // Posições de stake_tokens abertas
+    pub reserved: [u8; 40],
 }
 
-#[program]
-pub mod adr_token_mint {
-    use super::*;
+// Campos da ConfigAccount keypair antiga; contas criadas antes de config_version não têm o
+// último campo, que é lido à parte em migrate_config
+#[derive(AnchorDeserialize)]
+struct LegacyConfigAccount {
+    payment_token_mint: Pubkey,
+    admin: Pubkey,
+    staking_enabled: bool,
+    staking_reward_rate: u64,
+    max_stake_amount: u64,
+    emergency_paused: bool,
+    reward_reserve: Pubkey,
+}
 
-    // Inicializa a coleção de NFTs
-    pub fn initialize_collection(
-        ctx: Context<InitializeCollection>,
-        name: String,
-        symbol: String,
-        uri: String,
+impl ConfigAccount {
+    // Incrementa a versão e emite o ConfigUpdateEvent da alteração
+    pub fn record_change(
+        &mut self,
+        event_sequence: &mut EventSequence,
+        admin: Pubkey,
+        change: ConfigField,
     ) -> Result<()> {
-        // Validar que os inputs não estão vazios
-        require!(!name.is_empty(), ErrorCode::InvalidInput);
-        require!(!symbol.is_empty(), ErrorCode::InvalidInput);
-        require!(!uri.is_empty(), ErrorCode::InvalidInput);
-        
-        msg!("Inicializando a coleção de NFTs: {}, {}, {}", name, symbol, uri);
-        
-        // Armazenar informações na conta de metadados
-        let metadata = &mut ctx.accounts.collection_metadata;
-        metadata.name = name;
-        metadata.symbol = symbol;
-        metadata.uri = uri;
-        metadata.authority = ctx.accounts.payer.key();
-        
-        // Configurar a conta do token
-        let config = &mut ctx.accounts.config;
-        config.admin = ctx.accounts.payer.key();
-        config.payment_token_mint = Pubkey::default();
-        config.staking_enabled = false;
-        config.staking_reward_rate = 0; // taxa base de recompensa (será configurada depois)
-        config.max_stake_amount = 1_000_000 * 10u64.pow(9); // Limite máximo de stake: 1 milhão de tokens
-        config.emergency_paused = false; // Inicialmente não pausado
-        config.reward_reserve = Pubkey::default(); // Será configurada depois
-        
-        // Inicializar o contador
-        let counter = &mut ctx.accounts.nft_counter;
-        counter.count = 0;
-        
-        msg!("Coleção inicializada e contador de NFTs zerado");
-        
+        self.config_version = self.config_version
+            .checked_add(1)
+            .ok_or(ErrorCode::MathOverflow)?;
+        emit!(ConfigUpdateEvent {
+            event_seq: event_sequence.next_seq()?,
+            admin,
+            change,
+            config_version: self.config_version,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        Ok(())
+    }
+    
+    // Estatísticas de stake_tokens: `opened` indica uma posição nova (não um aporte)
+    pub fn record_stake(&mut self, amount: u64, opened: bool) -> Result<()> {
+        self.total_staked = self.total_staked
+            .checked_add(amount)
+            .ok_or(ErrorCode::MathOverflow)?;
+        if opened {
+            self.active_stakes = self.active_stakes
+                .checked_add(1)
+                .ok_or(ErrorCode::MathOverflow)?;
+        }
         Ok(())
     }
+    
+    // Desconta a posição resgatada. Posições abertas antes das estatísticas não foram
+    // contadas, por isso o desconto satura em zero
+    pub fn record_unstake(&mut self, amount: u64) {
+        self.total_staked = self.total_staked.saturating_sub(amount);
+        self.active_stakes = self.active_stakes.saturating_sub(1);
+    }
+}
 
+// Tamanhos máximos (em bytes) dos campos de texto dos metadados
+pub const MAX_NAME_LENGTH: usize = 50;
+pub const MAX_SYMBOL_LENGTH: usize = 10;
+pub const MAX_URI_LENGTH: usize = 200;
 
-    // Função atualizada de mint
-    pub fn mint_nft_with_payment(
-        ctx: Context<MintNFTWithPayment>,
-        name: String,
-        symbol: String,
-        uri: String,
-        amount: u64,
+// Versão do layout de todas as contas do programa, gravada no primeiro byte após o
+// discriminator. Contas v1 não têm versão nem área reservada e são convertidas por
+// migrate_account; campos novos devem ocupar a área `reserved` sempre que couberem.
+pub const ACCOUNT_VERSION: u8 = 2;
+
+// Espaço da ConfigAccount (discriminator + campos)
+pub const CONFIG_ACCOUNT_SPACE: usize = 8 + ConfigAccount::INIT_SPACE;
+
+// ConfigAccount keypair criada na devnet antes da PDA [b"config"]; é a única conta que
+// migrate_config aceita como origem
+pub const LEGACY_CONFIG_ACCOUNT: Pubkey = pubkey!("GBee25TDA1Tym5iZsrT4imGpzmC3cYwKRsstX6GMLbhd");
+
+// Espaço da conta NFTMetadata (discriminator + campos)
+pub const NFT_METADATA_SPACE: usize = 8 + NFTMetadata::INIT_SPACE;
+
+// Quantidade máxima de NFTs por chamada de mint_batch
+// Cada NFT consome ~50k CU (mint, metadados, ATA e mint_to); o cliente deve pedir
+// orçamento de compute proporcional, sempre abaixo do limite de 1.4M por transação
+// Contas: 14 fixas + 3 por NFT + programa de compute budget = 46 com 10 NFTs, dentro do
+// limite de 64 contas por transação. Numa transação legada (32 bytes por conta, pacote de
+// 1232 bytes) só cabem ~3 NFTs; lotes maiores exigem transação v0 com address lookup table
+pub const MAX_BATCH_SIZE: u8 = 10;
+
+// Número máximo de faixas de preço por coleção
+pub const MAX_PRICE_TIERS: usize = 5;
+
+// Bônus inicial sobre a recompensa de stake_tokens com um NFT da coleção travado na posição
+// (bps); o admin ajusta com set_nft_holder_boost até MAX_NFT_HOLDER_BOOST_BPS
+pub const DEFAULT_NFT_HOLDER_BOOST_BPS: u64 = 1000;
+pub const MAX_NFT_HOLDER_BOOST_BPS: u64 = 10000;
+
+// Nome e símbolo dos NFTs de recibo de stake
+pub const STAKE_RECEIPT_NAME: &str = "ADR Stake Receipt";
+pub const STAKE_RECEIPT_SYMBOL: &str = "ADRSR";
+
+// Escala de ponto fixo usada na curva exponencial
+const PRICE_SCALE: u128 = 1_000_000_000;
+
+// Modo de cálculo do preço do mint público
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
+pub enum PricingMode {
+    Fixed,        // Sempre mint_price
+    Linear,       // mint_price + price_increment * count
+    Exponential,  // mint_price * (1 + price_increment / 10000) ^ count
+    Tiered,       // Preço da última faixa cujo início já foi alcançado
+}
+
+// Forma de pagamento aceita pela coleção
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
+pub enum PaymentMode {
+    SplToken,  // Token de pagamento configurado (queima/tesouraria/reserva)
+    Sol,       // Lamports enviados ao PDA de tesouraria da coleção
+}
+
+// Bumps das PDAs de um NFT do mint_batch, calculados pelo cliente (find_program_address)
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, PartialEq, Eq)]
+pub struct NftBumps {
+    pub nft_mint: u8,
+    pub nft_metadata: u8,
+}
+
+// Faixa de preço: a partir de start_count NFTs mintados, o preço passa a ser price
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, PartialEq, Eq, InitSpace)]
+pub struct PriceTier {
+    pub start_count: u64,
+    pub price: u64,
+}
+
+// Configuração de venda específica de cada coleção
+#[account]
+#[derive(InitSpace)]
+pub struct CollectionConfig {
+    pub version: u8,
+    pub collection: Pubkey,  // Conta de metadados da coleção
+    pub max_supply: u64,     // Quantidade máxima de NFTs (0 = ilimitado)
+    pub mint_start: i64,     // Início da janela de mint (0 = sem início definido)
+    pub mint_end: i64,       // Fim da janela de mint (0 = sem fim definido)
+    pub mint_price: u64,     // Preço do mint público no modo Fixed e base das demais curvas
+    pub merkle_root: [u8; 32], // Raiz da allowlist (zeros = allowlist inativa)
+    pub allowlist_price: u64,  // Preço do mint na fase de allowlist
+    pub max_per_wallet: u64,   // Limite de mints públicos por carteira (0 = ilimitado)
+    pub pricing_mode: PricingMode,
+    pub price_increment: u64,  // Incremento linear (em tokens) ou crescimento exponencial (em bps)
+    pub tier_count: u8,        // Quantidade de faixas válidas em price_tiers
+    pub price_tiers: [PriceTier; MAX_PRICE_TIERS],
+    pub burn_bps: u16,      // Parte do pagamento queimada (bps)
+    pub treasury_bps: u16,  // Parte do pagamento enviada à tesouraria (bps)
+    pub reserve_bps: u16,   // Parte do pagamento enviada à reserva de recompensas (bps)
+    pub treasury: Pubkey,   // Conta de token da tesouraria
+    pub payment_mode: PaymentMode,  // No modo Sol, mint_price e a curva são em lamports
+    pub live_supply: u64,           // NFTs mintados menos NFTs queimados
+    pub redemption_amount: u64,     // Tokens devolvidos por NFT queimado (0 = sem resgate)
+    #[max_len(MAX_URI_LENGTH)]
+    pub placeholder_uri: String,    // URI "não revelada" usada nos mints (vazia = sem reveal)
+    #[max_len(MAX_URI_LENGTH)]
+    pub base_uri: String,           // URI base revelada; cada NFT recebe base_uri + índice
+    pub provenance_hash: [u8; 32],  // Hash que prova a ordem dos metadados definida antes do mint
+    pub reveal_committed: bool,     // base_uri e provenance_hash já foram definidos
+    pub soulbound: bool,            // NFTs intransferíveis (Token-2022 NonTransferable)
+    pub nft_reward_rate: u64,       // Tokens de recompensa por NFT em stake por segundo
+    pub minted: u64,                // NFTs já mintados nesta coleção (base do max_supply)
+    pub allow_free_mint: bool,      // Permite mint_price = 0 no modo Fixed (mint gratuito)
+    pub reserved: [u8; 55],
+}
+
+impl CollectionConfig {
+    // Emite o CollectionUpdateEvent de uma alteração feita pelo admin
+    pub fn record_change(
+        &self,
+        event_sequence: &mut EventSequence,
+        admin: Pubkey,
+        change: CollectionField,
     ) -> Result<()> {
-        // Verificar se o sistema está pausado
-        require!(!ctx.accounts.config.emergency_paused, ErrorCode::SystemPaused);
-        
-        // Validar inputs
-        require!(!name.is_empty(), ErrorCode::InvalidInput);
-        require!(!symbol.is_empty(), ErrorCode::InvalidInput);
-        require!(!uri.is_empty(), ErrorCode::InvalidInput);
-        
-        // Verificar saldo do usuário
+        emit!(CollectionUpdateEvent {
+            event_seq: event_sequence.next_seq()?,
+            admin,
+            collection: self.collection,
+            change,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        Ok(())
+    }
+    
+    // Faixas de preço em uso
+    pub fn active_price_tiers(&self) -> Vec<PriceTier> {
+        self.price_tiers[..self.tier_count as usize].to_vec()
+    }
+    
+    // Verifica janela de mint e se mais `quantity` NFTs cabem no supply máximo da coleção
+    // A pré-venda não respeita mint_start
+    pub fn check_mint_available(&self, quantity: u64, now: i64, enforce_start: bool) -> Result<()> {
         require!(
-            ctx.accounts.payer_payment_token_account.amount >= amount,
-            ErrorCode::InsufficientFunds
+            !enforce_start || self.mint_start == 0 || now >= self.mint_start,
+            ErrorCode::MintNotStarted
         );
-        
-        // Queimar os tokens como pagamento
-        let burn_ctx = CpiContext::new(
-            ctx.accounts.token_program.to_account_info(),
-            Burn {
-                mint: ctx.accounts.payment_token_mint.to_account_info(),
-                from: ctx.accounts.payer_payment_token_account.to_account_info(),
-                authority: ctx.accounts.payer.to_account_info(),
-            },
+        require!(
+            self.mint_end == 0 || now < self.mint_end,
+            ErrorCode::MintEnded
+        );
+        let minted_after = self.minted
+            .checked_add(quantity).ok_or(ErrorCode::MathOverflow)?;
+        require!(
+            self.max_supply == 0 || minted_after <= self.max_supply,
+            ErrorCode::CollectionSoldOut
         );
-        
-        burn(burn_ctx, amount)?;
-        
-        // Emitir evento com informações da queima
-        let clock = Clock::get()?;
-        emit!(TokenBurnEvent {
-            payer: ctx.accounts.payer.key(),
-            token_mint: ctx.accounts.payment_token_mint.key(),
-            amount,
-            nft_mint: ctx.accounts.nft_mint.key(),
-            timestamp: clock.unix_timestamp,
-        });
-        
-        // Criar o NFT
-        let metadata = &mut ctx.accounts.nft_metadata;
-        metadata.name = name;
-        metadata.symbol = symbol;
-        metadata.uri = uri;
-        metadata.authority = ctx.accounts.payer.key();
-        metadata.collection = Some(ctx.accounts.collection_metadata.key());
-        
-        // Mintar o NFT
-        let cpi_accounts = MintTo {
-            mint: ctx.accounts.nft_mint.to_account_info(),
-            to: ctx.accounts.nft_token_account.to_account_info(),
-            authority: ctx.accounts.payer.to_account_info(),
-        };
-        
-        let cpi_program = ctx.accounts.token_program.to_account_info();
-        let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
-        mint_to(cpi_ctx, 1)?;
-        
-        // Incrementar o contador
-        let counter = &mut ctx.accounts.nft_counter;
-        counter.count = counter.count.checked_add(1).ok_or(ErrorCode::MathOverflow)?;
-        
         Ok(())
     }
-
-    // Aprovar uma carteira para gastar tokens
-    pub fn approve_delegate(
-        ctx: Context<ApproveDelegate>,
-        amount: u64,
-    ) -> Result<()> {
-        msg!("Aprovando delegado para gastar tokens: {}", amount);
-
-        let cpi_accounts = Approve {
-            to: ctx.accounts.token_account.to_account_info(),
-            delegate: ctx.accounts.delegate.to_account_info(),
-            authority: ctx.accounts.owner.to_account_info(),
+    
+    // Coleção em modo placeholder aguardando o commit_reveal
+    pub fn reveal_pending(&self) -> bool {
+        !self.placeholder_uri.is_empty() && !self.reveal_committed
+    }
+    
+    // URI gravada no NFT recém-mintado: placeholder enquanto a coleção não foi revelada
+    pub fn minted_uri(&self, uri: String) -> String {
+        if self.reveal_pending() {
+            self.placeholder_uri.clone()
+        } else {
+            uri
+        }
+    }
+    
+    // URI revelada do NFT de índice `index`
+    pub fn revealed_uri(&self, index: u64) -> String {
+        format!("{}{}", self.base_uri, index)
+    }
+    
+    // Programa de token dos NFTs da coleção (Token-2022 para NonTransferable)
+    pub fn nft_token_program_id(&self) -> Pubkey {
+        if self.soulbound {
+            token_2022::ID
+        } else {
+            token::ID
+        }
+    }
+    
+    // Verifica se a allowlist foi configurada
+    pub fn allowlist_active(&self) -> bool {
+        self.merkle_root != [0u8; 32]
+    }
+    
+    // Preço do mint público do NFT de índice `count`: no modo Fixed, preço zero só com
+    // allow_free_mint, para que coleções recém-criadas (mint_price = 0) não mintem de graça
+    pub fn public_price_at(&self, count: u64) -> Result<u64> {
+        require!(
+            self.pricing_mode != PricingMode::Fixed || self.mint_price > 0 || self.allow_free_mint,
+            ErrorCode::FreeMintNotAllowed
+        );
+        self.price_at(count)
+    }
+    
+    // Calcula o preço do NFT de índice `count` de acordo com a curva configurada
+    pub fn price_at(&self, count: u64) -> Result<u64> {
+        match self.pricing_mode {
+            PricingMode::Fixed => Ok(self.mint_price),
+            PricingMode::Linear => {
+                let increase = self.price_increment
+                    .checked_mul(count).ok_or(ErrorCode::MathOverflow)?;
+                Ok(self.mint_price.checked_add(increase).ok_or(ErrorCode::MathOverflow)?)
+            }
+            PricingMode::Exponential => {
+                // Exponenciação por quadrados em ponto fixo
+                let mut factor = PRICE_SCALE
+                    .checked_add(
+                        (self.price_increment as u128)
+                            .checked_mul(PRICE_SCALE).ok_or(ErrorCode::MathOverflow)?
+                            / 10000,
+                    )
+                    .ok_or(ErrorCode::MathOverflow)?;
+                let mut result = PRICE_SCALE;
+                let mut exponent = count;
+                while exponent > 0 {
+                    if exponent & 1 == 1 {
+                        result = result
+                            .checked_mul(factor).ok_or(ErrorCode::MathOverflow)?
+                            / PRICE_SCALE;
+                    }
+                    exponent >>= 1;
+                    if exponent > 0 {
+                        factor = factor
+                            .checked_mul(factor).ok_or(ErrorCode::MathOverflow)?
+                            / PRICE_SCALE;
+                    }
+                }
+                let price = (self.mint_price as u128)
+                    .checked_mul(result).ok_or(ErrorCode::MathOverflow)?
+                    / PRICE_SCALE;
+                Ok(u64::try_from(price).map_err(|_| ErrorCode::MathOverflow)?)
+            }
+            PricingMode::Tiered => {
+                let tiers = &self.price_tiers[..self.tier_count as usize];
+                Ok(tiers
+                    .iter()
+                    .rev()
+                    .find(|tier| tier.start_count <= count)
+                    .map(|tier| tier.price)
+                    .unwrap_or(self.mint_price))
+            }
+        }
+    }
+    
+    // Divide o pagamento em (queima, tesouraria, reserva) pelos bps configurados; o resto do
+    // arredondamento fica com a queima
+    pub fn split_payment(&self, amount: u64) -> Result<(u64, u64, u64)> {
+        let share = |bps: u16| -> Result<u64> {
+            let part = (amount as u128)
+                .checked_mul(bps as u128).ok_or(ErrorCode::MathOverflow)?
+                / 10000;
+            Ok(part as u64)
         };
-        
-        let cpi_program = ctx.accounts.token_program.to_account_info();
-        let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
-        approve(cpi_ctx, amount)?;
-
-        Ok(())
+        let burn_amount = share(self.burn_bps)?;
+        let treasury_amount = share(self.treasury_bps)?;
+        let reserve_amount = share(self.reserve_bps)?;
+        let remainder = amount
+            .checked_sub(burn_amount).ok_or(ErrorCode::MathOverflow)?
+            .checked_sub(treasury_amount).ok_or(ErrorCode::MathOverflow)?
+            .checked_sub(reserve_amount).ok_or(ErrorCode::MathOverflow)?;
+        Ok((burn_amount + remainder, treasury_amount, reserve_amount))
     }
+}
 
-    // Função para configurar o token após seu lançamento
-    pub fn set_payment_token(
-        ctx: Context<SetPaymentToken>,
-        payment_token_mint: Pubkey,
-    ) -> Result<()> {
-        // Verificar se o chamador é o administrador
-        require_keys_eq!(
-            ctx.accounts.admin.key(),
-            ctx.accounts.config.admin,
-            ErrorCode::Unauthorized
+// Etapas comuns a todas as formas de mint (mint_nft_with_payment, mint_batch,
+// mint_nft_with_sol e mint_allowlisted)
+
+// Sistema ativo e campos de texto dentro dos limites
+fn validate_mint_input(config: &ConfigAccount, name: &str, symbol: &str, uri: &str) -> Result<()> {
+    require!(!config.emergency_paused, ErrorCode::SystemPaused);
+    utils::validate_name(name)?;
+    utils::validate_symbol(symbol)?;
+    utils::validate_uri(uri)?;
+    Ok(())
+}
+
+// Soma `quantity` mints públicos da carteira, respeitando max_per_wallet
+fn record_wallet_mints(
+    mint_record: &mut MintRecord,
+    collection: Pubkey,
+    wallet: Pubkey,
+    quantity: u64,
+    max_per_wallet: u64,
+) -> Result<()> {
+    let new_count = mint_record.count
+        .checked_add(quantity).ok_or(ErrorCode::MathOverflow)?;
+    require!(
+        max_per_wallet == 0 || new_count <= max_per_wallet,
+        ErrorCode::WalletMintLimitReached
+    );
+    mint_record.version = ACCOUNT_VERSION;
+    mint_record.collection = collection;
+    mint_record.wallet = wallet;
+    mint_record.count = new_count;
+    Ok(())
+}
+
+// Atualiza o contador global, o total mintado e o supply em circulação da coleção
+// após `quantity` mints
+fn record_minted(
+    nft_counter: &mut NftCounter,
+    collection_config: &mut CollectionConfig,
+    quantity: u64,
+) -> Result<()> {
+    nft_counter.count = nft_counter.count
+        .checked_add(quantity).ok_or(ErrorCode::MathOverflow)?;
+    collection_config.minted = collection_config.minted
+        .checked_add(quantity).ok_or(ErrorCode::MathOverflow)?;
+    collection_config.live_supply = collection_config.live_supply
+        .checked_add(quantity).ok_or(ErrorCode::MathOverflow)?;
+    Ok(())
+}
+
+// Minta a unidade do NFT (Token ou Token-2022, conforme o programa do mint)
+fn mint_nft_token<'info>(
+    token_program: AccountInfo<'info>,
+    mint: AccountInfo<'info>,
+    to: AccountInfo<'info>,
+    authority: AccountInfo<'info>,
+) -> Result<()> {
+    token_interface::mint_to(
+        CpiContext::new(
+            token_program,
+            token_interface::MintTo { mint, to, authority },
+        ),
+        1,
+    )
+}
+
+// Cria uma conta PDA. Como o endereço é previsível, qualquer um pode enviar lamports a
+// ele antes do mint e create_account falharia; nesse caso o aluguel é completado e a
+// conta é criada com allocate + assign
+fn create_pda_account<'info>(
+    payer: AccountInfo<'info>,
+    account: AccountInfo<'info>,
+    space: usize,
+    owner: &Pubkey,
+    signer_seeds: &[&[u8]],
+    system_program: AccountInfo<'info>,
+) -> Result<()> {
+    let required_lamports = Rent::get()?.minimum_balance(space);
+    let current_lamports = account.lamports();
+    if current_lamports == 0 {
+        return system_program::create_account(
+            CpiContext::new_with_signer(
+                system_program,
+                system_program::CreateAccount { from: payer, to: account },
+                &[signer_seeds],
+            ),
+            required_lamports,
+            space as u64,
+            owner,
         );
-        
-        ctx.accounts.config.payment_token_mint = payment_token_mint;
-        msg!("Token de pagamento definido: {}", payment_token_mint);
-        
-        Ok(())
     }
+    
+    let top_up = required_lamports.saturating_sub(current_lamports);
+    if top_up > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                system_program.clone(),
+                system_program::Transfer { from: payer, to: account.clone() },
+            ),
+            top_up,
+        )?;
+    }
+    system_program::allocate(
+        CpiContext::new_with_signer(
+            system_program.clone(),
+            system_program::Allocate { account_to_allocate: account.clone() },
+            &[signer_seeds],
+        ),
+        space as u64,
+    )?;
+    system_program::assign(
+        CpiContext::new_with_signer(
+            system_program,
+            system_program::Assign { account_to_assign: account },
+            &[signer_seeds],
+        ),
+        owner,
+    )
+}
 
-    // Configurar a conta de reserva de recompensas
-    pub fn set_reward_reserve(
-        ctx: Context<SetRewardReserve>,
-        reward_reserve: Pubkey,
+// Valor cobrado por um NFT, reportado no TokenBurnEvent
+#[derive(Clone, Copy)]
+struct MintPayment {
+    price: u64,              // Preço da curva para o NFT
+    amount: u64,             // Valor cobrado (igual ao preço, exceto na allowlist e no aluguel da tesouraria SOL)
+    split: (u64, u64, u64),  // (queima, tesouraria, reserva) do valor cobrado
+    payment_type: PaymentMode,
+    token_mint: Pubkey,      // Pubkey::default() no modo Sol
+}
+
+impl MintPayment {
+    fn spl(collection_config: &CollectionConfig, amount: u64, token_mint: Pubkey) -> Result<Self> {
+        Ok(Self {
+            price: amount,
+            amount,
+            split: collection_config.split_payment(amount)?,
+            payment_type: PaymentMode::SplToken,
+            token_mint,
+        })
+    }
+
+    // No modo Sol todo o valor cobrado (com o complemento de aluguel) vai para a tesouraria
+    fn sol(amount: u64) -> Self {
+        Self {
+            price: amount,
+            amount,
+            split: (0, amount, 0),
+            payment_type: PaymentMode::Sol,
+            token_mint: Pubkey::default(),
+        }
+    }
+
+    // Mints cobrados fora da curva (allowlist) reportam também o preço da curva
+    fn with_curve_price(self, price: u64) -> Self {
+        Self { price, ..self }
+    }
+
+    fn emit(
+        &self,
+        event_sequence: &mut EventSequence,
+        payer: Pubkey,
+        nft_mint: Pubkey,
+        timestamp: i64,
     ) -> Result<()> {
-        // Verificar se o chamador é o administrador
-        require_keys_eq!(
-            ctx.accounts.admin.key(),
-            ctx.accounts.config.admin,
-            ErrorCode::Unauthorized
-        );
-        
-        ctx.accounts.config.reward_reserve = reward_reserve;
-        msg!("Reserva de recompensas configurada: {}", reward_reserve);
-        
+        emit!(TokenBurnEvent {
+            event_seq: event_sequence.next_seq()?,
+            payer,
+            token_mint: self.token_mint,
+            amount: self.amount,
+            nft_mint,
+            timestamp,
+            price: self.price,
+            treasury_amount: self.split.1,
+            reserve_amount: self.split.2,
+            payment_type: self.payment_type,
+            burn_amount: self.split.0,
+        });
         Ok(())
     }
+}
 
-    // Inicializar a reserva de recompensas
-    pub fn initialize_reward_reserve(
-        ctx: Context<InitializeRewardReserve>,
-    ) -> Result<()> {
-        // Verificar se o chamador é o administrador
-        require_keys_eq!(
-            ctx.accounts.admin.key(),
-            ctx.accounts.config.admin,
-            ErrorCode::Unauthorized
-        );
+// Contas do pagamento de um mint no token SPL
+struct SplPayment<'info> {
+    token_program: AccountInfo<'info>,
+    payment_token_mint: AccountInfo<'info>,
+    from: AccountInfo<'info>,
+    balance: u64,
+    authority: AccountInfo<'info>,
+    treasury: Option<AccountInfo<'info>>,
+    reward_reserve: Option<AccountInfo<'info>>,
+}
+
+impl<'info> SplPayment<'info> {
+    // Executa a divisão do pagamento: queima, envio à tesouraria e à reserva
+    fn settle(self, split: (u64, u64, u64)) -> Result<()> {
+        let (burn_amount, treasury_amount, reserve_amount) = split;
+        let total = burn_amount
+            .checked_add(treasury_amount).ok_or(ErrorCode::MathOverflow)?
+            .checked_add(reserve_amount).ok_or(ErrorCode::MathOverflow)?;
+        require!(self.balance >= total, ErrorCode::InsufficientFunds);
         
-        // Atualizar a configuração com o endereço da reserva
-        ctx.accounts.config.reward_reserve = ctx.accounts.reward_reserve_account.key();
+        if burn_amount > 0 {
+            let burn_ctx = CpiContext::new(
+                self.token_program.clone(),
+                Burn {
+                    mint: self.payment_token_mint.clone(),
+                    from: self.from.clone(),
+                    authority: self.authority.clone(),
+                },
+            );
+            burn(burn_ctx, burn_amount)?;
+        }
         
-        msg!("Reserva de recompensas inicializada: {}", ctx.accounts.reward_reserve_account.key());
+        if treasury_amount > 0 {
+            let treasury = self.treasury.ok_or(ErrorCode::InvalidTreasury)?;
+            let transfer_ctx = CpiContext::new(
+                self.token_program.clone(),
+                Transfer {
+                    from: self.from.clone(),
+                    to: treasury,
+                    authority: self.authority.clone(),
+                },
+            );
+            transfer(transfer_ctx, treasury_amount)?;
+        }
+        
+        if reserve_amount > 0 {
+            let reward_reserve = self.reward_reserve.ok_or(ErrorCode::InvalidRewardReserve)?;
+            let transfer_ctx = CpiContext::new(
+                self.token_program.clone(),
+                Transfer {
+                    from: self.from.clone(),
+                    to: reward_reserve,
+                    authority: self.authority.clone(),
+                },
+            );
+            transfer(transfer_ctx, reserve_amount)?;
+        }
         
         Ok(())
     }
+}
 
-    // Depositar tokens na reserva de recompensas
-    pub fn deposit_reward_reserve(
-        ctx: Context<DepositRewardReserve>,
-        amount: u64,
-    ) -> Result<()> {
-        // Verificar se o chamador é o administrador
-        require_keys_eq!(
-            ctx.accounts.admin.key(),
-            ctx.accounts.config.admin,
-            ErrorCode::Unauthorized
-        );
-        
-        // Transferir tokens do admin para a reserva
-        let transfer_ctx = CpiContext::new(
-            ctx.accounts.token_program.to_account_info(),
-            Transfer {
-                from: ctx.accounts.admin_token_account.to_account_info(),
-                to: ctx.accounts.reward_reserve_account.to_account_info(),
-                authority: ctx.accounts.admin.to_account_info(),
+// Envia `amount` lamports do payer à tesouraria SOL da coleção e retorna o total cobrado.
+// A tesouraria é um PDA sem dados que não pode ficar abaixo do mínimo isento de aluguel,
+// então o primeiro pagamento (ou o primeiro depois de esvaziá-la) também cobre esse mínimo;
+// o total, com esse complemento, não pode passar de `max_total`
+fn collect_sol_payment<'info>(
+    payer: AccountInfo<'info>,
+    sol_treasury: AccountInfo<'info>,
+    system_program: AccountInfo<'info>,
+    amount: u64,
+    max_total: u64,
+) -> Result<u64> {
+    if amount == 0 {
+        return Ok(0);
+    }
+    let rent_top_up = Rent::get()?
+        .minimum_balance(0)
+        .saturating_sub(sol_treasury.lamports());
+    let total = amount.checked_add(rent_top_up).ok_or(ErrorCode::MathOverflow)?;
+    require!(total <= max_total, ErrorCode::PriceExceedsMaximum);
+    require!(payer.lamports() >= total, ErrorCode::InsufficientFunds);
+    
+    system_program::transfer(
+        CpiContext::new(
+            system_program,
+            system_program::Transfer {
+                from: payer,
+                to: sol_treasury,
             },
-        );
-        transfer(transfer_ctx, amount)?;
-        
-        msg!("Depositados {} tokens na reserva de recompensas", amount);
-        
-        Ok(())
+        ),
+        total,
+    )?;
+    Ok(total)
+}
+
+// Registro de quantos NFTs uma carteira já mintou no mint público
+#[account]
+#[derive(InitSpace)]
+pub struct MintRecord {
+    pub version: u8,
+    pub collection: Pubkey,
+    pub wallet: Pubkey,
+    pub count: u64,
+    pub reserved: [u8; 16],
+}
+
+// Registro de quantos NFTs uma carteira já mintou pela allowlist
+#[account]
+#[derive(InitSpace)]
+pub struct AllowlistClaim {
+    pub version: u8,
+    pub collection: Pubkey,
+    pub wallet: Pubkey,
+    pub claimed: u64,
+    pub reserved: [u8; 16],
+}
+
+// Posição de staking de um NFT da coleção (o NFT fica no cofre da stake_authority)
+#[account]
+#[derive(InitSpace)]
+pub struct NftStakeAccount {
+    pub version: u8,
+    pub owner: Pubkey,
+    pub nft_mint: Pubkey,
+    pub collection: Pubkey,
+    pub staked_at: i64,
+    pub reserved: [u8; 32],
+}
+
+// NFT travado junto a uma posição de stake_tokens para receber o bônus de holder
+// (ConfigAccount.nft_holder_boost_bps). O bônus só vale se locked_at <= start_time da
+// posição: trave na mesma transação do stake_tokens ou antes de um aporte, que reinicia
+// start_time; um NFT travado depois do início da posição não rende bônus nela
+#[account]
+#[derive(InitSpace)]
+pub struct StakeBoost {
+    pub version: u8,
+    pub owner: Pubkey,
+    pub stake_account: Pubkey,
+    pub nft_mint: Pubkey,
+    pub collection: Pubkey,
+    pub locked_at: i64,
+    pub reserved: [u8; 32],
+}
+
+impl StakeBoost {
+    // O bônus só vale se o NFT já estava travado no início da posição
+    pub fn boost_bps(&self, stake_account: &StakeAccount, holder_boost_bps: u64) -> u64 {
+        if self.locked_at <= stake_account.start_time {
+            holder_boost_bps
+        } else {
+            0
+        }
     }
+}
 
-    // Ativar o sistema de staking e definir a taxa de recompensa
-    pub fn configure_staking(
-        ctx: Context<ConfigureStaking>,
-        enabled: bool,
-        reward_rate: u64,
+// Recibo transferível de uma posição de stake_tokens; quem tiver o NFT pode fazer unstake
+#[account]
+#[derive(InitSpace)]
+pub struct StakeReceipt {
+    pub version: u8,
+    pub stake_account: Pubkey,
+    pub receipt_mint: Pubkey,
+    pub issued_at: i64,
+    pub staker: Pubkey,  // Quem abriu a posição e pagou o aluguel (ocupa a antiga área reservada)
+    pub reserved: [u8; 0],
+}
+
+impl StakeReceipt {
+    // Destino do aluguel no unstake: quem abriu a posição ou, em recibos migrados do v1
+    // (sem staker gravado), o holder que resgata
+    pub fn rent_recipient(&self, holder: Pubkey) -> Pubkey {
+        if self.staker == Pubkey::default() {
+            holder
+        } else {
+            self.staker
+        }
+    }
+}
+
+#[program]
+pub mod adr_token_mint {
+    use super::*;
+
+    // Inicializa a coleção de NFTs
+    pub fn initialize_collection(
+        ctx: Context<InitializeCollection>,
+        name: String,
+        symbol: String,
+        uri: String,
     ) -> Result<()> {
-        // Verificar se o chamador é o administrador
-        require_keys_eq!(
-            ctx.accounts.admin.key(),
-            ctx.accounts.config.admin,
-            ErrorCode::Unauthorized
-        );
+        // Validar tamanho e formato dos campos
+        utils::validate_name(&name)?;
+        utils::validate_symbol(&symbol)?;
+        utils::validate_uri(&uri)?;
         
-        let old_enabled = ctx.accounts.config.staking_enabled;
-        let old_rate = ctx.accounts.config.staking_reward_rate;
+        msg!("Inicializando a coleção de NFTs: {}, {}, {}", name, symbol, uri);
         
-        ctx.accounts.config.staking_enabled = enabled;
-        ctx.accounts.config.staking_reward_rate = reward_rate;
+        // Armazenar informações na conta de metadados
+        let metadata = &mut ctx.accounts.collection_metadata;
+        metadata.version = ACCOUNT_VERSION;
+        metadata.name = name;
+        metadata.symbol = symbol;
+        metadata.uri = uri;
+        metadata.authority = ctx.accounts.payer.key();
+        metadata.collection = None;
+        metadata.locked = false;
+        metadata.index = 0;
         
-        // Emitir eventos de atualização de configuração
-        emit!(ConfigUpdateEvent {
-            admin: ctx.accounts.admin.key(),
-            field: "staking_enabled".to_string(),
-            old_value: old_enabled.to_string(),
-            new_value: enabled.to_string(),
+        // Configurar a conta do token
+        let config = &mut ctx.accounts.config;
+        config.version = ACCOUNT_VERSION;
+        config.admin = ctx.accounts.payer.key();
+        config.payment_token_mint = Pubkey::default();
+        config.staking_enabled = false;
+        config.staking_reward_rate = 0; // taxa base de recompensa (será configurada depois)
+        config.max_stake_amount = 1_000_000 * 10u64.pow(9); // Limite máximo de stake: 1 milhão de tokens
+        config.emergency_paused = false; // Inicialmente não pausado
+        config.reward_reserve = Pubkey::default(); // Será configurada depois
+        config.config_version = 0;
+        config.nft_holder_boost_bps = DEFAULT_NFT_HOLDER_BOOST_BPS;
+        config.total_staked = 0;
+        config.active_stakes = 0;
+        
+        // Inicializar o contador
+        let counter = &mut ctx.accounts.nft_counter;
+        counter.version = ACCOUNT_VERSION;
+        counter.count = 0;
+        ctx.accounts.event_sequence.version = ACCOUNT_VERSION;
+        ctx.accounts.event_sequence.seq = 0;
+        
+        // Configuração de venda da coleção: sem limite de supply e sem janela de mint
+        let collection_config = &mut ctx.accounts.collection_config;
+        collection_config.version = ACCOUNT_VERSION;
+        collection_config.collection = ctx.accounts.collection_metadata.key();
+        collection_config.max_supply = 0;
+        collection_config.mint_start = 0;
+        collection_config.mint_end = 0;
+        collection_config.mint_price = 0;
+        collection_config.merkle_root = [0u8; 32];
+        collection_config.allowlist_price = 0;
+        collection_config.max_per_wallet = 0;
+        collection_config.pricing_mode = PricingMode::Fixed;
+        collection_config.price_increment = 0;
+        collection_config.tier_count = 0;
+        collection_config.price_tiers = [PriceTier::default(); MAX_PRICE_TIERS];
+        collection_config.burn_bps = 10000; // Por padrão todo o pagamento é queimado
+        collection_config.treasury_bps = 0;
+        collection_config.reserve_bps = 0;
+        collection_config.treasury = Pubkey::default();
+        collection_config.payment_mode = PaymentMode::SplToken;
+        collection_config.live_supply = 0;
+        collection_config.redemption_amount = 0;
+        collection_config.placeholder_uri = String::new();
+        collection_config.base_uri = String::new();
+        collection_config.provenance_hash = [0u8; 32];
+        collection_config.reveal_committed = false;
+        collection_config.soulbound = false;
+        collection_config.nft_reward_rate = 0;
+        collection_config.allow_free_mint = false;
+        
+        msg!("Coleção inicializada e contador de NFTs zerado");
+        
+        let metadata = &ctx.accounts.collection_metadata;
+        let event_seq = ctx.accounts.event_sequence.next_seq()?;
+        emit!(CollectionInitializedEvent {
+            event_seq,
+            admin: ctx.accounts.payer.key(),
+            collection: metadata.key(),
+            collection_mint: ctx.accounts.collection_mint.key(),
+            config: ctx.accounts.config.key(),
+            name: metadata.name.clone(),
+            symbol: metadata.symbol.clone(),
+            uri: metadata.uri.clone(),
             timestamp: Clock::get()?.unix_timestamp,
         });
         
-        emit!(ConfigUpdateEvent {
-            admin: ctx.accounts.admin.key(),
-            field: "staking_reward_rate".to_string(),
-            old_value: old_rate.to_string(),
-            new_value: reward_rate.to_string(),
+        Ok(())
+    }
+
+
+    // Cria a sequência global de eventos em implantações anteriores a ela
+    // (initialize_collection já cria a conta nas implantações novas)
+    pub fn initialize_event_sequence(ctx: Context<InitializeEventSequence>) -> Result<()> {
+        ctx.accounts.event_sequence.version = ACCOUNT_VERSION;
+        ctx.accounts.event_sequence.seq = 0;
+        msg!("Sequência de eventos inicializada");
+        Ok(())
+    }
+
+    // Copia a ConfigAccount keypair da devnet para a PDA [b"config"] e fecha a conta antiga
+    // (o aluguel volta ao admin). Também cria a EventSequence se ainda não existir, o que
+    // dispensa initialize_event_sequence nessas implantações.
+    pub fn migrate_config(ctx: Context<MigrateConfig>) -> Result<()> {
+        let legacy_info = ctx.accounts.legacy_config.to_account_info();
+        let (legacy, config_version) = {
+            let data = legacy_info.try_borrow_data()?;
+            require!(
+                data.len() >= 8 && data[..8] == *ConfigAccount::DISCRIMINATOR,
+                ErrorCode::InvalidLegacyConfig
+            );
+            let mut payload = &data[8..];
+            let legacy = LegacyConfigAccount::deserialize(&mut payload)
+                .map_err(|_| error!(ErrorCode::InvalidLegacyConfig))?;
+            (legacy, u64::deserialize(&mut payload).unwrap_or(0))
+        };
+        require_keys_eq!(ctx.accounts.admin.key(), legacy.admin, ErrorCode::Unauthorized);
+
+        let config = &mut ctx.accounts.config;
+        config.version = ACCOUNT_VERSION;
+        config.payment_token_mint = legacy.payment_token_mint;
+        config.admin = legacy.admin;
+        config.staking_enabled = legacy.staking_enabled;
+        config.staking_reward_rate = legacy.staking_reward_rate;
+        config.max_stake_amount = legacy.max_stake_amount;
+        config.emergency_paused = legacy.emergency_paused;
+        config.reward_reserve = legacy.reward_reserve;
+        config.config_version = config_version;
+        config.nft_holder_boost_bps = DEFAULT_NFT_HOLDER_BOOST_BPS;
+        config.total_staked = 0;
+        config.active_stakes = 0;
+
+        // Fechar a conta antiga
+        let admin_info = ctx.accounts.admin.to_account_info();
+        let lamports = legacy_info.lamports();
+        **admin_info.try_borrow_mut_lamports()? = admin_info
+            .lamports()
+            .checked_add(lamports)
+            .ok_or(ErrorCode::MathOverflow)?;
+        **legacy_info.try_borrow_mut_lamports()? = 0;
+        legacy_info.realloc(0, false)?;
+        legacy_info.assign(&system_program::ID);
+
+        // Sequência criada agora (init_if_needed) começa zerada e sem versão
+        let event_sequence = &mut ctx.accounts.event_sequence;
+        if event_sequence.version == 0 {
+            event_sequence.version = ACCOUNT_VERSION;
+        }
+        emit!(ConfigMigratedEvent {
+            event_seq: event_sequence.next_seq()?,
+            admin: legacy.admin,
+            legacy_config: LEGACY_CONFIG_ACCOUNT,
+            config: config.key(),
+            config_version,
             timestamp: Clock::get()?.unix_timestamp,
         });
-        
-        msg!("Staking configurado: enabled={}, reward_rate={}", enabled, reward_rate);
-        
+
+        msg!("Configuração migrada para a PDA: {}", config.key());
         Ok(())
     }
-    
 
+    // Converte no lugar uma conta do programa ainda no layout v1 (sem versão nem área
+    // reservada). Qualquer um pode chamar: os valores não mudam e o payer cobre o aluguel
+    // do espaço extra. Não emite evento porque a própria EventSequence pode ser v1.
+    pub fn migrate_account(ctx: Context<MigrateAccount>) -> Result<()> {
+        let account_info = ctx.accounts.account.to_account_info();
+        let upgraded = migration::upgrade_account_data(&account_info.try_borrow_data()?)?;
 
-    // Função de stake simplificada
-    pub fn stake_tokens(
-        ctx: Context<StakeTokens>,
-        amount: u64,
-        period: StakingPeriod,
-    ) -> Result<()> {
-        require!(!ctx.accounts.config.emergency_paused, ErrorCode::SystemPaused);
-        require!(ctx.accounts.config.staking_enabled, ErrorCode::StakingNotEnabled);
-        require!(amount > 0, ErrorCode::InvalidStakeAmount);
-        require!(amount <= ctx.accounts.config.max_stake_amount, ErrorCode::StakeAmountTooLarge);
-    
-        let clock = Clock::get()?;
-        let current_time = clock.unix_timestamp;
-        let unlock_time = current_time
-            .checked_add(period.duration_in_seconds())
-            .ok_or(ErrorCode::MathOverflow)?;
-    
-        let stake_account = &mut ctx.accounts.stake_account;
-    
-        // Seeds para o PDA
-        let signer_seeds: &[&[u8]] = &[b"stake_authority", &[ctx.bumps.stake_authority]];
-    
-        if stake_account.amount > 0 && !stake_account.claimed {
-            let stake_authority_seed: &[u8] = b"stake_authority";
-            let bump_seed: &[u8] = &[ctx.bumps.stake_authority];
-            let signer_seeds: [&[u8]; 2] = [stake_authority_seed, bump_seed];
-            let signer_seeds_slice: &[&[u8]] = &signer_seeds;
-            let signer_seeds_nested: &[&[&[u8]]] = &[signer_seeds_slice];
-            
-            let return_ctx = CpiContext::new_with_signer(
-                ctx.accounts.token_program.to_account_info(),
-                Transfer {
-                    from: ctx.accounts.stake_token_account.to_account_info(),
-                    to: ctx.accounts.staker_token_account.to_account_info(),
-                    authority: ctx.accounts.stake_authority.to_account_info(),
-                },
-                signer_seeds_nested,
-            );
-            transfer(return_ctx, stake_account.amount)?;
-    
-            // CORREÇÃO: Sempre somar o valor anterior com o novo, independente do período
-            let new_amount = stake_account.amount.checked_add(amount).ok_or(ErrorCode::MathOverflow)?;
-    
-            // Transfere todos os tokens (antigos + novos) do staker para a conta de stake
-            let stake_ctx = CpiContext::new(
-                ctx.accounts.token_program.to_account_info(),
-                Transfer {
-                    from: ctx.accounts.staker_token_account.to_account_info(),
-                    to: ctx.accounts.stake_token_account.to_account_info(),
-                    authority: ctx.accounts.staker.to_account_info(),
-                },
-            );
-            transfer(stake_ctx, new_amount)?;
-    
-            // Atualiza stake
-            let old_amount = stake_account.amount;
-            let old_period = stake_account.period;
-            stake_account.amount = new_amount;
-            stake_account.period = period;
-            stake_account.start_time = current_time;
-            stake_account.unlock_time = unlock_time;
-    
+        let rent = Rent::get()?
+            .minimum_balance(upgraded.len())
+            .saturating_sub(account_info.lamports());
+        if rent > 0 {
+            system_program::transfer(
+                CpiContext::new(
+                    ctx.accounts.system_program.to_account_info(),
+                    system_program::Transfer {
+                        from: ctx.accounts.payer.to_account_info(),
+                        to: account_info.clone(),
+                    },
+                ),
+                rent,
+            )?;
+        }
+        account_info.realloc(upgraded.len(), false)?;
+        account_info.try_borrow_mut_data()?.copy_from_slice(&upgraded);
+
+        msg!("Conta {} migrada para a versão {}", account_info.key(), ACCOUNT_VERSION);
+        Ok(())
+    }
 
+    // Função atualizada de mint
+    // O preço é calculado on-chain; max_price protege o usuário contra variações da curva
+    // Em coleções soulbound o NFT é criado no Token-2022 com a extensão NonTransferable
+    // (passe o Token-2022 em nft_token_program)
+    pub fn mint_nft_with_payment(
+        ctx: Context<MintNFTWithPayment>,
+        name: String,
+        symbol: String,
+        uri: String,
+        max_price: u64,
+    ) -> Result<()> {
+        validate_mint_input(&ctx.accounts.config, &name, &symbol, &uri)?;
+        
+        // Verificar janela de mint e supply máximo da coleção
+        let clock = Clock::get()?;
+        let index = ctx.accounts.nft_counter.count;
+        let collection_config = &ctx.accounts.collection_config;
+        require!(
+            collection_config.payment_mode == PaymentMode::SplToken,
+            ErrorCode::InvalidPaymentMode
+        );
+        collection_config.check_mint_available(1, clock.unix_timestamp, true)?;
+        
+        // Calcular o preço do próximo NFT pela curva da coleção
+        let price = collection_config.public_price_at(index)?;
+        require!(price <= max_price, ErrorCode::PriceExceedsMaximum);
+        let payment = MintPayment::spl(collection_config, price, ctx.accounts.payment_token_mint.key())?;
+        let max_per_wallet = collection_config.max_per_wallet;
+        let soulbound = collection_config.soulbound;
+        
+        let payer_key = ctx.accounts.payer.key();
+        let collection_key = ctx.accounts.collection_metadata.key();
+        record_wallet_mints(&mut ctx.accounts.mint_record, collection_key, payer_key, 1, max_per_wallet)?;
+        
+        // Dividir o pagamento entre queima, tesouraria e reserva de recompensas
+        ctx.accounts.spl_payment().settle(payment.split)?;
+        payment.emit(
+            &mut ctx.accounts.event_sequence,
+            payer_key,
+            ctx.accounts.nft_mint.key(),
+            clock.unix_timestamp,
+        )?;
+        
+        // Criar o mint do NFT
+        let index_bytes = index.to_le_bytes();
+        let mint_seeds: &[&[u8]] = &[
+            b"nft_mint",
+            collection_key.as_ref(),
+            index_bytes.as_ref(),
+            &[ctx.bumps.nft_mint],
+        ];
+        let mint_space = if soulbound {
+            token_interface::find_mint_account_size(Some(&vec![ExtensionType::NonTransferable]))?
         } else {
-            // Primeiro stake ou após claim
-            let stake_ctx = CpiContext::new(
-                ctx.accounts.token_program.to_account_info(),
-                Transfer {
-                    from: ctx.accounts.staker_token_account.to_account_info(),
-                    to: ctx.accounts.stake_token_account.to_account_info(),
-                    authority: ctx.accounts.staker.to_account_info(),
+            token::spl_token::state::Mint::LEN
+        };
+        create_pda_account(
+            ctx.accounts.payer.to_account_info(),
+            ctx.accounts.nft_mint.to_account_info(),
+            mint_space,
+            &ctx.accounts.nft_token_program.key(),
+            mint_seeds,
+            ctx.accounts.system_program.to_account_info(),
+        )?;
+        if soulbound {
+            // A extensão precisa ser inicializada antes do próprio mint
+            token_interface::non_transferable_mint_initialize(CpiContext::new(
+                ctx.accounts.nft_token_program.to_account_info(),
+                token_interface::NonTransferableMintInitialize {
+                    token_program_id: ctx.accounts.nft_token_program.to_account_info(),
+                    mint: ctx.accounts.nft_mint.to_account_info(),
                 },
-            );
-            transfer(stake_ctx, amount)?;
-    
-            stake_account.owner = ctx.accounts.staker.key();
-            stake_account.amount = amount;
-            stake_account.start_time = current_time;
-            stake_account.unlock_time = unlock_time;
-            stake_account.period = period;
-            stake_account.claimed = false;
-    
-            emit!(StakingEvent {
-                staker: ctx.accounts.staker.key(),
-                amount,
-                period,
-                start_time: current_time,
-                unlock_time,
-                stake_account: stake_account.key(),
-            });
+            ))?;
         }
-    
-        Ok(())
-    }    
-    
-    // Resgatar tokens do staking e receber recompensas
-    pub fn unstake_tokens(ctx: Context<UnstakeTokens>) -> Result<()> {
-        // Verificar se o sistema está pausado para emergências
-        require!(!ctx.accounts.config.emergency_paused, ErrorCode::SystemPaused);
+        token_interface::initialize_mint2(
+            CpiContext::new(
+                ctx.accounts.nft_token_program.to_account_info(),
+                token_interface::InitializeMint2 {
+                    mint: ctx.accounts.nft_mint.to_account_info(),
+                },
+            ),
+            0,
+            &payer_key,
+            None,
+        )?;
         
-        // Verificar se o período de staking terminou
-        let clock = Clock::get()?;
-        let current_time = clock.unix_timestamp;
+        // Criar a conta de token associada (no Token-2022 o programa ATA adiciona ImmutableOwner)
+        associated_token::create(CpiContext::new(
+            ctx.accounts.associated_token_program.to_account_info(),
+            associated_token::Create {
+                payer: ctx.accounts.payer.to_account_info(),
+                associated_token: ctx.accounts.nft_token_account.to_account_info(),
+                authority: ctx.accounts.payer.to_account_info(),
+                mint: ctx.accounts.nft_mint.to_account_info(),
+                system_program: ctx.accounts.system_program.to_account_info(),
+                token_program: ctx.accounts.nft_token_program.to_account_info(),
+            },
+        ))?;
+        
+        // Criar o NFT
+        let uri = ctx.accounts.collection_config.minted_uri(uri);
+        let collection_index = ctx.accounts.collection_config.minted;
+        ctx.accounts.nft_metadata.set_inner(
+            NFTMetadata::minted(payer_key, collection_key, name, symbol, uri, collection_index),
+        );
+        mint_nft_token(
+            ctx.accounts.nft_token_program.to_account_info(),
+            ctx.accounts.nft_mint.to_account_info(),
+            ctx.accounts.nft_token_account.to_account_info(),
+            ctx.accounts.payer.to_account_info(),
+        )?;
         
+        record_minted(&mut ctx.accounts.nft_counter, &mut ctx.accounts.collection_config, 1)?;
+        
+        Ok(())
+    }
+
+    // Mintar vários NFTs em uma única instrução, com um único pagamento agregado
+    // remaining_accounts: para cada NFT, [nft_mint, nft_metadata, nft_token_account] graváveis
+    // bumps: bumps canônicos das PDAs de cada NFT, na mesma ordem; bumps não canônicos são rejeitados
+    pub fn mint_batch<'info>(
+        ctx: Context<'_, '_, 'info, 'info, MintBatch<'info>>,
+        quantity: u8,
+        name: String,
+        symbol: String,
+        uri: String,
+        max_total_price: u64,
+        bumps: Vec<NftBumps>,
+    ) -> Result<()> {
+        validate_mint_input(&ctx.accounts.config, &name, &symbol, &uri)?;
         require!(
-            current_time >= ctx.accounts.stake_account.unlock_time,
-            ErrorCode::StakingPeriodNotCompleted
+            quantity > 0 && quantity <= MAX_BATCH_SIZE,
+            ErrorCode::InvalidBatchSize
+        );
+        require!(
+            ctx.remaining_accounts.len() == quantity as usize * 3
+                && bumps.len() == quantity as usize,
+            ErrorCode::InvalidBatchAccounts
         );
         
+        let clock = Clock::get()?;
+        let first_index = ctx.accounts.nft_counter.count;
+        let last_index = first_index
+            .checked_add(quantity as u64 - 1).ok_or(ErrorCode::MathOverflow)?;
+        let collection_config = &ctx.accounts.collection_config;
         require!(
-            !ctx.accounts.stake_account.claimed,
-            ErrorCode::RewardsAlreadyClaimed
+            collection_config.payment_mode == PaymentMode::SplToken,
+            ErrorCode::InvalidPaymentMode
         );
+        require!(!collection_config.soulbound, ErrorCode::SoulboundCollection);
+        // Verificar a janela e se o lote inteiro ainda cabe no supply
+        collection_config.check_mint_available(quantity as u64, clock.unix_timestamp, true)?;
         
-        // Calcular recompensas com verificações de overflow
-        let staked_amount = ctx.accounts.stake_account.amount;
-        let period = ctx.accounts.stake_account.period;
-        let base_rate = ctx.accounts.config.staking_reward_rate;
-        let multiplier = period.reward_multiplier();
+        // Preço e divisão de cada NFT, somados para um único pagamento
+        let payment_token_mint = ctx.accounts.payment_token_mint.key();
+        let mut payments = Vec::with_capacity(quantity as usize);
+        let mut total_split = (0u64, 0u64, 0u64);
+        for index in first_index..=last_index {
+            let payment = MintPayment::spl(collection_config, collection_config.public_price_at(index)?, payment_token_mint)?;
+            total_split.0 = total_split.0.checked_add(payment.split.0).ok_or(ErrorCode::MathOverflow)?;
+            total_split.1 = total_split.1.checked_add(payment.split.1).ok_or(ErrorCode::MathOverflow)?;
+            total_split.2 = total_split.2.checked_add(payment.split.2).ok_or(ErrorCode::MathOverflow)?;
+            payments.push(payment);
+        }
+        let total_price = total_split.0
+            .checked_add(total_split.1).ok_or(ErrorCode::MathOverflow)?
+            .checked_add(total_split.2).ok_or(ErrorCode::MathOverflow)?;
+        require!(total_price <= max_total_price, ErrorCode::PriceExceedsMaximum);
+        let max_per_wallet = collection_config.max_per_wallet;
         
-        // Cálculo: staked_amount * (base_rate / 10000) * (multiplier / 100)
-        let reward_amount = staked_amount
-            .checked_mul(base_rate).ok_or(ErrorCode::MathOverflow)?
-            .checked_div(10000).ok_or(ErrorCode::MathOverflow)?
-            .checked_mul(multiplier).ok_or(ErrorCode::MathOverflow)?
-            .checked_div(100).ok_or(ErrorCode::MathOverflow)?;
+        let collection_key = ctx.accounts.collection_metadata.key();
+        let payer_key = ctx.accounts.payer.key();
+        record_wallet_mints(
+            &mut ctx.accounts.mint_record,
+            collection_key,
+            payer_key,
+            quantity as u64,
+            max_per_wallet,
+        )?;
         
-        // Transferir os tokens originais de volta para o staker
-        let stake_authority_seed = b"stake_authority";
-        let authority_seeds = &[
-            stake_authority_seed.as_ref(),
-            &[ctx.bumps.stake_authority],
-        ];
-        let signer_seeds = &[&authority_seeds[..]];
-
-        let transfer_ctx = CpiContext::new_with_signer(
-            ctx.accounts.token_program.to_account_info(),
-            Transfer {
-                from: ctx.accounts.stake_token_account.to_account_info(),
-                to: ctx.accounts.staker_token_account.to_account_info(),
-                authority: ctx.accounts.stake_authority.to_account_info(),
-            },
-            signer_seeds,
-        );
-        transfer(transfer_ctx, staked_amount)?;
+        // Pagamento agregado de todo o lote
+        ctx.accounts.spl_payment().settle(total_split)?;
         
-        // Se houver recompensas, transferir da reserva para o staker
-        if reward_amount > 0 {
-            // Verificar se há saldo suficiente na reserva
-            require!(
-                ctx.accounts.reward_reserve_account.amount >= reward_amount,
-                ErrorCode::InsufficientRewardReserve
+        // Índices na coleção: o supply da coleção já foi verificado para o lote inteiro
+        let first_collection_index = ctx.accounts.collection_config.minted;
+        let payer_info = ctx.accounts.payer.to_account_info();
+        let token_program_info = ctx.accounts.token_program.to_account_info();
+        let system_program_info = ctx.accounts.system_program.to_account_info();
+        
+        for (offset, accounts) in ctx.remaining_accounts.chunks(3).enumerate() {
+            let index = first_index + offset as u64;
+            let nft_mint_info = &accounts[0];
+            let nft_metadata_info = &accounts[1];
+            let nft_token_account_info = &accounts[2];
+            
+            // Conferir os endereços esperados para este índice
+            let index_bytes = index.to_le_bytes();
+            let mint_seeds: &[&[u8]] = &[
+                b"nft_mint",
+                collection_key.as_ref(),
+                index_bytes.as_ref(),
+                &[bumps[offset].nft_mint],
+            ];
+            // Só os bumps canônicos: BurnNft, StakeNft e LockBoostNft derivam apenas esses endereços
+            let (mint_key, metadata_key) = utils::batch_nft_addresses(&collection_key, index, &bumps[offset])?;
+            require_keys_eq!(nft_mint_info.key(), mint_key, ErrorCode::InvalidBatchAccounts);
+            let metadata_seeds: &[&[u8]] = &[
+                b"nft_metadata",
+                mint_key.as_ref(),
+                &[bumps[offset].nft_metadata],
+            ];
+            require_keys_eq!(nft_metadata_info.key(), metadata_key, ErrorCode::InvalidBatchAccounts);
+            require_keys_eq!(
+                nft_token_account_info.key(),
+                get_associated_token_address(&payer_key, &mint_key),
+                ErrorCode::InvalidBatchAccounts
             );
             
-            // Transferir recompensas da reserva para o staker
-            let reward_transfer_ctx = CpiContext::new_with_signer(
-                ctx.accounts.token_program.to_account_info(),
-                Transfer {
-                    from: ctx.accounts.reward_reserve_account.to_account_info(),
-                    to: ctx.accounts.staker_token_account.to_account_info(),
-                    authority: ctx.accounts.stake_authority.to_account_info(),
-                },
-                signer_seeds,
+            // Criar e inicializar o mint do NFT
+            create_pda_account(
+                payer_info.clone(),
+                nft_mint_info.clone(),
+                token::spl_token::state::Mint::LEN,
+                &token::ID,
+                mint_seeds,
+                system_program_info.clone(),
+            )?;
+            token::initialize_mint2(
+                CpiContext::new(
+                    token_program_info.clone(),
+                    InitializeMint2 { mint: nft_mint_info.clone() },
+                ),
+                0,
+                &payer_key,
+                None,
+            )?;
+            
+            // Criar a conta de metadados
+            create_pda_account(
+                payer_info.clone(),
+                nft_metadata_info.clone(),
+                NFT_METADATA_SPACE,
+                &crate::ID,
+                metadata_seeds,
+                system_program_info.clone(),
+            )?;
+            let metadata = NFTMetadata::minted(
+                payer_key,
+                collection_key,
+                name.clone(),
+                symbol.clone(),
+                ctx.accounts.collection_config.minted_uri(uri.clone()),
+                first_collection_index + offset as u64,
             );
-            transfer(reward_transfer_ctx, reward_amount)?;
+            metadata.try_serialize(&mut &mut nft_metadata_info.try_borrow_mut_data()?[..])?;
+            
+            // Criar a conta de token associada e mintar o NFT
+            associated_token::create(CpiContext::new(
+                ctx.accounts.associated_token_program.to_account_info(),
+                associated_token::Create {
+                    payer: payer_info.clone(),
+                    associated_token: nft_token_account_info.clone(),
+                    authority: payer_info.clone(),
+                    mint: nft_mint_info.clone(),
+                    system_program: system_program_info.clone(),
+                    token_program: token_program_info.clone(),
+                },
+            ))?;
+            mint_nft_token(
+                token_program_info.clone(),
+                nft_mint_info.clone(),
+                nft_token_account_info.clone(),
+                payer_info.clone(),
+            )?;
+            
+            payments[offset].emit(
+                &mut ctx.accounts.event_sequence,
+                payer_key,
+                mint_key,
+                clock.unix_timestamp,
+            )?;
         }
         
-        // Marcar como reivindicado
-        ctx.accounts.stake_account.claimed = true;
+        record_minted(&mut ctx.accounts.nft_counter, &mut ctx.accounts.collection_config, quantity as u64)?;
         
-        // Emitir evento de unstaking
-        emit!(UnstakingEvent {
-            staker: ctx.accounts.staker.key(),
-            stake_account: ctx.accounts.stake_account.key(),
-            original_amount: staked_amount,
-            reward_amount,
-            total_amount: staked_amount.checked_add(reward_amount).ok_or(ErrorCode::MathOverflow)?,
-            timestamp: clock.unix_timestamp,
-        });
+        msg!("Lote de {} NFTs mintado, total pago: {}", quantity, total_price);
         
-        msg!("Unstake concluído: {} tokens originais + {} tokens de recompensa", 
-            staked_amount, reward_amount);
+        Ok(())
+    }
+
+    // Mint pago em SOL, para coleções com payment_mode = Sol
+    // O preço (em lamports) é calculado pela curva da coleção; max_price protege o usuário
+    pub fn mint_nft_with_sol(
+        ctx: Context<MintNFTWithSol>,
+        name: String,
+        symbol: String,
+        uri: String,
+        max_price: u64,
+    ) -> Result<()> {
+        validate_mint_input(&ctx.accounts.config, &name, &symbol, &uri)?;
+        
+        let clock = Clock::get()?;
+        let index = ctx.accounts.nft_counter.count;
+        let collection_config = &ctx.accounts.collection_config;
+        require!(
+            collection_config.payment_mode == PaymentMode::Sol,
+            ErrorCode::InvalidPaymentMode
+        );
+        require!(!collection_config.soulbound, ErrorCode::SoulboundCollection);
+        collection_config.check_mint_available(1, clock.unix_timestamp, true)?;
+        
+        let price = collection_config.public_price_at(index)?;
+        require!(price <= max_price, ErrorCode::PriceExceedsMaximum);
+        let max_per_wallet = collection_config.max_per_wallet;
+        
+        let payer_key = ctx.accounts.payer.key();
+        let collection_key = ctx.accounts.collection_metadata.key();
+        record_wallet_mints(&mut ctx.accounts.mint_record, collection_key, payer_key, 1, max_per_wallet)?;
+        
+        // Transferir os lamports para a tesouraria da coleção; max_price limita também o
+        // complemento de aluguel do primeiro pagamento
+        let charged = collect_sol_payment(
+            ctx.accounts.payer.to_account_info(),
+            ctx.accounts.sol_treasury.to_account_info(),
+            ctx.accounts.system_program.to_account_info(),
+            price,
+            max_price,
+        )?;
+        MintPayment::sol(charged).with_curve_price(price).emit(
+            &mut ctx.accounts.event_sequence,
+            payer_key,
+            ctx.accounts.nft_mint.key(),
+            clock.unix_timestamp,
+        )?;
+        
+        // Criar o NFT
+        let uri = ctx.accounts.collection_config.minted_uri(uri);
+        let collection_index = ctx.accounts.collection_config.minted;
+        ctx.accounts.nft_metadata.set_inner(
+            NFTMetadata::minted(payer_key, collection_key, name, symbol, uri, collection_index),
+        );
+        mint_nft_token(
+            ctx.accounts.token_program.to_account_info(),
+            ctx.accounts.nft_mint.to_account_info(),
+            ctx.accounts.nft_token_account.to_account_info(),
+            ctx.accounts.payer.to_account_info(),
+        )?;
+        
+        record_minted(&mut ctx.accounts.nft_counter, &mut ctx.accounts.collection_config, 1)?;
         
         Ok(())
     }
 
-    pub fn set_emergency_pause(
-        ctx: Context<EmergencyPause>,
-        paused: bool,
-        reason: String,
+    // Definir se a coleção é paga com o token SPL ou com SOL
+    pub fn set_payment_mode(
+        ctx: Context<SetPaymentMode>,
+        payment_mode: PaymentMode,
     ) -> Result<()> {
-        require_keys_eq!(
+        let old_mode = ctx.accounts.collection_config.payment_mode;
+        ctx.accounts.collection_config.payment_mode = payment_mode;
+        ctx.accounts.collection_config.record_change(
+            &mut ctx.accounts.event_sequence,
             ctx.accounts.admin.key(),
-            ctx.accounts.config.admin,
-            ErrorCode::Unauthorized
+            CollectionField::PaymentMode { old: old_mode, new: payment_mode },
+        )?;
+        
+        msg!("Forma de pagamento da coleção atualizada: sol={}", payment_mode == PaymentMode::Sol);
+        
+        Ok(())
+    }
+
+    // Ativar/desativar o modo soulbound (NFTs intransferíveis) da coleção
+    pub fn set_soulbound(
+        ctx: Context<SetSoulbound>,
+        soulbound: bool,
+    ) -> Result<()> {
+        let old_soulbound = ctx.accounts.collection_config.soulbound;
+        ctx.accounts.collection_config.soulbound = soulbound;
+        ctx.accounts.collection_config.record_change(
+            &mut ctx.accounts.event_sequence,
+            ctx.accounts.admin.key(),
+            CollectionField::Soulbound { old: old_soulbound, new: soulbound },
+        )?;
+        
+        msg!("Modo soulbound da coleção atualizado: {}", soulbound);
+        
+        Ok(())
+    }
+
+    // Definir a taxa de recompensa por segundo dos NFTs da coleção em stake
+    pub fn set_nft_reward_rate(
+        ctx: Context<SetNftRewardRate>,
+        nft_reward_rate: u64,
+    ) -> Result<()> {
+        let old_rate = ctx.accounts.collection_config.nft_reward_rate;
+        ctx.accounts.collection_config.nft_reward_rate = nft_reward_rate;
+        ctx.accounts.collection_config.record_change(
+            &mut ctx.accounts.event_sequence,
+            ctx.accounts.admin.key(),
+            CollectionField::NftRewardRate { old: old_rate, new: nft_reward_rate },
+        )?;
+        
+        msg!("Taxa de recompensa de NFTs em stake atualizada: {} por segundo", nft_reward_rate);
+        
+        Ok(())
+    }
+
+    // Sacar lamports da tesouraria SOL da coleção
+    pub fn withdraw_sol_treasury(
+        ctx: Context<WithdrawSolTreasury>,
+        amount: u64,
+    ) -> Result<()> {
+        let treasury_balance = ctx.accounts.sol_treasury.lamports();
+        require!(treasury_balance >= amount, ErrorCode::InsufficientFunds);
+        
+        // A tesouraria precisa continuar isenta de aluguel ou ser esvaziada
+        let remaining = treasury_balance - amount;
+        let rent_exempt_minimum = Rent::get()?.minimum_balance(0);
+        require!(
+            remaining == 0 || remaining >= rent_exempt_minimum,
+            ErrorCode::InsufficientFunds
         );
         
-        ctx.accounts.config.emergency_paused = paused;
+        let collection_key = ctx.accounts.collection_metadata.key();
+        let treasury_seeds: &[&[u8]] = &[
+            b"sol_treasury",
+            collection_key.as_ref(),
+            &[ctx.bumps.sol_treasury],
+        ];
+        let signer_seeds = &[treasury_seeds];
         
-        // Emitir evento de pausa
-        emit!(EmergencyPauseEvent {
+        let transfer_ctx = CpiContext::new_with_signer(
+            ctx.accounts.system_program.to_account_info(),
+            system_program::Transfer {
+                from: ctx.accounts.sol_treasury.to_account_info(),
+                to: ctx.accounts.destination.to_account_info(),
+            },
+            signer_seeds,
+        );
+        system_program::transfer(transfer_ctx, amount)?;
+        
+        let event_seq = ctx.accounts.event_sequence.next_seq()?;
+        emit!(SolTreasuryWithdrawEvent {
+            event_seq,
             admin: ctx.accounts.admin.key(),
-            paused,
-            reason: reason.clone(),
+            collection: collection_key,
+            sol_treasury: ctx.accounts.sol_treasury.key(),
+            destination: ctx.accounts.destination.key(),
+            amount,
+            old_balance: treasury_balance,
+            new_balance: remaining,
             timestamp: Clock::get()?.unix_timestamp,
         });
         
-        msg!("Status de emergência atualizado: paused={}, reason={}", paused, reason);
+        msg!("Sacados {} lamports da tesouraria da coleção", amount);
         
         Ok(())
     }
+
+    // Mint na fase de allowlist, com prova de Merkle e alocação por carteira
+    pub fn mint_allowlisted(
+        ctx: Context<MintAllowlisted>,
+        name: String,
+        symbol: String,
+        uri: String,
+        allocation: u64,
+        proof: Vec<[u8; 32]>,
+    ) -> Result<()> {
+        validate_mint_input(&ctx.accounts.config, &name, &symbol, &uri)?;
+        
+        let collection_config = &ctx.accounts.collection_config;
+        require!(collection_config.allowlist_active(), ErrorCode::AllowlistNotActive);
+        require!(!collection_config.soulbound, ErrorCode::SoulboundCollection);
+        
+        // A pré-venda ignora mint_start, mas respeita o fim da janela e o supply
+        let clock = Clock::get()?;
+        collection_config.check_mint_available(1, clock.unix_timestamp, false)?;
+        
+        // Verificar que (carteira, alocação) pertence à allowlist
+        let payer_key = ctx.accounts.payer.key();
+        let leaf = utils::allowlist_leaf(&payer_key, allocation);
+        require!(
+            utils::verify_merkle_proof(&proof, collection_config.merkle_root, leaf),
+            ErrorCode::InvalidMerkleProof
+        );
+        
+        // Verificar alocação restante da carteira
+        let collection_key = ctx.accounts.collection_metadata.key();
+        let claim = &mut ctx.accounts.allowlist_claim;
+        require!(claim.claimed < allocation, ErrorCode::AllowlistAllocationExceeded);
+        claim.version = ACCOUNT_VERSION;
+        claim.collection = collection_key;
+        claim.wallet = payer_key;
+        claim.claimed = claim.claimed.checked_add(1).ok_or(ErrorCode::MathOverflow)?;
+        
+        // Preço da allowlist é definido pelo admin, não pelo usuário, e cobrado na
+        // forma de pagamento da coleção (em lamports no modo Sol)
+        let amount = ctx.accounts.collection_config.allowlist_price;
+        let curve_price = ctx.accounts.collection_config.price_at(ctx.accounts.nft_counter.count)?;
+        let payment = match ctx.accounts.collection_config.payment_mode {
+            PaymentMode::SplToken => {
+                let payment = MintPayment::spl(
+                    &ctx.accounts.collection_config,
+                    amount,
+                    ctx.accounts.config.payment_token_mint,
+                )?;
+                ctx.accounts.spl_payment()?.settle(payment.split)?;
+                payment
+            }
+            PaymentMode::Sol => {
+                let sol_treasury = ctx.accounts.sol_treasury.as_ref()
+                    .ok_or(ErrorCode::InvalidTreasury)?;
+                // Sem limite do comprador: o preço da allowlist é fixado pelo admin
+                let charged = collect_sol_payment(
+                    ctx.accounts.payer.to_account_info(),
+                    sol_treasury.to_account_info(),
+                    ctx.accounts.system_program.to_account_info(),
+                    amount,
+                    u64::MAX,
+                )?;
+                MintPayment::sol(charged)
+            }
+        };
+        payment.with_curve_price(curve_price).emit(
+            &mut ctx.accounts.event_sequence,
+            payer_key,
+            ctx.accounts.nft_mint.key(),
+            clock.unix_timestamp,
+        )?;
+        
+        // Criar o NFT
+        let uri = ctx.accounts.collection_config.minted_uri(uri);
+        let collection_index = ctx.accounts.collection_config.minted;
+        ctx.accounts.nft_metadata.set_inner(
+            NFTMetadata::minted(payer_key, collection_key, name, symbol, uri, collection_index),
+        );
+        mint_nft_token(
+            ctx.accounts.token_program.to_account_info(),
+            ctx.accounts.nft_mint.to_account_info(),
+            ctx.accounts.nft_token_account.to_account_info(),
+            ctx.accounts.payer.to_account_info(),
+        )?;
+        
+        record_minted(&mut ctx.accounts.nft_counter, &mut ctx.accounts.collection_config, 1)?;
+        
+        msg!("Mint de allowlist: {}/{} para {}", 
+            ctx.accounts.allowlist_claim.claimed, allocation, payer_key);
+        
+        Ok(())
+    }
+
+    // Configurar a curva de preço do mint público
+    // allow_free_mint libera mint_price = 0 no modo Fixed; sem ele o mint gratuito é rejeitado
+    pub fn set_pricing_curve(
+        ctx: Context<SetPricingCurve>,
+        pricing_mode: PricingMode,
+        price_increment: u64,
+        tiers: Vec<PriceTier>,
+        allow_free_mint: bool,
+    ) -> Result<()> {
+        require!(tiers.len() <= MAX_PRICE_TIERS, ErrorCode::InvalidPriceTiers);
+        // Faixas precisam estar em ordem crescente de início
+        require!(
+            tiers.windows(2).all(|pair| pair[0].start_count < pair[1].start_count),
+            ErrorCode::InvalidPriceTiers
+        );
+        if pricing_mode == PricingMode::Tiered {
+            require!(!tiers.is_empty(), ErrorCode::InvalidPriceTiers);
+        }
+        
+        let collection_config = &mut ctx.accounts.collection_config;
+        let old_mode = collection_config.pricing_mode;
+        let old_increment = collection_config.price_increment;
+        let old_tiers = collection_config.active_price_tiers();
+        let old_allow_free_mint = collection_config.allow_free_mint;
+        collection_config.pricing_mode = pricing_mode;
+        collection_config.allow_free_mint = allow_free_mint;
+        collection_config.price_increment = price_increment;
+        collection_config.tier_count = tiers.len() as u8;
+        collection_config.price_tiers = [PriceTier::default(); MAX_PRICE_TIERS];
+        collection_config.price_tiers[..tiers.len()].copy_from_slice(&tiers);
+        
+        // Garantir que o preço atual pode ser calculado com a nova curva
+        let current_price = collection_config.price_at(ctx.accounts.nft_counter.count)?;
+        
+        let admin = ctx.accounts.admin.key();
+        let event_sequence = &mut ctx.accounts.event_sequence;
+        collection_config.record_change(event_sequence, admin, CollectionField::PricingMode { old: old_mode, new: pricing_mode })?;
+        collection_config.record_change(event_sequence, admin, CollectionField::PriceIncrement { old: old_increment, new: price_increment })?;
+        collection_config.record_change(event_sequence, admin, CollectionField::PriceTiers { old: old_tiers, new: tiers.clone() })?;
+        collection_config.record_change(event_sequence, admin, CollectionField::AllowFreeMint { old: old_allow_free_mint, new: allow_free_mint })?;
+        
+        msg!("Curva de preço configurada: increment={}, faixas={}, preço atual={}", 
+            price_increment, tiers.len(), current_price);
+        
+        Ok(())
+    }
+
+    // Queimar um NFT da coleção, fechando metadados e conta de token
+    // Opcionalmente devolve redemption_amount tokens da pool de resgate da coleção
+    pub fn burn_nft(ctx: Context<BurnNft>) -> Result<()> {
+        require!(!ctx.accounts.config.emergency_paused, ErrorCode::SystemPaused);
+        
+        let held = ctx.accounts.nft_token_account.amount;
+        require!(held > 0, ErrorCode::InsufficientFunds);
+        
+        // Queimar todo o saldo do NFT para permitir fechar a conta de token
+        // (via nft_token_program, que pode ser Token ou Token-2022 em coleções soulbound)
+        let burn_ctx = CpiContext::new(
+            ctx.accounts.nft_token_program.to_account_info(),
+            token_interface::Burn {
+                mint: ctx.accounts.nft_mint.to_account_info(),
+                from: ctx.accounts.nft_token_account.to_account_info(),
+                authority: ctx.accounts.holder.to_account_info(),
+            },
+        );
+        token_interface::burn(burn_ctx, held)?;
+        
+        // Fechar a conta de token e devolver o aluguel ao holder
+        let close_ctx = CpiContext::new(
+            ctx.accounts.nft_token_program.to_account_info(),
+            token_interface::CloseAccount {
+                account: ctx.accounts.nft_token_account.to_account_info(),
+                destination: ctx.accounts.holder.to_account_info(),
+                authority: ctx.accounts.holder.to_account_info(),
+            },
+        );
+        token_interface::close_account(close_ctx)?;
+        
+        // Resgate opcional na pool da coleção
+        let refund_amount = ctx.accounts.collection_config.redemption_amount;
+        if refund_amount > 0 {
+            let redemption_pool = ctx.accounts.redemption_pool
+                .as_ref()
+                .ok_or(ErrorCode::InvalidRedemptionPool)?;
+            let holder_payment_token_account = ctx.accounts.holder_payment_token_account
+                .as_ref()
+                .ok_or(ErrorCode::InvalidPaymentToken)?;
+            require!(
+                redemption_pool.amount >= refund_amount,
+                ErrorCode::InsufficientRedemptionPool
+            );
+            
+            let collection_key = ctx.accounts.collection_metadata.key();
+            let authority_seeds: &[&[u8]] = &[
+                b"redemption_authority",
+                collection_key.as_ref(),
+                &[ctx.bumps.redemption_authority],
+            ];
+            let signer_seeds = &[authority_seeds];
+            
+            let refund_ctx = CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: redemption_pool.to_account_info(),
+                    to: holder_payment_token_account.to_account_info(),
+                    authority: ctx.accounts.redemption_authority.to_account_info(),
+                },
+                signer_seeds,
+            );
+            transfer(refund_ctx, refund_amount)?;
+        }
+        
+        // Atualizar o supply em circulação; NFTs mintados antes do registro de uma coleção
+        // antiga não entraram no live_supply, então a contagem não fica negativa
+        let collection_config = &mut ctx.accounts.collection_config;
+        collection_config.live_supply = collection_config.live_supply.saturating_sub(1);
+        
+        let event_seq = ctx.accounts.event_sequence.next_seq()?;
+        emit!(NftBurnEvent {
+            event_seq,
+            holder: ctx.accounts.holder.key(),
+            nft_mint: ctx.accounts.nft_mint.key(),
+            collection: ctx.accounts.collection_metadata.key(),
+            refund_amount,
+            live_supply: collection_config.live_supply,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        
+        msg!("NFT {} queimado, resgate de {} tokens", ctx.accounts.nft_mint.key(), refund_amount);
+        
+        Ok(())
+    }
+
+    // Atualizar nome, símbolo e/ou URI de um NFT (ou da coleção) pela autoridade
+    pub fn update_nft_metadata(
+        ctx: Context<UpdateNftMetadata>,
+        name: Option<String>,
+        symbol: Option<String>,
+        uri: Option<String>,
+    ) -> Result<()> {
+        ctx.accounts.check_reveal()?;
+        let metadata = &mut ctx.accounts.nft_metadata;
+        
+        if let Some(name) = name {
+            utils::validate_name(&name)?;
+            metadata.name = name;
+        }
+        if let Some(symbol) = symbol {
+            utils::validate_symbol(&symbol)?;
+            metadata.symbol = symbol;
+        }
+        if let Some(uri) = uri {
+            utils::validate_uri(&uri)?;
+            metadata.uri = uri;
+        }
+        
+        let event_seq = ctx.accounts.event_sequence.next_seq()?;
+        emit!(NftMetadataUpdateEvent {
+            event_seq,
+            nft_metadata: metadata.key(),
+            authority: ctx.accounts.authority.key(),
+            name: metadata.name.clone(),
+            symbol: metadata.symbol.clone(),
+            uri: metadata.uri.clone(),
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        
+        msg!("Metadados atualizados: {}", metadata.key());
+        
+        Ok(())
+    }
+
+    // Tornar os metadados imutáveis para sempre
+    pub fn lock_metadata(ctx: Context<UpdateNftMetadata>) -> Result<()> {
+        ctx.accounts.check_reveal()?;
+        ctx.accounts.nft_metadata.locked = true;
+        
+        let event_seq = ctx.accounts.event_sequence.next_seq()?;
+        emit!(NftMetadataLockEvent {
+            event_seq,
+            nft_metadata: ctx.accounts.nft_metadata.key(),
+            authority: ctx.accounts.authority.key(),
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        
+        msg!("Metadados bloqueados: {}", ctx.accounts.nft_metadata.key());
+        
+        Ok(())
+    }
+
+    // Ativar o modo de reveal atrasado: novos mints recebem a URI placeholder
+    pub fn set_reveal_placeholder(
+        ctx: Context<ConfigureReveal>,
+        placeholder_uri: String,
+    ) -> Result<()> {
+        // URI vazia desativa o modo placeholder
+        if !placeholder_uri.is_empty() {
+            utils::validate_uri(&placeholder_uri)?;
+        }
+        require!(!ctx.accounts.collection_config.reveal_committed, ErrorCode::RevealAlreadyCommitted);
+        
+        let old_uri = std::mem::replace(
+            &mut ctx.accounts.collection_config.placeholder_uri,
+            placeholder_uri.clone(),
+        );
+        ctx.accounts.collection_config.record_change(
+            &mut ctx.accounts.event_sequence,
+            ctx.accounts.admin.key(),
+            CollectionField::PlaceholderUri { old: old_uri, new: placeholder_uri },
+        )?;
+        
+        msg!("URI placeholder configurada: {}", ctx.accounts.collection_config.placeholder_uri);
+        
+        Ok(())
+    }
+
+    // Publicar a URI base e o hash de proveniência; só pode ser feito uma vez
+    pub fn commit_reveal(
+        ctx: Context<ConfigureReveal>,
+        base_uri: String,
+        provenance_hash: [u8; 32],
+    ) -> Result<()> {
+        let collection_config = &mut ctx.accounts.collection_config;
+        require!(!collection_config.reveal_committed, ErrorCode::RevealAlreadyCommitted);
+        utils::validate_uri(&base_uri)?;
+        // Espaço para o índice (até 20 dígitos) na URI final do NFT
+        require!(base_uri.len() + 20 <= MAX_URI_LENGTH, ErrorCode::UriTooLong);
+        
+        collection_config.base_uri = base_uri;
+        collection_config.provenance_hash = provenance_hash;
+        collection_config.reveal_committed = true;
+        
+        let event_seq = ctx.accounts.event_sequence.next_seq()?;
+        emit!(RevealCommitEvent {
+            event_seq,
+            admin: ctx.accounts.admin.key(),
+            collection: ctx.accounts.collection_metadata.key(),
+            base_uri: collection_config.base_uri.clone(),
+            provenance_hash,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        
+        msg!("Reveal publicado: {}", collection_config.base_uri);
+        
+        Ok(())
+    }
+
+    // Reescrever a URI dos NFTs passados em remaining_accounts para base_uri + índice
+    // (metadados bloqueados são ignorados e listados em NftsRevealedEvent.skipped)
+    pub fn reveal_nfts<'info>(ctx: Context<'_, '_, 'info, 'info, RevealNfts<'info>>) -> Result<()> {
+        let collection_config = &ctx.accounts.collection_config;
+        require!(collection_config.reveal_committed, ErrorCode::RevealNotCommitted);
+        
+        let collection_key = ctx.accounts.collection_metadata.key();
+        let mut revealed: u64 = 0;
+        let mut skipped = Vec::new();
+        
+        for account_info in ctx.remaining_accounts.iter() {
+            let mut metadata: Account<NFTMetadata> = Account::try_from(account_info)?;
+            require!(
+                metadata.collection == Some(collection_key),
+                ErrorCode::InvalidCollection
+            );
+            
+            // Metadados bloqueados não podem ser reescritos: pular sem travar o resto do lote
+            if metadata.locked {
+                skipped.push(account_info.key());
+                continue;
+            }
+            
+            metadata.uri = collection_config.revealed_uri(metadata.index);
+            metadata.exit(&crate::ID)?;
+            revealed = revealed.checked_add(1).ok_or(ErrorCode::MathOverflow)?;
+        }
+        
+        msg!("{} NFTs revelados, {} bloqueados ignorados", revealed, skipped.len());
+        
+        let event_seq = ctx.accounts.event_sequence.next_seq()?;
+        emit!(NftsRevealedEvent {
+            event_seq,
+            admin: ctx.accounts.admin.key(),
+            collection: collection_key,
+            revealed,
+            skipped,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        
+        Ok(())
+    }
+
+    // Definir quantos tokens cada NFT queimado recebe da pool de resgate
+    pub fn configure_redemption(
+        ctx: Context<ConfigureRedemption>,
+        redemption_amount: u64,
+    ) -> Result<()> {
+        let old_amount = ctx.accounts.collection_config.redemption_amount;
+        ctx.accounts.collection_config.redemption_amount = redemption_amount;
+        ctx.accounts.collection_config.record_change(
+            &mut ctx.accounts.event_sequence,
+            ctx.accounts.admin.key(),
+            CollectionField::RedemptionAmount { old: old_amount, new: redemption_amount },
+        )?;
+        
+        msg!("Resgate por NFT queimado configurado: {}", redemption_amount);
+        
+        Ok(())
+    }
+
+    // Depositar tokens de pagamento na pool de resgate da coleção (ATA da redemption_authority,
+    // criada no primeiro depósito)
+    pub fn deposit_redemption_pool(
+        ctx: Context<DepositRedemptionPool>,
+        amount: u64,
+    ) -> Result<()> {
+        require!(amount > 0, ErrorCode::InvalidPaymentAmount);
+        
+        let transfer_ctx = CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.admin_token_account.to_account_info(),
+                to: ctx.accounts.redemption_pool.to_account_info(),
+                authority: ctx.accounts.admin.to_account_info(),
+            },
+        );
+        transfer(transfer_ctx, amount)?;
+        
+        ctx.accounts.redemption_pool.reload()?;
+        let event_seq = ctx.accounts.event_sequence.next_seq()?;
+        emit!(RedemptionPoolDepositEvent {
+            event_seq,
+            admin: ctx.accounts.admin.key(),
+            collection: ctx.accounts.collection_metadata.key(),
+            redemption_pool: ctx.accounts.redemption_pool.key(),
+            amount,
+            new_balance: ctx.accounts.redemption_pool.amount,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        
+        msg!("Depositados {} tokens na pool de resgate", amount);
+        
+        Ok(())
+    }
+
+    // Configurar a divisão do pagamento do mint entre queima, tesouraria e reserva
+    pub fn set_payment_split(
+        ctx: Context<SetPaymentSplit>,
+        burn_bps: u16,
+        treasury_bps: u16,
+        reserve_bps: u16,
+    ) -> Result<()> {
+        let total = burn_bps as u32 + treasury_bps as u32 + reserve_bps as u32;
+        require!(total == 10000, ErrorCode::InvalidPaymentSplit);
+        
+        // A tesouraria é obrigatória quando recebe parte do pagamento
+        let treasury = ctx.accounts.treasury_token_account
+            .as_ref()
+            .map(|account| account.key())
+            .unwrap_or_default();
+        require!(
+            treasury_bps == 0 || treasury != Pubkey::default(),
+            ErrorCode::InvalidTreasury
+        );
+        require!(
+            reserve_bps == 0 || ctx.accounts.config.reward_reserve != Pubkey::default(),
+            ErrorCode::InvalidRewardReserve
+        );
+        
+        let collection_config = &mut ctx.accounts.collection_config;
+        let changes = [
+            CollectionField::BurnBps { old: collection_config.burn_bps, new: burn_bps },
+            CollectionField::TreasuryBps { old: collection_config.treasury_bps, new: treasury_bps },
+            CollectionField::ReserveBps { old: collection_config.reserve_bps, new: reserve_bps },
+            CollectionField::Treasury { old: collection_config.treasury, new: treasury },
+        ];
+        collection_config.burn_bps = burn_bps;
+        collection_config.treasury_bps = treasury_bps;
+        collection_config.reserve_bps = reserve_bps;
+        collection_config.treasury = treasury;
+        let admin = ctx.accounts.admin.key();
+        for change in changes {
+            collection_config.record_change(&mut ctx.accounts.event_sequence, admin, change)?;
+        }
+        
+        msg!("Divisão do pagamento configurada: queima={}, tesouraria={}, reserva={}", 
+            burn_bps, treasury_bps, reserve_bps);
+        
+        Ok(())
+    }
+
+    // Configurar a raiz de Merkle e o preço da allowlist
+    pub fn set_allowlist(
+        ctx: Context<SetAllowlist>,
+        merkle_root: [u8; 32],
+        allowlist_price: u64,
+    ) -> Result<()> {
+        let collection_config = &mut ctx.accounts.collection_config;
+        let changes = [
+            CollectionField::MerkleRoot { old: collection_config.merkle_root, new: merkle_root },
+            CollectionField::AllowlistPrice { old: collection_config.allowlist_price, new: allowlist_price },
+        ];
+        collection_config.merkle_root = merkle_root;
+        collection_config.allowlist_price = allowlist_price;
+        let admin = ctx.accounts.admin.key();
+        for change in changes {
+            collection_config.record_change(&mut ctx.accounts.event_sequence, admin, change)?;
+        }
+        
+        msg!("Allowlist configurada: preço={}, ativa={}", 
+            allowlist_price, collection_config.allowlist_active());
+        
+        Ok(())
+    }
+
+    // Configurar supply máximo, janela de mint e preço público da coleção
+    pub fn configure_collection(
+        ctx: Context<ConfigureCollection>,
+        max_supply: u64,
+        mint_start: i64,
+        mint_end: i64,
+        mint_price: u64,
+        max_per_wallet: u64,
+    ) -> Result<()> {
+        // Janela precisa ser coerente quando início e fim estão definidos
+        require!(
+            mint_start == 0 || mint_end == 0 || mint_start < mint_end,
+            ErrorCode::InvalidMintWindow
+        );
+        // Não permitir supply menor que o que já foi mintado na coleção
+        let collection_config = &mut ctx.accounts.collection_config;
+        require!(
+            max_supply == 0 || max_supply >= collection_config.minted,
+            ErrorCode::InvalidInput
+        );
+        
+        // Conta criada agora para uma coleção antiga: o contador global não diz quantos
+        // NFTs são desta coleção, então minted e live_supply começam em zero e contam
+        // os mints feitos a partir daqui
+        if collection_config.collection == Pubkey::default() {
+            collection_config.burn_bps = 10000;
+            collection_config.live_supply = 0;
+        }
+        let changes = [
+            CollectionField::MaxSupply { old: collection_config.max_supply, new: max_supply },
+            CollectionField::MintStart { old: collection_config.mint_start, new: mint_start },
+            CollectionField::MintEnd { old: collection_config.mint_end, new: mint_end },
+            CollectionField::MintPrice { old: collection_config.mint_price, new: mint_price },
+            CollectionField::MaxPerWallet { old: collection_config.max_per_wallet, new: max_per_wallet },
+        ];
+        collection_config.version = ACCOUNT_VERSION;
+        collection_config.collection = ctx.accounts.collection_metadata.key();
+        collection_config.max_supply = max_supply;
+        collection_config.mint_start = mint_start;
+        collection_config.mint_end = mint_end;
+        collection_config.mint_price = mint_price;
+        collection_config.max_per_wallet = max_per_wallet;
+        let admin = ctx.accounts.admin.key();
+        for change in changes {
+            collection_config.record_change(&mut ctx.accounts.event_sequence, admin, change)?;
+        }
+        
+        msg!("Coleção configurada: max_supply={}, mint_start={}, mint_end={}, mint_price={}, max_per_wallet={}", 
+            max_supply, mint_start, mint_end, mint_price, max_per_wallet);
+        
+        Ok(())
+    }
+
+    // Aprovar uma carteira para gastar tokens
+    pub fn approve_delegate(
+        ctx: Context<ApproveDelegate>,
+        amount: u64,
+    ) -> Result<()> {
+        msg!("Aprovando delegado para gastar tokens: {}", amount);
+
+        let old_delegate: Option<Pubkey> = ctx.accounts.token_account.delegate.into();
+        let old_delegated_amount = ctx.accounts.token_account.delegated_amount;
+
+        let cpi_accounts = Approve {
+            to: ctx.accounts.token_account.to_account_info(),
+            delegate: ctx.accounts.delegate.to_account_info(),
+            authority: ctx.accounts.owner.to_account_info(),
+        };
+        
+        let cpi_program = ctx.accounts.token_program.to_account_info();
+        let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
+        approve(cpi_ctx, amount)?;
+
+        let event_seq = ctx.accounts.event_sequence.next_seq()?;
+        emit!(DelegateApprovedEvent {
+            event_seq,
+            owner: ctx.accounts.owner.key(),
+            token_account: ctx.accounts.token_account.key(),
+            old_delegate,
+            old_delegated_amount,
+            new_delegate: ctx.accounts.delegate.key(),
+            new_delegated_amount: amount,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        Ok(())
+    }
+
+    // Função para configurar o token após seu lançamento
+    pub fn set_payment_token(
+        ctx: Context<SetPaymentToken>,
+        payment_token_mint: Pubkey,
+    ) -> Result<()> {
+        // Verificar se o chamador é o administrador
+        require_keys_eq!(
+            ctx.accounts.admin.key(),
+            ctx.accounts.config.admin,
+            ErrorCode::Unauthorized
+        );
+        
+        let old_payment_token_mint = ctx.accounts.config.payment_token_mint;
+        ctx.accounts.config.payment_token_mint = payment_token_mint;
+        msg!("Token de pagamento definido: {}", payment_token_mint);
+        
+        ctx.accounts.config.record_change(
+            &mut ctx.accounts.event_sequence,
+            ctx.accounts.admin.key(),
+            ConfigField::PaymentTokenMint { old: old_payment_token_mint, new: payment_token_mint },
+        )?;
+        
+        Ok(())
+    }
+
+    // Configurar a conta de reserva de recompensas
+    pub fn set_reward_reserve(
+        ctx: Context<SetRewardReserve>,
+        reward_reserve: Pubkey,
+    ) -> Result<()> {
+        // Verificar se o chamador é o administrador
+        require_keys_eq!(
+            ctx.accounts.admin.key(),
+            ctx.accounts.config.admin,
+            ErrorCode::Unauthorized
+        );
+        
+        let old_reward_reserve = ctx.accounts.config.reward_reserve;
+        ctx.accounts.config.reward_reserve = reward_reserve;
+        msg!("Reserva de recompensas configurada: {}", reward_reserve);
+        
+        ctx.accounts.config.record_change(
+            &mut ctx.accounts.event_sequence,
+            ctx.accounts.admin.key(),
+            ConfigField::RewardReserve { old: old_reward_reserve, new: reward_reserve },
+        )?;
+        
+        Ok(())
+    }
+
+    // Inicializar a reserva de recompensas
+    pub fn initialize_reward_reserve(
+        ctx: Context<InitializeRewardReserve>,
+    ) -> Result<()> {
+        // Verificar se o chamador é o administrador
+        require_keys_eq!(
+            ctx.accounts.admin.key(),
+            ctx.accounts.config.admin,
+            ErrorCode::Unauthorized
+        );
+        
+        // Atualizar a configuração com o endereço da reserva
+        let old_reward_reserve = ctx.accounts.config.reward_reserve;
+        ctx.accounts.config.reward_reserve = ctx.accounts.reward_reserve_account.key();
+        
+        msg!("Reserva de recompensas inicializada: {}", ctx.accounts.reward_reserve_account.key());
+        
+        let new_reward_reserve = ctx.accounts.reward_reserve_account.key();
+        ctx.accounts.config.record_change(
+            &mut ctx.accounts.event_sequence,
+            ctx.accounts.admin.key(),
+            ConfigField::RewardReserve { old: old_reward_reserve, new: new_reward_reserve },
+        )?;
+        
+        Ok(())
+    }
+
+    // Depositar tokens na reserva de recompensas
+    pub fn deposit_reward_reserve(
+        ctx: Context<DepositRewardReserve>,
+        amount: u64,
+    ) -> Result<()> {
+        // Verificar se o chamador é o administrador
+        require_keys_eq!(
+            ctx.accounts.admin.key(),
+            ctx.accounts.config.admin,
+            ErrorCode::Unauthorized
+        );
+        
+        // Transferir tokens do admin para a reserva
+        let old_balance = ctx.accounts.reward_reserve_account.amount;
+        let transfer_ctx = CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.admin_token_account.to_account_info(),
+                to: ctx.accounts.reward_reserve_account.to_account_info(),
+                authority: ctx.accounts.admin.to_account_info(),
+            },
+        );
+        transfer(transfer_ctx, amount)?;
+        
+        msg!("Depositados {} tokens na reserva de recompensas", amount);
+        
+        ctx.accounts.reward_reserve_account.reload()?;
+        let event_seq = ctx.accounts.event_sequence.next_seq()?;
+        emit!(RewardReserveDepositEvent {
+            event_seq,
+            admin: ctx.accounts.admin.key(),
+            reward_reserve: ctx.accounts.reward_reserve_account.key(),
+            amount,
+            old_balance,
+            new_balance: ctx.accounts.reward_reserve_account.amount,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        
+        Ok(())
+    }
+
+    // Ativar o sistema de staking e definir a taxa de recompensa
+    pub fn configure_staking(
+        ctx: Context<ConfigureStaking>,
+        enabled: bool,
+        reward_rate: u64,
+    ) -> Result<()> {
+        // Verificar se o chamador é o administrador
+        require_keys_eq!(
+            ctx.accounts.admin.key(),
+            ctx.accounts.config.admin,
+            ErrorCode::Unauthorized
+        );
+        
+        let old_enabled = ctx.accounts.config.staking_enabled;
+        let old_rate = ctx.accounts.config.staking_reward_rate;
+        
+        ctx.accounts.config.staking_enabled = enabled;
+        ctx.accounts.config.staking_reward_rate = reward_rate;
+        
+        // Emitir eventos de atualização de configuração
+        let admin = ctx.accounts.admin.key();
+        let config = &mut ctx.accounts.config;
+        config.record_change(&mut ctx.accounts.event_sequence, admin, ConfigField::StakingEnabled { old: old_enabled, new: enabled })?;
+        config.record_change(&mut ctx.accounts.event_sequence, admin, ConfigField::StakingRewardRate { old: old_rate, new: reward_rate })?;
+        
+        msg!("Staking configurado: enabled={}, reward_rate={}", enabled, reward_rate);
+        
+        Ok(())
+    }
+
+    // Definir o bônus de holder (bps) sobre a recompensa de stake_tokens com NFT travado
+    pub fn set_nft_holder_boost(
+        ctx: Context<ConfigureStaking>,
+        boost_bps: u64,
+    ) -> Result<()> {
+        require!(boost_bps <= MAX_NFT_HOLDER_BOOST_BPS, ErrorCode::InvalidHolderBoost);
+        
+        let old_boost = ctx.accounts.config.nft_holder_boost_bps;
+        ctx.accounts.config.nft_holder_boost_bps = boost_bps;
+        ctx.accounts.config.record_change(
+            &mut ctx.accounts.event_sequence,
+            ctx.accounts.admin.key(),
+            ConfigField::NftHolderBoostBps { old: old_boost, new: boost_bps },
+        )?;
+        
+        msg!("Bônus de holder atualizado: {} bps", boost_bps);
+        
+        Ok(())
+    }
+    
+
+
+    // Função de stake simplificada
+    // Com `receipt_uri`, a posição recebe um NFT de recibo transferível (contas stake_receipt,
+    // receipt_mint, receipt_metadata e receipt_token_account) e passa a pertencer a ele
+    pub fn stake_tokens(
+        ctx: Context<StakeTokens>,
+        amount: u64,
+        period: StakingPeriod,
+        receipt_uri: Option<String>,
+    ) -> Result<()> {
+        require!(!ctx.accounts.config.emergency_paused, ErrorCode::SystemPaused);
+        // O recibo é inicializado pelo contexto e só é preenchido com a URI
+        require!(
+            receipt_uri.is_some() == ctx.accounts.stake_receipt.is_some(),
+            ErrorCode::InvalidStakeReceipt
+        );
+        require!(ctx.accounts.config.staking_enabled, ErrorCode::StakingNotEnabled);
+        require!(amount > 0, ErrorCode::InvalidStakeAmount);
+        require!(amount <= ctx.accounts.config.max_stake_amount, ErrorCode::StakeAmountTooLarge);
+    
+        let clock = Clock::get()?;
+        let current_time = clock.unix_timestamp;
+        let unlock_time = current_time
+            .checked_add(period.duration_in_seconds())
+            .ok_or(ErrorCode::MathOverflow)?;
+    
+        let stake_account = &mut ctx.accounts.stake_account;
+    
+        if stake_account.amount > 0 && !stake_account.claimed {
+            // Posições com recibo pertencem ao holder do NFT e não aceitam aporte
+            require_keys_eq!(stake_account.owner, ctx.accounts.staker.key(), ErrorCode::Unauthorized);
+            
+            let stake_authority_seed: &[u8] = b"stake_authority";
+            let bump_seed: &[u8] = &[ctx.bumps.stake_authority];
+            let signer_seeds: [&[u8]; 2] = [stake_authority_seed, bump_seed];
+            let signer_seeds_slice: &[&[u8]] = &signer_seeds;
+            let signer_seeds_nested: &[&[&[u8]]] = &[signer_seeds_slice];
+            
+            let return_ctx = CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.stake_token_account.to_account_info(),
+                    to: ctx.accounts.staker_token_account.to_account_info(),
+                    authority: ctx.accounts.stake_authority.to_account_info(),
+                },
+                signer_seeds_nested,
+            );
+            transfer(return_ctx, stake_account.amount)?;
+    
+            let old_amount = stake_account.amount;
+            let old_period = stake_account.period;
+            let old_unlock_time = stake_account.unlock_time;
+    
+            // CORREÇÃO: Sempre somar o valor anterior com o novo, independente do período
+            let new_amount = stake_account.amount.checked_add(amount).ok_or(ErrorCode::MathOverflow)?;
+    
+            // Transfere todos os tokens (antigos + novos) do staker para a conta de stake
+            let stake_ctx = CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.staker_token_account.to_account_info(),
+                    to: ctx.accounts.stake_token_account.to_account_info(),
+                    authority: ctx.accounts.staker.to_account_info(),
+                },
+            );
+            transfer(stake_ctx, new_amount)?;
+    
+            // Atualiza stake
+            stake_account.amount = new_amount;
+            stake_account.period = period;
+            stake_account.start_time = current_time;
+            stake_account.unlock_time = unlock_time;
+            ctx.accounts.config.record_stake(amount, false)?;
+    
+            let event_seq = ctx.accounts.event_sequence.next_seq()?;
+            emit!(StakeTopUpEvent {
+                event_seq,
+                staker: ctx.accounts.staker.key(),
+                stake_account: stake_account.key(),
+                old_amount,
+                added_amount: amount,
+                new_amount,
+                old_period,
+                new_period: period,
+                old_unlock_time,
+                new_unlock_time: unlock_time,
+                timestamp: current_time,
+            });
+        } else {
+            // Primeiro stake ou após claim
+            let stake_ctx = CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.staker_token_account.to_account_info(),
+                    to: ctx.accounts.stake_token_account.to_account_info(),
+                    authority: ctx.accounts.staker.to_account_info(),
+                },
+            );
+            transfer(stake_ctx, amount)?;
+    
+            stake_account.version = ACCOUNT_VERSION;
+            stake_account.owner = ctx.accounts.staker.key();
+            stake_account.amount = amount;
+            stake_account.start_time = current_time;
+            stake_account.unlock_time = unlock_time;
+            stake_account.period = period;
+            stake_account.claimed = false;
+            ctx.accounts.config.record_stake(amount, true)?;
+    
+            let event_seq = ctx.accounts.event_sequence.next_seq()?;
+            emit!(StakingEvent {
+                event_seq,
+                staker: ctx.accounts.staker.key(),
+                amount,
+                period,
+                start_time: current_time,
+                unlock_time,
+                stake_account: stake_account.key(),
+            });
+        }
+        
+        if let Some(uri) = receipt_uri {
+            ctx.accounts.issue_receipt(uri, current_time)?;
+        }
+    
+        Ok(())
+    }    
+    
+    // Resgatar tokens do staking e receber recompensas
+    pub fn unstake_tokens(ctx: Context<UnstakeTokens>) -> Result<()> {
+        // Verificar se o sistema está pausado para emergências
+        require!(!ctx.accounts.config.emergency_paused, ErrorCode::SystemPaused);
+        
+        // Verificar se o período de staking terminou
+        let clock = Clock::get()?;
+        let current_time = clock.unix_timestamp;
+        
+        require!(
+            current_time >= ctx.accounts.stake_account.unlock_time,
+            ErrorCode::StakingPeriodNotCompleted
+        );
+        
+        require!(
+            !ctx.accounts.stake_account.claimed,
+            ErrorCode::RewardsAlreadyClaimed
+        );
+        
+        // Com recibo, só o holder do NFT pode resgatar e o recibo é queimado
+        if let Some(stake_receipt) = ctx.accounts.stake_receipt.as_ref() {
+            require_keys_eq!(
+                ctx.accounts.stake_account.owner,
+                stake_receipt.key(),
+                ErrorCode::Unauthorized
+            );
+            let receipt_mint = ctx.accounts.receipt_mint
+                .as_ref()
+                .ok_or(ErrorCode::InvalidStakeReceipt)?;
+            let receipt_token_account = ctx.accounts.receipt_token_account
+                .as_ref()
+                .ok_or(ErrorCode::InvalidStakeReceipt)?;
+            require_keys_eq!(receipt_mint.key(), stake_receipt.receipt_mint, ErrorCode::InvalidStakeReceipt);
+            require_keys_eq!(receipt_token_account.mint, stake_receipt.receipt_mint, ErrorCode::InvalidStakeReceipt);
+            require_keys_eq!(receipt_token_account.owner, ctx.accounts.staker.key(), ErrorCode::Unauthorized);
+            require!(receipt_token_account.amount == 1, ErrorCode::InvalidStakeReceipt);
+            // Os metadados do recibo também precisam ser fechados, com o aluguel para o staker
+            require!(ctx.accounts.receipt_metadata.is_some(), ErrorCode::InvalidStakeReceipt);
+            require!(ctx.accounts.rent_recipient.is_some(), ErrorCode::InvalidStakeReceipt);
+            
+            burn(
+                CpiContext::new(
+                    ctx.accounts.token_program.to_account_info(),
+                    Burn {
+                        mint: receipt_mint.to_account_info(),
+                        from: receipt_token_account.to_account_info(),
+                        authority: ctx.accounts.staker.to_account_info(),
+                    },
+                ),
+                1,
+            )?;
+            close_account(CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                CloseAccount {
+                    account: receipt_token_account.to_account_info(),
+                    destination: ctx.accounts.staker.to_account_info(),
+                    authority: ctx.accounts.staker.to_account_info(),
+                },
+            ))?;
+        } else {
+            require_keys_eq!(
+                ctx.accounts.stake_account.owner,
+                ctx.accounts.staker.key(),
+                ErrorCode::Unauthorized
+            );
+        }
+        
+        // Calcular recompensas com verificações de overflow
+        let staked_amount = ctx.accounts.stake_account.amount;
+        let period = ctx.accounts.stake_account.period;
+        let base_rate = ctx.accounts.config.staking_reward_rate;
+        let multiplier = period.reward_multiplier();
+        
+        // Bônus para quem travou um NFT da coleção junto à posição
+        let boost_bps = ctx.accounts.stake_boost
+            .as_ref()
+            .map(|boost| boost.boost_bps(&ctx.accounts.stake_account, ctx.accounts.config.nft_holder_boost_bps))
+            .unwrap_or(0);
+        let reward_amount = utils::calculate_reward(staked_amount, base_rate, multiplier, boost_bps)?;
+        
+        // Transferir os tokens originais de volta para o staker
+        let stake_authority_seed = b"stake_authority";
+        let authority_seeds = &[
+            stake_authority_seed.as_ref(),
+            &[ctx.bumps.stake_authority],
+        ];
+        let signer_seeds = &[&authority_seeds[..]];
+
+        let transfer_ctx = CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.stake_token_account.to_account_info(),
+                to: ctx.accounts.staker_token_account.to_account_info(),
+                authority: ctx.accounts.stake_authority.to_account_info(),
+            },
+            signer_seeds,
+        );
+        transfer(transfer_ctx, staked_amount)?;
+        
+        // Se houver recompensas, transferir da reserva para o staker
+        if reward_amount > 0 {
+            // Verificar se há saldo suficiente na reserva
+            require!(
+                ctx.accounts.reward_reserve_account.amount >= reward_amount,
+                ErrorCode::InsufficientRewardReserve
+            );
+            
+            // Transferir recompensas da reserva para o staker
+            let reward_transfer_ctx = CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.reward_reserve_account.to_account_info(),
+                    to: ctx.accounts.staker_token_account.to_account_info(),
+                    authority: ctx.accounts.stake_authority.to_account_info(),
+                },
+                signer_seeds,
+            );
+            transfer(reward_transfer_ctx, reward_amount)?;
+        }
+        
+        // A ATA da stake_authority fica aberta: é a mesma conta da reserva de recompensas
+        // e guarda o principal das demais posições
+        ctx.accounts.config.record_unstake(staked_amount);
+        
+        // Fechar a posição (e o recibo); um novo stake_tokens recria a conta zerada
+        let rent_recipient = match ctx.accounts.rent_recipient.as_ref() {
+            Some(rent_recipient) => rent_recipient.to_account_info(),
+            None => ctx.accounts.staker.to_account_info(),
+        };
+        ctx.accounts.stake_account.close(rent_recipient.clone())?;
+        if let Some(stake_receipt) = ctx.accounts.stake_receipt.as_ref() {
+            stake_receipt.close(rent_recipient.clone())?;
+        }
+        if let Some(receipt_metadata) = ctx.accounts.receipt_metadata.as_ref() {
+            receipt_metadata.close(rent_recipient)?;
+        }
+        
+        // Emitir evento de unstaking
+        let event_seq = ctx.accounts.event_sequence.next_seq()?;
+        emit!(UnstakingEvent {
+            event_seq,
+            staker: ctx.accounts.staker.key(),
+            stake_account: ctx.accounts.stake_account.key(),
+            original_amount: staked_amount,
+            reward_amount,
+            total_amount: staked_amount.checked_add(reward_amount).ok_or(ErrorCode::MathOverflow)?,
+            timestamp: clock.unix_timestamp,
+        });
+        
+        msg!("Unstake concluído: {} tokens originais + {} tokens de recompensa", 
+            staked_amount, reward_amount);
+        
+        Ok(())
+    }
+    
+    // Fecha uma posição resgatada antes de o unstake passar a fechar a conta; o aluguel
+    // volta a quem abriu a posição
+    pub fn close_stake(ctx: Context<CloseStake>) -> Result<()> {
+        require!(ctx.accounts.stake_account.claimed, ErrorCode::StakeStillActive);
+        
+        let event_seq = ctx.accounts.event_sequence.next_seq()?;
+        emit!(StakeClosedEvent {
+            event_seq,
+            staker: ctx.accounts.staker.key(),
+            stake_account: ctx.accounts.stake_account.key(),
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        
+        msg!("Posição {} fechada", ctx.accounts.stake_account.key());
+        
+        Ok(())
+    }
+
+    // Bloquear um NFT da coleção no cofre da stake_authority
+    pub fn stake_nft(ctx: Context<StakeNft>) -> Result<()> {
+        require!(!ctx.accounts.config.emergency_paused, ErrorCode::SystemPaused);
+        require!(ctx.accounts.config.staking_enabled, ErrorCode::StakingNotEnabled);
+        
+        // Transferir o NFT para o cofre
+        let transfer_ctx = CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.staker_nft_account.to_account_info(),
+                to: ctx.accounts.nft_vault.to_account_info(),
+                authority: ctx.accounts.staker.to_account_info(),
+            },
+        );
+        transfer(transfer_ctx, 1)?;
+        
+        let clock = Clock::get()?;
+        let nft_stake = &mut ctx.accounts.nft_stake;
+        nft_stake.version = ACCOUNT_VERSION;
+        nft_stake.owner = ctx.accounts.staker.key();
+        nft_stake.nft_mint = ctx.accounts.nft_mint.key();
+        nft_stake.collection = ctx.accounts.collection_metadata.key();
+        nft_stake.staked_at = clock.unix_timestamp;
+        
+        let event_seq = ctx.accounts.event_sequence.next_seq()?;
+        emit!(NftStakingEvent {
+            event_seq,
+            staker: ctx.accounts.staker.key(),
+            nft_mint: ctx.accounts.nft_mint.key(),
+            collection: ctx.accounts.collection_metadata.key(),
+            nft_stake: ctx.accounts.nft_stake.key(),
+            timestamp: clock.unix_timestamp,
+        });
+        
+        msg!("NFT {} em stake", ctx.accounts.nft_mint.key());
+        
+        Ok(())
+    }
+
+    // Devolver o NFT e pagar as recompensas acumuladas pela taxa da coleção
+    pub fn unstake_nft(ctx: Context<UnstakeNft>) -> Result<()> {
+        require!(!ctx.accounts.config.emergency_paused, ErrorCode::SystemPaused);
+        
+        let clock = Clock::get()?;
+        let staked_seconds = clock.unix_timestamp
+            .checked_sub(ctx.accounts.nft_stake.staked_at).ok_or(ErrorCode::MathOverflow)?
+            .max(0);
+        // Saturado para que uma taxa alta nunca impeça a devolução do NFT
+        let accrued_reward = ctx.accounts.collection_config.nft_reward_rate
+            .saturating_mul(staked_seconds as u64);
+        
+        let authority_seeds: &[&[u8]] = &[
+            b"stake_authority",
+            &[ctx.bumps.stake_authority],
+        ];
+        let signer_seeds = &[authority_seeds];
+        
+        // Devolver o NFT ao staker
+        let transfer_ctx = CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.nft_vault.to_account_info(),
+                to: ctx.accounts.staker_nft_account.to_account_info(),
+                authority: ctx.accounts.stake_authority.to_account_info(),
+            },
+            signer_seeds,
+        );
+        transfer(transfer_ctx, 1)?;
+        
+        // Fechar o cofre vazio e devolver o aluguel ao staker
+        let close_ctx = CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            CloseAccount {
+                account: ctx.accounts.nft_vault.to_account_info(),
+                destination: ctx.accounts.staker.to_account_info(),
+                authority: ctx.accounts.stake_authority.to_account_info(),
+            },
+            signer_seeds,
+        );
+        close_account(close_ctx)?;
+        
+        // Pagar da pool de recompensas da coleção, nunca da reserva que guarda o principal de
+        // stake_tokens; o que a pool não cobre fica registrado em unpaid_reward
+        let mut reward_amount = 0;
+        if let Some(nft_reward_pool) = ctx.accounts.nft_reward_pool.as_ref() {
+            reward_amount = accrued_reward.min(nft_reward_pool.amount);
+            if reward_amount > 0 {
+                let collection_key = ctx.accounts.collection_metadata.key();
+                let pool_authority_seeds: &[&[u8]] = &[
+                    b"nft_reward_authority",
+                    collection_key.as_ref(),
+                    &[ctx.bumps.nft_reward_authority],
+                ];
+                let pool_signer_seeds = &[pool_authority_seeds];
+                let reward_transfer_ctx = CpiContext::new_with_signer(
+                    ctx.accounts.token_program.to_account_info(),
+                    Transfer {
+                        from: nft_reward_pool.to_account_info(),
+                        to: ctx.accounts.staker_token_account.to_account_info(),
+                        authority: ctx.accounts.nft_reward_authority.to_account_info(),
+                    },
+                    pool_signer_seeds,
+                );
+                transfer(reward_transfer_ctx, reward_amount)?;
+            }
+        }
+        let unpaid_reward = accrued_reward - reward_amount;
+        
+        let event_seq = ctx.accounts.event_sequence.next_seq()?;
+        emit!(NftUnstakingEvent {
+            event_seq,
+            staker: ctx.accounts.staker.key(),
+            nft_mint: ctx.accounts.nft_mint.key(),
+            collection: ctx.accounts.collection_metadata.key(),
+            reward_amount,
+            unpaid_reward,
+            staked_seconds,
+            timestamp: clock.unix_timestamp,
+        });
+        
+        msg!("NFT {} fora de stake, recompensa de {} tokens ({} não pagos)",
+            ctx.accounts.nft_mint.key(), reward_amount, unpaid_reward);
+        
+        Ok(())
+    }
+
+    // Depositar tokens na pool de recompensas dos NFTs da coleção em stake
+    pub fn deposit_nft_reward_pool(
+        ctx: Context<DepositNftRewardPool>,
+        amount: u64,
+    ) -> Result<()> {
+        require!(amount > 0, ErrorCode::InvalidPaymentAmount);
+        
+        let transfer_ctx = CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.admin_token_account.to_account_info(),
+                to: ctx.accounts.nft_reward_pool.to_account_info(),
+                authority: ctx.accounts.admin.to_account_info(),
+            },
+        );
+        transfer(transfer_ctx, amount)?;
+        
+        ctx.accounts.nft_reward_pool.reload()?;
+        let event_seq = ctx.accounts.event_sequence.next_seq()?;
+        emit!(NftRewardPoolDepositEvent {
+            event_seq,
+            admin: ctx.accounts.admin.key(),
+            collection: ctx.accounts.collection_metadata.key(),
+            nft_reward_pool: ctx.accounts.nft_reward_pool.key(),
+            amount,
+            new_balance: ctx.accounts.nft_reward_pool.amount,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        
+        msg!("Depositados {} tokens na pool de recompensas de NFTs", amount);
+        
+        Ok(())
+    }
+
+    // Travar um NFT da coleção junto à posição de stake_tokens para receber o bônus
+    // Envie na mesma transação do stake (ou antes de um aporte), pois o bônus só vale
+    // se o NFT já estava travado no início da posição
+    pub fn lock_boost_nft(ctx: Context<LockBoostNft>) -> Result<()> {
+        require!(!ctx.accounts.config.emergency_paused, ErrorCode::SystemPaused);
+        
+        let stake_account = &ctx.accounts.stake_account;
+        require!(
+            stake_account.amount > 0 && !stake_account.claimed,
+            ErrorCode::NoActiveStake
+        );
+        
+        // Transferir o NFT para o cofre da stake_authority
+        let transfer_ctx = CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.staker_nft_account.to_account_info(),
+                to: ctx.accounts.nft_vault.to_account_info(),
+                authority: ctx.accounts.staker.to_account_info(),
+            },
+        );
+        transfer(transfer_ctx, 1)?;
+        
+        let clock = Clock::get()?;
+        let stake_boost = &mut ctx.accounts.stake_boost;
+        stake_boost.version = ACCOUNT_VERSION;
+        stake_boost.owner = ctx.accounts.staker.key();
+        stake_boost.stake_account = ctx.accounts.stake_account.key();
+        stake_boost.nft_mint = ctx.accounts.nft_mint.key();
+        stake_boost.collection = ctx.accounts.collection_metadata.key();
+        stake_boost.locked_at = clock.unix_timestamp;
+        
+        let event_seq = ctx.accounts.event_sequence.next_seq()?;
+        emit!(BoostNftLockedEvent {
+            event_seq,
+            staker: ctx.accounts.staker.key(),
+            stake_account: ctx.accounts.stake_account.key(),
+            nft_mint: ctx.accounts.nft_mint.key(),
+            collection: ctx.accounts.collection_metadata.key(),
+            timestamp: clock.unix_timestamp,
+        });
+        
+        msg!("NFT {} travado como bônus da posição {}",
+            ctx.accounts.nft_mint.key(), ctx.accounts.stake_account.key());
+        
+        Ok(())
+    }
+
+    // Devolver o NFT de bônus depois que a posição foi resgatada
+    pub fn unlock_boost_nft(ctx: Context<UnlockBoostNft>) -> Result<()> {
+        // Posições resgatadas são fechadas no unstake; se a conta existe, precisa estar encerrada
+        let stake_info = ctx.accounts.stake_account.to_account_info();
+        if stake_info.owner == &crate::ID && !stake_info.data_is_empty() {
+            let stake_account = StakeAccount::try_deserialize(&mut &stake_info.try_borrow_data()?[..])?;
+            require!(
+                stake_account.amount == 0 || stake_account.claimed,
+                ErrorCode::StakeStillActive
+            );
+        }
+        
+        let authority_seeds: &[&[u8]] = &[
+            b"stake_authority",
+            &[ctx.bumps.stake_authority],
+        ];
+        let signer_seeds = &[authority_seeds];
+        
+        let transfer_ctx = CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.nft_vault.to_account_info(),
+                to: ctx.accounts.staker_nft_account.to_account_info(),
+                authority: ctx.accounts.stake_authority.to_account_info(),
+            },
+            signer_seeds,
+        );
+        transfer(transfer_ctx, 1)?;
+        
+        // Fechar o cofre vazio e devolver o aluguel ao staker
+        let close_ctx = CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            CloseAccount {
+                account: ctx.accounts.nft_vault.to_account_info(),
+                destination: ctx.accounts.staker.to_account_info(),
+                authority: ctx.accounts.stake_authority.to_account_info(),
+            },
+            signer_seeds,
+        );
+        close_account(close_ctx)?;
+        
+        let event_seq = ctx.accounts.event_sequence.next_seq()?;
+        emit!(BoostNftUnlockedEvent {
+            event_seq,
+            staker: ctx.accounts.staker.key(),
+            stake_account: ctx.accounts.stake_account.key(),
+            nft_mint: ctx.accounts.nft_mint.key(),
+            collection: ctx.accounts.stake_boost.collection,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        
+        msg!("NFT de bônus {} devolvido", ctx.accounts.nft_mint.key());
+        
+        Ok(())
+    }
+
+    // Instruções somente leitura: o resultado volta via set_return_data (simule a transação)
+    pub fn get_stake_summary(ctx: Context<GetStakeSummary>) -> Result<views::StakingSummary> {
+        // O bônus informado precisa ser da posição consultada
+        if let (Some(stake_account), Some(stake_boost)) =
+            (ctx.accounts.stake_account.as_ref(), ctx.accounts.stake_boost.as_ref())
+        {
+            require_keys_eq!(stake_boost.stake_account, stake_account.key(), ErrorCode::InvalidInput);
+        }
+        
+        views::get_stake_summary(
+            ctx.accounts.stake_account.as_deref(),
+            ctx.accounts.stake_boost.as_deref(),
+            &ctx.accounts.config,
+        )
+    }
+
+    pub fn get_config_summary(ctx: Context<GetConfigSummary>) -> Result<views::ConfigSummary> {
+        Ok(views::get_config_summary(&ctx.accounts.config, &ctx.accounts.event_sequence))
+    }
+
+    pub fn get_collection_info(ctx: Context<GetCollectionInfo>) -> Result<views::CollectionInfo> {
+        Ok(views::get_collection_info(
+            &ctx.accounts.collection_metadata,
+            &ctx.accounts.nft_counter,
+            &ctx.accounts.collection_config,
+        ))
+    }
+
+    pub fn get_wallet_mint_count(ctx: Context<GetWalletMintCount>) -> Result<views::WalletMintCount> {
+        Ok(views::get_wallet_mint_count(
+            ctx.accounts.mint_record.as_deref(),
+            &ctx.accounts.collection_config,
+        ))
+    }
+
+    // Cota o preço atual e dos próximos `count` mints (até MAX_PRICE_QUOTES)
+    pub fn get_mint_quote(ctx: Context<GetCollectionInfo>, count: u64) -> Result<views::MintQuote> {
+        views::get_mint_quote(&ctx.accounts.collection_config, &ctx.accounts.nft_counter, count)
+    }
+
+    pub fn set_emergency_pause(
+        ctx: Context<EmergencyPause>,
+        paused: bool,
+        reason: String,
+    ) -> Result<()> {
+        require_keys_eq!(
+            ctx.accounts.admin.key(),
+            ctx.accounts.config.admin,
+            ErrorCode::Unauthorized
+        );
+        
+        let old_paused = ctx.accounts.config.emergency_paused;
+        ctx.accounts.config.emergency_paused = paused;
+        ctx.accounts.config.record_change(
+            &mut ctx.accounts.event_sequence,
+            ctx.accounts.admin.key(),
+            ConfigField::EmergencyPaused { old: old_paused, new: paused },
+        )?;
+        
+        // Emitir evento de pausa
+        let event_seq = ctx.accounts.event_sequence.next_seq()?;
+        emit!(EmergencyPauseEvent {
+            event_seq,
+            admin: ctx.accounts.admin.key(),
+            paused,
+            reason: reason.clone(),
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+        
+        msg!("Status de emergência atualizado: paused={}, reason={}", paused, reason);
+        
+        Ok(())
+    }
+    
+
+}
+
+#[derive(Accounts)]
+pub struct InitializeCollection<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        init,
+        payer = payer,
+        mint::decimals = 0,
+        mint::authority = payer,
+    )]
+    pub collection_mint: Account<'info, Mint>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = NFT_METADATA_SPACE,
+    )]
+    pub collection_metadata: Account<'info, NFTMetadata>,
+
+    #[account(
+        init,
+        payer = payer,
+        associated_token::mint = collection_mint,
+        associated_token::authority = payer,
+    )]
+    pub collection_token_account: Account<'info, TokenAccount>,
+
+            // Conta para armazenar a configuração
+        #[account(
+            init,
+            payer = payer,
+            space = CONFIG_ACCOUNT_SPACE,
+            seeds = [b"config"],
+            bump,
+        )]
+        pub config: Account<'info, ConfigAccount>,
+
+    // Adicionar conta do contador
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + NftCounter::INIT_SPACE,
+        seeds = [b"nft_counter"],
+        bump,
+    )]
+    pub nft_counter: Account<'info, NftCounter>,
+
+    // Configuração de venda da coleção
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + CollectionConfig::INIT_SPACE,
+        seeds = [b"collection_config", collection_metadata.key().as_ref()],
+        bump,
+    )]
+    pub collection_config: Account<'info, CollectionConfig>,
+
+    // Sequência global de eventos
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + EventSequence::INIT_SPACE,
+        seeds = [b"event_sequence"],
+        bump,
+    )]
+    pub event_sequence: Account<'info, EventSequence>,
+
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+#[derive(Accounts)]
+pub struct InitializeEventSequence<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        constraint = config.admin == admin.key() @ ErrorCode::Unauthorized,
+        seeds = [b"config"],
+        bump,
+    )]
+    pub config: Account<'info, ConfigAccount>,
+
+    #[account(
+        init,
+        payer = admin,
+        space = 8 + EventSequence::INIT_SPACE,
+        seeds = [b"event_sequence"],
+        bump,
+    )]
+    pub event_sequence: Account<'info, EventSequence>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct MigrateConfig<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        address = LEGACY_CONFIG_ACCOUNT @ ErrorCode::InvalidLegacyConfig,
+        owner = crate::ID @ ErrorCode::InvalidLegacyConfig,
+    )]
+    /// CHECK: Discriminator e admin verificados em migrate_config
+    pub legacy_config: UncheckedAccount<'info>,
+
+    #[account(
+        init,
+        payer = admin,
+        space = CONFIG_ACCOUNT_SPACE,
+        seeds = [b"config"],
+        bump,
+    )]
+    pub config: Account<'info, ConfigAccount>,
+
+    // Criada aqui se a implantação ainda não tiver a sequência; uma sequência v1 precisa
+    // passar antes por migrate_account
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = 8 + EventSequence::INIT_SPACE,
+        seeds = [b"event_sequence"],
+        bump,
+    )]
+    pub event_sequence: Account<'info, EventSequence>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct MigrateAccount<'info> {
+    // Paga o aluguel do espaço adicionado
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(mut, owner = crate::ID @ ErrorCode::AccountNotMigratable)]
+    /// CHECK: Tipo e layout v1 verificados pelo discriminator e tamanho em migrate_account
+    pub account: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ConfigureCollection<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        constraint = config.admin == admin.key() @ ErrorCode::Unauthorized,
+        seeds = [b"config"],
+        bump,
+    )]
+    pub config: Account<'info, ConfigAccount>,
+
+    pub collection_metadata: Account<'info, NFTMetadata>,
+
+    #[account(
+        seeds = [b"nft_counter"],
+        bump,
+    )]
+    pub nft_counter: Account<'info, NftCounter>,
+
+    // Criada aqui para coleções inicializadas antes da existência desta conta
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = 8 + CollectionConfig::INIT_SPACE,
+        seeds = [b"collection_config", collection_metadata.key().as_ref()],
+        bump,
+    )]
+    pub collection_config: Account<'info, CollectionConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"event_sequence"],
+        bump,
+    )]
+    pub event_sequence: Account<'info, EventSequence>,
+
+    pub system_program: Program<'info, System>,
+}
+
+
+
+#[derive(Accounts)]
+pub struct ApproveDelegate<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        constraint = token_account.owner == owner.key() @ ErrorCode::Unauthorized,
+    )]
+    pub token_account: Account<'info, TokenAccount>,
+
+    /// CHECK: Esta é a carteira delegada
+    pub delegate: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"event_sequence"],
+        bump,
+    )]
+    pub event_sequence: Account<'info, EventSequence>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+#[derive(Accounts)]
+pub struct SetPaymentToken<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump,
+    )]
+    pub config: Account<'info, ConfigAccount>,
+
+    #[account(
+        mut,
+        seeds = [b"event_sequence"],
+        bump,
+    )]
+    pub event_sequence: Account<'info, EventSequence>,
+}
+
+#[derive(Accounts)]
+pub struct SetRewardReserve<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump,
+    )]
+    pub config: Account<'info, ConfigAccount>,
+
+    #[account(
+        mut,
+        seeds = [b"event_sequence"],
+        bump,
+    )]
+    pub event_sequence: Account<'info, EventSequence>,
+}
+
+#[derive(Accounts)]
+pub struct InitializeRewardReserve<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        init,
+        payer = admin,
+        associated_token::mint = token_mint,
+        associated_token::authority = stake_authority,
+    )]
+    pub reward_reserve_account: Account<'info, TokenAccount>,
+
+    pub token_mint: Account<'info, Mint>,
+
+    #[account(
+        seeds = [b"stake_authority"],
+        bump,
+    )]
+    /// CHECK: Este é um PDA usado como autoridade
+    pub stake_authority: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump,
+    )]
+    pub config: Account<'info, ConfigAccount>,
+    
+    #[account(
+        mut,
+        seeds = [b"event_sequence"],
+        bump,
+    )]
+    pub event_sequence: Account<'info, EventSequence>,
+
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+#[derive(Accounts)]
+pub struct DepositRewardReserve<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        associated_token::mint = token_mint,
+        associated_token::authority = admin,
+    )]
+    pub admin_token_account: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        associated_token::mint = token_mint,
+        associated_token::authority = stake_authority,
+    )]
+    pub reward_reserve_account: Account<'info, TokenAccount>,
+
+    pub token_mint: Account<'info, Mint>,
+
+    #[account(
+        seeds = [b"stake_authority"],
+        bump,
+    )]
+    /// CHECK: Este é um PDA usado como autoridade
+    pub stake_authority: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [b"config"],
+        bump,
+    )]
+    pub config: Account<'info, ConfigAccount>,
+
+    #[account(
+        mut,
+        seeds = [b"event_sequence"],
+        bump,
+    )]
+    pub event_sequence: Account<'info, EventSequence>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+#[derive(Accounts)]
+pub struct MintNFTWithPayment<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [b"nft_counter"],
+        bump,
+    )]
+    pub nft_counter: Box<Account<'info, NftCounter>>,
+    
+    // Criado no handler pelo programa de token da coleção (Token-2022 com a extensão
+    // NonTransferable em coleções soulbound)
+    #[account(
+        mut,
+        seeds = [
+            b"nft_mint",
+            collection_metadata.key().as_ref(),
+            nft_counter.count.to_le_bytes().as_ref(),
+        ],
+        bump,
+    )]
+    /// CHECK: Conta ainda não criada; o endereço é verificado pelas seeds
+    pub nft_mint: UncheckedAccount<'info>,
+    
+    #[account(
+        init,
+        payer = payer,
+        space = NFT_METADATA_SPACE,
+        seeds = [
+            b"nft_metadata",
+            nft_mint.key().as_ref(),
+        ],
+        bump,
+    )]
+    pub nft_metadata: Box<Account<'info, NFTMetadata>>,
+    
+    #[account(
+        mut,
+        address = get_associated_token_address_with_program_id(
+            &payer.key(),
+            &nft_mint.key(),
+            &nft_token_program.key(),
+        ),
+    )]
+    /// CHECK: ATA criada no handler; o endereço é verificado acima
+    pub nft_token_account: UncheckedAccount<'info>,
+    
+    pub collection_metadata: Box<Account<'info, NFTMetadata>>,
+    
+    #[account(
+        mut,
+        seeds = [b"collection_config", collection_metadata.key().as_ref()],
+        bump,
+    )]
+    pub collection_config: Box<Account<'info, CollectionConfig>>,
+    
+    // Quantos NFTs esta carteira já mintou no mint público
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + MintRecord::INIT_SPACE,
+        seeds = [b"mint_record", collection_metadata.key().as_ref(), payer.key().as_ref()],
+        bump,
+    )]
+    pub mint_record: Box<Account<'info, MintRecord>>,
+    
+    #[account(
+        mut,
+        constraint = payment_token_mint.key() == config.payment_token_mint @ ErrorCode::InvalidPaymentToken,
+    )]
+    pub payment_token_mint: Box<Account<'info, Mint>>,
+    
+    #[account(
+        mut,
+        associated_token::mint = payment_token_mint,
+        associated_token::authority = payer,
+    )]
+    pub payer_payment_token_account: Box<Account<'info, TokenAccount>>,
+    
+    // Tesouraria da coleção (obrigatória quando treasury_bps > 0); o token de pagamento
+    // pode ter mudado depois do set_payment_split, então o mint é conferido a cada uso
+    #[account(
+        mut,
+        constraint = treasury_token_account.key() == collection_config.treasury @ ErrorCode::InvalidTreasury,
+        constraint = treasury_token_account.mint == config.payment_token_mint @ ErrorCode::InvalidPaymentToken,
+    )]
+    pub treasury_token_account: Option<Box<Account<'info, TokenAccount>>>,
+    
+    // Reserva de recompensas de staking (obrigatória quando reserve_bps > 0)
+    #[account(
+        mut,
+        constraint = reward_reserve_account.key() == config.reward_reserve @ ErrorCode::InvalidRewardReserve,
+        constraint = reward_reserve_account.mint == config.payment_token_mint @ ErrorCode::InvalidPaymentToken,
+    )]
+    pub reward_reserve_account: Option<Box<Account<'info, TokenAccount>>>,
+    
+    #[account(
+        constraint = config.payment_token_mint != Pubkey::default() 
+            @ ErrorCode::PaymentTokenNotConfigured,
+        seeds = [b"config"],
+        bump,
+    )]
+    pub config: Box<Account<'info, ConfigAccount>>,
+    
+    #[account(
+        mut,
+        seeds = [b"event_sequence"],
+        bump,
+    )]
+    pub event_sequence: Account<'info, EventSequence>,
+
+    // Token para o pagamento; o NFT usa nft_token_program (Token-2022 se soulbound)
+    pub token_program: Program<'info, Token>,
+    #[account(
+        constraint = nft_token_program.key() == collection_config.nft_token_program_id()
+            @ ErrorCode::InvalidNftTokenProgram,
+    )]
+    pub nft_token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+impl<'info> MintNFTWithPayment<'info> {
+    fn spl_payment(&self) -> SplPayment<'info> {
+        SplPayment {
+            token_program: self.token_program.to_account_info(),
+            payment_token_mint: self.payment_token_mint.to_account_info(),
+            from: self.payer_payment_token_account.to_account_info(),
+            balance: self.payer_payment_token_account.amount,
+            authority: self.payer.to_account_info(),
+            treasury: self.treasury_token_account.as_ref().map(|account| account.to_account_info()),
+            reward_reserve: self.reward_reserve_account.as_ref().map(|account| account.to_account_info()),
+        }
+    }
+}
+
+// Contas de cada NFT do lote são passadas em remaining_accounts
+#[derive(Accounts)]
+pub struct MintBatch<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [b"nft_counter"],
+        bump,
+    )]
+    pub nft_counter: Account<'info, NftCounter>,
+    
+    pub collection_metadata: Account<'info, NFTMetadata>,
+    
+    #[account(
+        mut,
+        seeds = [b"collection_config", collection_metadata.key().as_ref()],
+        bump,
+    )]
+    pub collection_config: Box<Account<'info, CollectionConfig>>,
+    
+    // Quantos NFTs esta carteira já mintou no mint público
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + MintRecord::INIT_SPACE,
+        seeds = [b"mint_record", collection_metadata.key().as_ref(), payer.key().as_ref()],
+        bump,
+    )]
+    pub mint_record: Account<'info, MintRecord>,
+    
+    #[account(
+        mut,
+        constraint = payment_token_mint.key() == config.payment_token_mint @ ErrorCode::InvalidPaymentToken,
+    )]
+    pub payment_token_mint: Account<'info, Mint>,
+    
+    #[account(
+        mut,
+        associated_token::mint = payment_token_mint,
+        associated_token::authority = payer,
+    )]
+    pub payer_payment_token_account: Account<'info, TokenAccount>,
+    
+    // Tesouraria da coleção (obrigatória quando treasury_bps > 0); o token de pagamento
+    // pode ter mudado depois do set_payment_split, então o mint é conferido a cada uso
+    #[account(
+        mut,
+        constraint = treasury_token_account.key() == collection_config.treasury @ ErrorCode::InvalidTreasury,
+        constraint = treasury_token_account.mint == config.payment_token_mint @ ErrorCode::InvalidPaymentToken,
+    )]
+    pub treasury_token_account: Option<Box<Account<'info, TokenAccount>>>,
+    
+    // Reserva de recompensas de staking (obrigatória quando reserve_bps > 0)
+    #[account(
+        mut,
+        constraint = reward_reserve_account.key() == config.reward_reserve @ ErrorCode::InvalidRewardReserve,
+        constraint = reward_reserve_account.mint == config.payment_token_mint @ ErrorCode::InvalidPaymentToken,
+    )]
+    pub reward_reserve_account: Option<Box<Account<'info, TokenAccount>>>,
+    
+    #[account(
+        constraint = config.payment_token_mint != Pubkey::default() 
+            @ ErrorCode::PaymentTokenNotConfigured,
+        seeds = [b"config"],
+        bump,
+    )]
+    pub config: Account<'info, ConfigAccount>,
+    
+    #[account(
+        mut,
+        seeds = [b"event_sequence"],
+        bump,
+    )]
+    pub event_sequence: Account<'info, EventSequence>,
+
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> MintBatch<'info> {
+    fn spl_payment(&self) -> SplPayment<'info> {
+        SplPayment {
+            token_program: self.token_program.to_account_info(),
+            payment_token_mint: self.payment_token_mint.to_account_info(),
+            from: self.payer_payment_token_account.to_account_info(),
+            balance: self.payer_payment_token_account.amount,
+            authority: self.payer.to_account_info(),
+            treasury: self.treasury_token_account.as_ref().map(|account| account.to_account_info()),
+            reward_reserve: self.reward_reserve_account.as_ref().map(|account| account.to_account_info()),
+        }
+    }
+}
+
+#[derive(Accounts)]
+pub struct MintNFTWithSol<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [b"nft_counter"],
+        bump,
+    )]
+    pub nft_counter: Account<'info, NftCounter>,
+    
+    #[account(
+        init,
+        payer = payer,
+        mint::decimals = 0,
+        mint::authority = payer,
+        seeds = [
+            b"nft_mint",
+            collection_metadata.key().as_ref(),
+            nft_counter.count.to_le_bytes().as_ref(),
+        ],
+        bump,
+    )]
+    pub nft_mint: Account<'info, Mint>,
+    
+    #[account(
+        init,
+        payer = payer,
+        space = NFT_METADATA_SPACE,
+        seeds = [
+            b"nft_metadata",
+            nft_mint.key().as_ref(),
+        ],
+        bump,
+    )]
+    pub nft_metadata: Account<'info, NFTMetadata>,
+    
+    #[account(
+        init,
+        payer = payer,
+        associated_token::mint = nft_mint,
+        associated_token::authority = payer,
+    )]
+    pub nft_token_account: Account<'info, TokenAccount>,
+    
+    pub collection_metadata: Account<'info, NFTMetadata>,
+    
+    #[account(
+        mut,
+        seeds = [b"collection_config", collection_metadata.key().as_ref()],
+        bump,
+    )]
+    pub collection_config: Account<'info, CollectionConfig>,
+    
+    // Quantos NFTs esta carteira já mintou no mint público
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + MintRecord::INIT_SPACE,
+        seeds = [b"mint_record", collection_metadata.key().as_ref(), payer.key().as_ref()],
+        bump,
+    )]
+    pub mint_record: Account<'info, MintRecord>,
+    
+    // PDA que recebe os lamports pagos pelos mints da coleção
+    #[account(
+        mut,
+        seeds = [b"sol_treasury", collection_metadata.key().as_ref()],
+        bump,
+    )]
+    pub sol_treasury: SystemAccount<'info>,
+    
+    #[account(
+        seeds = [b"config"],
+        bump,
+    )]
+    pub config: Account<'info, ConfigAccount>,
+    
+    #[account(
+        mut,
+        seeds = [b"event_sequence"],
+        bump,
+    )]
+    pub event_sequence: Account<'info, EventSequence>,
+
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+#[derive(Accounts)]
+pub struct SetPaymentMode<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        constraint = config.admin == admin.key() @ ErrorCode::Unauthorized,
+        seeds = [b"config"],
+        bump,
+    )]
+    pub config: Account<'info, ConfigAccount>,
+
+    pub collection_metadata: Account<'info, NFTMetadata>,
+
+    #[account(
+        mut,
+        seeds = [b"collection_config", collection_metadata.key().as_ref()],
+        bump,
+    )]
+    pub collection_config: Account<'info, CollectionConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"event_sequence"],
+        bump,
+    )]
+    pub event_sequence: Account<'info, EventSequence>,
+}
+
+#[derive(Accounts)]
+pub struct SetSoulbound<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        constraint = config.admin == admin.key() @ ErrorCode::Unauthorized,
+        seeds = [b"config"],
+        bump,
+    )]
+    pub config: Account<'info, ConfigAccount>,
+
+    pub collection_metadata: Account<'info, NFTMetadata>,
+
+    #[account(
+        mut,
+        seeds = [b"collection_config", collection_metadata.key().as_ref()],
+        bump,
+    )]
+    pub collection_config: Account<'info, CollectionConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"event_sequence"],
+        bump,
+    )]
+    pub event_sequence: Account<'info, EventSequence>,
+}
+
+#[derive(Accounts)]
+pub struct SetNftRewardRate<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        constraint = config.admin == admin.key() @ ErrorCode::Unauthorized,
+        seeds = [b"config"],
+        bump,
+    )]
+    pub config: Account<'info, ConfigAccount>,
+
+    pub collection_metadata: Account<'info, NFTMetadata>,
+
+    #[account(
+        mut,
+        seeds = [b"collection_config", collection_metadata.key().as_ref()],
+        bump,
+    )]
+    pub collection_config: Account<'info, CollectionConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"event_sequence"],
+        bump,
+    )]
+    pub event_sequence: Account<'info, EventSequence>,
+}
+
+#[derive(Accounts)]
+pub struct WithdrawSolTreasury<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        constraint = config.admin == admin.key() @ ErrorCode::Unauthorized,
+        seeds = [b"config"],
+        bump,
+    )]
+    pub config: Account<'info, ConfigAccount>,
+
+    pub collection_metadata: Account<'info, NFTMetadata>,
+
+    #[account(
+        mut,
+        seeds = [b"sol_treasury", collection_metadata.key().as_ref()],
+        bump,
+    )]
+    pub sol_treasury: SystemAccount<'info>,
+
+    /// CHECK: Carteira que recebe os lamports sacados, escolhida pelo admin
+    #[account(mut)]
+    pub destination: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"event_sequence"],
+        bump,
+    )]
+    pub event_sequence: Account<'info, EventSequence>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct MintAllowlisted<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [b"nft_counter"],
+        bump,
+    )]
+    pub nft_counter: Account<'info, NftCounter>,
+    
+    #[account(
+        init,
+        payer = payer,
+        mint::decimals = 0,
+        mint::authority = payer,
+        seeds = [
+            b"nft_mint",
+            collection_metadata.key().as_ref(),
+            nft_counter.count.to_le_bytes().as_ref(),
+        ],
+        bump,
+    )]
+    pub nft_mint: Account<'info, Mint>,
+    
+    #[account(
+        init,
+        payer = payer,
+        space = NFT_METADATA_SPACE,
+        seeds = [
+            b"nft_metadata",
+            nft_mint.key().as_ref(),
+        ],
+        bump,
+    )]
+    pub nft_metadata: Account<'info, NFTMetadata>,
+    
+    #[account(
+        init,
+        payer = payer,
+        associated_token::mint = nft_mint,
+        associated_token::authority = payer,
+    )]
+    pub nft_token_account: Account<'info, TokenAccount>,
+    
+    pub collection_metadata: Account<'info, NFTMetadata>,
+    
+    #[account(
+        mut,
+        seeds = [b"collection_config", collection_metadata.key().as_ref()],
+        bump,
+    )]
+    pub collection_config: Account<'info, CollectionConfig>,
+    
+    // Quantos NFTs esta carteira já mintou pela allowlist
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + AllowlistClaim::INIT_SPACE,
+        seeds = [b"allowlist_claim", collection_metadata.key().as_ref(), payer.key().as_ref()],
+        bump,
+    )]
+    pub allowlist_claim: Account<'info, AllowlistClaim>,
+    
+    // Token de pagamento e conta do payer: obrigatórios no modo SplToken
+    #[account(
+        mut,
+        constraint = payment_token_mint.key() == config.payment_token_mint @ ErrorCode::InvalidPaymentToken,
+    )]
+    pub payment_token_mint: Option<Box<Account<'info, Mint>>>,
+    
+    #[account(
+        mut,
+        constraint = payer_payment_token_account.owner == payer.key() @ ErrorCode::Unauthorized,
+        constraint = payer_payment_token_account.mint == config.payment_token_mint @ ErrorCode::InvalidPaymentToken,
+    )]
+    pub payer_payment_token_account: Option<Box<Account<'info, TokenAccount>>>,
+    
+    // Tesouraria da coleção (obrigatória quando treasury_bps > 0); o token de pagamento
+    // pode ter mudado depois do set_payment_split, então o mint é conferido a cada uso
+    #[account(
+        mut,
+        constraint = treasury_token_account.key() == collection_config.treasury @ ErrorCode::InvalidTreasury,
+        constraint = treasury_token_account.mint == config.payment_token_mint @ ErrorCode::InvalidPaymentToken,
+    )]
+    pub treasury_token_account: Option<Box<Account<'info, TokenAccount>>>,
+    
+    // Reserva de recompensas de staking (obrigatória quando reserve_bps > 0)
+    #[account(
+        mut,
+        constraint = reward_reserve_account.key() == config.reward_reserve @ ErrorCode::InvalidRewardReserve,
+        constraint = reward_reserve_account.mint == config.payment_token_mint @ ErrorCode::InvalidPaymentToken,
+    )]
+    pub reward_reserve_account: Option<Box<Account<'info, TokenAccount>>>,
+    
+    // Tesouraria SOL da coleção: obrigatória no modo Sol
+    #[account(
+        mut,
+        seeds = [b"sol_treasury", collection_metadata.key().as_ref()],
+        bump,
+    )]
+    pub sol_treasury: Option<SystemAccount<'info>>,
+    
+    #[account(
+        seeds = [b"config"],
+        bump,
+    )]
+    pub config: Account<'info, ConfigAccount>,
+    
+    #[account(
+        mut,
+        seeds = [b"event_sequence"],
+        bump,
+    )]
+    pub event_sequence: Account<'info, EventSequence>,
+
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+impl<'info> MintAllowlisted<'info> {
+    // Contas do pagamento em token SPL, que são opcionais no contexto
+    fn spl_payment(&self) -> Result<SplPayment<'info>> {
+        require!(
+            self.config.payment_token_mint != Pubkey::default(),
+            ErrorCode::PaymentTokenNotConfigured
+        );
+        let payment_token_mint = self.payment_token_mint.as_ref()
+            .ok_or(ErrorCode::InvalidPaymentToken)?;
+        let payer_payment_token_account = self.payer_payment_token_account.as_ref()
+            .ok_or(ErrorCode::InvalidPaymentToken)?;
+        Ok(SplPayment {
+            token_program: self.token_program.to_account_info(),
+            payment_token_mint: payment_token_mint.to_account_info(),
+            from: payer_payment_token_account.to_account_info(),
+            balance: payer_payment_token_account.amount,
+            authority: self.payer.to_account_info(),
+            treasury: self.treasury_token_account.as_ref().map(|account| account.to_account_info()),
+            reward_reserve: self.reward_reserve_account.as_ref().map(|account| account.to_account_info()),
+        })
+    }
+}
+
+#[derive(Accounts)]
+pub struct SetPricingCurve<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        constraint = config.admin == admin.key() @ ErrorCode::Unauthorized,
+        seeds = [b"config"],
+        bump,
+    )]
+    pub config: Account<'info, ConfigAccount>,
+
+    pub collection_metadata: Account<'info, NFTMetadata>,
+
+    #[account(
+        seeds = [b"nft_counter"],
+        bump,
+    )]
+    pub nft_counter: Account<'info, NftCounter>,
+
+    #[account(
+        mut,
+        seeds = [b"collection_config", collection_metadata.key().as_ref()],
+        bump,
+    )]
+    pub collection_config: Account<'info, CollectionConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"event_sequence"],
+        bump,
+    )]
+    pub event_sequence: Account<'info, EventSequence>,
+}
+
+#[derive(Accounts)]
+pub struct BurnNft<'info> {
+    #[account(mut)]
+    pub holder: Signer<'info>,
+    
+    #[account(
+        mut,
+        mint::token_program = nft_token_program,
+    )]
+    pub nft_mint: InterfaceAccount<'info, token_interface::Mint>,
+    
+    // Metadados do NFT, fechados com o aluguel devolvido ao holder
+    #[account(
+        mut,
+        close = holder,
+        seeds = [
+            b"nft_metadata",
+            nft_mint.key().as_ref(),
+        ],
+        bump,
+        constraint = nft_metadata.collection == Some(collection_metadata.key()) @ ErrorCode::InvalidCollection,
+    )]
+    pub nft_metadata: Account<'info, NFTMetadata>,
+    
+    #[account(
+        mut,
+        associated_token::mint = nft_mint,
+        associated_token::authority = holder,
+        associated_token::token_program = nft_token_program,
+    )]
+    pub nft_token_account: InterfaceAccount<'info, token_interface::TokenAccount>,
+    
+    pub collection_metadata: Account<'info, NFTMetadata>,
+    
+    #[account(
+        mut,
+        seeds = [b"collection_config", collection_metadata.key().as_ref()],
+        bump,
+    )]
+    pub collection_config: Account<'info, CollectionConfig>,
+    
+    // Autoridade PDA da pool de resgate da coleção
+    #[account(
+        seeds = [b"redemption_authority", collection_metadata.key().as_ref()],
+        bump,
+    )]
+    /// CHECK: Este é um PDA usado como autoridade
+    pub redemption_authority: UncheckedAccount<'info>,
+    
+    // Pool de resgate (obrigatória quando redemption_amount > 0)
+    #[account(
+        mut,
+        constraint = redemption_pool.owner == redemption_authority.key() @ ErrorCode::InvalidRedemptionPool,
+        constraint = redemption_pool.mint == config.payment_token_mint @ ErrorCode::InvalidPaymentToken,
+    )]
+    pub redemption_pool: Option<Box<Account<'info, TokenAccount>>>,
+    
+    #[account(
+        mut,
+        constraint = holder_payment_token_account.owner == holder.key() @ ErrorCode::Unauthorized,
+        constraint = holder_payment_token_account.mint == config.payment_token_mint @ ErrorCode::InvalidPaymentToken,
+    )]
+    pub holder_payment_token_account: Option<Box<Account<'info, TokenAccount>>>,
+    
+    #[account(
+        seeds = [b"config"],
+        bump,
+    )]
+    pub config: Account<'info, ConfigAccount>,
+    
+    #[account(
+        mut,
+        seeds = [b"event_sequence"],
+        bump,
+    )]
+    pub event_sequence: Account<'info, EventSequence>,
+
+    // Programa de token do NFT (Token-2022 em coleções soulbound)
+    pub nft_token_program: Interface<'info, TokenInterface>,
+    pub token_program: Program<'info, Token>,
+}
+
+#[derive(Accounts)]
+pub struct UpdateNftMetadata<'info> {
+    pub authority: Signer<'info>,
+    
+    #[account(
+        mut,
+        constraint = nft_metadata.authority == authority.key() @ ErrorCode::Unauthorized,
+        constraint = !nft_metadata.locked @ ErrorCode::MetadataLocked,
+    )]
+    pub nft_metadata: Account<'info, NFTMetadata>,
+
+    /// CHECK: CollectionConfig da coleção do NFT; pode não existir (coleção sem configuração ou a própria coleção)
+    #[account(
+        seeds = [b"collection_config", nft_metadata.collection.unwrap_or_default().as_ref()],
+        bump,
+    )]
+    pub collection_config: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"event_sequence"],
+        bump,
+    )]
+    pub event_sequence: Account<'info, EventSequence>,
+}
+
+impl<'info> UpdateNftMetadata<'info> {
+    // Enquanto a coleção mostra a placeholder, os metadados ficam congelados até o reveal
+    fn check_reveal(&self) -> Result<()> {
+        if self.collection_config.data_is_empty() {
+            return Ok(());
+        }
+        require_keys_eq!(*self.collection_config.owner, crate::ID, ErrorCode::InvalidCollection);
+        let collection_config = CollectionConfig::try_deserialize(
+            &mut &self.collection_config.try_borrow_data()?[..],
+        )?;
+        require!(!collection_config.reveal_pending(), ErrorCode::RevealPending);
+        Ok(())
+    }
+}
+
+#[derive(Accounts)]
+pub struct ConfigureReveal<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        constraint = config.admin == admin.key() @ ErrorCode::Unauthorized,
+        seeds = [b"config"],
+        bump,
+    )]
+    pub config: Account<'info, ConfigAccount>,
+
+    pub collection_metadata: Account<'info, NFTMetadata>,
+
+    #[account(
+        mut,
+        seeds = [b"collection_config", collection_metadata.key().as_ref()],
+        bump,
+    )]
+    pub collection_config: Account<'info, CollectionConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"event_sequence"],
+        bump,
+    )]
+    pub event_sequence: Account<'info, EventSequence>,
+}
+
+// Os metadados a revelar são passados como remaining_accounts (graváveis)
+#[derive(Accounts)]
+pub struct RevealNfts<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        constraint = config.admin == admin.key() @ ErrorCode::Unauthorized,
+        seeds = [b"config"],
+        bump,
+    )]
+    pub config: Account<'info, ConfigAccount>,
+
+    pub collection_metadata: Account<'info, NFTMetadata>,
+
+    #[account(
+        seeds = [b"collection_config", collection_metadata.key().as_ref()],
+        bump,
+    )]
+    pub collection_config: Account<'info, CollectionConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"event_sequence"],
+        bump,
+    )]
+    pub event_sequence: Account<'info, EventSequence>,
+}
+
+#[derive(Accounts)]
+pub struct ConfigureRedemption<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        constraint = config.admin == admin.key() @ ErrorCode::Unauthorized,
+        seeds = [b"config"],
+        bump,
+    )]
+    pub config: Account<'info, ConfigAccount>,
+
+    pub collection_metadata: Account<'info, NFTMetadata>,
+
+    #[account(
+        mut,
+        seeds = [b"collection_config", collection_metadata.key().as_ref()],
+        bump,
+    )]
+    pub collection_config: Account<'info, CollectionConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"event_sequence"],
+        bump,
+    )]
+    pub event_sequence: Account<'info, EventSequence>,
+}
+
+#[derive(Accounts)]
+pub struct DepositRedemptionPool<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        constraint = config.admin == admin.key() @ ErrorCode::Unauthorized,
+        seeds = [b"config"],
+        bump,
+    )]
+    pub config: Account<'info, ConfigAccount>,
+
+    pub collection_metadata: Account<'info, NFTMetadata>,
+
+    // Autoridade PDA da pool de resgate da coleção
+    #[account(
+        seeds = [b"redemption_authority", collection_metadata.key().as_ref()],
+        bump,
+    )]
+    /// CHECK: Este é um PDA usado como autoridade
+    pub redemption_authority: UncheckedAccount<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = admin,
+        associated_token::mint = payment_token_mint,
+        associated_token::authority = redemption_authority,
+    )]
+    pub redemption_pool: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        associated_token::mint = payment_token_mint,
+        associated_token::authority = admin,
+    )]
+    pub admin_token_account: Box<Account<'info, TokenAccount>>,
+
+    // O resgate é pago no token de pagamento configurado
+    #[account(
+        constraint = payment_token_mint.key() == config.payment_token_mint @ ErrorCode::InvalidPaymentToken,
+    )]
+    pub payment_token_mint: Box<Account<'info, Mint>>,
+
+    #[account(
+        mut,
+        seeds = [b"event_sequence"],
+        bump,
+    )]
+    pub event_sequence: Account<'info, EventSequence>,
+
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct SetPaymentSplit<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        constraint = config.admin == admin.key() @ ErrorCode::Unauthorized,
+        seeds = [b"config"],
+        bump,
+    )]
+    pub config: Account<'info, ConfigAccount>,
+
+    pub collection_metadata: Account<'info, NFTMetadata>,
+
+    #[account(
+        mut,
+        seeds = [b"collection_config", collection_metadata.key().as_ref()],
+        bump,
+    )]
+    pub collection_config: Account<'info, CollectionConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"event_sequence"],
+        bump,
+    )]
+    pub event_sequence: Account<'info, EventSequence>,
+
+    // Conta de token da tesouraria, no mesmo token de pagamento
+    #[account(
+        constraint = treasury_token_account.mint == config.payment_token_mint @ ErrorCode::InvalidPaymentToken,
+    )]
+    pub treasury_token_account: Option<Account<'info, TokenAccount>>,
+}
+
+#[derive(Accounts)]
+pub struct SetAllowlist<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        constraint = config.admin == admin.key() @ ErrorCode::Unauthorized,
+        seeds = [b"config"],
+        bump,
+    )]
+    pub config: Account<'info, ConfigAccount>,
+
+    pub collection_metadata: Account<'info, NFTMetadata>,
+
+    #[account(
+        mut,
+        seeds = [b"collection_config", collection_metadata.key().as_ref()],
+        bump,
+    )]
+    pub collection_config: Account<'info, CollectionConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"event_sequence"],
+        bump,
+    )]
+    pub event_sequence: Account<'info, EventSequence>,
+}
+
+#[derive(Accounts)]
+pub struct ConfigureStaking<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        constraint = config.admin == admin.key() @ ErrorCode::Unauthorized,
+        seeds = [b"config"],
+        bump,
+    )]
+    pub config: Account<'info, ConfigAccount>,
+
+    #[account(
+        mut,
+        seeds = [b"event_sequence"],
+        bump,
+    )]
+    pub event_sequence: Account<'info, EventSequence>,
+}
+
+#[derive(Accounts)]
+#[instruction(amount: u64, period: StakingPeriod)]
+pub struct StakeTokens<'info> {
+    #[account(mut)]
+    pub staker: Signer<'info>,
+    
+    // Token a ser usado para staking (o mesmo token de pagamento)
+    pub token_mint: Account<'info, Mint>,
+    
+    #[account(
+        mut,
+        associated_token::mint = token_mint,
+        associated_token::authority = staker,
+        constraint = staker_token_account.amount >= amount @ ErrorCode::InsufficientFunds,
+    )]
+    pub staker_token_account: Account<'info, TokenAccount>,
+    
+    // Conta que vai guardar os tokens em stake
+    #[account(
+        init_if_needed,
+        payer = staker,
+        space = 8 + StakeAccount::INIT_SPACE,
+        seeds = [b"stake_account", staker.key().as_ref(), token_mint.key().as_ref()],
+        bump
+    )]
+    pub stake_account: Account<'info, StakeAccount>,
+
+    // Conta que vai armazenar os tokens em stake
+    #[account(
+        init_if_needed,
+        payer = staker,
+        associated_token::mint = token_mint,
+        associated_token::authority = stake_authority,
+    )]
+    pub stake_token_account: Account<'info, TokenAccount>,
+    
+    // Autoridade PDA para controlar os tokens em stake
+    #[account(
+        seeds = [b"stake_authority"],
+        bump,
+    )]
+    /// CHECK: Este é um PDA usado como autoridade
+    pub stake_authority: UncheckedAccount<'info>,
+    
+    // Recibo da posição (somente com receipt_uri)
+    #[account(
+        init,
+        payer = staker,
+        space = 8 + StakeReceipt::INIT_SPACE,
+        seeds = [b"stake_receipt", stake_account.key().as_ref()],
+        bump,
+    )]
+    pub stake_receipt: Option<Box<Account<'info, StakeReceipt>>>,
+    
+    // Mint do NFT de recibo, um keypair novo por posição (criado no handler)
+    #[account(mut)]
+    pub receipt_mint: Option<Signer<'info>>,
+    
+    // Metadados do recibo, PDA [b"nft_metadata", receipt_mint] criado no handler
+    #[account(mut)]
+    /// CHECK: Endereço verificado no handler antes da criação
+    pub receipt_metadata: Option<UncheckedAccount<'info>>,
+    
+    // ATA do staker para o recibo, criada no handler
+    #[account(mut)]
+    /// CHECK: Endereço verificado no handler antes da criação
+    pub receipt_token_account: Option<UncheckedAccount<'info>>,
+    
+    // Configuração do token
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump,
+    )]
+    pub config: Account<'info, ConfigAccount>,
+    
+    #[account(
+        mut,
+        seeds = [b"event_sequence"],
+        bump,
+    )]
+    pub event_sequence: Account<'info, EventSequence>,
+
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+impl<'info> StakeTokens<'info> {
+    // Minta o recibo da posição como os NFTs da coleção (mint, metadados e ATA criados aqui)
+    // e revoga a autoridade de mint, para que exista uma única unidade
+    fn issue_receipt(&mut self, uri: String, issued_at: i64) -> Result<()> {
+        utils::validate_uri(&uri)?;
+        let (Some(stake_receipt), Some(receipt_mint), Some(receipt_metadata), Some(receipt_token_account)) = (
+            self.stake_receipt.as_mut(),
+            self.receipt_mint.as_ref(),
+            self.receipt_metadata.as_ref(),
+            self.receipt_token_account.as_ref(),
+        ) else {
+            return err!(ErrorCode::InvalidStakeReceipt);
+        };
+        
+        let staker_key = self.staker.key();
+        let mint_key = receipt_mint.key();
+        let (metadata_key, metadata_bump) = Pubkey::find_program_address(
+            &[b"nft_metadata", mint_key.as_ref()],
+            &crate::ID,
+        );
+        require_keys_eq!(receipt_metadata.key(), metadata_key, ErrorCode::InvalidStakeReceipt);
+        require_keys_eq!(
+            receipt_token_account.key(),
+            get_associated_token_address(&staker_key, &mint_key),
+            ErrorCode::InvalidStakeReceipt
+        );
+        
+        // Criar e inicializar o mint do recibo
+        system_program::create_account(
+            CpiContext::new(
+                self.system_program.to_account_info(),
+                system_program::CreateAccount {
+                    from: self.staker.to_account_info(),
+                    to: receipt_mint.to_account_info(),
+                },
+            ),
+            Rent::get()?.minimum_balance(token::spl_token::state::Mint::LEN),
+            token::spl_token::state::Mint::LEN as u64,
+            &token::ID,
+        )?;
+        token::initialize_mint2(
+            CpiContext::new(
+                self.token_program.to_account_info(),
+                InitializeMint2 { mint: receipt_mint.to_account_info() },
+            ),
+            0,
+            &staker_key,
+            None,
+        )?;
+        
+        // Criar a conta de metadados
+        let metadata_seeds: &[&[u8]] = &[
+            b"nft_metadata",
+            mint_key.as_ref(),
+            &[metadata_bump],
+        ];
+        create_pda_account(
+            self.staker.to_account_info(),
+            receipt_metadata.to_account_info(),
+            NFT_METADATA_SPACE,
+            &crate::ID,
+            metadata_seeds,
+            self.system_program.to_account_info(),
+        )?;
+        let metadata = NFTMetadata::stake_receipt(staker_key, uri);
+        metadata.try_serialize(&mut &mut receipt_metadata.try_borrow_mut_data()?[..])?;
+        
+        // Criar a conta de token associada e mintar o recibo
+        associated_token::create(CpiContext::new(
+            self.associated_token_program.to_account_info(),
+            associated_token::Create {
+                payer: self.staker.to_account_info(),
+                associated_token: receipt_token_account.to_account_info(),
+                authority: self.staker.to_account_info(),
+                mint: receipt_mint.to_account_info(),
+                system_program: self.system_program.to_account_info(),
+                token_program: self.token_program.to_account_info(),
+            },
+        ))?;
+        mint_nft_token(
+            self.token_program.to_account_info(),
+            receipt_mint.to_account_info(),
+            receipt_token_account.to_account_info(),
+            self.staker.to_account_info(),
+        )?;
+        token::set_authority(
+            CpiContext::new(
+                self.token_program.to_account_info(),
+                token::SetAuthority {
+                    current_authority: self.staker.to_account_info(),
+                    account_or_mint: receipt_mint.to_account_info(),
+                },
+            ),
+            AuthorityType::MintTokens,
+            None,
+        )?;
+        
+        // A posição passa a pertencer ao recibo
+        stake_receipt.set_inner(StakeReceipt {
+            version: ACCOUNT_VERSION,
+            stake_account: self.stake_account.key(),
+            receipt_mint: mint_key,
+            issued_at,
+            staker: staker_key,
+            reserved: [],
+        });
+        self.stake_account.owner = stake_receipt.key();
+        
+        let event_seq = self.event_sequence.next_seq()?;
+        emit!(StakeReceiptIssuedEvent {
+            event_seq,
+            staker: staker_key,
+            stake_account: self.stake_account.key(),
+            receipt_mint: mint_key,
+            timestamp: issued_at,
+        });
+        
+        msg!("Recibo {} emitido para a posição {}", mint_key, self.stake_account.key());
+        
+        Ok(())
+    }
+}
+
+#[derive(Accounts)]
+pub struct UnstakeTokens<'info> {
+    #[account(mut)]
+    pub staker: Signer<'info>,
+    
+    // Token a ser usado para staking (o mesmo token de pagamento)
+    pub token_mint: Account<'info, Mint>,
+    
+    #[account(
+        mut,
+        associated_token::mint = token_mint,
+        associated_token::authority = staker,
+    )]
+    pub staker_token_account: Account<'info, TokenAccount>,
+    
+    // Conta que guarda os tokens em stake
+    #[account(
+        mut,
+        associated_token::mint = token_mint,
+        associated_token::authority = stake_authority,
+    )]
+    pub stake_token_account: Account<'info, TokenAccount>,
+    
+    // Conta de reserva de recompensas
+    #[account(
+        mut,
+        associated_token::mint = token_mint,
+        associated_token::authority = stake_authority,
+        constraint = reward_reserve_account.key() == config.reward_reserve @ ErrorCode::InvalidRewardReserve,
+    )]
+    pub reward_reserve_account: Account<'info, TokenAccount>,
+    
+    // Autoridade PDA para controlar os tokens em stake
+    #[account(
+        seeds = [b"stake_authority"],
+        bump,
+    )]
+    /// CHECK: Este é um PDA usado como autoridade
+    pub stake_authority: UncheckedAccount<'info>,
     
-
-}
-
-#[derive(Accounts)]
-pub struct InitializeCollection<'info> {
+    // Conta que rastreia informações do staking, fechada no resgate com o aluguel devolvido
+    // O dono é o staker ou, com recibo emitido, o PDA stake_receipt (verificado no handler)
     #[account(mut)]
-    pub payer: Signer<'info>,
-
+    pub stake_account: Account<'info, StakeAccount>,
+    
+    // Recibo da posição (obrigatório quando emitido), fechado no resgate
     #[account(
-        init,
-        payer = payer,
-        mint::decimals = 0,
-        mint::authority = payer,
+        mut,
+        seeds = [b"stake_receipt", stake_account.key().as_ref()],
+        bump,
     )]
-    pub collection_mint: Account<'info, Mint>,
-
+    pub stake_receipt: Option<Box<Account<'info, StakeReceipt>>>,
+    
+    #[account(mut)]
+    pub receipt_mint: Option<Box<Account<'info, Mint>>>,
+    
+    // Conta de token do holder com o NFT de recibo, queimado e fechada no resgate
+    #[account(mut)]
+    pub receipt_token_account: Option<Box<Account<'info, TokenAccount>>>,
+    
+    // Metadados do recibo, fechados no resgate
     #[account(
-        init,
-        payer = payer,
-        space = 8 + 32 + 4 + 50 + 4 + 10 + 4 + 200, // discriminator + pubkey + name + symbol + uri
+        mut,
+        constraint = Some(receipt_metadata.key()) == stake_receipt.as_ref()
+            .map(|receipt| Pubkey::find_program_address(
+                &[b"nft_metadata", receipt.receipt_mint.as_ref()],
+                &crate::ID,
+            ).0) @ ErrorCode::InvalidStakeReceipt,
     )]
-    pub collection_metadata: Account<'info, NFTMetadata>,
-
+    pub receipt_metadata: Option<Box<Account<'info, NFTMetadata>>>,
+    
+    // Com recibo, o aluguel da posição, do recibo e dos metadados volta a quem abriu a
+    // posição e pagou por eles (stake_receipt.staker), não ao holder; recibos migrados do
+    // v1 não gravaram o staker e reembolsam o holder
     #[account(
-        init,
-        payer = payer,
-        associated_token::mint = collection_mint,
-        associated_token::authority = payer,
+        mut,
+        constraint = Some(rent_recipient.key()) == stake_receipt.as_ref()
+            .map(|receipt| receipt.rent_recipient(staker.key())) @ ErrorCode::InvalidStakeReceipt,
     )]
-    pub collection_token_account: Account<'info, TokenAccount>,
-
-            // Conta para armazenar a configuração
-        #[account(
-            init,
-            payer = payer,
-            space = 8 + 32 + 32 + 1 + 8 + 8 + 1 + 32, // discriminator + payment_token_mint + admin + staking_enabled + staking_reward_rate + max_stake_amount + emergency_paused + reward_reserve
-        )]
-        pub config: Account<'info, ConfigAccount>,
-
-    // Adicionar conta do contador
+    /// CHECK: Apenas recebe lamports; endereço conferido com o recibo
+    pub rent_recipient: Option<UncheckedAccount<'info>>,
+    
+    // NFT travado junto à posição (opcional, aplica NFT_HOLDER_BOOST_BPS)
     #[account(
-        init,
-        payer = payer,
-        space = 8 + 8, // discriminator + u64
-        seeds = [b"nft_counter"],
+        seeds = [b"stake_boost", stake_account.key().as_ref()],
         bump,
     )]
-    pub nft_counter: Account<'info, NftCounter>,
+    pub stake_boost: Option<Box<Account<'info, StakeBoost>>>,
+    
+    // Configuração do token
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump,
+    )]
+    pub config: Account<'info, ConfigAccount>,
+    
+    #[account(
+        mut,
+        seeds = [b"event_sequence"],
+        bump,
+    )]
+    pub event_sequence: Account<'info, EventSequence>,
 
     pub token_program: Program<'info, Token>,
-    pub associated_token_program: Program<'info, AssociatedToken>,
     pub system_program: Program<'info, System>,
-    pub rent: Sysvar<'info, Rent>,
 }
 
-
-
 #[derive(Accounts)]
-pub struct ApproveDelegate<'info> {
+pub struct CloseStake<'info> {
+    // Quem abriu a posição (seed do PDA), mesmo que o resgate tenha sido feito pelo recibo
     #[account(mut)]
-    pub owner: Signer<'info>,
-
+    pub staker: Signer<'info>,
+    
+    pub token_mint: Account<'info, Mint>,
+    
     #[account(
         mut,
-        constraint = token_account.owner == owner.key() @ ErrorCode::Unauthorized,
+        close = staker,
+        seeds = [b"stake_account", staker.key().as_ref(), token_mint.key().as_ref()],
+        bump,
     )]
-    pub token_account: Account<'info, TokenAccount>,
-
-    /// CHECK: Esta é a carteira delegada
-    pub delegate: UncheckedAccount<'info>,
+    pub stake_account: Account<'info, StakeAccount>,
+    
+    #[account(
+        mut,
+        seeds = [b"event_sequence"],
+        bump,
+    )]
+    pub event_sequence: Account<'info, EventSequence>,
+}
 
-    pub token_program: Program<'info, Token>,
+#[account]
+#[derive(InitSpace)]
+pub struct NFTMetadata {
+    pub version: u8,
+    pub authority: Pubkey,
+    #[max_len(MAX_NAME_LENGTH)]
+    pub name: String,
+    #[max_len(MAX_SYMBOL_LENGTH)]
+    pub symbol: String,
+    #[max_len(MAX_URI_LENGTH)]
+    pub uri: String,
+    pub collection: Option<Pubkey>,
+    pub locked: bool,  // Metadados imutáveis após lock_metadata
+    pub index: u64,    // Posição do NFT na coleção (base_uri + index no reveal)
+    pub reserved: [u8; 32],
 }
 
-#[derive(Accounts)]
-pub struct SetPaymentToken<'info> {
-    #[account(mut)]
-    pub admin: Signer<'info>,
+impl NFTMetadata {
+    // Metadados de um NFT recém-mintado
+    pub fn minted(
+        authority: Pubkey,
+        collection: Pubkey,
+        name: String,
+        symbol: String,
+        uri: String,
+        index: u64,
+    ) -> Self {
+        Self {
+            version: ACCOUNT_VERSION,
+            authority,
+            name,
+            symbol,
+            uri,
+            collection: Some(collection),
+            locked: false,
+            index,
+            reserved: [0; 32],
+        }
+    }
+    
+    // Metadados do recibo de uma posição de stake: fora de coleções e imutáveis
+    pub fn stake_receipt(authority: Pubkey, uri: String) -> Self {
+        Self {
+            version: ACCOUNT_VERSION,
+            authority,
+            name: STAKE_RECEIPT_NAME.to_string(),
+            symbol: STAKE_RECEIPT_SYMBOL.to_string(),
+            uri,
+            collection: None,
+            locked: true,
+            index: 0,
+            reserved: [0; 32],
+        }
+    }
+}
 
-    #[account(mut)]
-    pub config: Account<'info, ConfigAccount>,
+#[account]
+#[derive(InitSpace)]
+pub struct StakeAccount {
+    pub version: u8,
+    pub owner: Pubkey,           
+    pub amount: u64,             
+    pub start_time: i64,        
+    pub unlock_time: i64,      
+    pub period: StakingPeriod,   
+    pub claimed: bool,         
+    pub reserved: [u8; 32],
 }
 
 #[derive(Accounts)]
-pub struct SetRewardReserve<'info> {
+pub struct StakeNft<'info> {
     #[account(mut)]
-    pub admin: Signer<'info>,
+    pub staker: Signer<'info>,
+    
+    pub nft_mint: Box<Account<'info, Mint>>,
+    
+    // Só NFTs registrados na coleção informada podem entrar em stake
+    #[account(
+        seeds = [b"nft_metadata", nft_mint.key().as_ref()],
+        bump,
+        constraint = nft_metadata.collection == Some(collection_metadata.key()) @ ErrorCode::InvalidCollection,
+    )]
+    pub nft_metadata: Box<Account<'info, NFTMetadata>>,
+    
+    pub collection_metadata: Box<Account<'info, NFTMetadata>>,
+    
+    #[account(
+        seeds = [b"collection_config", collection_metadata.key().as_ref()],
+        bump,
+    )]
+    pub collection_config: Box<Account<'info, CollectionConfig>>,
+    
+    #[account(
+        mut,
+        associated_token::mint = nft_mint,
+        associated_token::authority = staker,
+    )]
+    pub staker_nft_account: Box<Account<'info, TokenAccount>>,
+    
+    // Cofre do NFT, controlado pela stake_authority
+    #[account(
+        init_if_needed,
+        payer = staker,
+        associated_token::mint = nft_mint,
+        associated_token::authority = stake_authority,
+    )]
+    pub nft_vault: Box<Account<'info, TokenAccount>>,
+    
+    #[account(
+        seeds = [b"stake_authority"],
+        bump,
+    )]
+    /// CHECK: Este é um PDA usado como autoridade
+    pub stake_authority: UncheckedAccount<'info>,
+    
+    #[account(
+        init,
+        payer = staker,
+        space = 8 + NftStakeAccount::INIT_SPACE,
+        seeds = [b"nft_stake", nft_mint.key().as_ref()],
+        bump,
+    )]
+    pub nft_stake: Box<Account<'info, NftStakeAccount>>,
+    
+    #[account(
+        seeds = [b"config"],
+        bump,
+    )]
+    pub config: Box<Account<'info, ConfigAccount>>,
+    
+    #[account(
+        mut,
+        seeds = [b"event_sequence"],
+        bump,
+    )]
+    pub event_sequence: Account<'info, EventSequence>,
 
-    #[account(mut)]
-    pub config: Account<'info, ConfigAccount>,
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
 }
 
 #[derive(Accounts)]
-pub struct InitializeRewardReserve<'info> {
+pub struct UnstakeNft<'info> {
     #[account(mut)]
-    pub admin: Signer<'info>,
-
+    pub staker: Signer<'info>,
+    
+    pub nft_mint: Box<Account<'info, Mint>>,
+    
+    pub collection_metadata: Box<Account<'info, NFTMetadata>>,
+    
     #[account(
-        init,
-        payer = admin,
-        associated_token::mint = token_mint,
+        seeds = [b"collection_config", collection_metadata.key().as_ref()],
+        bump,
+    )]
+    pub collection_config: Box<Account<'info, CollectionConfig>>,
+    
+    // Posição fechada com o aluguel devolvido ao staker
+    #[account(
+        mut,
+        close = staker,
+        seeds = [b"nft_stake", nft_mint.key().as_ref()],
+        bump,
+        constraint = nft_stake.owner == staker.key() @ ErrorCode::Unauthorized,
+        constraint = nft_stake.collection == collection_metadata.key() @ ErrorCode::InvalidCollection,
+    )]
+    pub nft_stake: Box<Account<'info, NftStakeAccount>>,
+    
+    #[account(
+        init_if_needed,
+        payer = staker,
+        associated_token::mint = nft_mint,
+        associated_token::authority = staker,
+    )]
+    pub staker_nft_account: Box<Account<'info, TokenAccount>>,
+    
+    #[account(
+        mut,
+        associated_token::mint = nft_mint,
         associated_token::authority = stake_authority,
     )]
-    pub reward_reserve_account: Account<'info, TokenAccount>,
-
-    pub token_mint: Account<'info, Mint>,
-
+    pub nft_vault: Box<Account<'info, TokenAccount>>,
+    
+    // Token de recompensa (o mesmo token de pagamento)
+    #[account(
+        constraint = token_mint.key() == config.payment_token_mint @ ErrorCode::InvalidPaymentToken,
+    )]
+    pub token_mint: Box<Account<'info, Mint>>,
+    
+    #[account(
+        mut,
+        associated_token::mint = token_mint,
+        associated_token::authority = staker,
+    )]
+    pub staker_token_account: Box<Account<'info, TokenAccount>>,
+    
+    // Autoridade PDA da pool de recompensas de NFTs da coleção
+    #[account(
+        seeds = [b"nft_reward_authority", collection_metadata.key().as_ref()],
+        bump,
+    )]
+    /// CHECK: Este é um PDA usado como autoridade
+    pub nft_reward_authority: UncheckedAccount<'info>,
+    
+    // Ausente enquanto o admin não abastecer a pool; o NFT volta sem recompensa
+    #[account(
+        mut,
+        associated_token::mint = token_mint,
+        associated_token::authority = nft_reward_authority,
+    )]
+    pub nft_reward_pool: Option<Box<Account<'info, TokenAccount>>>,
+    
     #[account(
         seeds = [b"stake_authority"],
         bump,
     )]
     /// CHECK: Este é um PDA usado como autoridade
     pub stake_authority: UncheckedAccount<'info>,
-
-    #[account(mut)]
-    pub config: Account<'info, ConfigAccount>,
     
+    #[account(
+        seeds = [b"config"],
+        bump,
+    )]
+    pub config: Box<Account<'info, ConfigAccount>>,
+    
+    #[account(
+        mut,
+        seeds = [b"event_sequence"],
+        bump,
+    )]
+    pub event_sequence: Account<'info, EventSequence>,
+
     pub token_program: Program<'info, Token>,
     pub associated_token_program: Program<'info, AssociatedToken>,
     pub system_program: Program<'info, System>,
-    pub rent: Sysvar<'info, Rent>,
 }
 
-#[derive(Accounts)]
-pub struct DepositRewardReserve<'info> {
-    #[account(mut)]
-    pub admin: Signer<'info>,
+#[derive(Accounts)]
+pub struct DepositNftRewardPool<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        constraint = config.admin == admin.key() @ ErrorCode::Unauthorized,
+        seeds = [b"config"],
+        bump,
+    )]
+    pub config: Account<'info, ConfigAccount>,
+
+    pub collection_metadata: Account<'info, NFTMetadata>,
+
+    // Autoridade PDA da pool de recompensas de NFTs da coleção
+    #[account(
+        seeds = [b"nft_reward_authority", collection_metadata.key().as_ref()],
+        bump,
+    )]
+    /// CHECK: Este é um PDA usado como autoridade
+    pub nft_reward_authority: UncheckedAccount<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = admin,
+        associated_token::mint = payment_token_mint,
+        associated_token::authority = nft_reward_authority,
+    )]
+    pub nft_reward_pool: Box<Account<'info, TokenAccount>>,
 
     #[account(
         mut,
-        associated_token::mint = token_mint,
+        associated_token::mint = payment_token_mint,
         associated_token::authority = admin,
     )]
-    pub admin_token_account: Account<'info, TokenAccount>,
+    pub admin_token_account: Box<Account<'info, TokenAccount>>,
 
+    // As recompensas são pagas no token de pagamento configurado
     #[account(
-        mut,
-        associated_token::mint = token_mint,
-        associated_token::authority = stake_authority,
+        constraint = payment_token_mint.key() == config.payment_token_mint @ ErrorCode::InvalidPaymentToken,
     )]
-    pub reward_reserve_account: Account<'info, TokenAccount>,
-
-    pub token_mint: Account<'info, Mint>,
+    pub payment_token_mint: Box<Account<'info, Mint>>,
 
     #[account(
-        seeds = [b"stake_authority"],
+        mut,
+        seeds = [b"event_sequence"],
         bump,
     )]
-    /// CHECK: Este é um PDA usado como autoridade
-    pub stake_authority: UncheckedAccount<'info>,
+    pub event_sequence: Account<'info, EventSequence>,
 
-    pub config: Account<'info, ConfigAccount>,
     pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
 }
 
 #[derive(Accounts)]
-pub struct MintNFTWithPayment<'info> {
+pub struct LockBoostNft<'info> {
     #[account(mut)]
-    pub payer: Signer<'info>,
+    pub staker: Signer<'info>,
     
     #[account(
-        mut,
-        seeds = [b"nft_counter"],
-        bump,
+        constraint = stake_account.owner == staker.key() @ ErrorCode::Unauthorized,
     )]
-    pub nft_counter: Account<'info, NftCounter>,
+    pub stake_account: Box<Account<'info, StakeAccount>>,
+    
+    pub nft_mint: Box<Account<'info, Mint>>,
     
+    // Só NFTs de uma coleção registrada dão direito ao bônus
     #[account(
-        init,
-        payer = payer,
-        mint::decimals = 0,
-        mint::authority = payer,
-        seeds = [
-            b"nft_mint",
-            collection_metadata.key().as_ref(),
-            nft_counter.count.to_le_bytes().as_ref(),
-        ],
+        seeds = [b"nft_metadata", nft_mint.key().as_ref()],
         bump,
+        constraint = nft_metadata.collection == Some(collection_metadata.key()) @ ErrorCode::InvalidCollection,
     )]
-    pub nft_mint: Account<'info, Mint>,
+    pub nft_metadata: Box<Account<'info, NFTMetadata>>,
+    
+    pub collection_metadata: Box<Account<'info, NFTMetadata>>,
     
     #[account(
-        init,
-        payer = payer,
-        space = 8 + 32 + 4 + 50 + 4 + 10 + 4 + 200 + 1 + 32,
-        seeds = [
-            b"nft_metadata",
-            nft_mint.key().as_ref(),
-        ],
+        seeds = [b"collection_config", collection_metadata.key().as_ref()],
         bump,
     )]
-    pub nft_metadata: Account<'info, NFTMetadata>,
+    pub collection_config: Box<Account<'info, CollectionConfig>>,
     
     #[account(
-        init,
-        payer = payer,
+        mut,
         associated_token::mint = nft_mint,
-        associated_token::authority = payer,
+        associated_token::authority = staker,
     )]
-    pub nft_token_account: Account<'info, TokenAccount>,
+    pub staker_nft_account: Box<Account<'info, TokenAccount>>,
     
-    pub collection_metadata: Account<'info, NFTMetadata>,
+    #[account(
+        init_if_needed,
+        payer = staker,
+        associated_token::mint = nft_mint,
+        associated_token::authority = stake_authority,
+    )]
+    pub nft_vault: Box<Account<'info, TokenAccount>>,
     
-    #[account(mut)]
-    pub payment_token_mint: Account<'info, Mint>,
+    #[account(
+        seeds = [b"stake_authority"],
+        bump,
+    )]
+    /// CHECK: Este é um PDA usado como autoridade
+    pub stake_authority: UncheckedAccount<'info>,
     
     #[account(
-        mut,
-        associated_token::mint = payment_token_mint,
-        associated_token::authority = payer,
+        init,
+        payer = staker,
+        space = 8 + StakeBoost::INIT_SPACE,
+        seeds = [b"stake_boost", stake_account.key().as_ref()],
+        bump,
     )]
-    pub payer_payment_token_account: Account<'info, TokenAccount>,
+    pub stake_boost: Box<Account<'info, StakeBoost>>,
+    
+    #[account(
+        seeds = [b"config"],
+        bump,
+    )]
+    pub config: Box<Account<'info, ConfigAccount>>,
     
     #[account(
         mut,
-        constraint = config.payment_token_mint != Pubkey::default() 
-            @ ErrorCode::PaymentTokenNotConfigured,
+        seeds = [b"event_sequence"],
+        bump,
     )]
-    pub config: Account<'info, ConfigAccount>,
+    pub event_sequence: Box<Account<'info, EventSequence>>,
     
     pub token_program: Program<'info, Token>,
     pub associated_token_program: Program<'info, AssociatedToken>,
     pub system_program: Program<'info, System>,
-    pub rent: Sysvar<'info, Rent>,
-}
-
-#[derive(Accounts)]
-pub struct ConfigureStaking<'info> {
-    #[account(mut)]
-    pub admin: Signer<'info>,
-
-    #[account(
-        mut,
-        constraint = config.admin == admin.key() @ ErrorCode::Unauthorized,
-    )]
-    pub config: Account<'info, ConfigAccount>,
 }
 
 #[derive(Accounts)]
-#[instruction(amount: u64, period: StakingPeriod)]
-pub struct StakeTokens<'info> {
+pub struct UnlockBoostNft<'info> {
     #[account(mut)]
     pub staker: Signer<'info>,
     
-    // Token a ser usado para staking (o mesmo token de pagamento)
-    pub token_mint: Account<'info, Mint>,
+    // A posição pode ter sido resgatada (e fechada) pelo holder do recibo; o NFT volta ao
+    // dono do bônus
+    /// CHECK: Só define a seed do stake_boost; se ainda existir, é verificada no handler
+    pub stake_account: UncheckedAccount<'info>,
     
     #[account(
         mut,
-        associated_token::mint = token_mint,
-        associated_token::authority = staker,
-        constraint = staker_token_account.amount >= amount @ ErrorCode::InsufficientFunds,
+        close = staker,
+        seeds = [b"stake_boost", stake_account.key().as_ref()],
+        bump,
+        constraint = stake_boost.owner == staker.key() @ ErrorCode::Unauthorized,
     )]
-    pub staker_token_account: Account<'info, TokenAccount>,
+    pub stake_boost: Box<Account<'info, StakeBoost>>,
     
-    // Conta que vai guardar os tokens em stake
     #[account(
-        init_if_needed,
-        payer = staker,
-        space = 8 + 32 + 8 + 8 + 8 + 4 + 1,
-        seeds = [b"stake_account", staker.key().as_ref(), token_mint.key().as_ref()],
-        bump
+        constraint = nft_mint.key() == stake_boost.nft_mint @ ErrorCode::InvalidCollection,
     )]
-    pub stake_account: Account<'info, StakeAccount>,
-
-    // Conta que vai armazenar os tokens em stake
+    pub nft_mint: Box<Account<'info, Mint>>,
+    
     #[account(
         init_if_needed,
         payer = staker,
-        associated_token::mint = token_mint,
+        associated_token::mint = nft_mint,
+        associated_token::authority = staker,
+    )]
+    pub staker_nft_account: Box<Account<'info, TokenAccount>>,
+    
+    #[account(
+        mut,
+        associated_token::mint = nft_mint,
         associated_token::authority = stake_authority,
     )]
-    pub stake_token_account: Account<'info, TokenAccount>,
+    pub nft_vault: Box<Account<'info, TokenAccount>>,
     
-    // Autoridade PDA para controlar os tokens em stake
     #[account(
         seeds = [b"stake_authority"],
         bump,
@@ -855,86 +5227,93 @@ pub struct StakeTokens<'info> {
     /// CHECK: Este é um PDA usado como autoridade
     pub stake_authority: UncheckedAccount<'info>,
     
-    // Configuração do token
-    pub config: Account<'info, ConfigAccount>,
+    #[account(
+        mut,
+        seeds = [b"event_sequence"],
+        bump,
+    )]
+    pub event_sequence: Box<Account<'info, EventSequence>>,
     
     pub token_program: Program<'info, Token>,
     pub associated_token_program: Program<'info, AssociatedToken>,
     pub system_program: Program<'info, System>,
-    pub rent: Sysvar<'info, Rent>,
 }
 
 #[derive(Accounts)]
-pub struct UnstakeTokens<'info> {
-    #[account(mut)]
-    pub staker: Signer<'info>,
+pub struct GetStakeSummary<'info> {
+    /// CHECK: Apenas usado como seed da posição consultada
+    pub staker: UncheckedAccount<'info>,
     
-    // Token a ser usado para staking (o mesmo token de pagamento)
-    pub token_mint: Account<'info, Mint>,
+    /// CHECK: Apenas usado como seed da posição consultada
+    pub token_mint: UncheckedAccount<'info>,
     
+    // Ausente quando o staker nunca fez stake deste token
     #[account(
-        mut,
-        associated_token::mint = token_mint,
-        associated_token::authority = staker,
+        seeds = [b"stake_account", staker.key().as_ref(), token_mint.key().as_ref()],
+        bump,
     )]
-    pub staker_token_account: Account<'info, TokenAccount>,
+    pub stake_account: Option<Account<'info, StakeAccount>>,
+    
+    pub stake_boost: Option<Account<'info, StakeBoost>>,
     
-    // Conta que guarda os tokens em stake
     #[account(
-        mut,
-        associated_token::mint = token_mint,
-        associated_token::authority = stake_authority,
+        seeds = [b"config"],
+        bump,
     )]
-    pub stake_token_account: Account<'info, TokenAccount>,
+    pub config: Account<'info, ConfigAccount>,
+}
+
+#[derive(Accounts)]
+pub struct GetWalletMintCount<'info> {
+    /// CHECK: Apenas usado como seed do registro consultado
+    pub wallet: UncheckedAccount<'info>,
+    
+    pub collection_metadata: Account<'info, NFTMetadata>,
     
-    // Conta de reserva de recompensas
     #[account(
-        mut,
-        associated_token::mint = token_mint,
-        associated_token::authority = stake_authority,
-        constraint = reward_reserve_account.key() == config.reward_reserve @ ErrorCode::InvalidRewardReserve,
+        seeds = [b"collection_config", collection_metadata.key().as_ref()],
+        bump,
     )]
-    pub reward_reserve_account: Account<'info, TokenAccount>,
+    pub collection_config: Account<'info, CollectionConfig>,
     
-    // Autoridade PDA para controlar os tokens em stake
+    // Ausente quando a carteira ainda não mintou nesta coleção
     #[account(
-        seeds = [b"stake_authority"],
+        seeds = [b"mint_record", collection_metadata.key().as_ref(), wallet.key().as_ref()],
         bump,
     )]
-    /// CHECK: Este é um PDA usado como autoridade
-    pub stake_authority: UncheckedAccount<'info>,
-    
-    // Conta que rastreia informações do staking
+    pub mint_record: Option<Account<'info, MintRecord>>,
+}
+
+#[derive(Accounts)]
+pub struct GetConfigSummary<'info> {
     #[account(
-        mut,
-        constraint = stake_account.owner == staker.key() @ ErrorCode::Unauthorized,
+        seeds = [b"config"],
+        bump,
     )]
-    pub stake_account: Account<'info, StakeAccount>,
-    
-    // Configuração do token
     pub config: Account<'info, ConfigAccount>,
-    
-    pub token_program: Program<'info, Token>,
-    pub system_program: Program<'info, System>,
-}
 
-#[account]
-pub struct NFTMetadata {
-    pub authority: Pubkey,
-    pub name: String,
-    pub symbol: String,
-    pub uri: String,
-    pub collection: Option<Pubkey>,
+    #[account(
+        seeds = [b"event_sequence"],
+        bump,
+    )]
+    pub event_sequence: Account<'info, EventSequence>,
 }
 
-#[account]
-pub struct StakeAccount {
-    pub owner: Pubkey,           
-    pub amount: u64,             
-    pub start_time: i64,        
-    pub unlock_time: i64,      
-    pub period: StakingPeriod,   
-    pub claimed: bool,         
+#[derive(Accounts)]
+pub struct GetCollectionInfo<'info> {
+    pub collection_metadata: Account<'info, NFTMetadata>,
+    
+    #[account(
+        seeds = [b"nft_counter"],
+        bump,
+    )]
+    pub nft_counter: Account<'info, NftCounter>,
+    
+    #[account(
+        seeds = [b"collection_config", collection_metadata.key().as_ref()],
+        bump,
+    )]
+    pub collection_config: Account<'info, CollectionConfig>,
 }
 
 #[derive(Accounts)]
@@ -945,8 +5324,17 @@ pub struct EmergencyPause<'info> {
     #[account(
         mut,
         constraint = config.admin == admin.key() @ ErrorCode::Unauthorized,
+        seeds = [b"config"],
+        bump,
     )]
     pub config: Account<'info, ConfigAccount>,
+
+    #[account(
+        mut,
+        seeds = [b"event_sequence"],
+        bump,
+    )]
+    pub event_sequence: Account<'info, EventSequence>,
 }
 
 #[error_code]
@@ -1001,4 +5389,109 @@ pub enum ErrorCode {
     
     #[msg("Conta de reserva de recompensas inválida")]
     InvalidRewardReserve,
+    
+    #[msg("Todos os NFTs da coleção já foram mintados")]
+    CollectionSoldOut,
+    
+    #[msg("O período de mint ainda não começou")]
+    MintNotStarted,
+    
+    #[msg("O período de mint já terminou")]
+    MintEnded,
+    
+    #[msg("Janela de mint inválida")]
+    InvalidMintWindow,
+    
+    #[msg("A allowlist não está ativa para esta coleção")]
+    AllowlistNotActive,
+    
+    #[msg("Prova de Merkle inválida")]
+    InvalidMerkleProof,
+    
+    #[msg("Alocação da allowlist esgotada para esta carteira")]
+    AllowlistAllocationExceeded,
+    
+    #[msg("Limite de mints por carteira atingido")]
+    WalletMintLimitReached,
+    
+    #[msg("O preço atual excede o máximo aceito")]
+    PriceExceedsMaximum,
+    
+    #[msg("Faixas de preço inválidas")]
+    InvalidPriceTiers,
+    
+    #[msg("A divisão do pagamento precisa somar 10000 bps")]
+    InvalidPaymentSplit,
+    
+    #[msg("Conta de tesouraria inválida")]
+    InvalidTreasury,
+    
+    #[msg("Forma de pagamento não aceita por esta coleção")]
+    InvalidPaymentMode,
+    
+    #[msg("O NFT não pertence a esta coleção")]
+    InvalidCollection,
+    
+    #[msg("Pool de resgate inválida")]
+    InvalidRedemptionPool,
+    
+    #[msg("Saldo insuficiente na pool de resgate")]
+    InsufficientRedemptionPool,
+    
+    #[msg("Os metadados estão bloqueados e não podem ser alterados")]
+    MetadataLocked,
+    
+    #[msg("O reveal desta coleção já foi publicado")]
+    RevealAlreadyCommitted,
+    
+    #[msg("O reveal desta coleção ainda não foi publicado")]
+    RevealNotCommitted,
+    
+    #[msg("Os metadados não podem ser alterados antes do reveal da coleção")]
+    RevealPending,
+    
+    #[msg("Quantidade inválida para mint em lote")]
+    InvalidBatchSize,
+    
+    #[msg("Contas do lote ausentes ou fora da ordem esperada")]
+    InvalidBatchAccounts,
+    
+    #[msg("Nome excede o tamanho máximo permitido")]
+    NameTooLong,
+    
+    #[msg("Símbolo excede o tamanho máximo permitido")]
+    SymbolTooLong,
+    
+    #[msg("URI excede o tamanho máximo permitido")]
+    UriTooLong,
+    
+    #[msg("URI precisa usar https://, ipfs:// ou ar://")]
+    InvalidUriScheme,
+    
+    #[msg("Coleção soulbound: use mint_nft_with_payment")]
+    SoulboundCollection,
+    
+    #[msg("Programa de token do NFT inválido para esta coleção")]
+    InvalidNftTokenProgram,
+    
+    #[msg("Não há posição de stake ativa")]
+    NoActiveStake,
+    
+    #[msg("A posição de stake ainda está ativa")]
+    StakeStillActive,
+    
+    #[msg("Recibo de stake inválido ou ausente")]
+    InvalidStakeReceipt,
+    
+    #[msg("Bônus de holder acima do máximo permitido")]
+    InvalidHolderBoost,
+    
+    #[msg("Conta de configuração antiga inválida")]
+    InvalidLegacyConfig,
+
+    #[msg("A conta não está no layout v1 de um tipo do programa")]
+    AccountNotMigratable,
+
+    #[msg("Preço fixo zero sem mint gratuito liberado em set_pricing_curve")]
+    FreeMintNotAllowed,
 }
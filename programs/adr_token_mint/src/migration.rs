@@ -0,0 +1,116 @@
+use anchor_lang::prelude::*;
+use anchor_lang::Discriminator;
+use crate::{
+    AllowlistClaim, CollectionConfig, ConfigAccount, ErrorCode, EventSequence, MintRecord, NFTMetadata,
+    NftCounter, NftStakeAccount, StakeAccount, StakeBoost, StakeReceipt, ACCOUNT_VERSION,
+    DEFAULT_NFT_HOLDER_BOOST_BPS,
+};
+
+// Conversão das contas do layout v1 para o layout atual (ACCOUNT_VERSION)
+//
+// O layout v1 é o discriminator seguido dos campos. O atual insere `version` logo após o
+// discriminator e termina em `reserved`, com a conta alocada em 8 + INIT_SPACE. Como os
+// demais campos não mudaram, a conversão desloca os dados um byte, completa com zeros e
+// regrava a conta pelo tipo, o que também limpa sobras de strings que encolheram.
+//
+// Contas criadas pela versão implantada antes do versionamento são menores: faltam os
+// campos acrescentados no fim do layout depois dela (NFTMetadata sem locked e index,
+// ConfigAccount sem config_version). Esses campos são lidos dos zeros do preenchimento,
+// por isso qualquer tamanho entre o da versão implantada e o v1 completo é aceito.
+
+// Conta com versão e área reservada
+pub trait VersionedAccount: AccountSerialize + AccountDeserialize + Discriminator + Space {
+    // Menor tamanho alocado no layout v1 (o da versão implantada, se o tipo já existia)
+    const V1_MIN_SPACE: usize;
+    // Tamanho alocado para a conta no layout v1 completo
+    const V1_SPACE: usize;
+    // Tamanho alocado no layout atual
+    const SPACE: usize = 8 + Self::INIT_SPACE;
+
+    // Marca a conta com a versão atual, zera a área reservada e aplica os valores
+    // padrão dos campos que não existiam no v1
+    fn reset_version(&mut self);
+}
+
+macro_rules! versioned_account {
+    ($($account:ty => $v1_min_space:expr, $v1_space:expr $(, |$upgraded:ident| $defaults:block)?);* $(;)?) => {
+        $(
+            impl VersionedAccount for $account {
+                const V1_MIN_SPACE: usize = $v1_min_space;
+                const V1_SPACE: usize = $v1_space;
+
+                fn reset_version(&mut self) {
+                    self.version = ACCOUNT_VERSION;
+                    self.reserved.fill(0);
+                    $(
+                        let $upgraded = self;
+                        $defaults
+                    )?
+                }
+            }
+        )*
+    };
+}
+
+// Tamanhos v1 (mínimo, máximo) como eram alocados nos contextos de criação. Os mínimos
+// de NftCounter, ConfigAccount, NFTMetadata e StakeAccount vêm da versão implantada; a
+// metadata da coleção era criada com 8 + 32 + 4 + 50 + 4 + 10 + 4 + 200 = 312 bytes e a dos
+// NFTs com mais 1 + 32 = 345. Os demais tipos não existiam nela.
+//
+// Campos novos lidos dos zeros recebem aqui o mesmo padrão dos caminhos de criação quando
+// zero não é o padrão (nft_holder_boost_bps). O StakeReceipt v1 não tem `staker`, que ocupa
+// a antiga área reservada: fica Pubkey::default() e o unstake reembolsa o holder.
+versioned_account! {
+    NftCounter => 8 + 8, 8 + 8;
+    EventSequence => 8 + 8, 8 + 8;
+    ConfigAccount => 8 + 32 + 32 + 1 + 8 + 8 + 1 + 32, 8 + 32 + 32 + 1 + 8 + 8 + 1 + 32 + 8, |config| {
+        config.nft_holder_boost_bps = DEFAULT_NFT_HOLDER_BOOST_BPS;
+    };
+    CollectionConfig => 715, 715;
+    MintRecord => 8 + 32 + 32 + 8, 8 + 32 + 32 + 8;
+    AllowlistClaim => 8 + 32 + 32 + 8, 8 + 32 + 32 + 8;
+    NftStakeAccount => 8 + 32 + 32 + 32 + 8, 8 + 32 + 32 + 32 + 8;
+    StakeBoost => 8 + 32 + 32 + 32 + 32 + 8, 8 + 32 + 32 + 32 + 32 + 8;
+    StakeReceipt => 8 + 32 + 32 + 8, 8 + 32 + 32 + 8;  // Sem staker (ver acima)
+    NFTMetadata => 8 + 32 + 4 + 50 + 4 + 10 + 4 + 200, 354;
+    StakeAccount => 8 + 32 + 8 + 8 + 8 + 4 + 1, 8 + 32 + 8 + 8 + 8 + 4 + 1;
+}
+
+// Dados de uma conta v1 do tipo T convertidos para o layout atual
+pub fn upgrade_data<T: VersionedAccount>(data: &[u8]) -> Result<Vec<u8>> {
+    require!(
+        (T::V1_MIN_SPACE..=T::V1_SPACE).contains(&data.len())
+            && data.starts_with(T::DISCRIMINATOR),
+        ErrorCode::AccountNotMigratable
+    );
+    let mut shifted = Vec::with_capacity(T::SPACE);
+    shifted.extend_from_slice(&data[..8]);
+    shifted.push(ACCOUNT_VERSION);
+    shifted.extend_from_slice(&data[8..]);
+    shifted.resize(T::SPACE, 0);
+
+    let mut account = T::try_deserialize(&mut shifted.as_slice())?;
+    account.reset_version();
+    let mut upgraded = vec![0u8; T::SPACE];
+    account.try_serialize(&mut upgraded.as_mut_slice())?;
+    Ok(upgraded)
+}
+
+// Converte os dados de qualquer conta v1 do programa, escolhendo o tipo pelo discriminator
+pub fn upgrade_account_data(data: &[u8]) -> Result<Vec<u8>> {
+    let discriminator = data.get(..8).ok_or(ErrorCode::AccountNotMigratable)?;
+    match discriminator {
+        d if d == NftCounter::DISCRIMINATOR => upgrade_data::<NftCounter>(data),
+        d if d == EventSequence::DISCRIMINATOR => upgrade_data::<EventSequence>(data),
+        d if d == ConfigAccount::DISCRIMINATOR => upgrade_data::<ConfigAccount>(data),
+        d if d == CollectionConfig::DISCRIMINATOR => upgrade_data::<CollectionConfig>(data),
+        d if d == MintRecord::DISCRIMINATOR => upgrade_data::<MintRecord>(data),
+        d if d == AllowlistClaim::DISCRIMINATOR => upgrade_data::<AllowlistClaim>(data),
+        d if d == NftStakeAccount::DISCRIMINATOR => upgrade_data::<NftStakeAccount>(data),
+        d if d == StakeBoost::DISCRIMINATOR => upgrade_data::<StakeBoost>(data),
+        d if d == StakeReceipt::DISCRIMINATOR => upgrade_data::<StakeReceipt>(data),
+        d if d == NFTMetadata::DISCRIMINATOR => upgrade_data::<NFTMetadata>(data),
+        d if d == StakeAccount::DISCRIMINATOR => upgrade_data::<StakeAccount>(data),
+        _ => err!(ErrorCode::AccountNotMigratable),
+    }
+}
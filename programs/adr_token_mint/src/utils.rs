@@ -1,18 +1,41 @@
 use anchor_lang::prelude::*;
-use crate::NftCounter;
+use anchor_lang::solana_program::keccak;
+use crate::{ErrorCode, NftBumps, MAX_NAME_LENGTH, MAX_SYMBOL_LENGTH, MAX_URI_LENGTH};
+
+// Esquemas aceitos para URIs de metadados
+pub const ALLOWED_URI_SCHEMES: [&str; 3] = ["https://", "ipfs://", "ar://"];
 
 // Constantes para seeds de PDAs
 pub const NFT_COUNTER_SEED: &[u8] = b"nft_counter";
+pub const CONFIG_SEED: &[u8] = b"config";
+pub const EVENT_SEQUENCE_SEED: &[u8] = b"event_sequence";
 pub const NFT_MINT_SEED: &[u8] = b"nft_mint";
 pub const NFT_METADATA_SEED: &[u8] = b"nft_metadata";
 pub const STAKE_ACCOUNT_SEED: &[u8] = b"stake_account";
 pub const STAKE_AUTHORITY_SEED: &[u8] = b"stake_authority";
+pub const COLLECTION_CONFIG_SEED: &[u8] = b"collection_config";
+pub const ALLOWLIST_CLAIM_SEED: &[u8] = b"allowlist_claim";
+pub const MINT_RECORD_SEED: &[u8] = b"mint_record";
+pub const SOL_TREASURY_SEED: &[u8] = b"sol_treasury";
+pub const REDEMPTION_AUTHORITY_SEED: &[u8] = b"redemption_authority";
+pub const NFT_REWARD_AUTHORITY_SEED: &[u8] = b"nft_reward_authority";
+pub const NFT_STAKE_SEED: &[u8] = b"nft_stake";
+pub const STAKE_BOOST_SEED: &[u8] = b"stake_boost";
+pub const STAKE_RECEIPT_SEED: &[u8] = b"stake_receipt";
 
 // Funções para encontrar PDAs
 pub fn find_nft_counter_pda() -> (Pubkey, u8) {
     Pubkey::find_program_address(&[NFT_COUNTER_SEED], &crate::ID)
 }
 
+pub fn find_config_pda() -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[CONFIG_SEED], &crate::ID)
+}
+
+pub fn find_event_sequence_pda() -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[EVENT_SEQUENCE_SEED], &crate::ID)
+}
+
 pub fn find_nft_mint_pda(collection: &Pubkey, count: u64) -> (Pubkey, u8) {
     Pubkey::find_program_address(
         &[
@@ -34,6 +57,17 @@ pub fn find_nft_metadata_pda(mint: &Pubkey) -> (Pubkey, u8) {
     )
 }
 
+// Endereços de mint e metadados do NFT `index` de um mint_batch, exigindo os bumps canônicos
+pub fn batch_nft_addresses(collection: &Pubkey, index: u64, bumps: &NftBumps) -> Result<(Pubkey, Pubkey)> {
+    let (nft_mint, nft_mint_bump) = find_nft_mint_pda(collection, index);
+    let (nft_metadata, nft_metadata_bump) = find_nft_metadata_pda(&nft_mint);
+    require!(
+        bumps.nft_mint == nft_mint_bump && bumps.nft_metadata == nft_metadata_bump,
+        ErrorCode::InvalidBatchAccounts
+    );
+    Ok((nft_mint, nft_metadata))
+}
+
 pub fn find_stake_account_pda(staker: &Pubkey, token_mint: &Pubkey) -> (Pubkey, u8) {
     Pubkey::find_program_address(
         &[
@@ -49,18 +83,156 @@ pub fn find_stake_authority_pda() -> (Pubkey, u8) {
     Pubkey::find_program_address(&[STAKE_AUTHORITY_SEED], &crate::ID)
 }
 
-// Funções de utilidade para o frontend
+pub fn find_stake_boost_pda(stake_account: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            STAKE_BOOST_SEED,
+            stake_account.as_ref(),
+        ],
+        &crate::ID,
+    )
+}
+
+pub fn find_stake_receipt_pda(stake_account: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            STAKE_RECEIPT_SEED,
+            stake_account.as_ref(),
+        ],
+        &crate::ID,
+    )
+}
+
+pub fn find_nft_stake_pda(nft_mint: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            NFT_STAKE_SEED,
+            nft_mint.as_ref(),
+        ],
+        &crate::ID,
+    )
+}
+
+pub fn find_collection_config_pda(collection: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            COLLECTION_CONFIG_SEED,
+            collection.as_ref(),
+        ],
+        &crate::ID,
+    )
+}
+
+pub fn find_mint_record_pda(collection: &Pubkey, wallet: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            MINT_RECORD_SEED,
+            collection.as_ref(),
+            wallet.as_ref(),
+        ],
+        &crate::ID,
+    )
+}
+
+pub fn find_sol_treasury_pda(collection: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            SOL_TREASURY_SEED,
+            collection.as_ref(),
+        ],
+        &crate::ID,
+    )
+}
+
+pub fn find_redemption_authority_pda(collection: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            REDEMPTION_AUTHORITY_SEED,
+            collection.as_ref(),
+        ],
+        &crate::ID,
+    )
+}
+
+pub fn find_nft_reward_authority_pda(collection: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            NFT_REWARD_AUTHORITY_SEED,
+            collection.as_ref(),
+        ],
+        &crate::ID,
+    )
+}
+
+pub fn find_allowlist_claim_pda(collection: &Pubkey, wallet: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            ALLOWLIST_CLAIM_SEED,
+            collection.as_ref(),
+            wallet.as_ref(),
+        ],
+        &crate::ID,
+    )
+}
+
+// Folha da allowlist: keccak(wallet || allocation em little-endian)
+pub fn allowlist_leaf(wallet: &Pubkey, allocation: u64) -> [u8; 32] {
+    keccak::hashv(&[wallet.as_ref(), &allocation.to_le_bytes()]).to_bytes()
+}
+
+// Verifica uma prova de Merkle com pares ordenados (mesmo formato do merkletreejs com sortPairs)
+pub fn verify_merkle_proof(proof: &[[u8; 32]], root: [u8; 32], leaf: [u8; 32]) -> bool {
+    let computed = proof.iter().fold(leaf, |node, sibling| {
+        if node <= *sibling {
+            keccak::hashv(&[&node, sibling]).to_bytes()
+        } else {
+            keccak::hashv(&[sibling, &node]).to_bytes()
+        }
+    });
+    computed == root
+}
+
+// Validações dos campos de texto dos metadados (tamanho em bytes, como serializado)
+pub fn validate_name(name: &str) -> Result<()> {
+    require!(!name.is_empty(), ErrorCode::InvalidInput);
+    require!(name.len() <= MAX_NAME_LENGTH, ErrorCode::NameTooLong);
+    Ok(())
+}
+
+pub fn validate_symbol(symbol: &str) -> Result<()> {
+    require!(!symbol.is_empty(), ErrorCode::InvalidInput);
+    require!(symbol.len() <= MAX_SYMBOL_LENGTH, ErrorCode::SymbolTooLong);
+    Ok(())
+}
+
+pub fn validate_uri(uri: &str) -> Result<()> {
+    require!(!uri.is_empty(), ErrorCode::InvalidInput);
+    require!(uri.len() <= MAX_URI_LENGTH, ErrorCode::UriTooLong);
+    require!(
+        ALLOWED_URI_SCHEMES.iter().any(|scheme| uri.starts_with(scheme)),
+        ErrorCode::InvalidUriScheme
+    );
+    Ok(())
+}
+
+// Cálculo de recompensa compartilhado por unstake_tokens e get_stake_summary
 pub fn calculate_reward(
     staked_amount: u64,
     base_rate: u64,
     multiplier: u64,
+    boost_bps: u64,
 ) -> Result<u64> {
-    // Cálculo: staked_amount * (base_rate / 10000) * (multiplier / 100)
+    // Cálculo: staked_amount * (base_rate / 10000) * (multiplier / 100) * (1 + boost_bps / 10000)
     let reward_amount = staked_amount
-        .checked_mul(base_rate).ok_or(ProgramError::ArithmeticOverflow)?
-        .checked_div(10000).ok_or(ProgramError::ArithmeticOverflow)?
-        .checked_mul(multiplier).ok_or(ProgramError::ArithmeticOverflow)?
-        .checked_div(100).ok_or(ProgramError::ArithmeticOverflow)?;
+        .checked_mul(base_rate).ok_or(ErrorCode::MathOverflow)?
+        .checked_div(10000).ok_or(ErrorCode::MathOverflow)?
+        .checked_mul(multiplier).ok_or(ErrorCode::MathOverflow)?
+        .checked_div(100).ok_or(ErrorCode::MathOverflow)?;
     
-    Ok(reward_amount)
-} 
\ No newline at end of file
+    let boosted_amount = reward_amount
+        .checked_mul(10000u64.checked_add(boost_bps).ok_or(ErrorCode::MathOverflow)?)
+        .ok_or(ErrorCode::MathOverflow)?
+        .checked_div(10000).ok_or(ErrorCode::MathOverflow)?;
+    
+    Ok(boosted_amount)
+}
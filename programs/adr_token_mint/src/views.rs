@@ -1,6 +1,6 @@
 use anchor_lang::prelude::*;
-use crate::{StakeAccount, ConfigAccount, StakingPeriod, NftCounter, NFTMetadata};
-use crate::utils::{find_stake_account_pda, calculate_reward};
+use crate::{StakeAccount, StakeBoost, ConfigAccount, EventSequence, StakingPeriod, NftCounter, NFTMetadata, CollectionConfig, MintRecord};
+use crate::utils::calculate_reward;
 
 // Estrutura para informações resumidas de staking
 #[derive(AnchorSerialize, AnchorDeserialize)]
@@ -24,18 +24,36 @@ pub struct ConfigSummary {
     pub staking_enabled: bool,
     pub staking_reward_rate: u64,
     pub max_stake_amount: u64,
+    pub nft_holder_boost_bps: u64,
+    pub total_staked: u64,   // Principal das posições abertas de stake_tokens
+    pub active_stakes: u64,
     pub emergency_paused: bool,
+    pub config_version: u64,
+    pub event_seq: u64,  // Número do último evento emitido pelo programa
+}
+
+// Estrutura para informações resumidas da coleção de NFTs
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct CollectionInfo {
+    pub name: String,
+    pub symbol: String,
+    pub uri: String,
+    pub count: u64,        // Contador global de NFTs do programa
+    pub minted: u64,       // NFTs mintados nesta coleção
+    pub live_supply: u64,
+    pub max_supply: u64,
+    pub mint_start: i64,
+    pub mint_end: i64,
 }
 
 // Função para obter informações de stake de um usuário
 pub fn get_stake_summary(
-    staker: &Pubkey,
-    token_mint: &Pubkey,
-    stake_account: Option<Account<StakeAccount>>,
-    config: Account<ConfigAccount>,
+    stake_account: Option<&StakeAccount>,
+    stake_boost: Option<&StakeBoost>,
+    config: &ConfigAccount,
 ) -> Result<StakingSummary> {
     // Se não temos uma conta de stake válida, retornamos um sumário vazio
-    if stake_account.is_none() {
+    let Some(stake) = stake_account else {
         return Ok(StakingSummary {
             is_staking: false,
             amount: 0,
@@ -47,18 +65,21 @@ pub fn get_stake_summary(
             estimated_reward: 0,
             time_remaining: 0,
         });
-    }
+    };
     
-    let stake = stake_account.unwrap();
     let clock = Clock::get()?;
     let current_time = clock.unix_timestamp;
     
-    // Calcular recompensa estimada
+    // Calcular recompensa estimada, incluindo o bônus de NFT travado
     let multiplier = stake.period.reward_multiplier();
+    let boost_bps = stake_boost
+        .map(|boost| boost.boost_bps(stake, config.nft_holder_boost_bps))
+        .unwrap_or(0);
     let estimated_reward = calculate_reward(
         stake.amount,
         config.staking_reward_rate,
         multiplier,
+        boost_bps,
     )?;
     
     // Calcular tempo restante
@@ -82,26 +103,96 @@ pub fn get_stake_summary(
 }
 
 // Função para obter informações de configuração do programa
-pub fn get_config_summary(config: Account<ConfigAccount>) -> ConfigSummary {
+pub fn get_config_summary(config: &ConfigAccount, event_sequence: &EventSequence) -> ConfigSummary {
     ConfigSummary {
         payment_token_mint: config.payment_token_mint,
         admin: config.admin,
         staking_enabled: config.staking_enabled,
         staking_reward_rate: config.staking_reward_rate,
         max_stake_amount: config.max_stake_amount,
+        nft_holder_boost_bps: config.nft_holder_boost_bps,
+        total_staked: config.total_staked,
+        active_stakes: config.active_stakes,
         emergency_paused: config.emergency_paused,
+        config_version: config.config_version,
+        event_seq: event_sequence.seq,
     }
 }
 
 // Função para obter informações sobre a coleção de NFTs
 pub fn get_collection_info(
-    collection_metadata: Account<NFTMetadata>,
-    nft_counter: Account<NftCounter>,
-) -> (String, String, String, u64) {
-    (
-        collection_metadata.name.clone(),
-        collection_metadata.symbol.clone(),
-        collection_metadata.uri.clone(),
-        nft_counter.count,
-    )
-} 
\ No newline at end of file
+    collection_metadata: &NFTMetadata,
+    nft_counter: &NftCounter,
+    collection_config: &CollectionConfig,
+) -> CollectionInfo {
+    CollectionInfo {
+        name: collection_metadata.name.clone(),
+        symbol: collection_metadata.symbol.clone(),
+        uri: collection_metadata.uri.clone(),
+        count: nft_counter.count,
+        minted: collection_config.minted,
+        live_supply: collection_config.live_supply,
+        max_supply: collection_config.max_supply,
+        mint_start: collection_config.mint_start,
+        mint_end: collection_config.mint_end,
+    }
+}
+
+// Cotação de preços da curva da coleção
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct MintQuote {
+    pub current_price: u64,
+    pub next_prices: Vec<u64>,  // Preços dos próximos mints a partir do atual
+}
+
+// Número máximo de preços futuros retornados por get_mint_quote
+pub const MAX_PRICE_QUOTES: u64 = 20;
+
+// Função para cotar o preço atual e dos próximos `count` mints
+pub fn get_mint_quote(
+    collection_config: &CollectionConfig,
+    nft_counter: &NftCounter,
+    count: u64,
+) -> Result<MintQuote> {
+    let current = nft_counter.count;
+    let current_price = collection_config.price_at(current)?;
+    
+    // Não cotar além do supply máximo da coleção
+    let mut quotes = count.min(MAX_PRICE_QUOTES);
+    if collection_config.max_supply > 0 {
+        quotes = quotes.min(collection_config.max_supply.saturating_sub(collection_config.minted));
+    }
+    
+    let next_prices = (0..quotes)
+        .map(|offset| {
+            let index = current.checked_add(offset).ok_or(crate::ErrorCode::MathOverflow)?;
+            collection_config.price_at(index)
+        })
+        .collect::<Result<Vec<u64>>>()?;
+    
+    Ok(MintQuote {
+        current_price,
+        next_prices,
+    })
+}
+
+// Mints públicos de uma carteira na coleção
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct WalletMintCount {
+    pub count: u64,
+    pub remaining: u64,  // u64::MAX quando não há limite por carteira
+}
+
+// Função para obter quantos NFTs uma carteira já mintou no mint público
+pub fn get_wallet_mint_count(
+    mint_record: Option<&MintRecord>,
+    collection_config: &CollectionConfig,
+) -> WalletMintCount {
+    let count = mint_record.map(|record| record.count).unwrap_or(0);
+    let remaining = if collection_config.max_per_wallet == 0 {
+        u64::MAX
+    } else {
+        collection_config.max_per_wallet.saturating_sub(count)
+    };
+    WalletMintCount { count, remaining }
+}
@@ -0,0 +1,49 @@
+use adr_token_mint::utils::{validate_name, validate_symbol, validate_uri, ALLOWED_URI_SCHEMES};
+use adr_token_mint::{ErrorCode, MAX_NAME_LENGTH, MAX_SYMBOL_LENGTH, MAX_URI_LENGTH};
+use anchor_lang::error::Error;
+
+fn error_code(result: anchor_lang::Result<()>) -> u32 {
+    match result.unwrap_err() {
+        Error::AnchorError(error) => error.error_code_number,
+        Error::ProgramError(error) => panic!("erro inesperado: {}", error),
+    }
+}
+
+fn code(error: ErrorCode) -> u32 {
+    match Error::from(error) {
+        Error::AnchorError(error) => error.error_code_number,
+        Error::ProgramError(_) => unreachable!(),
+    }
+}
+
+#[test]
+fn name_and_symbol_accept_the_maximum_length_and_reject_one_more_byte() {
+    assert!(validate_name(&"n".repeat(MAX_NAME_LENGTH)).is_ok());
+    assert_eq!(error_code(validate_name(&"n".repeat(MAX_NAME_LENGTH + 1))), code(ErrorCode::NameTooLong));
+
+    assert!(validate_symbol(&"S".repeat(MAX_SYMBOL_LENGTH)).is_ok());
+    assert_eq!(
+        error_code(validate_symbol(&"S".repeat(MAX_SYMBOL_LENGTH + 1))),
+        code(ErrorCode::SymbolTooLong)
+    );
+
+    // O limite é em bytes (o espaço da conta), não em caracteres
+    assert_eq!(
+        error_code(validate_name(&"ã".repeat(MAX_NAME_LENGTH / 2 + 1))),
+        code(ErrorCode::NameTooLong)
+    );
+    assert_eq!(error_code(validate_name("")), code(ErrorCode::InvalidInput));
+}
+
+#[test]
+fn uri_is_limited_in_length_and_scheme() {
+    for scheme in ALLOWED_URI_SCHEMES {
+        let uri = format!("{}{}", scheme, "a".repeat(MAX_URI_LENGTH - scheme.len()));
+        assert!(validate_uri(&uri).is_ok(), "{}", scheme);
+        assert_eq!(error_code(validate_uri(&format!("{}a", uri))), code(ErrorCode::UriTooLong));
+    }
+
+    for uri in ["http://example.com/nft.json", "javascript:alert(1)", "example.com/nft.json", "HTTPS://example.com"] {
+        assert_eq!(error_code(validate_uri(uri)), code(ErrorCode::InvalidUriScheme), "{}", uri);
+    }
+}
@@ -0,0 +1,242 @@
+use adr_token_mint::migration::{upgrade_account_data, upgrade_data, VersionedAccount};
+use adr_token_mint::{
+    ConfigAccount, EventSequence, NFTMetadata, StakeAccount, StakeReceipt, StakingPeriod, ACCOUNT_VERSION,
+    DEFAULT_NFT_HOLDER_BOOST_BPS,
+};
+use anchor_lang::prelude::*;
+
+// Layouts v1, como gravados antes de `version` e `reserved`
+#[derive(AnchorSerialize)]
+struct StakeAccountV1 {
+    owner: Pubkey,
+    amount: u64,
+    start_time: i64,
+    unlock_time: i64,
+    period: StakingPeriod,
+    claimed: bool,
+}
+
+#[derive(AnchorSerialize)]
+struct ConfigAccountV1 {
+    payment_token_mint: Pubkey,
+    admin: Pubkey,
+    staking_enabled: bool,
+    staking_reward_rate: u64,
+    max_stake_amount: u64,
+    emergency_paused: bool,
+    reward_reserve: Pubkey,
+    config_version: u64,
+}
+
+#[derive(AnchorSerialize)]
+struct NFTMetadataV1 {
+    authority: Pubkey,
+    name: String,
+    symbol: String,
+    uri: String,
+    collection: Option<Pubkey>,
+    locked: bool,
+    index: u64,
+}
+
+// NFTMetadata como criada pela versão implantada antes do versionamento (sem locked e index)
+#[derive(AnchorSerialize)]
+struct NFTMetadataBaseline {
+    authority: Pubkey,
+    name: String,
+    symbol: String,
+    uri: String,
+    collection: Option<Pubkey>,
+}
+
+#[derive(AnchorSerialize)]
+struct StakeReceiptV1 {
+    stake_account: Pubkey,
+    receipt_mint: Pubkey,
+    issued_at: i64,
+}
+
+// Conta v1 como está na rede: discriminator, campos e zeros até o espaço alocado
+fn v1_fixture<T: VersionedAccount>(fields: &impl AnchorSerialize) -> Vec<u8> {
+    sized_fixture::<T>(fields, T::V1_SPACE)
+}
+
+fn sized_fixture<T: VersionedAccount>(fields: &impl AnchorSerialize, space: usize) -> Vec<u8> {
+    let mut data = T::DISCRIMINATOR.to_vec();
+    fields.serialize(&mut data).unwrap();
+    assert!(data.len() <= space);
+    data.resize(space, 0);
+    data
+}
+
+#[test]
+fn stake_account_v1_is_migrated_with_the_same_values() {
+    let owner = Pubkey::new_unique();
+    let v1 = v1_fixture::<StakeAccount>(&StakeAccountV1 {
+        owner,
+        amount: 5_000,
+        start_time: 100,
+        unlock_time: 700,
+        period: StakingPeriod::Minutes10,
+        claimed: false,
+    });
+    // O layout atual não lê uma conta v1 antes da migração
+    assert!(StakeAccount::try_deserialize(&mut v1.as_slice()).is_err());
+
+    let upgraded = upgrade_data::<StakeAccount>(&v1).unwrap();
+    assert_eq!(upgraded.len(), StakeAccount::SPACE);
+    let account = StakeAccount::try_deserialize(&mut upgraded.as_slice()).unwrap();
+    assert_eq!(account.version, ACCOUNT_VERSION);
+    assert_eq!(account.owner, owner);
+    assert_eq!(account.amount, 5_000);
+    assert_eq!(account.start_time, 100);
+    assert_eq!(account.unlock_time, 700);
+    assert!(account.period == StakingPeriod::Minutes10);
+    assert!(!account.claimed);
+    assert_eq!(account.reserved, [0; 32]);
+}
+
+#[test]
+fn config_v1_is_migrated_by_discriminator() {
+    let admin = Pubkey::new_unique();
+    let reward_reserve = Pubkey::new_unique();
+    let v1 = v1_fixture::<ConfigAccount>(&ConfigAccountV1 {
+        payment_token_mint: Pubkey::new_unique(),
+        admin,
+        staking_enabled: true,
+        staking_reward_rate: 800,
+        max_stake_amount: 1_000_000,
+        emergency_paused: false,
+        reward_reserve,
+        config_version: 12,
+    });
+
+    let upgraded = upgrade_account_data(&v1).unwrap();
+    let config = ConfigAccount::try_deserialize(&mut upgraded.as_slice()).unwrap();
+    assert_eq!(config.version, ACCOUNT_VERSION);
+    assert_eq!(config.admin, admin);
+    assert!(config.staking_enabled);
+    assert_eq!(config.staking_reward_rate, 800);
+    assert_eq!(config.reward_reserve, reward_reserve);
+    assert_eq!(config.config_version, 12);
+    // O bônus de holder não existia no v1 e recebe o mesmo padrão das contas novas
+    assert_eq!(config.nft_holder_boost_bps, DEFAULT_NFT_HOLDER_BOOST_BPS);
+    // Estatísticas de stake também começam zeradas
+    assert_eq!(config.total_staked, 0);
+    assert_eq!(config.active_stakes, 0);
+    assert_eq!(config.reserved, [0; 40]);
+}
+
+#[test]
+fn metadata_v1_leftovers_do_not_leak_into_reserved() {
+    let fields = NFTMetadataV1 {
+        authority: Pubkey::new_unique(),
+        name: "ADR #7".to_string(),
+        symbol: "ADR".to_string(),
+        uri: "https://example.com/7.json".to_string(),
+        collection: Some(Pubkey::new_unique()),
+        locked: true,
+        index: 7,
+    };
+    let mut v1 = v1_fixture::<NFTMetadata>(&fields);
+    // Sobras de uma URI maior, regravada depois com um texto menor, logo após os campos
+    let used = 8 + fields.try_to_vec().unwrap().len();
+    v1[used..].fill(0xAB);
+
+    let upgraded = upgrade_account_data(&v1).unwrap();
+    assert_eq!(upgraded.len(), NFTMetadata::SPACE);
+    let metadata = NFTMetadata::try_deserialize(&mut upgraded.as_slice()).unwrap();
+    assert_eq!(metadata.version, ACCOUNT_VERSION);
+    assert_eq!(metadata.name, "ADR #7");
+    assert_eq!(metadata.uri, "https://example.com/7.json");
+    assert!(metadata.locked);
+    assert_eq!(metadata.index, 7);
+    assert_eq!(metadata.reserved, [0; 32]);
+    assert!(!upgraded.contains(&0xAB));
+}
+
+#[test]
+fn migrated_and_unknown_accounts_are_rejected() {
+    let v1 = v1_fixture::<EventSequence>(&42u64);
+    let upgraded = upgrade_account_data(&v1).unwrap();
+    let sequence = EventSequence::try_deserialize(&mut upgraded.as_slice()).unwrap();
+    assert_eq!(sequence.seq, 42);
+
+    // Contas já convertidas, de tipo desconhecido ou truncadas não são v1
+    assert!(upgrade_account_data(&upgraded).is_err());
+    assert!(upgrade_account_data(&[7u8; 16]).is_err());
+    assert!(upgrade_account_data(&v1[..4]).is_err());
+}
+
+#[test]
+fn baseline_nft_and_collection_metadata_are_migrated() {
+    let authority = Pubkey::new_unique();
+    let collection = Pubkey::new_unique();
+    // NFT mintado pela versão implantada: 8 + 32 + 4 + 50 + 4 + 10 + 4 + 200 + 1 + 32 bytes
+    let nft = sized_fixture::<NFTMetadata>(
+        &NFTMetadataBaseline {
+            authority,
+            name: "ADR #3".to_string(),
+            symbol: "ADR".to_string(),
+            uri: "https://example.com/3.json".to_string(),
+            collection: Some(collection),
+        },
+        345,
+    );
+    let upgraded = upgrade_account_data(&nft).unwrap();
+    assert_eq!(upgraded.len(), NFTMetadata::SPACE);
+    let metadata = NFTMetadata::try_deserialize(&mut upgraded.as_slice()).unwrap();
+    assert_eq!(metadata.version, ACCOUNT_VERSION);
+    assert_eq!(metadata.authority, authority);
+    assert_eq!(metadata.uri, "https://example.com/3.json");
+    assert_eq!(metadata.collection, Some(collection));
+    assert!(!metadata.locked);
+    assert_eq!(metadata.index, 0);
+
+    // Metadata da coleção, criada sem espaço para `collection`: 312 bytes
+    let collection_metadata = sized_fixture::<NFTMetadata>(
+        &NFTMetadataBaseline {
+            authority,
+            name: "ADR Collection".to_string(),
+            symbol: "ADR".to_string(),
+            uri: "https://example.com/collection.json".to_string(),
+            collection: None,
+        },
+        312,
+    );
+    let upgraded = upgrade_account_data(&collection_metadata).unwrap();
+    let metadata = NFTMetadata::try_deserialize(&mut upgraded.as_slice()).unwrap();
+    assert_eq!(metadata.name, "ADR Collection");
+    assert_eq!(metadata.collection, None);
+    assert!(!metadata.locked);
+
+    // Menor que a versão implantada não é um layout conhecido
+    assert!(upgrade_account_data(&collection_metadata[..311]).is_err());
+}
+
+#[test]
+fn stake_receipt_v1_refunds_the_holder_on_unstake() {
+    let stake_account = Pubkey::new_unique();
+    let receipt_mint = Pubkey::new_unique();
+    let v1 = v1_fixture::<StakeReceipt>(&StakeReceiptV1 { stake_account, receipt_mint, issued_at: 42 });
+
+    let upgraded = upgrade_account_data(&v1).unwrap();
+    // `staker` ocupa a antiga área reservada: o tamanho da conta não muda
+    assert_eq!(upgraded.len(), StakeReceipt::SPACE);
+    assert_eq!(StakeReceipt::SPACE, 8 + 1 + 32 + 32 + 8 + 32);
+    let receipt = StakeReceipt::try_deserialize(&mut upgraded.as_slice()).unwrap();
+    assert_eq!(receipt.version, ACCOUNT_VERSION);
+    assert_eq!(receipt.stake_account, stake_account);
+    assert_eq!(receipt.receipt_mint, receipt_mint);
+    assert_eq!(receipt.issued_at, 42);
+
+    // Sem staker gravado, o unstake devolve o aluguel ao holder que resgata
+    let holder = Pubkey::new_unique();
+    assert_eq!(receipt.staker, Pubkey::default());
+    assert_eq!(receipt.rent_recipient(holder), holder);
+
+    // Recibos emitidos depois do versionamento continuam reembolsando quem abriu a posição
+    let staker = Pubkey::new_unique();
+    let receipt = StakeReceipt { staker, ..receipt };
+    assert_eq!(receipt.rent_recipient(holder), staker);
+}
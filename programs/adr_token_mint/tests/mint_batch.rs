@@ -0,0 +1,50 @@
+use adr_token_mint::utils::{batch_nft_addresses, find_nft_metadata_pda, find_nft_mint_pda};
+use adr_token_mint::{ErrorCode, NftBumps};
+use anchor_lang::prelude::*;
+
+// Maior bump abaixo do canônico que ainda gera um endereço fora da curva
+fn non_canonical_bump(seeds: &[&[u8]], canonical: u8) -> (Pubkey, u8) {
+    (0..canonical)
+        .rev()
+        .find_map(|bump| {
+            Pubkey::create_program_address(&[seeds, &[&[bump][..]]].concat(), &adr_token_mint::ID)
+                .ok()
+                .map(|address| (address, bump))
+        })
+        .unwrap()
+}
+
+#[test]
+fn canonical_bumps_resolve_the_batch_addresses() {
+    let collection = Pubkey::new_unique();
+    let (nft_mint, nft_mint_bump) = find_nft_mint_pda(&collection, 7);
+    let (nft_metadata, nft_metadata_bump) = find_nft_metadata_pda(&nft_mint);
+    let bumps = NftBumps { nft_mint: nft_mint_bump, nft_metadata: nft_metadata_bump };
+
+    assert_eq!(batch_nft_addresses(&collection, 7, &bumps).unwrap(), (nft_mint, nft_metadata));
+}
+
+#[test]
+fn non_canonical_bumps_are_rejected() {
+    let collection = Pubkey::new_unique();
+    let index = 7u64.to_le_bytes();
+    let (nft_mint, nft_mint_bump) = find_nft_mint_pda(&collection, 7);
+    let (_, nft_metadata_bump) = find_nft_metadata_pda(&nft_mint);
+
+    // Endereço válido de PDA, mas que BurnNft, StakeNft e LockBoostNft não conseguem derivar
+    let (alternate_mint, alternate_bump) =
+        non_canonical_bump(&[b"nft_mint", collection.as_ref(), index.as_ref()], nft_mint_bump);
+    assert_ne!(alternate_mint, nft_mint);
+    let bumps = NftBumps { nft_mint: alternate_bump, nft_metadata: nft_metadata_bump };
+    assert_eq!(
+        batch_nft_addresses(&collection, 7, &bumps).unwrap_err(),
+        ErrorCode::InvalidBatchAccounts.into()
+    );
+
+    let (_, alternate_bump) = non_canonical_bump(&[b"nft_metadata", nft_mint.as_ref()], nft_metadata_bump);
+    let bumps = NftBumps { nft_mint: nft_mint_bump, nft_metadata: alternate_bump };
+    assert_eq!(
+        batch_nft_addresses(&collection, 7, &bumps).unwrap_err(),
+        ErrorCode::InvalidBatchAccounts.into()
+    );
+}
@@ -0,0 +1,47 @@
+use adr_token_mint::CollectionConfig;
+use anchor_lang::prelude::*;
+
+// CollectionConfig zerada, como recém-criada antes de qualquer configuração
+fn collection_config(burn_bps: u16, treasury_bps: u16, reserve_bps: u16) -> CollectionConfig {
+    let data = vec![0u8; CollectionConfig::INIT_SPACE];
+    let mut config = CollectionConfig::deserialize(&mut &data[..]).unwrap();
+    config.burn_bps = burn_bps;
+    config.treasury_bps = treasury_bps;
+    config.reserve_bps = reserve_bps;
+    config
+}
+
+#[test]
+fn split_sends_each_leg_its_configured_share() {
+    let config = collection_config(5000, 3000, 2000);
+    assert_eq!(config.split_payment(1_000).unwrap(), (500, 300, 200));
+}
+
+#[test]
+fn split_rounding_remainder_goes_to_the_burn() {
+    // 1/3 de 10 para cada parte: sobra 1 depois dos arredondamentos
+    let config = collection_config(3333, 3333, 3334);
+    let (burn, treasury, reserve) = config.split_payment(10).unwrap();
+    assert_eq!((treasury, reserve), (3, 3));
+    assert_eq!(burn, 4);
+    assert_eq!(burn + treasury + reserve, 10);
+}
+
+#[test]
+fn split_without_burn_share_only_burns_the_remainder() {
+    let config = collection_config(0, 5000, 5000);
+    assert_eq!(config.split_payment(1_001).unwrap(), (1, 500, 500));
+}
+
+#[test]
+fn split_rejects_shares_above_the_total() {
+    // set_payment_split impede essa soma; contas inconsistentes falham em vez de cobrar a mais
+    let config = collection_config(10000, 5000, 0);
+    assert!(config.split_payment(1_000).is_err());
+}
+
+#[test]
+fn split_handles_the_largest_payment() {
+    let config = collection_config(0, 10000, 0);
+    assert_eq!(config.split_payment(u64::MAX).unwrap(), (0, u64::MAX, 0));
+}
@@ -0,0 +1,113 @@
+use adr_token_mint::views::{get_mint_quote, MAX_PRICE_QUOTES};
+use adr_token_mint::{CollectionConfig, ErrorCode, NftCounter, PriceTier, PricingMode, ACCOUNT_VERSION};
+use anchor_lang::prelude::*;
+
+// CollectionConfig zerada com a curva informada
+fn collection_config(pricing_mode: PricingMode, mint_price: u64, price_increment: u64) -> CollectionConfig {
+    let data = vec![0u8; CollectionConfig::INIT_SPACE];
+    let mut config = CollectionConfig::deserialize(&mut &data[..]).unwrap();
+    config.pricing_mode = pricing_mode;
+    config.mint_price = mint_price;
+    config.price_increment = price_increment;
+    config
+}
+
+fn nft_counter(count: u64) -> NftCounter {
+    NftCounter {
+        version: ACCOUNT_VERSION,
+        count,
+        reserved: [0; 16],
+    }
+}
+
+#[test]
+fn fixed_curve_always_charges_the_mint_price() {
+    let config = collection_config(PricingMode::Fixed, 1_000, 500);
+    assert_eq!(config.price_at(0).unwrap(), 1_000);
+    assert_eq!(config.price_at(1_000_000).unwrap(), 1_000);
+}
+
+#[test]
+fn fixed_zero_price_requires_free_mint_to_be_allowed() {
+    // Coleção recém-criada: modo Fixed com mint_price = 0
+    let mut config = collection_config(PricingMode::Fixed, 0, 0);
+    assert_eq!(config.price_at(0).unwrap(), 0);
+    assert_eq!(
+        config.public_price_at(0).unwrap_err(),
+        ErrorCode::FreeMintNotAllowed.into()
+    );
+
+    config.allow_free_mint = true;
+    assert_eq!(config.public_price_at(0).unwrap(), 0);
+
+    // As demais curvas e preços fixos positivos não dependem da liberação
+    let config = collection_config(PricingMode::Fixed, 1_000, 0);
+    assert_eq!(config.public_price_at(3).unwrap(), 1_000);
+    let config = collection_config(PricingMode::Linear, 0, 250);
+    assert_eq!(config.public_price_at(2).unwrap(), 500);
+}
+
+#[test]
+fn linear_curve_adds_the_increment_per_minted_nft() {
+    let config = collection_config(PricingMode::Linear, 1_000, 250);
+    assert_eq!(config.price_at(0).unwrap(), 1_000);
+    assert_eq!(config.price_at(1).unwrap(), 1_250);
+    assert_eq!(config.price_at(10).unwrap(), 3_500);
+
+    let config = collection_config(PricingMode::Linear, 1, u64::MAX);
+    assert!(config.price_at(2).is_err());
+}
+
+#[test]
+fn exponential_curve_compounds_the_increment_in_bps() {
+    // +10% por NFT: 1000 * 1.1^n, truncado
+    let config = collection_config(PricingMode::Exponential, 1_000, 1_000);
+    assert_eq!(config.price_at(0).unwrap(), 1_000);
+    assert_eq!(config.price_at(1).unwrap(), 1_100);
+    assert_eq!(config.price_at(2).unwrap(), 1_210);
+    assert_eq!(config.price_at(10).unwrap(), 2_593);
+
+    // Preço que não cabe em u64 é erro, não valor truncado
+    let config = collection_config(PricingMode::Exponential, u64::MAX, 10_000);
+    assert!(config.price_at(1).is_err());
+}
+
+#[test]
+fn tiered_curve_uses_the_last_tier_reached() {
+    let mut config = collection_config(PricingMode::Tiered, 1_000, 0);
+    config.tier_count = 2;
+    config.price_tiers[0] = PriceTier { start_count: 10, price: 2_000 };
+    config.price_tiers[1] = PriceTier { start_count: 20, price: 3_000 };
+    // Faixas além de tier_count são ignoradas
+    config.price_tiers[2] = PriceTier { start_count: 30, price: 9_000 };
+
+    assert_eq!(config.price_at(9).unwrap(), 1_000);
+    assert_eq!(config.price_at(10).unwrap(), 2_000);
+    assert_eq!(config.price_at(19).unwrap(), 2_000);
+    assert_eq!(config.price_at(20).unwrap(), 3_000);
+    assert_eq!(config.price_at(50).unwrap(), 3_000);
+}
+
+#[test]
+fn mint_quote_follows_the_curve_from_the_current_count() {
+    let config = collection_config(PricingMode::Linear, 1_000, 100);
+    let quote = get_mint_quote(&config, &nft_counter(5), 3).unwrap();
+    assert_eq!(quote.current_price, 1_500);
+    assert_eq!(quote.next_prices, vec![1_500, 1_600, 1_700]);
+
+    let quote = get_mint_quote(&config, &nft_counter(0), 1_000).unwrap();
+    assert_eq!(quote.next_prices.len() as u64, MAX_PRICE_QUOTES);
+}
+
+#[test]
+fn mint_quote_stops_at_the_remaining_supply() {
+    let mut config = collection_config(PricingMode::Fixed, 1_000, 0);
+    config.max_supply = 10;
+    config.minted = 8;
+    let quote = get_mint_quote(&config, &nft_counter(100), 5).unwrap();
+    assert_eq!(quote.next_prices, vec![1_000, 1_000]);
+
+    config.minted = 10;
+    let quote = get_mint_quote(&config, &nft_counter(100), 5).unwrap();
+    assert!(quote.next_prices.is_empty());
+}
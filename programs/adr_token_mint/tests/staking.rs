@@ -0,0 +1,140 @@
+use adr_token_mint::utils::calculate_reward;
+use adr_token_mint::views::get_stake_summary;
+use adr_token_mint::{
+    ConfigAccount, StakeAccount, StakeBoost, StakingPeriod, ACCOUNT_VERSION, DEFAULT_NFT_HOLDER_BOOST_BPS,
+};
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::program_stubs::{set_syscall_stubs, SyscallStubs};
+
+const NOW: i64 = 1_700_000_000;
+
+// get_stake_summary lê o Clock; fora da rede o sysvar vem deste stub
+struct FixedClock;
+
+impl SyscallStubs for FixedClock {
+    fn sol_get_clock_sysvar(&self, var_addr: *mut u8) -> u64 {
+        let clock = Clock {
+            unix_timestamp: NOW,
+            ..Clock::default()
+        };
+        unsafe { *(var_addr as *mut Clock) = clock };
+        0
+    }
+}
+
+fn config(nft_holder_boost_bps: u64) -> ConfigAccount {
+    set_syscall_stubs(Box::new(FixedClock));
+    ConfigAccount {
+        version: ACCOUNT_VERSION,
+        payment_token_mint: Pubkey::new_unique(),
+        admin: Pubkey::new_unique(),
+        staking_enabled: true,
+        staking_reward_rate: 1000,
+        max_stake_amount: u64::MAX,
+        emergency_paused: false,
+        reward_reserve: Pubkey::new_unique(),
+        config_version: 0,
+        nft_holder_boost_bps,
+        total_staked: 0,
+        active_stakes: 0,
+        reserved: [0; 40],
+    }
+}
+
+// Posição de 1_000_000 tokens por 10 minutos: 10% de taxa * 140% = 140_000 sem bônus
+fn stake_account(start_time: i64) -> StakeAccount {
+    StakeAccount {
+        version: ACCOUNT_VERSION,
+        owner: Pubkey::new_unique(),
+        amount: 1_000_000,
+        start_time,
+        unlock_time: start_time + StakingPeriod::Minutes10.duration_in_seconds(),
+        period: StakingPeriod::Minutes10,
+        claimed: false,
+        reserved: [0; 32],
+    }
+}
+
+fn stake_boost(stake: &StakeAccount, locked_at: i64) -> StakeBoost {
+    StakeBoost {
+        version: ACCOUNT_VERSION,
+        owner: stake.owner,
+        stake_account: Pubkey::new_unique(),
+        nft_mint: Pubkey::new_unique(),
+        collection: Pubkey::new_unique(),
+        locked_at,
+        reserved: [0; 32],
+    }
+}
+
+#[test]
+fn holder_boost_is_applied_on_top_of_the_period_multiplier() {
+    assert_eq!(calculate_reward(1_000_000, 1000, 140, 0).unwrap(), 140_000);
+    assert_eq!(calculate_reward(1_000_000, 1000, 140, DEFAULT_NFT_HOLDER_BOOST_BPS).unwrap(), 154_000);
+    assert!(calculate_reward(1_000_000, 1000, 140, u64::MAX).is_err());
+}
+
+#[test]
+fn estimated_reward_includes_the_boost_locked_with_the_stake() {
+    let config = config(DEFAULT_NFT_HOLDER_BOOST_BPS);
+    let stake = stake_account(NOW - 60);
+    // lock_boost_nft na mesma transação do stake_tokens: locked_at == start_time
+    let boost = stake_boost(&stake, stake.start_time);
+
+    let summary = get_stake_summary(Some(&stake), Some(&boost), &config).unwrap();
+    assert!(summary.is_staking);
+    assert_eq!(summary.estimated_reward, 154_000);
+    assert_eq!(summary.time_remaining, stake.unlock_time - NOW);
+
+    let unboosted = get_stake_summary(Some(&stake), None, &config).unwrap();
+    assert_eq!(unboosted.estimated_reward, 140_000);
+}
+
+#[test]
+fn boost_locked_after_the_position_started_is_ignored() {
+    let config = config(DEFAULT_NFT_HOLDER_BOOST_BPS);
+    let stake = stake_account(NOW - 60);
+    let boost = stake_boost(&stake, stake.start_time + 1);
+
+    assert_eq!(boost.boost_bps(&stake, config.nft_holder_boost_bps), 0);
+    let summary = get_stake_summary(Some(&stake), Some(&boost), &config).unwrap();
+    assert_eq!(summary.estimated_reward, 140_000);
+
+    // Um aporte reinicia start_time e o NFT já travado passa a valer
+    let topped_up = stake_account(NOW);
+    let summary = get_stake_summary(Some(&topped_up), Some(&boost), &config).unwrap();
+    assert_eq!(summary.estimated_reward, 154_000);
+}
+
+#[test]
+fn boost_follows_the_configured_bps() {
+    let stake = stake_account(NOW - 60);
+    let boost = stake_boost(&stake, stake.start_time);
+
+    let summary = get_stake_summary(Some(&stake), Some(&boost), &config(2500)).unwrap();
+    assert_eq!(summary.estimated_reward, 175_000);
+
+    // Bônus desligado pelo admin (ou configuração migrada do v1)
+    let summary = get_stake_summary(Some(&stake), Some(&boost), &config(0)).unwrap();
+    assert_eq!(summary.estimated_reward, 140_000);
+}
+
+#[test]
+fn stake_stats_follow_open_positions() {
+    let mut config = config(0);
+    config.record_stake(1_000, true).unwrap();
+    config.record_stake(500, false).unwrap();
+    config.record_stake(200, true).unwrap();
+    assert_eq!(config.total_staked, 1_700);
+    assert_eq!(config.active_stakes, 2);
+
+    config.record_unstake(1_500);
+    assert_eq!(config.total_staked, 200);
+    assert_eq!(config.active_stakes, 1);
+
+    // Posição aberta antes das estatísticas: o desconto satura em vez de falhar
+    config.record_unstake(10_000);
+    config.record_unstake(10_000);
+    assert_eq!(config.total_staked, 0);
+    assert_eq!(config.active_stakes, 0);
+}
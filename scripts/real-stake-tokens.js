@@ -112,7 +112,8 @@ async function main() {
     const tx = await program.methods
       .stakeTokens(
         new anchor.BN(amount),
-        { [Object.keys(StakingPeriod).find(key => StakingPeriod[key].value === period.value).toLowerCase()]: {} }
+        { [Object.keys(StakingPeriod).find(key => StakingPeriod[key].value === period.value).toLowerCase()]: {} },
+        null
       )
       .accounts({
         staker: walletKeypair.publicKey,
@@ -0,0 +1,406 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Program } from "@coral-xyz/anchor";
+import {
+  Keypair,
+  PublicKey,
+  SystemProgram,
+  SYSVAR_RENT_PUBKEY,
+} from "@solana/web3.js";
+import {
+  TOKEN_PROGRAM_ID,
+  ASSOCIATED_TOKEN_PROGRAM_ID,
+  getAssociatedTokenAddressSync,
+  createMint,
+  mintTo,
+  getOrCreateAssociatedTokenAccount
+} from "@solana/spl-token";
+import { AdrTokenMint } from "../target/types/adr_token_mint";
+import { assert } from "chai";
+
+describe("ADR Token Admin Events Tests", () => {
+  anchor.setProvider(anchor.AnchorProvider.env());
+
+  const program = anchor.workspace.AdrTokenMint as Program<AdrTokenMint>;
+  const [configPDA] = PublicKey.findProgramAddressSync(
+    [Buffer.from("config")],
+    program.programId
+  );
+  const [eventSequencePDA] = PublicKey.findProgramAddressSync(
+    [Buffer.from("event_sequence")],
+    program.programId
+  );
+  const wallet = program.provider.wallet;
+  const connection = anchor.getProvider().connection;
+  const parser = new anchor.EventParser(program.programId, program.coder);
+
+  let paymentTokenMint: Keypair;
+  let collectionMint: Keypair;
+  let collectionMetadata: Keypair;
+  let payerPaymentTokenAccount: PublicKey;
+  let stakeAuthorityPDA: PublicKey;
+  let rewardReserve: PublicKey;
+
+  // Eventos emitidos pelo programa em uma transação confirmada
+  async function eventsOf(signature: string) {
+    const tx = await connection.getTransaction(signature, {
+      commitment: "confirmed",
+      maxSupportedTransactionVersion: 0,
+    });
+    assert(tx !== null, "Transação não encontrada");
+    return Array.from(parser.parseLogs(tx.meta?.logMessages || []));
+  }
+
+  async function eventOf(signature: string, name: string) {
+    const event = (await eventsOf(signature)).find((e) => e.name === name);
+    assert(event !== undefined, `Evento ${name} não encontrado`);
+    return event.data as any;
+  }
+
+  before(async () => {
+    paymentTokenMint = Keypair.generate();
+    collectionMint = Keypair.generate();
+    collectionMetadata = Keypair.generate();
+
+    [stakeAuthorityPDA] = PublicKey.findProgramAddressSync(
+      [Buffer.from("stake_authority")],
+      program.programId
+    );
+    rewardReserve = getAssociatedTokenAddressSync(
+      paymentTokenMint.publicKey,
+      stakeAuthorityPDA,
+      true
+    );
+
+    await createMint(
+      connection,
+      wallet.payer,
+      wallet.publicKey,
+      wallet.publicKey,
+      9,
+      paymentTokenMint
+    );
+    const paymentTokenAccount = await getOrCreateAssociatedTokenAccount(
+      connection,
+      wallet.payer,
+      paymentTokenMint.publicKey,
+      wallet.publicKey
+    );
+    payerPaymentTokenAccount = paymentTokenAccount.address;
+    await mintTo(
+      connection,
+      wallet.payer,
+      paymentTokenMint.publicKey,
+      payerPaymentTokenAccount,
+      wallet.publicKey,
+      1000 * 10**9
+    );
+  });
+
+  it("Emite CollectionInitializedEvent na inicialização", async () => {
+    const [nftCounterPDA] = PublicKey.findProgramAddressSync(
+      [Buffer.from("nft_counter")],
+      program.programId
+    );
+    const [collectionConfigPDA] = PublicKey.findProgramAddressSync(
+      [Buffer.from("collection_config"), collectionMetadata.publicKey.toBuffer()],
+      program.programId
+    );
+
+    const tx = await program.methods
+      .initializeCollection("Test Collection", "TEST", "https://test-uri.com")
+      .accounts({
+        payer: wallet.publicKey,
+        collectionMint: collectionMint.publicKey,
+        collectionMetadata: collectionMetadata.publicKey,
+        collectionTokenAccount: getAssociatedTokenAddressSync(
+          collectionMint.publicKey,
+          wallet.publicKey
+        ),
+        config: configPDA,
+        eventSequence: eventSequencePDA,
+        nftCounter: nftCounterPDA,
+        collectionConfig: collectionConfigPDA,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+        rent: SYSVAR_RENT_PUBKEY,
+      })
+      .signers([collectionMint, collectionMetadata])
+      .rpc({ commitment: "confirmed" });
+
+    const event = await eventOf(tx, "collectionInitializedEvent");
+    assert.ok(event.admin.equals(wallet.publicKey));
+    assert.ok(event.collection.equals(collectionMetadata.publicKey));
+    assert.ok(event.config.equals(configPDA));
+    assert.equal(event.symbol, "TEST");
+  });
+
+  it("Emite um único ConfigUpdateEvent ao definir o token de pagamento", async () => {
+    const tx = await program.methods
+      .setPaymentToken(paymentTokenMint.publicKey)
+      .accounts({ admin: wallet.publicKey, config: configPDA, eventSequence: eventSequencePDA })
+      .rpc({ commitment: "confirmed" });
+
+    // Alteração tipada da configuração, com a primeira versão após a inicialização
+    const events = await eventsOf(tx);
+    assert.equal(events.length, 1);
+    const update = await eventOf(tx, "configUpdateEvent");
+    assert.ok(update.change.paymentTokenMint.old.equals(PublicKey.default));
+    assert.ok(update.change.paymentTokenMint.new.equals(paymentTokenMint.publicKey));
+    assert.equal(update.configVersion.toNumber(), 1);
+  });
+
+  it("Emite eventos de inicialização e depósito da reserva", async () => {
+    const initTx = await program.methods
+      .initializeRewardReserve()
+      .accounts({
+        admin: wallet.publicKey,
+        rewardReserveAccount: rewardReserve,
+        tokenMint: paymentTokenMint.publicKey,
+        stakeAuthority: stakeAuthorityPDA,
+        config: configPDA,
+        eventSequence: eventSequencePDA,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+        rent: SYSVAR_RENT_PUBKEY,
+      })
+      .rpc({ commitment: "confirmed" });
+
+    const initEvents = await eventsOf(initTx);
+    assert.equal(initEvents.length, 1);
+    const initEvent = await eventOf(initTx, "configUpdateEvent");
+    assert.ok(initEvent.change.rewardReserve.old.equals(PublicKey.default));
+    assert.ok(initEvent.change.rewardReserve.new.equals(rewardReserve));
+
+    const depositTx = await program.methods
+      .depositRewardReserve(new anchor.BN(100 * 10**9))
+      .accounts({
+        admin: wallet.publicKey,
+        adminTokenAccount: payerPaymentTokenAccount,
+        rewardReserveAccount: rewardReserve,
+        tokenMint: paymentTokenMint.publicKey,
+        stakeAuthority: stakeAuthorityPDA,
+        config: configPDA,
+        eventSequence: eventSequencePDA,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .rpc({ commitment: "confirmed" });
+
+    const depositEvent = await eventOf(depositTx, "rewardReserveDepositEvent");
+    assert.equal(depositEvent.amount.toString(), (100 * 10**9).toString());
+    assert.equal(
+      depositEvent.newBalance.sub(depositEvent.oldBalance).toString(),
+      depositEvent.amount.toString()
+    );
+  });
+
+  it("Emite ConfigUpdateEvent ao trocar a reserva", async () => {
+    const customReserve = Keypair.generate().publicKey;
+    const tx = await program.methods
+      .setRewardReserve(customReserve)
+      .accounts({ admin: wallet.publicKey, config: configPDA, eventSequence: eventSequencePDA })
+      .rpc({ commitment: "confirmed" });
+
+    const event = await eventOf(tx, "configUpdateEvent");
+    assert.ok(event.change.rewardReserve.old.equals(rewardReserve));
+    assert.ok(event.change.rewardReserve.new.equals(customReserve));
+
+    // Restaurar a reserva padrão
+    await program.methods
+      .setRewardReserve(rewardReserve)
+      .accounts({ admin: wallet.publicKey, config: configPDA, eventSequence: eventSequencePDA })
+      .rpc();
+  });
+
+  it("Emite DelegateApprovedEvent com o delegado anterior", async () => {
+    const firstDelegate = Keypair.generate().publicKey;
+    const secondDelegate = Keypair.generate().publicKey;
+    const approveTo = (delegate: PublicKey, amount: number) =>
+      program.methods
+        .approveDelegate(new anchor.BN(amount))
+        .accounts({
+          owner: wallet.publicKey,
+          tokenAccount: payerPaymentTokenAccount,
+          delegate,
+          eventSequence: eventSequencePDA,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .rpc({ commitment: "confirmed" });
+
+    await approveTo(firstDelegate, 10);
+    const event = await eventOf(await approveTo(secondDelegate, 20), "delegateApprovedEvent");
+    assert.ok(event.oldDelegate.equals(firstDelegate));
+    assert.equal(event.oldDelegatedAmount.toNumber(), 10);
+    assert.ok(event.newDelegate.equals(secondDelegate));
+    assert.equal(event.newDelegatedAmount.toNumber(), 20);
+  });
+
+  it("Emite um ConfigUpdateEvent por campo com versões crescentes", async () => {
+    const before = await program.account.configAccount.fetch(configPDA);
+    const tx = await program.methods
+      .configureStaking(true, new anchor.BN(1000))
+      .accounts({ admin: wallet.publicKey, config: configPDA, eventSequence: eventSequencePDA })
+      .rpc({ commitment: "confirmed" });
+
+    const updates = (await eventsOf(tx))
+      .filter((e) => e.name === "configUpdateEvent")
+      .map((e) => e.data as any);
+    assert.equal(updates.length, 2);
+    assert.deepEqual(updates[0].change.stakingEnabled, { old: false, new: true });
+    assert.equal(updates[1].change.stakingRewardRate.new.toNumber(), 1000);
+    assert.equal(
+      updates[1].configVersion.toNumber(),
+      updates[0].configVersion.toNumber() + 1
+    );
+
+    const after = await program.account.configAccount.fetch(configPDA);
+    assert.equal(after.configVersion.toNumber(), before.configVersion.toNumber() + 2);
+  });
+
+  it("Emite StakeTopUpEvent em aportes e StakingEvent só no primeiro stake", async () => {
+
+    const [stakeAccountPDA] = PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("stake_account"),
+        wallet.publicKey.toBuffer(),
+        paymentTokenMint.publicKey.toBuffer(),
+      ],
+      program.programId
+    );
+    const stake = (amount: number) =>
+      program.methods
+        .stakeTokens(new anchor.BN(amount), { minutes5: {} }, null)
+        .accounts({
+          staker: wallet.publicKey,
+          tokenMint: paymentTokenMint.publicKey,
+          stakerTokenAccount: payerPaymentTokenAccount,
+          stakeAccount: stakeAccountPDA,
+          stakeTokenAccount: rewardReserve,
+          stakeAuthority: stakeAuthorityPDA,
+          config: configPDA,
+          eventSequence: eventSequencePDA,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+          rent: SYSVAR_RENT_PUBKEY,
+        })
+        .rpc({ commitment: "confirmed" });
+
+    const firstEvents = await eventsOf(await stake(1000));
+    assert.deepEqual(firstEvents.map((e) => e.name), ["stakingEvent"]);
+
+    const topUp = await eventOf(await stake(500), "stakeTopUpEvent");
+    assert.equal(topUp.oldAmount.toNumber(), 1000);
+    assert.equal(topUp.addedAmount.toNumber(), 500);
+    assert.equal(topUp.newAmount.toNumber(), 1500);
+    assert.ok(topUp.newUnlockTime.gte(topUp.oldUnlockTime));
+  });
+
+  it("close_stake rejeita posição ainda ativa", async () => {
+    const [stakeAccountPDA] = PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("stake_account"),
+        wallet.publicKey.toBuffer(),
+        paymentTokenMint.publicKey.toBuffer(),
+      ],
+      program.programId
+    );
+
+    try {
+      await program.methods
+        .closeStake()
+        .accounts({
+          staker: wallet.publicKey,
+          tokenMint: paymentTokenMint.publicKey,
+          stakeAccount: stakeAccountPDA,
+          eventSequence: eventSequencePDA,
+        })
+        .rpc();
+      assert.fail("Deveria ter rejeitado o fechamento de posição ativa");
+    } catch (e) {
+      const errorMessage = e.toString();
+      assert(
+        errorMessage.includes("StakeStillActive"),
+        "Erro diferente do esperado: " + errorMessage
+      );
+    }
+
+    // A posição continua aberta
+    const position = await program.account.stakeAccount.fetch(stakeAccountPDA);
+    assert.equal(position.amount.toNumber(), 1500);
+  });
+
+  it("Numera os eventos em sequência e expõe o último no ConfigSummary", async () => {
+    const tx = await program.methods
+      .configureStaking(true, new anchor.BN(1200))
+      .accounts({ admin: wallet.publicKey, config: configPDA, eventSequence: eventSequencePDA })
+      .rpc({ commitment: "confirmed" });
+
+    const seqs = (await eventsOf(tx)).map((e) => (e.data as any).eventSeq.toNumber());
+    assert.equal(seqs.length, 2);
+    assert.equal(seqs[1], seqs[0] + 1);
+
+    const sequence = await program.account.eventSequence.fetch(eventSequencePDA);
+    assert.equal(sequence.seq.toNumber(), seqs[1]);
+
+    const summary = await program.methods
+      .getConfigSummary()
+      .accounts({ config: configPDA, eventSequence: eventSequencePDA })
+      .view();
+    assert.equal(summary.eventSeq.toNumber(), seqs[1]);
+  });
+
+  it("Emite um CollectionUpdateEvent por campo da coleção", async () => {
+    const [nftCounterPDA] = PublicKey.findProgramAddressSync(
+      [Buffer.from("nft_counter")],
+      program.programId
+    );
+    const [collectionConfigPDA] = PublicKey.findProgramAddressSync(
+      [Buffer.from("collection_config"), collectionMetadata.publicKey.toBuffer()],
+      program.programId
+    );
+
+    const tx = await program.methods
+      .configureCollection(
+        new anchor.BN(50),
+        new anchor.BN(0),
+        new anchor.BN(0),
+        new anchor.BN(10),
+        new anchor.BN(3)
+      )
+      .accounts({
+        admin: wallet.publicKey,
+        config: configPDA,
+        collectionMetadata: collectionMetadata.publicKey,
+        nftCounter: nftCounterPDA,
+        collectionConfig: collectionConfigPDA,
+        eventSequence: eventSequencePDA,
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc({ commitment: "confirmed" });
+
+    const updates = (await eventsOf(tx))
+      .filter((e) => e.name === "collectionUpdateEvent")
+      .map((e) => e.data as any);
+    assert.equal(updates.length, 5);
+    assert.ok(updates.every((u) => u.collection.equals(collectionMetadata.publicKey)));
+    assert.equal(updates[0].change.maxSupply.new.toNumber(), 50);
+    assert.equal(updates[3].change.mintPrice.new.toNumber(), 10);
+    assert.equal(updates[4].change.maxPerWallet.new.toNumber(), 3);
+
+    const modeTx = await program.methods
+      .setPaymentMode({ sol: {} })
+      .accounts({
+        admin: wallet.publicKey,
+        config: configPDA,
+        collectionMetadata: collectionMetadata.publicKey,
+        collectionConfig: collectionConfigPDA,
+        eventSequence: eventSequencePDA,
+      })
+      .rpc({ commitment: "confirmed" });
+
+    const modeUpdate = await eventOf(modeTx, "collectionUpdateEvent");
+    assert.deepEqual(modeUpdate.change.paymentMode, { old: { splToken: {} }, new: { sol: {} } });
+  });
+});
@@ -22,11 +22,18 @@ describe("ADR Token Burn Security Tests", () => {
   anchor.setProvider(anchor.AnchorProvider.env());
 
   const program = anchor.workspace.AdrTokenMint as Program<AdrTokenMint>;
+  const [configPDA] = PublicKey.findProgramAddressSync(
+    [Buffer.from("config")],
+    program.programId
+  );
+  const [eventSequencePDA] = PublicKey.findProgramAddressSync(
+    [Buffer.from("event_sequence")],
+    program.programId
+  );
   const wallet = program.provider.wallet;
   const connection = anchor.getProvider().connection;
 
   // Keypairs e contas
-  let configAccount: Keypair;
   let paymentTokenMint: Keypair;
   let attackerWallet: Keypair;
   let attackerTokenAccount: PublicKey;
@@ -37,7 +44,6 @@ describe("ADR Token Burn Security Tests", () => {
 
   before(async () => {
     // Gerar keypairs
-    configAccount = Keypair.generate();
     paymentTokenMint = Keypair.generate();
     attackerWallet = Keypair.generate();
     collectionMint = Keypair.generate();
@@ -114,13 +120,14 @@ describe("ADR Token Burn Security Tests", () => {
         collectionMint: collectionMint.publicKey,
         collectionMetadata: collectionMetadata.publicKey,
         collectionTokenAccount: collectionTokenAccount,
-        config: configAccount.publicKey,
+        config: configPDA,
+        eventSequence: eventSequencePDA,
         tokenProgram: TOKEN_PROGRAM_ID,
         associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
         systemProgram: SystemProgram.programId,
         rent: SYSVAR_RENT_PUBKEY,
       } as any)
-      .signers([configAccount, collectionMint, collectionMetadata])
+      .signers([collectionMint, collectionMetadata])
       .rpc();
 
     // Configurar o token de pagamento
@@ -128,7 +135,8 @@ describe("ADR Token Burn Security Tests", () => {
       .setPaymentToken(paymentTokenMint.publicKey)
       .accounts({
         admin: wallet.publicKey,
-        config: configAccount.publicKey,
+        config: configPDA,
+        eventSequence: eventSequencePDA,
       })
       .rpc();
   });
@@ -161,8 +169,10 @@ describe("ADR Token Burn Security Tests", () => {
           collectionMetadata: collectionMetadata.publicKey,
           paymentTokenMint: paymentTokenMint.publicKey,
           payerPaymentTokenAccount: payerPaymentTokenAccount,
-          config: configAccount.publicKey,
+          config: configPDA,
+          eventSequence: eventSequencePDA,
           tokenProgram: TOKEN_PROGRAM_ID,
+          nftTokenProgram: TOKEN_PROGRAM_ID,
           associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
           systemProgram: SystemProgram.programId,
           rent: SYSVAR_RENT_PUBKEY,
@@ -209,8 +219,10 @@ describe("ADR Token Burn Security Tests", () => {
           collectionMetadata: collectionMetadata.publicKey,
           paymentTokenMint: paymentTokenMint.publicKey,
           payerPaymentTokenAccount: payerPaymentTokenAccount,
-          config: configAccount.publicKey,
+          config: configPDA,
+          eventSequence: eventSequencePDA,
           tokenProgram: TOKEN_PROGRAM_ID,
+          nftTokenProgram: TOKEN_PROGRAM_ID,
           associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
           systemProgram: SystemProgram.programId,
           rent: SYSVAR_RENT_PUBKEY,
@@ -258,8 +270,10 @@ describe("ADR Token Burn Security Tests", () => {
           collectionMetadata: collectionMetadata.publicKey,
           paymentTokenMint: paymentTokenMint.publicKey,
           payerPaymentTokenAccount: payerPaymentTokenAccount,
-          config: configAccount.publicKey,
+          config: configPDA,
+          eventSequence: eventSequencePDA,
           tokenProgram: TOKEN_PROGRAM_ID,
+          nftTokenProgram: TOKEN_PROGRAM_ID,
           associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
           systemProgram: SystemProgram.programId,
           rent: SYSVAR_RENT_PUBKEY,
@@ -284,7 +298,8 @@ describe("ADR Token Burn Security Tests", () => {
       .setEmergencyPause(true, "Teste de segurança")
       .accounts({
         admin: wallet.publicKey,
-        config: configAccount.publicKey,
+        config: configPDA,
+        eventSequence: eventSequencePDA,
       })
       .rpc();
 
@@ -315,8 +330,10 @@ describe("ADR Token Burn Security Tests", () => {
           collectionMetadata: collectionMetadata.publicKey,
           paymentTokenMint: paymentTokenMint.publicKey,
           payerPaymentTokenAccount: payerPaymentTokenAccount,
-          config: configAccount.publicKey,
+          config: configPDA,
+          eventSequence: eventSequencePDA,
           tokenProgram: TOKEN_PROGRAM_ID,
+          nftTokenProgram: TOKEN_PROGRAM_ID,
           associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
           systemProgram: SystemProgram.programId,
           rent: SYSVAR_RENT_PUBKEY,
@@ -339,7 +356,8 @@ describe("ADR Token Burn Security Tests", () => {
       .setEmergencyPause(false, "Retomando operações")
       .accounts({
         admin: wallet.publicKey,
-        config: configAccount.publicKey,
+        config: configPDA,
+        eventSequence: eventSequencePDA,
       })
       .rpc();
   });
@@ -372,8 +390,10 @@ describe("ADR Token Burn Security Tests", () => {
           collectionMetadata: collectionMetadata.publicKey,
           paymentTokenMint: paymentTokenMint.publicKey,
           payerPaymentTokenAccount: attackerTokenAccount, // Conta do atacante
-          config: configAccount.publicKey,
+          config: configPDA,
+          eventSequence: eventSequencePDA,
           tokenProgram: TOKEN_PROGRAM_ID,
+          nftTokenProgram: TOKEN_PROGRAM_ID,
           associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
           systemProgram: SystemProgram.programId,
           rent: SYSVAR_RENT_PUBKEY,
@@ -402,7 +422,8 @@ describe("ADR Token Burn Security Tests", () => {
         .configureStaking(true, new anchor.BN(2000)) // 20%
         .accounts({
           admin: attackerWallet.publicKey, // Usando a carteira do atacante
-          config: configAccount.publicKey,
+          config: configPDA,
+          eventSequence: eventSequencePDA,
         })
         .signers([attackerWallet])
         .rpc();
@@ -425,7 +446,7 @@ describe("ADR Token Burn Security Tests", () => {
         .updateAdmin(attackerWallet.publicKey)
         .accounts({
           currentAdmin: attackerWallet.publicKey, // Usando a carteira do atacante
-          config: configAccount.publicKey,
+          config: configPDA,
         })
         .signers([attackerWallet])
         .rpc();
@@ -448,7 +469,8 @@ describe("ADR Token Burn Security Tests", () => {
         .setEmergencyPause(true, "Tentativa de ataque")
         .accounts({
           admin: attackerWallet.publicKey, // Usando a carteira do atacante
-          config: configAccount.publicKey,
+          config: configPDA,
+          eventSequence: eventSequencePDA,
         })
         .signers([attackerWallet])
         .rpc();
@@ -471,7 +493,7 @@ describe("ADR Token Burn Security Tests", () => {
         .updateMaxStakeAmount(new anchor.BN(1000000 * 10 ** 9)) // 1 milhão de tokens
         .accounts({
           admin: attackerWallet.publicKey, // Usando a carteira do atacante
-          config: configAccount.publicKey,
+          config: configPDA,
         })
         .signers([attackerWallet])
         .rpc();
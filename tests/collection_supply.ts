@@ -0,0 +1,461 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Program } from "@coral-xyz/anchor";
+import {
+  Keypair,
+  PublicKey,
+  SystemProgram,
+  SYSVAR_RENT_PUBKEY,
+} from "@solana/web3.js";
+import {
+  TOKEN_PROGRAM_ID,
+  ASSOCIATED_TOKEN_PROGRAM_ID,
+  getAssociatedTokenAddressSync,
+  createMint,
+  mintTo,
+  getOrCreateAssociatedTokenAccount
+} from "@solana/spl-token";
+import { AdrTokenMint } from "../target/types/adr_token_mint";
+import { assert } from "chai";
+
+describe("ADR Token Collection Supply Tests", () => {
+  anchor.setProvider(anchor.AnchorProvider.env());
+
+  const program = anchor.workspace.AdrTokenMint as Program<AdrTokenMint>;
+  const [configPDA] = PublicKey.findProgramAddressSync(
+    [Buffer.from("config")],
+    program.programId
+  );
+  const [eventSequencePDA] = PublicKey.findProgramAddressSync(
+    [Buffer.from("event_sequence")],
+    program.programId
+  );
+  const wallet = program.provider.wallet;
+  const connection = anchor.getProvider().connection;
+
+  const MINT_PRICE = 10 * 10**9;
+
+  // Keypairs e contas
+  let paymentTokenMint: Keypair;
+  let collectionMint: Keypair;
+  let collectionMetadata: Keypair;
+  let payerPaymentTokenAccount: PublicKey;
+  let nftCounterPDA: PublicKey;
+  let collectionConfigPDA: PublicKey;
+  let mintRecordPDA: PublicKey;
+
+  // Minta o NFT do próximo índice do contador e retorna seus metadados
+  async function mintNext({
+    collection = collectionMetadata.publicKey,
+    paymentMint = paymentTokenMint.publicKey,
+    paymentAccount = payerPaymentTokenAccount,
+  } = {}) {
+    const counter = await program.account.nftCounter.fetch(nftCounterPDA);
+    const [nftMint] = PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("nft_mint"),
+        collection.toBuffer(),
+        counter.count.toArrayLike(Buffer, "le", 8),
+      ],
+      program.programId
+    );
+    const [nftMetadata] = PublicKey.findProgramAddressSync(
+      [Buffer.from("nft_metadata"), nftMint.toBuffer()],
+      program.programId
+    );
+    const nftTokenAccount = getAssociatedTokenAddressSync(nftMint, wallet.publicKey);
+    const [mintRecord] = PublicKey.findProgramAddressSync(
+      [Buffer.from("mint_record"), collection.toBuffer(), wallet.publicKey.toBuffer()],
+      program.programId
+    );
+
+    await program.methods
+      .mintNftWithPayment("Test NFT", "TNFT", "https://test-nft-uri.com", new anchor.BN(MINT_PRICE))
+      .accounts({
+        payer: wallet.publicKey,
+        nftCounter: nftCounterPDA,
+        nftMint,
+        nftMetadata,
+        nftTokenAccount,
+        collectionMetadata: collection,
+        collectionConfig: collectionConfigFor(collection),
+        mintRecord,
+        paymentTokenMint: paymentMint,
+        payerPaymentTokenAccount: paymentAccount,
+        // Todo o pagamento é queimado na divisão padrão
+        treasuryTokenAccount: null,
+        rewardReserveAccount: null,
+        config: configPDA,
+        eventSequence: eventSequencePDA,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        nftTokenProgram: TOKEN_PROGRAM_ID,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+        rent: SYSVAR_RENT_PUBKEY,
+      })
+      .rpc();
+    return nftMetadata;
+  }
+
+  function collectionConfigFor(collection: PublicKey) {
+    return PublicKey.findProgramAddressSync(
+      [Buffer.from("collection_config"), collection.toBuffer()],
+      program.programId
+    )[0];
+  }
+
+  async function configureCollection(
+    maxSupply: number,
+    mintStart: number,
+    mintEnd: number,
+    mintPrice = 0,
+    maxPerWallet = 0
+  ) {
+    return program.methods
+      .configureCollection(
+        new anchor.BN(maxSupply),
+        new anchor.BN(mintStart),
+        new anchor.BN(mintEnd),
+        new anchor.BN(mintPrice),
+        new anchor.BN(maxPerWallet)
+      )
+      .accounts({
+        admin: wallet.publicKey,
+        config: configPDA,
+        collectionMetadata: collectionMetadata.publicKey,
+        nftCounter: nftCounterPDA,
+        collectionConfig: collectionConfigPDA,
+        eventSequence: eventSequencePDA,
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc();
+  }
+
+  // Preço fixo zero só minta com o mint gratuito liberado explicitamente
+  async function allowFreeMint(collection = collectionMetadata.publicKey) {
+    return program.methods
+      .setPricingCurve({ fixed: {} }, new anchor.BN(0), [], true)
+      .accounts({
+        admin: wallet.publicKey,
+        config: configPDA,
+        collectionMetadata: collection,
+        nftCounter: nftCounterPDA,
+        collectionConfig: collectionConfigFor(collection),
+        eventSequence: eventSequencePDA,
+      })
+      .rpc();
+  }
+
+  before(async () => {
+    // Gerar keypairs
+    paymentTokenMint = Keypair.generate();
+    collectionMint = Keypair.generate();
+    collectionMetadata = Keypair.generate();
+
+    [nftCounterPDA] = PublicKey.findProgramAddressSync(
+      [Buffer.from("nft_counter")],
+      program.programId
+    );
+    [collectionConfigPDA] = PublicKey.findProgramAddressSync(
+      [Buffer.from("collection_config"), collectionMetadata.publicKey.toBuffer()],
+      program.programId
+    );
+    [mintRecordPDA] = PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("mint_record"),
+        collectionMetadata.publicKey.toBuffer(),
+        wallet.publicKey.toBuffer(),
+      ],
+      program.programId
+    );
+
+    // Criar o token de pagamento
+    await createMint(
+      connection,
+      wallet.payer,
+      wallet.publicKey,
+      wallet.publicKey,
+      9, // 9 decimais
+      paymentTokenMint
+    );
+
+    const paymentTokenAccount = await getOrCreateAssociatedTokenAccount(
+      connection,
+      wallet.payer,
+      paymentTokenMint.publicKey,
+      wallet.publicKey
+    );
+    payerPaymentTokenAccount = paymentTokenAccount.address;
+
+    await mintTo(
+      connection,
+      wallet.payer,
+      paymentTokenMint.publicKey,
+      paymentTokenAccount.address,
+      wallet.publicKey,
+      1000 * 10**9 // 1000 tokens com 9 decimais
+    );
+
+    const collectionTokenAccount = getAssociatedTokenAddressSync(
+      collectionMint.publicKey,
+      wallet.publicKey
+    );
+
+    // Inicializar a coleção
+    await program.methods
+      .initializeCollection("Test Collection", "TEST", "https://test-uri.com")
+      .accounts({
+        payer: wallet.publicKey,
+        collectionMint: collectionMint.publicKey,
+        collectionMetadata: collectionMetadata.publicKey,
+        collectionTokenAccount,
+        config: configPDA,
+        eventSequence: eventSequencePDA,
+        nftCounter: nftCounterPDA,
+        collectionConfig: collectionConfigPDA,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+        rent: SYSVAR_RENT_PUBKEY,
+      })
+      .signers([collectionMint, collectionMetadata])
+      .rpc();
+
+    // Configurar o token de pagamento
+    await program.methods
+      .setPaymentToken(paymentTokenMint.publicKey)
+      .accounts({
+        admin: wallet.publicKey,
+        config: configPDA,
+        eventSequence: eventSequencePDA,
+      })
+      .rpc();
+  });
+
+  it("Inicializa a coleção sem limite de supply e sem janela", async () => {
+    const collectionConfig = await program.account.collectionConfig.fetch(collectionConfigPDA);
+    assert.equal(collectionConfig.maxSupply.toNumber(), 0);
+    assert.equal(collectionConfig.mintStart.toNumber(), 0);
+    assert.equal(collectionConfig.mintEnd.toNumber(), 0);
+  });
+
+  it("Rejeita mint a preço fixo zero até o admin liberar o mint gratuito", async () => {
+    try {
+      await mintNext();
+      assert.fail("Deveria ter rejeitado o mint gratuito");
+    } catch (e) {
+      assert(e.toString().includes("FreeMintNotAllowed"), "Erro diferente do esperado: " + e);
+    }
+
+    await allowFreeMint();
+    const collectionConfig = await program.account.collectionConfig.fetch(collectionConfigPDA);
+    assert.isTrue(collectionConfig.allowFreeMint);
+  });
+
+  it("Rejeita mint antes do início da janela", async () => {
+    const now = Math.floor(Date.now() / 1000);
+    await configureCollection(0, now + 3600, 0);
+
+    try {
+      await mintNext();
+      assert.fail("Deveria ter rejeitado mint antes do início");
+    } catch (e) {
+      assert(e.toString().includes("MintNotStarted"), "Erro diferente do esperado: " + e);
+    }
+  });
+
+  it("Rejeita mint após o fim da janela", async () => {
+    const now = Math.floor(Date.now() / 1000);
+    await configureCollection(0, now - 7200, now - 3600);
+
+    try {
+      await mintNext();
+      assert.fail("Deveria ter rejeitado mint após o fim");
+    } catch (e) {
+      assert(e.toString().includes("MintEnded"), "Erro diferente do esperado: " + e);
+    }
+  });
+
+  it("Rejeita mint quando o supply máximo foi atingido", async () => {
+    const config = await program.account.collectionConfig.fetch(collectionConfigPDA);
+    await configureCollection(config.minted.toNumber() + 1, 0, 0);
+
+    // O primeiro mint preenche o último slot disponível
+    await mintNext();
+
+    try {
+      await mintNext();
+      assert.fail("Deveria ter rejeitado mint com a coleção esgotada");
+    } catch (e) {
+      assert(e.toString().includes("CollectionSoldOut"), "Erro diferente do esperado: " + e);
+    }
+  });
+
+  it("Conta o supply máximo por coleção, não pelo contador global", async () => {
+    // Segunda coleção: qualquer conta de metadados registrada com configure_collection
+    await configureCollection(0, 0, 0);
+    const otherCollection = await mintNext();
+    await program.methods
+      .configureCollection(
+        new anchor.BN(0),
+        new anchor.BN(0),
+        new anchor.BN(0),
+        new anchor.BN(0),
+        new anchor.BN(0)
+      )
+      .accounts({
+        admin: wallet.publicKey,
+        config: configPDA,
+        collectionMetadata: otherCollection,
+        nftCounter: nftCounterPDA,
+        collectionConfig: collectionConfigFor(otherCollection),
+        eventSequence: eventSequencePDA,
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc();
+    await allowFreeMint(otherCollection);
+
+    // Um único slot restante nesta coleção
+    const config = await program.account.collectionConfig.fetch(collectionConfigPDA);
+    await configureCollection(config.minted.toNumber() + 1, 0, 0);
+
+    // Mints da outra coleção avançam o contador global, mas não o supply desta
+    await mintNext({ collection: otherCollection });
+    await mintNext({ collection: otherCollection });
+    await mintNext();
+
+    const after = await program.account.collectionConfig.fetch(collectionConfigPDA);
+    assert.equal(after.minted.toNumber(), config.minted.toNumber() + 1);
+    const other = await program.account.collectionConfig.fetch(collectionConfigFor(otherCollection));
+    assert.equal(other.minted.toNumber(), 2);
+  });
+
+  it("Rejeita mint quando o preço excede o máximo aceito", async () => {
+    await configureCollection(0, 0, 0, MINT_PRICE + 1);
+
+    try {
+      await mintNext();
+      assert.fail("Deveria ter rejeitado preço acima do máximo aceito");
+    } catch (e) {
+      assert(e.toString().includes("PriceExceedsMaximum"), "Erro diferente do esperado: " + e);
+    }
+
+    await configureCollection(0, 0, 0, 0);
+  });
+
+  it("Rejeita mint acima do limite por carteira", async () => {
+    const record = await program.account.mintRecord.fetch(mintRecordPDA);
+    await configureCollection(0, 0, 0, 0, record.count.toNumber() + 1);
+
+    // Último mint permitido para esta carteira
+    await mintNext();
+
+    const updated = await program.account.mintRecord.fetch(mintRecordPDA);
+    assert.equal(updated.count.toNumber(), record.count.toNumber() + 1);
+
+    try {
+      await mintNext();
+      assert.fail("Deveria ter rejeitado mint acima do limite por carteira");
+    } catch (e) {
+      assert(e.toString().includes("WalletMintLimitReached"), "Erro diferente do esperado: " + e);
+    }
+
+    await configureCollection(0, 0, 0, 0, 0);
+  });
+
+  it("Cobra o preço da curva linear e o registra no evento", async () => {
+    const counter = await program.account.nftCounter.fetch(nftCounterPDA);
+    const increment = 10**9;
+    await configureCollection(0, 0, 0, 10**9);
+    await program.methods
+      .setPricingCurve({ linear: {} }, new anchor.BN(increment), [], false)
+      .accounts({
+        admin: wallet.publicKey,
+        config: configPDA,
+        collectionMetadata: collectionMetadata.publicKey,
+        nftCounter: nftCounterPDA,
+        collectionConfig: collectionConfigPDA,
+        eventSequence: eventSequencePDA,
+      })
+      .rpc();
+
+    const expectedPrice = 10**9 + increment * counter.count.toNumber();
+    let eventPrice: number | null = null;
+    const listener = program.addEventListener("tokenBurnEvent", (event) => {
+      eventPrice = event.price.toNumber();
+    });
+
+    await mintNext();
+    await new Promise((resolve) => setTimeout(resolve, 2000));
+    await program.removeEventListener(listener);
+
+    assert.equal(eventPrice, expectedPrice);
+
+    await allowFreeMint();
+    await configureCollection(0, 0, 0, 0);
+  });
+
+  it("Rejeita configuração da coleção por não-admin", async () => {
+    const attacker = Keypair.generate();
+    try {
+      await program.methods
+        .configureCollection(
+          new anchor.BN(100),
+          new anchor.BN(0),
+          new anchor.BN(0),
+          new anchor.BN(0),
+          new anchor.BN(0)
+        )
+        .accounts({
+          admin: attacker.publicKey,
+          config: configPDA,
+          collectionMetadata: collectionMetadata.publicKey,
+          nftCounter: nftCounterPDA,
+          collectionConfig: collectionConfigPDA,
+          eventSequence: eventSequencePDA,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([attacker])
+        .rpc();
+      assert.fail("Deveria ter rejeitado configuração por não-admin");
+    } catch (e) {
+      assert(
+        e.toString().includes("Unauthorized") || e.toString().includes("Error"),
+        "Erro diferente do esperado: " + e
+      );
+    }
+  });
+
+  it("Rejeita pagamento com token diferente do configurado", async () => {
+    // Token criado pelo próprio usuário, sem valor
+    const fakeMint = Keypair.generate();
+    await createMint(
+      connection,
+      wallet.payer,
+      wallet.publicKey,
+      wallet.publicKey,
+      9,
+      fakeMint
+    );
+    const fakeAccount = await getOrCreateAssociatedTokenAccount(
+      connection,
+      wallet.payer,
+      fakeMint.publicKey,
+      wallet.publicKey
+    );
+    await mintTo(
+      connection,
+      wallet.payer,
+      fakeMint.publicKey,
+      fakeAccount.address,
+      wallet.publicKey,
+      1000 * 10**9
+    );
+
+    try {
+      await mintNext({ paymentMint: fakeMint.publicKey, paymentAccount: fakeAccount.address });
+      assert.fail("Deveria ter rejeitado o token de pagamento falso");
+    } catch (e) {
+      assert(e.toString().includes("InvalidPaymentToken"), "Erro diferente do esperado: " + e);
+    }
+  });
+});
@@ -20,11 +20,18 @@ describe("ADR Token Config Security Tests", () => {
   anchor.setProvider(anchor.AnchorProvider.env());
 
   const program = anchor.workspace.AdrTokenMint as Program<AdrTokenMint>;
+  const [configPDA] = PublicKey.findProgramAddressSync(
+    [Buffer.from("config")],
+    program.programId
+  );
+  const [eventSequencePDA] = PublicKey.findProgramAddressSync(
+    [Buffer.from("event_sequence")],
+    program.programId
+  );
   const wallet = program.provider.wallet;
   const connection = anchor.getProvider().connection;
 
   // Keypairs e contas
-  let configAccount: Keypair;
   let paymentTokenMint: Keypair;
   let attackerWallet: Keypair;
   let secondaryAdminWallet: Keypair;
@@ -34,7 +41,6 @@ describe("ADR Token Config Security Tests", () => {
 
   before(async () => {
     // Gerar keypairs
-    configAccount = Keypair.generate();
     paymentTokenMint = Keypair.generate();
     attackerWallet = Keypair.generate();
     secondaryAdminWallet = Keypair.generate();
@@ -78,13 +84,14 @@ describe("ADR Token Config Security Tests", () => {
         collectionMint: collectionMint.publicKey,
         collectionMetadata: collectionMetadata.publicKey,
         collectionTokenAccount: collectionTokenAccount,
-        config: configAccount.publicKey,
+        config: configPDA,
+        eventSequence: eventSequencePDA,
         tokenProgram: TOKEN_PROGRAM_ID,
         associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
         systemProgram: SystemProgram.programId,
         rent: SYSVAR_RENT_PUBKEY,
       } as any)
-      .signers([configAccount, collectionMint, collectionMetadata])
+      .signers([collectionMint, collectionMetadata])
       .rpc();
 
     // Configurar o token de pagamento
@@ -92,7 +99,8 @@ describe("ADR Token Config Security Tests", () => {
       .setPaymentToken(paymentTokenMint.publicKey)
       .accounts({
         admin: wallet.publicKey,
-        config: configAccount.publicKey,
+        config: configPDA,
+        eventSequence: eventSequencePDA,
       } as any)
       .rpc();
 
@@ -101,17 +109,15 @@ describe("ADR Token Config Security Tests", () => {
       .configureStaking(true, new anchor.BN(2000)) // 20%
       .accounts({
         admin: wallet.publicKey,
-        config: configAccount.publicKey,
+        config: configPDA,
+        eventSequence: eventSequencePDA,
       } as any)
       .rpc();
   });
 
-  it("Rejeita inicialização de configuração por não-admin", async () => {
-    // Criar uma nova conta de configuração
-    const newConfigAccount = Keypair.generate();
-
+  it("Rejeita reinicialização da configuração canônica", async () => {
     try {
-      // Tentar inicializar a configuração com a carteira do atacante
+      // A PDA [b"config"] já existe: o atacante não consegue recriá-la como admin
       await program.methods
         .initializeCollection("Test Collection", "TEST", "https://test-uri.com")
         .accounts({
@@ -119,32 +125,51 @@ describe("ADR Token Config Security Tests", () => {
           collectionMint: collectionMint.publicKey,
           collectionMetadata: collectionMetadata.publicKey,
           collectionTokenAccount: collectionTokenAccount,
-          config: newConfigAccount.publicKey,
+          config: configPDA,
+          eventSequence: eventSequencePDA,
           tokenProgram: TOKEN_PROGRAM_ID,
           associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
           systemProgram: SystemProgram.programId,
           rent: SYSVAR_RENT_PUBKEY,
         } as any)
-        .signers([
-          newConfigAccount,
-          attackerWallet,
-          collectionMint,
-          collectionMetadata,
-        ])
+        .signers([attackerWallet, collectionMint, collectionMetadata])
         .rpc();
 
-      assert.fail("Deveria ter rejeitado inicialização por não-admin");
+      assert.fail("Deveria ter rejeitado a reinicialização da configuração");
     } catch (e) {
       const errorMessage = e.toString();
       assert(
-        errorMessage.includes("Unauthorized") ||
-          errorMessage.includes("Você não está autorizado") ||
+        errorMessage.includes("already in use") ||
           errorMessage.includes("InsufficientFunds"),
         "Erro diferente do esperado: " + errorMessage
       );
     }
   });
 
+  it("Rejeita conta de configuração fora da PDA canônica", async () => {
+    // Uma configuração qualquer (ex.: criada pelo atacante) não passa na verificação de seeds
+    const fakeConfig = Keypair.generate();
+    try {
+      await program.methods
+        .setPaymentToken(paymentTokenMint.publicKey)
+        .accounts({
+          admin: wallet.publicKey,
+          config: fakeConfig.publicKey,
+          eventSequence: eventSequencePDA,
+        } as any)
+        .rpc();
+
+      assert.fail("Deveria ter rejeitado configuração fora da PDA");
+    } catch (e) {
+      const errorMessage = e.toString();
+      assert(
+        errorMessage.includes("ConstraintSeeds") ||
+          errorMessage.includes("AccountNotInitialized"),
+        "Erro diferente do esperado: " + errorMessage
+      );
+    }
+  });
+
   it("Rejeita configuração de token de pagamento por não-admin", async () => {
     try {
       // Tentar configurar o token de pagamento com a carteira do atacante
@@ -152,7 +177,8 @@ describe("ADR Token Config Security Tests", () => {
         .setPaymentToken(paymentTokenMint.publicKey)
         .accounts({
           admin: attackerWallet.publicKey, // Usando a carteira do atacante
-          config: configAccount.publicKey,
+          config: configPDA,
+          eventSequence: eventSequencePDA,
         } as any)
         .signers([attackerWallet])
         .rpc();
@@ -175,7 +201,8 @@ describe("ADR Token Config Security Tests", () => {
         .configureStaking(true, new anchor.BN(2000)) // 20%
         .accounts({
           admin: attackerWallet.publicKey, // Usando a carteira do atacante
-          config: configAccount.publicKey,
+          config: configPDA,
+          eventSequence: eventSequencePDA,
         } as any)
         .signers([attackerWallet])
         .rpc();
@@ -200,7 +227,7 @@ describe("ADR Token Config Security Tests", () => {
         .updateAdmin(attackerWallet.publicKey)
         .accounts({
           currentAdmin: attackerWallet.publicKey, // Usando a carteira do atacante
-          config: configAccount.publicKey,
+          config: configPDA,
         })
         .signers([attackerWallet])
         .rpc();
@@ -223,7 +250,8 @@ describe("ADR Token Config Security Tests", () => {
         .setEmergencyPause(true, "Teste de segurança")
         .accounts({
           admin: attackerWallet.publicKey, // Usando a carteira do atacante
-          config: configAccount.publicKey,
+          config: configPDA,
+          eventSequence: eventSequencePDA,
         })
         .signers([attackerWallet])
         .rpc();
@@ -246,7 +274,7 @@ describe("ADR Token Config Security Tests", () => {
         .updateMaxStakeAmount(new anchor.BN(1000000 * 10 ** 9)) // 1 milhão de tokens
         .accounts({
           admin: attackerWallet.publicKey, // Usando a carteira do atacante
-          config: configAccount.publicKey,
+          config: configPDA,
         })
         .signers([attackerWallet])
         .rpc();
@@ -272,7 +300,8 @@ describe("ADR Token Config Security Tests", () => {
         .setPaymentToken(invalidTokenMint.publicKey)
         .accounts({
           admin: wallet.publicKey,
-          config: configAccount.publicKey,
+          config: configPDA,
+          eventSequence: eventSequencePDA,
         })
         .rpc();
 
@@ -295,7 +324,8 @@ describe("ADR Token Config Security Tests", () => {
         .configureStaking(true, new anchor.BN(10001)) // 100.01%
         .accounts({
           admin: wallet.publicKey,
-          config: configAccount.publicKey,
+          config: configPDA,
+          eventSequence: eventSequencePDA,
         })
         .rpc();
 
@@ -312,6 +342,66 @@ describe("ADR Token Config Security Tests", () => {
     }
   });
 
+  it("Rejeita bônus de holder acima de 100% e por não-admin", async () => {
+    try {
+      await program.methods
+        .setNftHolderBoost(new anchor.BN(10001))
+        .accounts({
+          admin: wallet.publicKey,
+          config: configPDA,
+          eventSequence: eventSequencePDA,
+        })
+        .rpc();
+      assert.fail("Deveria ter rejeitado bônus acima do máximo");
+    } catch (e) {
+      assert(e.toString().includes("InvalidHolderBoost"), "Erro diferente do esperado: " + e);
+    }
+
+    try {
+      await program.methods
+        .setNftHolderBoost(new anchor.BN(5000))
+        .accounts({
+          admin: attackerWallet.publicKey,
+          config: configPDA,
+          eventSequence: eventSequencePDA,
+        } as any)
+        .signers([attackerWallet])
+        .rpc();
+      assert.fail("Deveria ter rejeitado bônus definido por não-admin");
+    } catch (e) {
+      assert(e.toString().includes("Unauthorized"), "Erro diferente do esperado: " + e);
+    }
+  });
+
+  it("Atualiza o bônus de holder e o expõe no resumo da configuração", async () => {
+    const before = await program.account.configAccount.fetch(configPDA);
+    await program.methods
+      .setNftHolderBoost(new anchor.BN(2500))
+      .accounts({
+        admin: wallet.publicKey,
+        config: configPDA,
+        eventSequence: eventSequencePDA,
+      })
+      .rpc();
+
+    const summary = await program.methods
+      .getConfigSummary()
+      .accounts({ config: configPDA, eventSequence: eventSequencePDA })
+      .view();
+    assert.equal(summary.nftHolderBoostBps.toNumber(), 2500);
+    assert.equal(summary.configVersion.toNumber(), before.configVersion.toNumber() + 1);
+
+    // Restaura o padrão para os demais testes
+    await program.methods
+      .setNftHolderBoost(before.nftHolderBoostBps)
+      .accounts({
+        admin: wallet.publicKey,
+        config: configPDA,
+        eventSequence: eventSequencePDA,
+      })
+      .rpc();
+  });
+
   it("Rejeita atualização de limite máximo de stake inválido", async () => {
     try {
       // Tentar atualizar o limite máximo com valor zero
@@ -319,7 +409,7 @@ describe("ADR Token Config Security Tests", () => {
         .updateMaxStakeAmount(new anchor.BN(0))
         .accounts({
           admin: wallet.publicKey,
-          config: configAccount.publicKey,
+          config: configPDA,
         })
         .rpc();
 
@@ -341,7 +431,7 @@ describe("ADR Token Config Security Tests", () => {
         .updateAdmin(PublicKey.default)
         .accounts({
           currentAdmin: wallet.publicKey,
-          config: configAccount.publicKey,
+          config: configPDA,
         })
         .rpc();
 
@@ -364,7 +454,8 @@ describe("ADR Token Config Security Tests", () => {
       .setPaymentToken(PublicKey.default)
       .accounts({
         admin: wallet.publicKey,
-        config: configAccount.publicKey,
+        config: configPDA,
+        eventSequence: eventSequencePDA,
       })
       .rpc();
 
@@ -374,7 +465,8 @@ describe("ADR Token Config Security Tests", () => {
         .configureStaking(true, new anchor.BN(2000)) // 20%
         .accounts({
           admin: wallet.publicKey,
-          config: configAccount.publicKey,
+          config: configPDA,
+          eventSequence: eventSequencePDA,
         })
         .rpc();
 
@@ -393,7 +485,8 @@ describe("ADR Token Config Security Tests", () => {
       .setPaymentToken(paymentTokenMint.publicKey)
       .accounts({
         admin: wallet.publicKey,
-        config: configAccount.publicKey,
+        config: configPDA,
+        eventSequence: eventSequencePDA,
       })
       .rpc();
   });
@@ -404,7 +497,8 @@ describe("ADR Token Config Security Tests", () => {
       .setEmergencyPause(true, "Teste de segurança")
       .accounts({
         admin: wallet.publicKey,
-        config: configAccount.publicKey,
+        config: configPDA,
+        eventSequence: eventSequencePDA,
       })
       .rpc();
 
@@ -414,7 +508,8 @@ describe("ADR Token Config Security Tests", () => {
         .configureStaking(true, new anchor.BN(2000)) // 20%
         .accounts({
           admin: wallet.publicKey,
-          config: configAccount.publicKey,
+          config: configPDA,
+          eventSequence: eventSequencePDA,
         })
         .rpc();
 
@@ -435,7 +530,8 @@ describe("ADR Token Config Security Tests", () => {
       .setEmergencyPause(false, "Teste de segurança")
       .accounts({
         admin: wallet.publicKey,
-        config: configAccount.publicKey,
+        config: configPDA,
+        eventSequence: eventSequencePDA,
       })
       .rpc();
   });
@@ -446,7 +542,8 @@ describe("ADR Token Config Security Tests", () => {
       .setEmergencyPause(true, "Teste de segurança")
       .accounts({
         admin: wallet.publicKey,
-        config: configAccount.publicKey,
+        config: configPDA,
+        eventSequence: eventSequencePDA,
       })
       .rpc();
 
@@ -456,7 +553,7 @@ describe("ADR Token Config Security Tests", () => {
         .updateAdmin(secondaryAdminWallet.publicKey)
         .accounts({
           currentAdmin: wallet.publicKey,
-          config: configAccount.publicKey,
+          config: configPDA,
         })
         .rpc();
 
@@ -477,7 +574,8 @@ describe("ADR Token Config Security Tests", () => {
       .setEmergencyPause(false, "Teste de segurança")
       .accounts({
         admin: wallet.publicKey,
-        config: configAccount.publicKey,
+        config: configPDA,
+        eventSequence: eventSequencePDA,
       })
       .rpc();
   });
@@ -488,7 +586,8 @@ describe("ADR Token Config Security Tests", () => {
       .setEmergencyPause(true, "Teste de segurança")
       .accounts({
         admin: wallet.publicKey,
-        config: configAccount.publicKey,
+        config: configPDA,
+        eventSequence: eventSequencePDA,
       })
       .rpc();
 
@@ -498,7 +597,7 @@ describe("ADR Token Config Security Tests", () => {
         .updateMaxStakeAmount(new anchor.BN(5000 * 10 ** 9)) // 5000 tokens
         .accounts({
           admin: wallet.publicKey,
-          config: configAccount.publicKey,
+          config: configPDA,
         })
         .rpc();
 
@@ -519,7 +618,8 @@ describe("ADR Token Config Security Tests", () => {
       .setEmergencyPause(false, "Teste de segurança")
       .accounts({
         admin: wallet.publicKey,
-        config: configAccount.publicKey,
+        config: configPDA,
+        eventSequence: eventSequencePDA,
       })
       .rpc();
   });
@@ -530,7 +630,8 @@ describe("ADR Token Config Security Tests", () => {
       .setEmergencyPause(true, "Teste de segurança")
       .accounts({
         admin: wallet.publicKey,
-        config: configAccount.publicKey,
+        config: configPDA,
+        eventSequence: eventSequencePDA,
       })
       .rpc();
 
@@ -540,7 +641,8 @@ describe("ADR Token Config Security Tests", () => {
         .setPaymentToken(paymentTokenMint.publicKey)
         .accounts({
           admin: wallet.publicKey,
-          config: configAccount.publicKey,
+          config: configPDA,
+          eventSequence: eventSequencePDA,
         })
         .rpc();
 
@@ -561,7 +663,8 @@ describe("ADR Token Config Security Tests", () => {
       .setEmergencyPause(false, "Teste de segurança")
       .accounts({
         admin: wallet.publicKey,
-        config: configAccount.publicKey,
+        config: configPDA,
+        eventSequence: eventSequencePDA,
       })
       .rpc();
   });
@@ -572,13 +675,14 @@ describe("ADR Token Config Security Tests", () => {
       .setEmergencyPause(true, "Manutenção programada")
       .accounts({
         admin: wallet.publicKey,
-        config: configAccount.publicKey,
+        config: configPDA,
+        eventSequence: eventSequencePDA,
       })
       .rpc();
 
     // Verificar se o sistema está pausado
     const configInfo = await program.account.configAccount.fetch(
-      configAccount.publicKey
+      configPDA
     );
     assert.isTrue(
       configInfo.emergencyPaused,
@@ -590,13 +694,14 @@ describe("ADR Token Config Security Tests", () => {
       .setEmergencyPause(false, "Manutenção concluída")
       .accounts({
         admin: wallet.publicKey,
-        config: configAccount.publicKey,
+        config: configPDA,
+        eventSequence: eventSequencePDA,
       })
       .rpc();
 
     // Verificar se o sistema está despausado
     const configInfo2 = await program.account.configAccount.fetch(
-      configAccount.publicKey
+      configPDA
     );
     assert.isFalse(
       configInfo2.emergencyPaused,
@@ -610,7 +715,8 @@ describe("ADR Token Config Security Tests", () => {
       .setEmergencyPause(true, "Teste de segurança com pausa")
       .accounts({
         admin: wallet.publicKey,
-        config: configAccount.publicKey,
+        config: configPDA,
+        eventSequence: eventSequencePDA,
       })
       .rpc();
 
@@ -620,7 +726,8 @@ describe("ADR Token Config Security Tests", () => {
         .configureStaking(true, new anchor.BN(3000)) // 30%
         .accounts({
           admin: wallet.publicKey,
-          config: configAccount.publicKey,
+          config: configPDA,
+          eventSequence: eventSequencePDA,
         })
         .rpc();
 
@@ -639,8 +746,36 @@ describe("ADR Token Config Security Tests", () => {
       .setEmergencyPause(false, "Retomando operações após teste")
       .accounts({
         admin: wallet.publicKey,
-        config: configAccount.publicKey,
+        config: configPDA,
+        eventSequence: eventSequencePDA,
       })
       .rpc();
   });
+
+  it("Cria contas versionadas e rejeita migrar contas já convertidas", async () => {
+    const config = await program.account.configAccount.fetch(configPDA);
+    assert.equal(config.version, 2);
+    assert.equal(config.reserved.length, 64);
+
+    try {
+      await program.methods
+        .migrateAccount()
+        .accounts({
+          payer: attackerWallet.publicKey,
+          account: configPDA,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([attackerWallet])
+        .rpc();
+
+      assert.fail("Deveria ter rejeitado conta que já está na versão atual");
+    } catch (e) {
+      const errorMessage = e.toString();
+      assert(
+        errorMessage.includes("AccountNotMigratable") ||
+          errorMessage.includes("A conta não está no layout v1 de um tipo do programa"),
+        "Erro diferente do esperado: " + errorMessage
+      );
+    }
+  });
 });
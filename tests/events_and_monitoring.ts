@@ -22,11 +22,18 @@ describe("ADR Token Events and Monitoring Tests", () => {
   anchor.setProvider(anchor.AnchorProvider.env());
 
   const program = anchor.workspace.AdrTokenMint as Program<AdrTokenMint>;
+  const [configPDA] = PublicKey.findProgramAddressSync(
+    [Buffer.from("config")],
+    program.programId
+  );
+  const [eventSequencePDA] = PublicKey.findProgramAddressSync(
+    [Buffer.from("event_sequence")],
+    program.programId
+  );
   const wallet = program.provider.wallet;
   const connection = anchor.getProvider().connection;
 
   // Keypairs e contas
-  let configAccount: Keypair;
   let paymentTokenMint: Keypair;
   let stakeAccount: Keypair;
   let payerPaymentTokenAccount: PublicKey;
@@ -37,7 +44,6 @@ describe("ADR Token Events and Monitoring Tests", () => {
 
   before(async () => {
     // Gerar keypairs
-    configAccount = Keypair.generate();
     paymentTokenMint = Keypair.generate();
     stakeAccount = Keypair.generate();
     collectionMint = Keypair.generate();
@@ -112,13 +118,14 @@ describe("ADR Token Events and Monitoring Tests", () => {
         collectionMint: collectionMint.publicKey,
         collectionMetadata: collectionMetadata.publicKey,
         collectionTokenAccount: collectionTokenAccount,
-        config: configAccount.publicKey,
+        config: configPDA,
+        eventSequence: eventSequencePDA,
         tokenProgram: TOKEN_PROGRAM_ID,
         associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
         systemProgram: SystemProgram.programId,
         rent: SYSVAR_RENT_PUBKEY,
       } as any)
-      .signers([configAccount, collectionMint, collectionMetadata])
+      .signers([collectionMint, collectionMetadata])
       .rpc();
 
     // Configurar o token de pagamento
@@ -126,7 +133,8 @@ describe("ADR Token Events and Monitoring Tests", () => {
       .setPaymentToken(paymentTokenMint.publicKey)
       .accounts({
         admin: wallet.publicKey,
-        config: configAccount.publicKey,
+        config: configPDA,
+        eventSequence: eventSequencePDA,
       })
       .rpc();
     
@@ -135,7 +143,8 @@ describe("ADR Token Events and Monitoring Tests", () => {
       .configureStaking(true, new anchor.BN(2000)) // 20%
       .accounts({
         admin: wallet.publicKey,
-        config: configAccount.publicKey,
+        config: configPDA,
+        eventSequence: eventSequencePDA,
       })
       .rpc();
   });
@@ -145,7 +154,7 @@ describe("ADR Token Events and Monitoring Tests", () => {
     const stakeAmount = new anchor.BN(100 * 10**9); // 100 tokens
     
     const tx = await program.methods
-      .stakeTokens(stakeAmount, { days7: {} })
+      .stakeTokens(stakeAmount, { days7: {} }, null)
       .accounts({
         staker: wallet.publicKey,
         tokenMint: paymentTokenMint.publicKey,
@@ -153,7 +162,8 @@ describe("ADR Token Events and Monitoring Tests", () => {
         stakeTokenAccount: stakerTokenAccount,
         stakeAuthority: wallet.publicKey,
         stakeAccount: stakeAccount.publicKey,
-        config: configAccount.publicKey,
+        config: configPDA,
+        eventSequence: eventSequencePDA,
         tokenProgram: TOKEN_PROGRAM_ID,
         associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
         systemProgram: SystemProgram.programId,
@@ -202,7 +212,8 @@ describe("ADR Token Events and Monitoring Tests", () => {
         stakeAccount: stakeAccount.publicKey,
         tokenMint: paymentTokenMint.publicKey,
         stakerTokenAccount: stakerTokenAccount,
-        config: configAccount.publicKey,
+        config: configPDA,
+        eventSequence: eventSequencePDA,
         tokenProgram: TOKEN_PROGRAM_ID,
       })
       .rpc();
@@ -241,7 +252,7 @@ describe("ADR Token Events and Monitoring Tests", () => {
       .updateMaxStakeAmount(newMaxStakeAmount)
       .accounts({
         admin: wallet.publicKey,
-        config: configAccount.publicKey,
+        config: configPDA,
       })
       .rpc();
     
@@ -278,7 +289,8 @@ describe("ADR Token Events and Monitoring Tests", () => {
       .setEmergencyPause(true, "Emergency pause for testing")
       .accounts({
         admin: wallet.publicKey,
-        config: configAccount.publicKey,
+        config: configPDA,
+        eventSequence: eventSequencePDA,
       } as any)
       .rpc();
     
@@ -312,7 +324,8 @@ describe("ADR Token Events and Monitoring Tests", () => {
       .setEmergencyPause(false)
       .accounts({
         admin: wallet.publicKey,
-        config: configAccount.publicKey,
+        config: configPDA,
+        eventSequence: eventSequencePDA,
       })
       .rpc();
   });
@@ -320,7 +333,7 @@ describe("ADR Token Events and Monitoring Tests", () => {
   it("Monitora eventos em tempo real", async () => {
     // Configurar o listener de eventos
     const eventListener = connection.onLogs(
-      configAccount.publicKey,
+      configPDA,
       (logs) => {
         // Verificar se o log contém um evento
         const eventLog = logs.logs.find(log => 
@@ -361,7 +374,7 @@ describe("ADR Token Events and Monitoring Tests", () => {
     
     // Stake
     await program.methods
-      .stakeTokens(stakeAmount, { days7: {} })
+      .stakeTokens(stakeAmount, { days7: {} }, null)
       .accounts({
         staker: wallet.publicKey,
         tokenMint: paymentTokenMint.publicKey,
@@ -369,7 +382,8 @@ describe("ADR Token Events and Monitoring Tests", () => {
         stakeTokenAccount: stakerTokenAccount,
         stakeAuthority: wallet.publicKey,
         stakeAccount: stakeAccount.publicKey,
-        config: configAccount.publicKey,
+        config: configPDA,
+        eventSequence: eventSequencePDA,
         tokenProgram: TOKEN_PROGRAM_ID,
         associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
         systemProgram: SystemProgram.programId,
@@ -383,7 +397,7 @@ describe("ADR Token Events and Monitoring Tests", () => {
       .updateMaxStakeAmount(new anchor.BN(3000 * 10**9)) // 3000 tokens
       .accounts({
         admin: wallet.publicKey,
-        config: configAccount.publicKey,
+        config: configPDA,
       })
       .rpc();
     
@@ -392,7 +406,8 @@ describe("ADR Token Events and Monitoring Tests", () => {
       .setEmergencyPause(true)
       .accounts({
         admin: wallet.publicKey,
-        config: configAccount.publicKey,
+        config: configPDA,
+        eventSequence: eventSequencePDA,
       })
       .rpc();
     
@@ -407,7 +422,8 @@ describe("ADR Token Events and Monitoring Tests", () => {
       .setEmergencyPause(false)
       .accounts({
         admin: wallet.publicKey,
-        config: configAccount.publicKey,
+        config: configPDA,
+        eventSequence: eventSequencePDA,
       })
       .rpc();
   });
@@ -415,7 +431,7 @@ describe("ADR Token Events and Monitoring Tests", () => {
   it("Verifica a retenção de logs", async () => {
     // Obter os logs mais recentes
     const signatures = await connection.getSignaturesForAddress(
-      configAccount.publicKey,
+      configPDA,
       { limit: 10 }
     );
     
@@ -442,7 +458,7 @@ describe("ADR Token Events and Monitoring Tests", () => {
         for (const eventLog of eventLogs) {
           assert(
             eventLog.includes(wallet.publicKey.toBase58()) ||
-            eventLog.includes(configAccount.publicKey.toBase58()) ||
+            eventLog.includes(configPDA.toBase58()) ||
             eventLog.includes(stakeAccount.publicKey.toBase58()),
             "Evento não contém as informações necessárias: " + eventLog
           );
@@ -454,7 +470,7 @@ describe("ADR Token Events and Monitoring Tests", () => {
   it("Verifica o intervalo de verificação de eventos", async () => {
     // Configurar o listener de eventos com um intervalo específico
     const eventListener = connection.onLogs(
-      configAccount.publicKey,
+      configPDA,
       (logs) => {
         // Verificar se o log contém um evento
         const eventLog = logs.logs.find(log => 
@@ -482,7 +498,7 @@ describe("ADR Token Events and Monitoring Tests", () => {
       .updateMaxStakeAmount(new anchor.BN(4000 * 10**9)) // 4000 tokens
       .accounts({
         admin: wallet.publicKey,
-        config: configAccount.publicKey,
+        config: configPDA,
       })
       .rpc();
     
@@ -496,7 +512,7 @@ describe("ADR Token Events and Monitoring Tests", () => {
   it("Verifica o limite de eventos por lote", async () => {
     // Configurar o listener de eventos
     const eventListener = connection.onLogs(
-      configAccount.publicKey,
+      configPDA,
       (logs) => {
         // Verificar se o número de eventos está dentro do limite
         const eventLogs = logs.logs.filter(log => 
@@ -518,7 +534,7 @@ describe("ADR Token Events and Monitoring Tests", () => {
         .updateMaxStakeAmount(new anchor.BN((4000 + i) * 10**9)) // Incrementar o valor
         .accounts({
           admin: wallet.publicKey,
-          config: configAccount.publicKey,
+          config: configPDA,
         })
         .rpc();
       
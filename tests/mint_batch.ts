@@ -0,0 +1,321 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Program } from "@coral-xyz/anchor";
+import {
+  ComputeBudgetProgram,
+  Keypair,
+  PublicKey,
+  SystemProgram,
+  SYSVAR_RENT_PUBKEY,
+  Transaction,
+} from "@solana/web3.js";
+import {
+  TOKEN_PROGRAM_ID,
+  ASSOCIATED_TOKEN_PROGRAM_ID,
+  getAssociatedTokenAddressSync,
+  createMint,
+  mintTo,
+  getOrCreateAssociatedTokenAccount
+} from "@solana/spl-token";
+import { AdrTokenMint } from "../target/types/adr_token_mint";
+import { assert } from "chai";
+
+describe("ADR Token Mint Batch Tests", () => {
+  anchor.setProvider(anchor.AnchorProvider.env());
+
+  const program = anchor.workspace.AdrTokenMint as Program<AdrTokenMint>;
+  const [configPDA] = PublicKey.findProgramAddressSync(
+    [Buffer.from("config")],
+    program.programId
+  );
+  const [eventSequencePDA] = PublicKey.findProgramAddressSync(
+    [Buffer.from("event_sequence")],
+    program.programId
+  );
+  const wallet = program.provider.wallet;
+  const connection = anchor.getProvider().connection;
+
+  const MINT_PRICE = 10 * 10**9;
+  const QUANTITY = 3;
+
+  let paymentTokenMint: Keypair;
+  let collectionMint: Keypair;
+  let collectionMetadata: Keypair;
+  let payerPaymentTokenAccount: PublicKey;
+  let nftCounterPDA: PublicKey;
+  let collectionConfigPDA: PublicKey;
+  let mintRecordPDA: PublicKey;
+
+  // Contas e bumps dos próximos `quantity` NFTs pelo contador global
+  async function nextBatch(quantity: number) {
+    const counter = await program.account.nftCounter.fetch(nftCounterPDA);
+    const nfts = [];
+    for (let offset = 0; offset < quantity; offset++) {
+      const index = counter.count.addn(offset);
+      const [nftMint, mintBump] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("nft_mint"),
+          collectionMetadata.publicKey.toBuffer(),
+          index.toArrayLike(Buffer, "le", 8),
+        ],
+        program.programId
+      );
+      const [nftMetadata, metadataBump] = PublicKey.findProgramAddressSync(
+        [Buffer.from("nft_metadata"), nftMint.toBuffer()],
+        program.programId
+      );
+      nfts.push({
+        nftMint,
+        nftMetadata,
+        nftTokenAccount: getAssociatedTokenAddressSync(nftMint, wallet.publicKey),
+        bumps: { nftMint: mintBump, nftMetadata: metadataBump },
+      });
+    }
+    return nfts;
+  }
+
+  function mintBatch(nfts: Awaited<ReturnType<typeof nextBatch>>, maxTotalPrice: number) {
+    return program.methods
+      .mintBatch(
+        nfts.length,
+        "Test NFT",
+        "TNFT",
+        "https://test-nft-uri.com",
+        new anchor.BN(maxTotalPrice),
+        nfts.map((nft) => nft.bumps)
+      )
+      .accounts({
+        payer: wallet.publicKey,
+        nftCounter: nftCounterPDA,
+        collectionMetadata: collectionMetadata.publicKey,
+        collectionConfig: collectionConfigPDA,
+        mintRecord: mintRecordPDA,
+        paymentTokenMint: paymentTokenMint.publicKey,
+        payerPaymentTokenAccount,
+        treasuryTokenAccount: null,
+        rewardReserveAccount: null,
+        config: configPDA,
+        eventSequence: eventSequencePDA,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+      })
+      .remainingAccounts(
+        nfts.flatMap((nft) => [
+          { pubkey: nft.nftMint, isWritable: true, isSigner: false },
+          { pubkey: nft.nftMetadata, isWritable: true, isSigner: false },
+          { pubkey: nft.nftTokenAccount, isWritable: true, isSigner: false },
+        ])
+      );
+  }
+
+  before(async () => {
+    paymentTokenMint = Keypair.generate();
+    collectionMint = Keypair.generate();
+    collectionMetadata = Keypair.generate();
+
+    [nftCounterPDA] = PublicKey.findProgramAddressSync(
+      [Buffer.from("nft_counter")],
+      program.programId
+    );
+    [collectionConfigPDA] = PublicKey.findProgramAddressSync(
+      [Buffer.from("collection_config"), collectionMetadata.publicKey.toBuffer()],
+      program.programId
+    );
+    [mintRecordPDA] = PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("mint_record"),
+        collectionMetadata.publicKey.toBuffer(),
+        wallet.publicKey.toBuffer(),
+      ],
+      program.programId
+    );
+
+    // Criar o token de pagamento
+    await createMint(
+      connection,
+      wallet.payer,
+      wallet.publicKey,
+      wallet.publicKey,
+      9,
+      paymentTokenMint
+    );
+    const paymentTokenAccount = await getOrCreateAssociatedTokenAccount(
+      connection,
+      wallet.payer,
+      paymentTokenMint.publicKey,
+      wallet.publicKey
+    );
+    payerPaymentTokenAccount = paymentTokenAccount.address;
+    await mintTo(
+      connection,
+      wallet.payer,
+      paymentTokenMint.publicKey,
+      payerPaymentTokenAccount,
+      wallet.publicKey,
+      1000 * 10**9
+    );
+
+    await program.methods
+      .initializeCollection("Test Collection", "TEST", "https://test-uri.com")
+      .accounts({
+        payer: wallet.publicKey,
+        collectionMint: collectionMint.publicKey,
+        collectionMetadata: collectionMetadata.publicKey,
+        collectionTokenAccount: getAssociatedTokenAddressSync(
+          collectionMint.publicKey,
+          wallet.publicKey
+        ),
+        config: configPDA,
+        eventSequence: eventSequencePDA,
+        nftCounter: nftCounterPDA,
+        collectionConfig: collectionConfigPDA,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+        rent: SYSVAR_RENT_PUBKEY,
+      })
+      .signers([collectionMint, collectionMetadata])
+      .rpc();
+
+    await program.methods
+      .setPaymentToken(paymentTokenMint.publicKey)
+      .accounts({
+        admin: wallet.publicKey,
+        config: configPDA,
+        eventSequence: eventSequencePDA,
+      })
+      .rpc();
+    await program.methods
+      .configureCollection(
+        new anchor.BN(0),
+        new anchor.BN(0),
+        new anchor.BN(0),
+        new anchor.BN(MINT_PRICE),
+        new anchor.BN(0)
+      )
+      .accounts({
+        admin: wallet.publicKey,
+        config: configPDA,
+        collectionMetadata: collectionMetadata.publicKey,
+        nftCounter: nftCounterPDA,
+        collectionConfig: collectionConfigPDA,
+        eventSequence: eventSequencePDA,
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc();
+  });
+
+  it("Rejeita bumps que não geram os endereços do lote", async () => {
+    const nfts = await nextBatch(QUANTITY);
+    // Bump não canônico para os metadados do segundo NFT
+    nfts[1].bumps = { ...nfts[1].bumps, nftMetadata: nfts[1].bumps.nftMetadata - 1 };
+    try {
+      await mintBatch(nfts, QUANTITY * MINT_PRICE).rpc();
+      assert.fail("Deveria ter rejeitado bumps incorretos");
+    } catch (e) {
+      assert(e.toString().includes("InvalidBatchAccounts"), "Erro diferente do esperado: " + e);
+    }
+  });
+
+  it("Rejeita um NFT em endereço de bump não canônico", async () => {
+    const nfts = await nextBatch(QUANTITY);
+    // Endereço válido de PDA com o maior bump abaixo do canônico: burn, stake e lock
+    // derivam só o canônico, então o NFT ficaria inutilizável
+    const counter = await program.account.nftCounter.fetch(nftCounterPDA);
+    const seeds = [
+      Buffer.from("nft_mint"),
+      collectionMetadata.publicKey.toBuffer(),
+      counter.count.toArrayLike(Buffer, "le", 8),
+    ];
+    for (let bump = nfts[0].bumps.nftMint - 1; bump >= 0; bump--) {
+      try {
+        const nftMint = PublicKey.createProgramAddressSync([...seeds, Buffer.from([bump])], program.programId);
+        const [nftMetadata, metadataBump] = PublicKey.findProgramAddressSync(
+          [Buffer.from("nft_metadata"), nftMint.toBuffer()],
+          program.programId
+        );
+        nfts[0] = {
+          nftMint,
+          nftMetadata,
+          nftTokenAccount: getAssociatedTokenAddressSync(nftMint, wallet.publicKey),
+          bumps: { nftMint: bump, nftMetadata: metadataBump },
+        };
+        break;
+      } catch (e) {
+        // Bump gera ponto na curva; tentar o próximo
+      }
+    }
+    try {
+      await mintBatch(nfts, QUANTITY * MINT_PRICE).rpc();
+      assert.fail("Deveria ter rejeitado o bump não canônico");
+    } catch (e) {
+      assert(e.toString().includes("InvalidBatchAccounts"), "Erro diferente do esperado: " + e);
+    }
+  });
+
+  it("Minta o lote mesmo com um endereço pré-financiado", async () => {
+    const nfts = await nextBatch(QUANTITY);
+    const counterBefore = await program.account.nftCounter.fetch(nftCounterPDA);
+    const balanceBefore = await connection.getTokenAccountBalance(payerPaymentTokenAccount);
+
+    // Endereços dos NFTs são previsíveis: lamports enviados antes não bloqueiam o mint
+    const rentExempt = await connection.getMinimumBalanceForRentExemption(0);
+    await program.provider.sendAndConfirm(
+      new Transaction().add(
+        SystemProgram.transfer({
+          fromPubkey: wallet.publicKey,
+          toPubkey: nfts[1].nftMint,
+          lamports: rentExempt,
+        }),
+        SystemProgram.transfer({
+          fromPubkey: wallet.publicKey,
+          toPubkey: nfts[2].nftMetadata,
+          lamports: rentExempt,
+        })
+      )
+    );
+
+    // Medir o consumo do lote e pedir o limite de CU a partir dele
+    const tx = await mintBatch(nfts, QUANTITY * MINT_PRICE).transaction();
+    tx.feePayer = wallet.publicKey;
+    tx.recentBlockhash = (await connection.getLatestBlockhash()).blockhash;
+    tx.instructions.unshift(ComputeBudgetProgram.setComputeUnitLimit({ units: 1_400_000 }));
+    const simulation = await connection.simulateTransaction(tx);
+    assert.isNull(simulation.value.err, JSON.stringify(simulation.value.logs));
+    const unitsConsumed = simulation.value.unitsConsumed;
+    console.log(`      mint_batch: ${unitsConsumed} CU para ${QUANTITY} NFTs (${Math.ceil(unitsConsumed / QUANTITY)} por NFT)`);
+
+    await mintBatch(nfts, QUANTITY * MINT_PRICE)
+      .preInstructions([
+        ComputeBudgetProgram.setComputeUnitLimit({ units: Math.ceil(unitsConsumed * 1.1) }),
+      ])
+      .rpc();
+
+    for (const nft of nfts) {
+      const balance = await connection.getTokenAccountBalance(nft.nftTokenAccount);
+      assert.equal(balance.value.amount, "1");
+      const metadata = await program.account.nftMetadata.fetch(nft.nftMetadata);
+      assert(metadata.collection.equals(collectionMetadata.publicKey));
+    }
+
+    const counterAfter = await program.account.nftCounter.fetch(nftCounterPDA);
+    assert.equal(counterAfter.count.toNumber(), counterBefore.count.toNumber() + QUANTITY);
+    const balanceAfter = await connection.getTokenAccountBalance(payerPaymentTokenAccount);
+    assert.equal(
+      Number(balanceBefore.value.amount) - Number(balanceAfter.value.amount),
+      QUANTITY * MINT_PRICE
+    );
+    const record = await program.account.mintRecord.fetch(mintRecordPDA);
+    assert.equal(record.count.toNumber(), QUANTITY);
+  });
+
+  it("Rejeita lote acima do preço máximo aceito", async () => {
+    const nfts = await nextBatch(2);
+    try {
+      await mintBatch(nfts, 2 * MINT_PRICE - 1).rpc();
+      assert.fail("Deveria ter rejeitado o preço");
+    } catch (e) {
+      assert(e.toString().includes("PriceExceedsMaximum"), "Erro diferente do esperado: " + e);
+    }
+  });
+});
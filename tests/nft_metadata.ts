@@ -0,0 +1,174 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Program } from "@coral-xyz/anchor";
+import {
+  Keypair,
+  PublicKey,
+  SystemProgram,
+  SYSVAR_RENT_PUBKEY,
+} from "@solana/web3.js";
+import {
+  TOKEN_PROGRAM_ID,
+  ASSOCIATED_TOKEN_PROGRAM_ID,
+  getAssociatedTokenAddressSync,
+} from "@solana/spl-token";
+import { AdrTokenMint } from "../target/types/adr_token_mint";
+import { assert } from "chai";
+
+describe("ADR Token NFT Metadata Tests", () => {
+  anchor.setProvider(anchor.AnchorProvider.env());
+
+  const program = anchor.workspace.AdrTokenMint as Program<AdrTokenMint>;
+  const [configPDA] = PublicKey.findProgramAddressSync(
+    [Buffer.from("config")],
+    program.programId
+  );
+  const [eventSequencePDA] = PublicKey.findProgramAddressSync(
+    [Buffer.from("event_sequence")],
+    program.programId
+  );
+  // Metadados da própria coleção não têm coleção: a PDA da chave padrão não existe
+  const [noCollectionConfigPDA] = PublicKey.findProgramAddressSync(
+    [Buffer.from("collection_config"), PublicKey.default.toBuffer()],
+    program.programId
+  );
+  const wallet = program.provider.wallet;
+
+  // Keypairs e contas
+  let collectionMint: Keypair;
+  let collectionMetadata: Keypair;
+
+  before(async () => {
+    collectionMint = Keypair.generate();
+    collectionMetadata = Keypair.generate();
+
+    const [nftCounter] = PublicKey.findProgramAddressSync(
+      [Buffer.from("nft_counter")],
+      program.programId
+    );
+    const [collectionConfig] = PublicKey.findProgramAddressSync(
+      [Buffer.from("collection_config"), collectionMetadata.publicKey.toBuffer()],
+      program.programId
+    );
+    const collectionTokenAccount = getAssociatedTokenAddressSync(
+      collectionMint.publicKey,
+      wallet.publicKey
+    );
+
+    // Os metadados da coleção têm o admin como autoridade
+    await program.methods
+      .initializeCollection("Test Collection", "TEST", "https://test-uri.com")
+      .accounts({
+        payer: wallet.publicKey,
+        collectionMint: collectionMint.publicKey,
+        collectionMetadata: collectionMetadata.publicKey,
+        collectionTokenAccount,
+        config: configPDA,
+        eventSequence: eventSequencePDA,
+        nftCounter,
+        collectionConfig,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+        rent: SYSVAR_RENT_PUBKEY,
+      })
+      .signers([collectionMint, collectionMetadata])
+      .rpc();
+  });
+
+  it("Atualiza apenas os campos informados", async () => {
+    await program.methods
+      .updateNftMetadata("Renamed Collection", null, "https://new-uri.com")
+      .accounts({
+        authority: wallet.publicKey,
+        nftMetadata: collectionMetadata.publicKey,
+        collectionConfig: noCollectionConfigPDA,
+        eventSequence: eventSequencePDA,
+      })
+      .rpc();
+
+    const metadata = await program.account.nftMetadata.fetch(collectionMetadata.publicKey);
+    assert.equal(metadata.name, "Renamed Collection");
+    assert.equal(metadata.symbol, "TEST");
+    assert.equal(metadata.uri, "https://new-uri.com");
+  });
+
+  it("Rejeita atualização por quem não é a autoridade", async () => {
+    const attacker = Keypair.generate();
+    try {
+      await program.methods
+        .updateNftMetadata("Hacked", null, null)
+        .accounts({
+          authority: attacker.publicKey,
+          nftMetadata: collectionMetadata.publicKey,
+          collectionConfig: noCollectionConfigPDA,
+          eventSequence: eventSequencePDA,
+        })
+        .signers([attacker])
+        .rpc();
+      assert.fail("Deveria ter rejeitado atualização por não-autoridade");
+    } catch (e) {
+      assert(e.toString().includes("Unauthorized"), "Erro diferente do esperado: " + e);
+    }
+  });
+
+  it("Rejeita nome, símbolo e URI fora dos limites", async () => {
+    const cases: [string | null, string | null, string | null, string][] = [
+      ["a".repeat(51), null, null, "NameTooLong"],
+      [null, "S".repeat(11), null, "SymbolTooLong"],
+      [null, null, "https://" + "a".repeat(200), "UriTooLong"],
+      [null, null, "http://new-uri.com", "InvalidUriScheme"],
+    ];
+
+    for (const [name, symbol, uri, expected] of cases) {
+      try {
+        await program.methods
+          .updateNftMetadata(name, symbol, uri)
+          .accounts({
+            authority: wallet.publicKey,
+            nftMetadata: collectionMetadata.publicKey,
+            collectionConfig: noCollectionConfigPDA,
+            eventSequence: eventSequencePDA,
+          })
+          .rpc();
+        assert.fail("Deveria ter rejeitado com " + expected);
+      } catch (e) {
+        assert(e.toString().includes(expected), "Erro diferente do esperado: " + e);
+      }
+    }
+
+    const metadata = await program.account.nftMetadata.fetch(collectionMetadata.publicKey);
+    assert.equal(metadata.name, "Renamed Collection");
+    assert.equal(metadata.symbol, "TEST");
+    assert.equal(metadata.uri, "https://new-uri.com");
+  });
+
+  it("Rejeita qualquer atualização depois do lock", async () => {
+    await program.methods
+      .lockMetadata()
+      .accounts({
+        authority: wallet.publicKey,
+        nftMetadata: collectionMetadata.publicKey,
+        collectionConfig: noCollectionConfigPDA,
+        eventSequence: eventSequencePDA,
+      })
+      .rpc();
+
+    const metadata = await program.account.nftMetadata.fetch(collectionMetadata.publicKey);
+    assert.isTrue(metadata.locked);
+
+    try {
+      await program.methods
+        .updateNftMetadata("Too Late", null, null)
+        .accounts({
+          authority: wallet.publicKey,
+          nftMetadata: collectionMetadata.publicKey,
+          collectionConfig: noCollectionConfigPDA,
+          eventSequence: eventSequencePDA,
+        })
+        .rpc();
+      assert.fail("Deveria ter rejeitado atualização após o lock");
+    } catch (e) {
+      assert(e.toString().includes("MetadataLocked"), "Erro diferente do esperado: " + e);
+    }
+  });
+});
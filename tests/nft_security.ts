@@ -26,11 +26,18 @@ describe("ADR Token NFT Security Tests", () => {
   anchor.setProvider(anchor.AnchorProvider.env());
 
   const program = anchor.workspace.AdrTokenMint as Program<AdrTokenMint>;
+  const [configPDA] = PublicKey.findProgramAddressSync(
+    [Buffer.from("config")],
+    program.programId
+  );
+  const [eventSequencePDA] = PublicKey.findProgramAddressSync(
+    [Buffer.from("event_sequence")],
+    program.programId
+  );
   const wallet = program.provider.wallet;
   const connection = anchor.getProvider().connection;
 
   // Keypairs e contas
-  let configAccount: Keypair;
   let paymentTokenMint: Keypair;
   let collectionMint: Keypair;
   let collectionMetadata: Keypair;
@@ -40,7 +47,6 @@ describe("ADR Token NFT Security Tests", () => {
 
   before(async () => {
     // Gerar keypairs
-    configAccount = Keypair.generate();
     paymentTokenMint = Keypair.generate();
     collectionMint = Keypair.generate();
     collectionMetadata = Keypair.generate();
@@ -117,13 +123,14 @@ describe("ADR Token NFT Security Tests", () => {
         collectionMint: collectionMint.publicKey,
         collectionMetadata: collectionMetadata.publicKey,
         collectionTokenAccount,
-        config: configAccount.publicKey,
+        config: configPDA,
+        eventSequence: eventSequencePDA,
         tokenProgram: TOKEN_PROGRAM_ID,
         associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
         systemProgram: SystemProgram.programId,
         rent: SYSVAR_RENT_PUBKEY,
       })
-      .signers([collectionMint, collectionMetadata, configAccount])
+      .signers([collectionMint, collectionMetadata])
       .rpc();
 
     // Configurar o token de pagamento
@@ -131,7 +138,8 @@ describe("ADR Token NFT Security Tests", () => {
       .setPaymentToken(paymentTokenMint.publicKey)
       .accounts({
         admin: wallet.publicKey,
-        config: configAccount.publicKey,
+        config: configPDA,
+        eventSequence: eventSequencePDA,
       })
       .rpc();
   });
@@ -160,7 +168,7 @@ describe("ADR Token NFT Security Tests", () => {
           collectionMetadata: collectionMetadata.publicKey,
           paymentTokenMint: paymentTokenMint.publicKey,
           payerPaymentTokenAccount,
-          config: configAccount.publicKey,
+          config: configPDA,
           tokenProgram: TOKEN_PROGRAM_ID,
           associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
           systemProgram: SystemProgram.programId,
@@ -205,7 +213,7 @@ describe("ADR Token NFT Security Tests", () => {
           collectionMetadata: collectionMetadata.publicKey,
           paymentTokenMint: paymentTokenMint.publicKey,
           payerPaymentTokenAccount,
-          config: configAccount.publicKey,
+          config: configPDA,
           tokenProgram: TOKEN_PROGRAM_ID,
           associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
           systemProgram: SystemProgram.programId,
@@ -254,7 +262,7 @@ describe("ADR Token NFT Security Tests", () => {
           collectionMetadata: collectionMetadata.publicKey,
           paymentTokenMint: paymentTokenMint.publicKey,
           payerPaymentTokenAccount,
-          config: configAccount.publicKey,
+          config: configPDA,
           tokenProgram: TOKEN_PROGRAM_ID,
           associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
           systemProgram: SystemProgram.programId,
@@ -298,7 +306,7 @@ describe("ADR Token NFT Security Tests", () => {
           collectionMetadata: collectionMetadata.publicKey,
           paymentTokenMint: paymentTokenMint.publicKey,
           payerPaymentTokenAccount,
-          config: configAccount.publicKey,
+          config: configPDA,
           tokenProgram: TOKEN_PROGRAM_ID,
           associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
           systemProgram: SystemProgram.programId,
@@ -342,7 +350,7 @@ describe("ADR Token NFT Security Tests", () => {
           collectionMetadata: collectionMetadata.publicKey,
           paymentTokenMint: paymentTokenMint.publicKey,
           payerPaymentTokenAccount,
-          config: configAccount.publicKey,
+          config: configPDA,
           tokenProgram: TOKEN_PROGRAM_ID,
           associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
           systemProgram: SystemProgram.programId,
@@ -390,7 +398,7 @@ describe("ADR Token NFT Security Tests", () => {
           collectionMetadata: invalidCollectionMetadata.publicKey, // Metadata inválida
           paymentTokenMint: paymentTokenMint.publicKey,
           payerPaymentTokenAccount,
-          config: configAccount.publicKey,
+          config: configPDA,
           tokenProgram: TOKEN_PROGRAM_ID,
           associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
           systemProgram: SystemProgram.programId,
@@ -438,7 +446,7 @@ describe("ADR Token NFT Security Tests", () => {
           collectionMetadata: collectionMetadata.publicKey,
           paymentTokenMint: invalidPaymentTokenMint.publicKey, // Token inválido
           payerPaymentTokenAccount,
-          config: configAccount.publicKey,
+          config: configPDA,
           tokenProgram: TOKEN_PROGRAM_ID,
           associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
           systemProgram: SystemProgram.programId,
@@ -483,7 +491,7 @@ describe("ADR Token NFT Security Tests", () => {
           collectionMetadata: collectionMetadata.publicKey,
           paymentTokenMint: paymentTokenMint.publicKey,
           payerPaymentTokenAccount,
-          config: configAccount.publicKey,
+          config: configPDA,
           tokenProgram: TOKEN_PROGRAM_ID,
           associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
           systemProgram: SystemProgram.programId,
@@ -576,7 +584,7 @@ describe("ADR Token NFT Security Tests", () => {
           collectionMetadata: collectionMetadata.publicKey,
           paymentTokenMint: paymentTokenMint.publicKey,
           payerPaymentTokenAccount,
-          config: configAccount.publicKey,
+          config: configPDA,
           tokenProgram: TOKEN_PROGRAM_ID,
           associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
           systemProgram: SystemProgram.programId,
@@ -624,7 +632,7 @@ describe("ADR Token NFT Security Tests", () => {
           collectionMetadata: collectionMetadata.publicKey,
           paymentTokenMint: paymentTokenMint.publicKey,
           payerPaymentTokenAccount,
-          config: configAccount.publicKey,
+          config: configPDA,
           tokenProgram: TOKEN_PROGRAM_ID,
           associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
           systemProgram: SystemProgram.programId,
@@ -657,7 +665,7 @@ describe("ADR Token NFT Security Tests", () => {
           collectionMetadata: collectionMetadata.publicKey,
           paymentTokenMint: paymentTokenMint.publicKey,
           payerPaymentTokenAccount,
-          config: configAccount.publicKey,
+          config: configPDA,
           tokenProgram: TOKEN_PROGRAM_ID,
           associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
           systemProgram: SystemProgram.programId,
@@ -683,7 +691,8 @@ describe("ADR Token NFT Security Tests", () => {
       .setEmergencyPause(true)
       .accounts({
         admin: wallet.publicKey,
-        config: configAccount.publicKey,
+        config: configPDA,
+        eventSequence: eventSequencePDA,
       })
       .rpc();
     
@@ -710,7 +719,7 @@ describe("ADR Token NFT Security Tests", () => {
           collectionMetadata: collectionMetadata.publicKey,
           paymentTokenMint: paymentTokenMint.publicKey,
           payerPaymentTokenAccount,
-          config: configAccount.publicKey,
+          config: configPDA,
           tokenProgram: TOKEN_PROGRAM_ID,
           associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
           systemProgram: SystemProgram.programId,
@@ -734,7 +743,8 @@ describe("ADR Token NFT Security Tests", () => {
       .setEmergencyPause(false)
       .accounts({
         admin: wallet.publicKey,
-        config: configAccount.publicKey,
+        config: configPDA,
+        eventSequence: eventSequencePDA,
       })
       .rpc();
   });
@@ -763,7 +773,7 @@ describe("ADR Token NFT Security Tests", () => {
           collectionMetadata: collectionMetadata.publicKey,
           paymentTokenMint: paymentTokenMint.publicKey,
           payerPaymentTokenAccount: attackerTokenAccount, // Conta do atacante
-          config: configAccount.publicKey,
+          config: configPDA,
           tokenProgram: TOKEN_PROGRAM_ID,
           associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
           systemProgram: SystemProgram.programId,
@@ -793,7 +803,8 @@ describe("ADR Token NFT Security Tests", () => {
         .configureStaking(true, new anchor.BN(2000)) // 20%
         .accounts({
           admin: attackerWallet.publicKey, // Usando a carteira do atacante
-          config: configAccount.publicKey,
+          config: configPDA,
+          eventSequence: eventSequencePDA,
         })
         .signers([attackerWallet])
         .rpc();
@@ -816,7 +827,7 @@ describe("ADR Token NFT Security Tests", () => {
         .updateAdmin(attackerWallet.publicKey)
         .accounts({
           current_admin: attackerWallet.publicKey, // Usando a carteira do atacante
-          config: configAccount.publicKey,
+          config: configPDA,
         })
         .signers([attackerWallet])
         .rpc();
@@ -839,7 +850,8 @@ describe("ADR Token NFT Security Tests", () => {
         .setEmergencyPause(true)
         .accounts({
           admin: attackerWallet.publicKey, // Usando a carteira do atacante
-          config: configAccount.publicKey,
+          config: configPDA,
+          eventSequence: eventSequencePDA,
         })
         .signers([attackerWallet])
         .rpc();
@@ -862,7 +874,7 @@ describe("ADR Token NFT Security Tests", () => {
         .updateMaxStakeAmount(new anchor.BN(1000000 * 10**9)) // 1 milhão de tokens
         .accounts({
           admin: attackerWallet.publicKey, // Usando a carteira do atacante
-          config: configAccount.publicKey,
+          config: configPDA,
         })
         .signers([attackerWallet])
         .rpc();
@@ -0,0 +1,369 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Program } from "@coral-xyz/anchor";
+import {
+  Keypair,
+  PublicKey,
+  SystemProgram,
+  SYSVAR_RENT_PUBKEY,
+} from "@solana/web3.js";
+import {
+  TOKEN_PROGRAM_ID,
+  ASSOCIATED_TOKEN_PROGRAM_ID,
+  getAssociatedTokenAddressSync,
+  createMint,
+  mintTo,
+  getOrCreateAssociatedTokenAccount
+} from "@solana/spl-token";
+import { AdrTokenMint } from "../target/types/adr_token_mint";
+import { assert } from "chai";
+
+describe("ADR Token NFT Staking Tests", () => {
+  anchor.setProvider(anchor.AnchorProvider.env());
+
+  const program = anchor.workspace.AdrTokenMint as Program<AdrTokenMint>;
+  const [configPDA] = PublicKey.findProgramAddressSync(
+    [Buffer.from("config")],
+    program.programId
+  );
+  const [eventSequencePDA] = PublicKey.findProgramAddressSync(
+    [Buffer.from("event_sequence")],
+    program.programId
+  );
+  const wallet = program.provider.wallet;
+  const connection = anchor.getProvider().connection;
+  const parser = new anchor.EventParser(program.programId, program.coder);
+
+  const NFT_REWARD_RATE = 1000; // tokens (menor unidade) por segundo
+  const POOL_DEPOSIT = 100 * 10**9;
+
+  let paymentTokenMint: Keypair;
+  let collectionMint: Keypair;
+  let collectionMetadata: Keypair;
+  let payerPaymentTokenAccount: PublicKey;
+  let nftCounterPDA: PublicKey;
+  let collectionConfigPDA: PublicKey;
+  let stakeAuthorityPDA: PublicKey;
+  let nftRewardAuthorityPDA: PublicKey;
+  let nftRewardPool: PublicKey;
+  let nftMint: PublicKey;
+  let nftMetadata: PublicKey;
+  let nftStakePDA: PublicKey;
+
+  function collectionConfigFor(collection: PublicKey) {
+    return PublicKey.findProgramAddressSync(
+      [Buffer.from("collection_config"), collection.toBuffer()],
+      program.programId
+    )[0];
+  }
+
+  function stakeNftAccounts(collection: PublicKey) {
+    return {
+      staker: wallet.publicKey,
+      nftMint,
+      nftMetadata,
+      collectionMetadata: collection,
+      collectionConfig: collectionConfigFor(collection),
+      stakerNftAccount: getAssociatedTokenAddressSync(nftMint, wallet.publicKey),
+      nftVault: getAssociatedTokenAddressSync(nftMint, stakeAuthorityPDA, true),
+      stakeAuthority: stakeAuthorityPDA,
+      nftStake: nftStakePDA,
+      config: configPDA,
+      eventSequence: eventSequencePDA,
+      tokenProgram: TOKEN_PROGRAM_ID,
+      associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+      systemProgram: SystemProgram.programId,
+    };
+  }
+
+  function unstakeNftAccounts(pool: PublicKey | null = nftRewardPool) {
+    return {
+      staker: wallet.publicKey,
+      nftMint,
+      collectionMetadata: collectionMetadata.publicKey,
+      collectionConfig: collectionConfigPDA,
+      nftStake: nftStakePDA,
+      stakerNftAccount: getAssociatedTokenAddressSync(nftMint, wallet.publicKey),
+      nftVault: getAssociatedTokenAddressSync(nftMint, stakeAuthorityPDA, true),
+      tokenMint: paymentTokenMint.publicKey,
+      stakerTokenAccount: payerPaymentTokenAccount,
+      nftRewardAuthority: nftRewardAuthorityPDA,
+      nftRewardPool: pool,
+      stakeAuthority: stakeAuthorityPDA,
+      config: configPDA,
+      eventSequence: eventSequencePDA,
+      tokenProgram: TOKEN_PROGRAM_ID,
+      associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+      systemProgram: SystemProgram.programId,
+    };
+  }
+
+  function setNftRewardRate(rate: number | anchor.BN) {
+    return program.methods
+      .setNftRewardRate(new anchor.BN(rate))
+      .accounts({
+        admin: wallet.publicKey,
+        config: configPDA,
+        collectionMetadata: collectionMetadata.publicKey,
+        collectionConfig: collectionConfigPDA,
+        eventSequence: eventSequencePDA,
+      })
+      .rpc();
+  }
+
+  before(async () => {
+    paymentTokenMint = Keypair.generate();
+    collectionMint = Keypair.generate();
+    collectionMetadata = Keypair.generate();
+
+    [nftCounterPDA] = PublicKey.findProgramAddressSync(
+      [Buffer.from("nft_counter")],
+      program.programId
+    );
+    [stakeAuthorityPDA] = PublicKey.findProgramAddressSync(
+      [Buffer.from("stake_authority")],
+      program.programId
+    );
+    collectionConfigPDA = collectionConfigFor(collectionMetadata.publicKey);
+    [nftRewardAuthorityPDA] = PublicKey.findProgramAddressSync(
+      [Buffer.from("nft_reward_authority"), collectionMetadata.publicKey.toBuffer()],
+      program.programId
+    );
+    nftRewardPool = getAssociatedTokenAddressSync(
+      paymentTokenMint.publicKey,
+      nftRewardAuthorityPDA,
+      true
+    );
+
+    // Criar o token de pagamento
+    await createMint(
+      connection,
+      wallet.payer,
+      wallet.publicKey,
+      wallet.publicKey,
+      9,
+      paymentTokenMint
+    );
+    const paymentTokenAccount = await getOrCreateAssociatedTokenAccount(
+      connection,
+      wallet.payer,
+      paymentTokenMint.publicKey,
+      wallet.publicKey
+    );
+    payerPaymentTokenAccount = paymentTokenAccount.address;
+    await mintTo(
+      connection,
+      wallet.payer,
+      paymentTokenMint.publicKey,
+      payerPaymentTokenAccount,
+      wallet.publicKey,
+      1000 * 10**9
+    );
+
+    await program.methods
+      .initializeCollection("Test Collection", "TEST", "https://test-uri.com")
+      .accounts({
+        payer: wallet.publicKey,
+        collectionMint: collectionMint.publicKey,
+        collectionMetadata: collectionMetadata.publicKey,
+        collectionTokenAccount: getAssociatedTokenAddressSync(
+          collectionMint.publicKey,
+          wallet.publicKey
+        ),
+        config: configPDA,
+        eventSequence: eventSequencePDA,
+        nftCounter: nftCounterPDA,
+        collectionConfig: collectionConfigPDA,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+        rent: SYSVAR_RENT_PUBKEY,
+      })
+      .signers([collectionMint, collectionMetadata])
+      .rpc();
+
+    await program.methods
+      .setPaymentToken(paymentTokenMint.publicKey)
+      .accounts({ admin: wallet.publicKey, config: configPDA, eventSequence: eventSequencePDA })
+      .rpc();
+    // Os NFTs deste teste são mintados de graça (preço fixo zero)
+    await program.methods
+      .setPricingCurve({ fixed: {} }, new anchor.BN(0), [], true)
+      .accounts({
+        admin: wallet.publicKey,
+        config: configPDA,
+        collectionMetadata: collectionMetadata.publicKey,
+        nftCounter: nftCounterPDA,
+        collectionConfig: collectionConfigPDA,
+        eventSequence: eventSequencePDA,
+      })
+      .rpc();
+    await program.methods
+      .configureStaking(true, new anchor.BN(1000))
+      .accounts({ admin: wallet.publicKey, config: configPDA, eventSequence: eventSequencePDA })
+      .rpc();
+
+    // Pool de recompensas da coleção, separada da reserva que guarda o principal de stake_tokens
+    await program.methods
+      .depositNftRewardPool(new anchor.BN(POOL_DEPOSIT))
+      .accounts({
+        admin: wallet.publicKey,
+        config: configPDA,
+        collectionMetadata: collectionMetadata.publicKey,
+        nftRewardAuthority: nftRewardAuthorityPDA,
+        nftRewardPool,
+        adminTokenAccount: payerPaymentTokenAccount,
+        paymentTokenMint: paymentTokenMint.publicKey,
+        eventSequence: eventSequencePDA,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc();
+
+    await setNftRewardRate(NFT_REWARD_RATE);
+
+    // Mintar o NFT que será colocado em stake
+    const counter = await program.account.nftCounter.fetch(nftCounterPDA);
+    [nftMint] = PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("nft_mint"),
+        collectionMetadata.publicKey.toBuffer(),
+        counter.count.toArrayLike(Buffer, "le", 8),
+      ],
+      program.programId
+    );
+    [nftMetadata] = PublicKey.findProgramAddressSync(
+      [Buffer.from("nft_metadata"), nftMint.toBuffer()],
+      program.programId
+    );
+    [nftStakePDA] = PublicKey.findProgramAddressSync(
+      [Buffer.from("nft_stake"), nftMint.toBuffer()],
+      program.programId
+    );
+    const [mintRecord] = PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("mint_record"),
+        collectionMetadata.publicKey.toBuffer(),
+        wallet.publicKey.toBuffer(),
+      ],
+      program.programId
+    );
+    await program.methods
+      .mintNftWithPayment("Test NFT", "TNFT", "https://test-nft-uri.com", new anchor.BN(0))
+      .accounts({
+        payer: wallet.publicKey,
+        nftCounter: nftCounterPDA,
+        nftMint,
+        nftMetadata,
+        nftTokenAccount: getAssociatedTokenAddressSync(nftMint, wallet.publicKey),
+        collectionMetadata: collectionMetadata.publicKey,
+        collectionConfig: collectionConfigPDA,
+        mintRecord,
+        paymentTokenMint: paymentTokenMint.publicKey,
+        payerPaymentTokenAccount,
+        treasuryTokenAccount: null,
+        rewardReserveAccount: null,
+        config: configPDA,
+        eventSequence: eventSequencePDA,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        nftTokenProgram: TOKEN_PROGRAM_ID,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+        rent: SYSVAR_RENT_PUBKEY,
+      })
+      .rpc();
+  });
+
+  it("Rejeita stake de NFT informando outra coleção", async () => {
+    try {
+      // Os metadados do próprio NFT não são uma coleção registrada
+      await program.methods
+        .stakeNft()
+        .accounts(stakeNftAccounts(nftMetadata))
+        .rpc();
+      assert.fail("Deveria ter rejeitado NFT de outra coleção");
+    } catch (e) {
+      assert(!e.toString().includes("Deveria"), "Stake não foi rejeitado: " + e);
+    }
+  });
+
+  it("Bloqueia o NFT no cofre e paga recompensas no unstake", async () => {
+    await program.methods
+      .stakeNft()
+      .accounts(stakeNftAccounts(collectionMetadata.publicKey))
+      .rpc();
+
+    const vault = getAssociatedTokenAddressSync(nftMint, stakeAuthorityPDA, true);
+    const vaultBalance = await connection.getTokenAccountBalance(vault);
+    assert.equal(vaultBalance.value.amount, "1");
+
+    await new Promise((resolve) => setTimeout(resolve, 2000));
+
+    const balanceBefore = await connection.getTokenAccountBalance(payerPaymentTokenAccount);
+    await program.methods
+      .unstakeNft()
+      .accounts(unstakeNftAccounts())
+      .rpc();
+
+    const balanceAfter = await connection.getTokenAccountBalance(payerPaymentTokenAccount);
+    const reward = Number(balanceAfter.value.amount) - Number(balanceBefore.value.amount);
+    assert(reward >= NFT_REWARD_RATE, "Recompensa deveria refletir o tempo em stake");
+    assert(reward % NFT_REWARD_RATE === 0, "Recompensa deveria ser múltiplo da taxa");
+
+    assert.isNull(await connection.getAccountInfo(vault));
+    assert.isNull(await connection.getAccountInfo(nftStakePDA));
+  });
+
+  it("Devolve o NFT e paga só o saldo da pool quando a recompensa passa dele", async () => {
+    // Taxa que acumula mais do que a pool tem já no primeiro segundo
+    await setNftRewardRate(new anchor.BN(POOL_DEPOSIT).muln(10));
+    await program.methods
+      .stakeNft()
+      .accounts(stakeNftAccounts(collectionMetadata.publicKey))
+      .rpc();
+    await new Promise((resolve) => setTimeout(resolve, 2000));
+
+    const pool = Number((await connection.getTokenAccountBalance(nftRewardPool)).value.amount);
+    const balanceBefore = await connection.getTokenAccountBalance(payerPaymentTokenAccount);
+    const signature = await program.methods
+      .unstakeNft()
+      .accounts(unstakeNftAccounts())
+      .rpc({ commitment: "confirmed" });
+
+    const nftAccount = getAssociatedTokenAddressSync(nftMint, wallet.publicKey);
+    assert.equal((await connection.getTokenAccountBalance(nftAccount)).value.amount, "1");
+    const balanceAfter = await connection.getTokenAccountBalance(payerPaymentTokenAccount);
+    assert.equal(
+      Number(balanceAfter.value.amount) - Number(balanceBefore.value.amount),
+      pool
+    );
+    assert.equal((await connection.getTokenAccountBalance(nftRewardPool)).value.amount, "0");
+
+    const tx = await connection.getTransaction(signature, {
+      commitment: "confirmed",
+      maxSupportedTransactionVersion: 0,
+    });
+    const event = Array.from(parser.parseLogs(tx.meta?.logMessages || []))
+      .find((e) => e.name === "nftUnstakingEvent");
+    assert(event !== undefined, "NftUnstakingEvent não encontrado");
+    const data = event.data as any;
+    assert.equal(data.rewardAmount.toNumber(), pool);
+    assert(data.unpaidReward.gtn(0), "A parte não paga deveria ser registrada");
+  });
+
+  it("Devolve o NFT sem recompensa quando a pool não é informada", async () => {
+    await program.methods
+      .stakeNft()
+      .accounts(stakeNftAccounts(collectionMetadata.publicKey))
+      .rpc();
+
+    await program.methods
+      .unstakeNft()
+      .accounts(unstakeNftAccounts(null))
+      .rpc();
+
+    const nftAccount = getAssociatedTokenAddressSync(nftMint, wallet.publicKey);
+    assert.equal((await connection.getTokenAccountBalance(nftAccount)).value.amount, "1");
+    assert.isNull(await connection.getAccountInfo(nftStakePDA));
+  });
+});
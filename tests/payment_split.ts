@@ -0,0 +1,323 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Program } from "@coral-xyz/anchor";
+import {
+  Keypair,
+  PublicKey,
+  SystemProgram,
+  SYSVAR_RENT_PUBKEY,
+} from "@solana/web3.js";
+import {
+  TOKEN_PROGRAM_ID,
+  ASSOCIATED_TOKEN_PROGRAM_ID,
+  getAssociatedTokenAddressSync,
+  createMint,
+  getMint,
+  mintTo,
+  getOrCreateAssociatedTokenAccount
+} from "@solana/spl-token";
+import { AdrTokenMint } from "../target/types/adr_token_mint";
+import { assert } from "chai";
+
+describe("ADR Token Payment Split Tests", () => {
+  anchor.setProvider(anchor.AnchorProvider.env());
+
+  const program = anchor.workspace.AdrTokenMint as Program<AdrTokenMint>;
+  const [configPDA] = PublicKey.findProgramAddressSync(
+    [Buffer.from("config")],
+    program.programId
+  );
+  const [eventSequencePDA] = PublicKey.findProgramAddressSync(
+    [Buffer.from("event_sequence")],
+    program.programId
+  );
+  const [stakeAuthorityPDA] = PublicKey.findProgramAddressSync(
+    [Buffer.from("stake_authority")],
+    program.programId
+  );
+  const wallet = program.provider.wallet;
+  const connection = anchor.getProvider().connection;
+  const parser = new anchor.EventParser(program.programId, program.coder);
+
+  const MINT_PRICE = 10 * 10**9;
+  const BURN_BPS = 5000;
+  const TREASURY_BPS = 3000;
+  const RESERVE_BPS = 2000;
+
+  // Keypairs e contas
+  let paymentTokenMint: Keypair;
+  let collectionMint: Keypair;
+  let collectionMetadata: Keypair;
+  let payerPaymentTokenAccount: PublicKey;
+  let treasuryTokenAccount: PublicKey;
+  let rewardReserve: PublicKey;
+  let nftCounterPDA: PublicKey;
+  let collectionConfigPDA: PublicKey;
+  let mintRecordPDA: PublicKey;
+
+  async function tokenBalance(account: PublicKey) {
+    return Number((await connection.getTokenAccountBalance(account)).value.amount);
+  }
+
+  // Minta o NFT do próximo índice do contador global e retorna a assinatura
+  async function mintNext({
+    paymentMint = paymentTokenMint.publicKey,
+    paymentAccount = payerPaymentTokenAccount,
+    treasury = treasuryTokenAccount as PublicKey | null,
+  } = {}) {
+    const counter = await program.account.nftCounter.fetch(nftCounterPDA);
+    const [nftMint] = PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("nft_mint"),
+        collectionMetadata.publicKey.toBuffer(),
+        counter.count.toArrayLike(Buffer, "le", 8),
+      ],
+      program.programId
+    );
+    const [nftMetadata] = PublicKey.findProgramAddressSync(
+      [Buffer.from("nft_metadata"), nftMint.toBuffer()],
+      program.programId
+    );
+
+    return program.methods
+      .mintNftWithPayment("Test NFT", "TNFT", "https://test-nft-uri.com", new anchor.BN(MINT_PRICE))
+      .accounts({
+        payer: wallet.publicKey,
+        nftCounter: nftCounterPDA,
+        nftMint,
+        nftMetadata,
+        nftTokenAccount: getAssociatedTokenAddressSync(nftMint, wallet.publicKey),
+        collectionMetadata: collectionMetadata.publicKey,
+        collectionConfig: collectionConfigPDA,
+        mintRecord: mintRecordPDA,
+        paymentTokenMint: paymentMint,
+        payerPaymentTokenAccount: paymentAccount,
+        treasuryTokenAccount: treasury,
+        rewardReserveAccount: rewardReserve,
+        config: configPDA,
+        eventSequence: eventSequencePDA,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        nftTokenProgram: TOKEN_PROGRAM_ID,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+        rent: SYSVAR_RENT_PUBKEY,
+      })
+      .rpc({ commitment: "confirmed" });
+  }
+
+  function setPaymentToken(mint: PublicKey) {
+    return program.methods
+      .setPaymentToken(mint)
+      .accounts({
+        admin: wallet.publicKey,
+        config: configPDA,
+        eventSequence: eventSequencePDA,
+      })
+      .rpc();
+  }
+
+  before(async () => {
+    paymentTokenMint = Keypair.generate();
+    collectionMint = Keypair.generate();
+    collectionMetadata = Keypair.generate();
+
+    [nftCounterPDA] = PublicKey.findProgramAddressSync(
+      [Buffer.from("nft_counter")],
+      program.programId
+    );
+    [collectionConfigPDA] = PublicKey.findProgramAddressSync(
+      [Buffer.from("collection_config"), collectionMetadata.publicKey.toBuffer()],
+      program.programId
+    );
+    [mintRecordPDA] = PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("mint_record"),
+        collectionMetadata.publicKey.toBuffer(),
+        wallet.publicKey.toBuffer(),
+      ],
+      program.programId
+    );
+    rewardReserve = getAssociatedTokenAddressSync(
+      paymentTokenMint.publicKey,
+      stakeAuthorityPDA,
+      true
+    );
+
+    // Criar o token de pagamento
+    await createMint(
+      connection,
+      wallet.payer,
+      wallet.publicKey,
+      wallet.publicKey,
+      9,
+      paymentTokenMint
+    );
+    const paymentTokenAccount = await getOrCreateAssociatedTokenAccount(
+      connection,
+      wallet.payer,
+      paymentTokenMint.publicKey,
+      wallet.publicKey
+    );
+    payerPaymentTokenAccount = paymentTokenAccount.address;
+    await mintTo(
+      connection,
+      wallet.payer,
+      paymentTokenMint.publicKey,
+      payerPaymentTokenAccount,
+      wallet.publicKey,
+      1000 * 10**9
+    );
+
+    // Tesouraria de uma carteira separada, no mesmo token de pagamento
+    const treasuryOwner = Keypair.generate();
+    treasuryTokenAccount = (
+      await getOrCreateAssociatedTokenAccount(
+        connection,
+        wallet.payer,
+        paymentTokenMint.publicKey,
+        treasuryOwner.publicKey
+      )
+    ).address;
+
+    await program.methods
+      .initializeCollection("Test Collection", "TEST", "https://test-uri.com")
+      .accounts({
+        payer: wallet.publicKey,
+        collectionMint: collectionMint.publicKey,
+        collectionMetadata: collectionMetadata.publicKey,
+        collectionTokenAccount: getAssociatedTokenAddressSync(
+          collectionMint.publicKey,
+          wallet.publicKey
+        ),
+        config: configPDA,
+        eventSequence: eventSequencePDA,
+        nftCounter: nftCounterPDA,
+        collectionConfig: collectionConfigPDA,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+        rent: SYSVAR_RENT_PUBKEY,
+      })
+      .signers([collectionMint, collectionMetadata])
+      .rpc();
+
+    await setPaymentToken(paymentTokenMint.publicKey);
+    await program.methods
+      .initializeRewardReserve()
+      .accounts({
+        admin: wallet.publicKey,
+        rewardReserveAccount: rewardReserve,
+        tokenMint: paymentTokenMint.publicKey,
+        stakeAuthority: stakeAuthorityPDA,
+        config: configPDA,
+        eventSequence: eventSequencePDA,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+        rent: SYSVAR_RENT_PUBKEY,
+      })
+      .rpc();
+    await program.methods
+      .configureCollection(
+        new anchor.BN(0),
+        new anchor.BN(0),
+        new anchor.BN(0),
+        new anchor.BN(MINT_PRICE),
+        new anchor.BN(0)
+      )
+      .accounts({
+        admin: wallet.publicKey,
+        config: configPDA,
+        collectionMetadata: collectionMetadata.publicKey,
+        nftCounter: nftCounterPDA,
+        collectionConfig: collectionConfigPDA,
+        eventSequence: eventSequencePDA,
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc();
+    await program.methods
+      .setPaymentSplit(BURN_BPS, TREASURY_BPS, RESERVE_BPS)
+      .accounts({
+        admin: wallet.publicKey,
+        config: configPDA,
+        collectionMetadata: collectionMetadata.publicKey,
+        collectionConfig: collectionConfigPDA,
+        treasuryTokenAccount,
+        eventSequence: eventSequencePDA,
+      })
+      .rpc();
+  });
+
+  it("Divide o pagamento entre queima, tesouraria e reserva", async () => {
+    const payerBefore = await tokenBalance(payerPaymentTokenAccount);
+    const treasuryBefore = await tokenBalance(treasuryTokenAccount);
+    const reserveBefore = await tokenBalance(rewardReserve);
+    const supplyBefore = (await getMint(connection, paymentTokenMint.publicKey)).supply;
+
+    const signature = await mintNext();
+
+    const burned = MINT_PRICE * BURN_BPS / 10000;
+    const toTreasury = MINT_PRICE * TREASURY_BPS / 10000;
+    const toReserve = MINT_PRICE * RESERVE_BPS / 10000;
+    assert.equal(payerBefore - await tokenBalance(payerPaymentTokenAccount), MINT_PRICE);
+    assert.equal(await tokenBalance(treasuryTokenAccount) - treasuryBefore, toTreasury);
+    assert.equal(await tokenBalance(rewardReserve) - reserveBefore, toReserve);
+    const supplyAfter = (await getMint(connection, paymentTokenMint.publicKey)).supply;
+    assert.equal(Number(supplyBefore - supplyAfter), burned);
+
+    // amount continua sendo o total pago; a parte queimada vem em burn_amount
+    const tx = await connection.getTransaction(signature, {
+      commitment: "confirmed",
+      maxSupportedTransactionVersion: 0,
+    });
+    const event = Array.from(parser.parseLogs(tx.meta?.logMessages || []))
+      .find((e) => e.name === "tokenBurnEvent");
+    assert(event !== undefined, "TokenBurnEvent não encontrado");
+    const data = event.data as any;
+    assert.equal(data.amount.toNumber(), MINT_PRICE);
+    assert.equal(data.price.toNumber(), MINT_PRICE);
+    assert.equal(data.burnAmount.toNumber(), burned);
+    assert.equal(data.treasuryAmount.toNumber(), toTreasury);
+    assert.equal(data.reserveAmount.toNumber(), toReserve);
+  });
+
+  it("Rejeita mint sem a tesouraria quando ela recebe parte do pagamento", async () => {
+    try {
+      await mintNext({ treasury: null });
+      assert.fail("Deveria ter rejeitado o mint sem tesouraria");
+    } catch (e) {
+      assert(e.toString().includes("InvalidTreasury"), "Erro diferente do esperado: " + e);
+    }
+  });
+
+  it("Rejeita tesouraria em outro token depois de trocar o token de pagamento", async () => {
+    const otherMint = Keypair.generate();
+    await createMint(connection, wallet.payer, wallet.publicKey, null, 9, otherMint);
+    const otherAccount = await getOrCreateAssociatedTokenAccount(
+      connection,
+      wallet.payer,
+      otherMint.publicKey,
+      wallet.publicKey
+    );
+    await mintTo(
+      connection,
+      wallet.payer,
+      otherMint.publicKey,
+      otherAccount.address,
+      wallet.publicKey,
+      100 * 10**9
+    );
+
+    await setPaymentToken(otherMint.publicKey);
+    try {
+      await mintNext({
+        paymentMint: otherMint.publicKey,
+        paymentAccount: otherAccount.address,
+      });
+      assert.fail("Deveria ter rejeitado a tesouraria no token antigo");
+    } catch (e) {
+      assert(e.toString().includes("InvalidPaymentToken"), "Erro diferente do esperado: " + e);
+    } finally {
+      await setPaymentToken(paymentTokenMint.publicKey);
+    }
+  });
+});
@@ -0,0 +1,312 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Program } from "@coral-xyz/anchor";
+import {
+  Keypair,
+  LAMPORTS_PER_SOL,
+  PublicKey,
+  SystemProgram,
+  SYSVAR_RENT_PUBKEY,
+} from "@solana/web3.js";
+import {
+  TOKEN_PROGRAM_ID,
+  ASSOCIATED_TOKEN_PROGRAM_ID,
+  getAssociatedTokenAddressSync,
+  createMint,
+  mintTo,
+  getOrCreateAssociatedTokenAccount
+} from "@solana/spl-token";
+import { AdrTokenMint } from "../target/types/adr_token_mint";
+import { assert } from "chai";
+
+describe("ADR Token Redemption Tests", () => {
+  anchor.setProvider(anchor.AnchorProvider.env());
+
+  const program = anchor.workspace.AdrTokenMint as Program<AdrTokenMint>;
+  const [configPDA] = PublicKey.findProgramAddressSync(
+    [Buffer.from("config")],
+    program.programId
+  );
+  const [eventSequencePDA] = PublicKey.findProgramAddressSync(
+    [Buffer.from("event_sequence")],
+    program.programId
+  );
+  const wallet = program.provider.wallet;
+  const connection = anchor.getProvider().connection;
+
+  const MINT_PRICE = 10 * 10**9;
+  const REDEMPTION_AMOUNT = 4 * 10**9;
+
+  // Keypairs e contas
+  let paymentTokenMint: Keypair;
+  let collectionMint: Keypair;
+  let collectionMetadata: Keypair;
+  let payerPaymentTokenAccount: PublicKey;
+  let nftCounterPDA: PublicKey;
+  let collectionConfigPDA: PublicKey;
+  let mintRecordPDA: PublicKey;
+  let redemptionAuthorityPDA: PublicKey;
+  let redemptionPool: PublicKey;
+
+  // Minta o NFT do próximo índice do contador global e retorna suas contas
+  async function mintNext() {
+    const counter = await program.account.nftCounter.fetch(nftCounterPDA);
+    const [nftMint] = PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("nft_mint"),
+        collectionMetadata.publicKey.toBuffer(),
+        counter.count.toArrayLike(Buffer, "le", 8),
+      ],
+      program.programId
+    );
+    const [nftMetadata] = PublicKey.findProgramAddressSync(
+      [Buffer.from("nft_metadata"), nftMint.toBuffer()],
+      program.programId
+    );
+    const nftTokenAccount = getAssociatedTokenAddressSync(nftMint, wallet.publicKey);
+
+    await program.methods
+      .mintNftWithPayment("Test NFT", "TNFT", "https://test-nft-uri.com", new anchor.BN(MINT_PRICE))
+      .accounts({
+        payer: wallet.publicKey,
+        nftCounter: nftCounterPDA,
+        nftMint,
+        nftMetadata,
+        nftTokenAccount,
+        collectionMetadata: collectionMetadata.publicKey,
+        collectionConfig: collectionConfigPDA,
+        mintRecord: mintRecordPDA,
+        paymentTokenMint: paymentTokenMint.publicKey,
+        payerPaymentTokenAccount,
+        treasuryTokenAccount: null,
+        rewardReserveAccount: null,
+        config: configPDA,
+        eventSequence: eventSequencePDA,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        nftTokenProgram: TOKEN_PROGRAM_ID,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+        rent: SYSVAR_RENT_PUBKEY,
+      })
+      .rpc();
+    return { nftMint, nftMetadata, nftTokenAccount };
+  }
+
+  function burn(nft: Awaited<ReturnType<typeof mintNext>>) {
+    return program.methods
+      .burnNft()
+      .accounts({
+        holder: wallet.publicKey,
+        nftMint: nft.nftMint,
+        nftMetadata: nft.nftMetadata,
+        nftTokenAccount: nft.nftTokenAccount,
+        collectionMetadata: collectionMetadata.publicKey,
+        collectionConfig: collectionConfigPDA,
+        redemptionAuthority: redemptionAuthorityPDA,
+        redemptionPool,
+        holderPaymentTokenAccount: payerPaymentTokenAccount,
+        config: configPDA,
+        eventSequence: eventSequencePDA,
+        nftTokenProgram: TOKEN_PROGRAM_ID,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .rpc();
+  }
+
+  function depositAccounts(admin: PublicKey) {
+    return {
+      admin,
+      config: configPDA,
+      collectionMetadata: collectionMetadata.publicKey,
+      redemptionAuthority: redemptionAuthorityPDA,
+      redemptionPool,
+      adminTokenAccount: getAssociatedTokenAddressSync(paymentTokenMint.publicKey, admin),
+      paymentTokenMint: paymentTokenMint.publicKey,
+      eventSequence: eventSequencePDA,
+      tokenProgram: TOKEN_PROGRAM_ID,
+      associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+      systemProgram: SystemProgram.programId,
+    };
+  }
+
+  before(async () => {
+    paymentTokenMint = Keypair.generate();
+    collectionMint = Keypair.generate();
+    collectionMetadata = Keypair.generate();
+
+    [nftCounterPDA] = PublicKey.findProgramAddressSync(
+      [Buffer.from("nft_counter")],
+      program.programId
+    );
+    [collectionConfigPDA] = PublicKey.findProgramAddressSync(
+      [Buffer.from("collection_config"), collectionMetadata.publicKey.toBuffer()],
+      program.programId
+    );
+    [mintRecordPDA] = PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("mint_record"),
+        collectionMetadata.publicKey.toBuffer(),
+        wallet.publicKey.toBuffer(),
+      ],
+      program.programId
+    );
+    [redemptionAuthorityPDA] = PublicKey.findProgramAddressSync(
+      [Buffer.from("redemption_authority"), collectionMetadata.publicKey.toBuffer()],
+      program.programId
+    );
+    // A pool é a ATA da autoridade PDA (fora da curva) no token de pagamento
+    redemptionPool = getAssociatedTokenAddressSync(
+      paymentTokenMint.publicKey,
+      redemptionAuthorityPDA,
+      true
+    );
+
+    // Criar o token de pagamento
+    await createMint(
+      connection,
+      wallet.payer,
+      wallet.publicKey,
+      wallet.publicKey,
+      9,
+      paymentTokenMint
+    );
+    const paymentTokenAccount = await getOrCreateAssociatedTokenAccount(
+      connection,
+      wallet.payer,
+      paymentTokenMint.publicKey,
+      wallet.publicKey
+    );
+    payerPaymentTokenAccount = paymentTokenAccount.address;
+    await mintTo(
+      connection,
+      wallet.payer,
+      paymentTokenMint.publicKey,
+      payerPaymentTokenAccount,
+      wallet.publicKey,
+      1000 * 10**9
+    );
+
+    await program.methods
+      .initializeCollection("Test Collection", "TEST", "https://test-uri.com")
+      .accounts({
+        payer: wallet.publicKey,
+        collectionMint: collectionMint.publicKey,
+        collectionMetadata: collectionMetadata.publicKey,
+        collectionTokenAccount: getAssociatedTokenAddressSync(
+          collectionMint.publicKey,
+          wallet.publicKey
+        ),
+        config: configPDA,
+        eventSequence: eventSequencePDA,
+        nftCounter: nftCounterPDA,
+        collectionConfig: collectionConfigPDA,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+        rent: SYSVAR_RENT_PUBKEY,
+      })
+      .signers([collectionMint, collectionMetadata])
+      .rpc();
+
+    await program.methods
+      .setPaymentToken(paymentTokenMint.publicKey)
+      .accounts({
+        admin: wallet.publicKey,
+        config: configPDA,
+        eventSequence: eventSequencePDA,
+      })
+      .rpc();
+    await program.methods
+      .configureCollection(
+        new anchor.BN(0),
+        new anchor.BN(0),
+        new anchor.BN(0),
+        new anchor.BN(MINT_PRICE),
+        new anchor.BN(0)
+      )
+      .accounts({
+        admin: wallet.publicKey,
+        config: configPDA,
+        collectionMetadata: collectionMetadata.publicKey,
+        nftCounter: nftCounterPDA,
+        collectionConfig: collectionConfigPDA,
+        eventSequence: eventSequencePDA,
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc();
+    await program.methods
+      .configureRedemption(new anchor.BN(REDEMPTION_AMOUNT))
+      .accounts({
+        admin: wallet.publicKey,
+        config: configPDA,
+        collectionMetadata: collectionMetadata.publicKey,
+        collectionConfig: collectionConfigPDA,
+        eventSequence: eventSequencePDA,
+      })
+      .rpc();
+  });
+
+  it("Cria a pool de resgate no primeiro depósito", async () => {
+    assert.isNull(await connection.getAccountInfo(redemptionPool));
+
+    await program.methods
+      .depositRedemptionPool(new anchor.BN(REDEMPTION_AMOUNT))
+      .accounts(depositAccounts(wallet.publicKey))
+      .rpc();
+
+    const pool = await connection.getTokenAccountBalance(redemptionPool);
+    assert.equal(Number(pool.value.amount), REDEMPTION_AMOUNT);
+  });
+
+  it("Rejeita depósito na pool por não-admin", async () => {
+    const attacker = Keypair.generate();
+    const signature = await connection.requestAirdrop(attacker.publicKey, LAMPORTS_PER_SOL);
+    await connection.confirmTransaction(signature);
+    await getOrCreateAssociatedTokenAccount(
+      connection,
+      wallet.payer,
+      paymentTokenMint.publicKey,
+      attacker.publicKey
+    );
+
+    try {
+      await program.methods
+        .depositRedemptionPool(new anchor.BN(1))
+        .accounts(depositAccounts(attacker.publicKey))
+        .signers([attacker])
+        .rpc();
+      assert.fail("Deveria ter rejeitado o depósito");
+    } catch (e) {
+      assert(e.toString().includes("Unauthorized"), "Erro diferente do esperado: " + e);
+    }
+  });
+
+  it("Devolve o resgate ao queimar e reduz o supply em circulação", async () => {
+    const nft = await mintNext();
+    const before = await program.account.collectionConfig.fetch(collectionConfigPDA);
+    const balanceBefore = await connection.getTokenAccountBalance(payerPaymentTokenAccount);
+
+    await burn(nft);
+
+    const balanceAfter = await connection.getTokenAccountBalance(payerPaymentTokenAccount);
+    assert.equal(
+      Number(balanceAfter.value.amount) - Number(balanceBefore.value.amount),
+      REDEMPTION_AMOUNT
+    );
+    const pool = await connection.getTokenAccountBalance(redemptionPool);
+    assert.equal(Number(pool.value.amount), 0);
+    const after = await program.account.collectionConfig.fetch(collectionConfigPDA);
+    assert.equal(after.liveSupply.toNumber(), before.liveSupply.toNumber() - 1);
+    assert.equal(after.minted.toNumber(), before.minted.toNumber());
+    assert.isNull(await connection.getAccountInfo(nft.nftMetadata));
+  });
+
+  it("Rejeita a queima quando a pool não cobre o resgate", async () => {
+    const nft = await mintNext();
+    try {
+      await burn(nft);
+      assert.fail("Deveria ter rejeitado a queima");
+    } catch (e) {
+      assert(e.toString().includes("InsufficientRedemptionPool"), "Erro diferente do esperado: " + e);
+    }
+  });
+});
@@ -0,0 +1,304 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Program } from "@coral-xyz/anchor";
+import {
+  Keypair,
+  PublicKey,
+  SystemProgram,
+  SYSVAR_RENT_PUBKEY,
+} from "@solana/web3.js";
+import {
+  TOKEN_PROGRAM_ID,
+  ASSOCIATED_TOKEN_PROGRAM_ID,
+  getAssociatedTokenAddressSync,
+  createMint,
+  mintTo,
+  getOrCreateAssociatedTokenAccount
+} from "@solana/spl-token";
+import { AdrTokenMint } from "../target/types/adr_token_mint";
+import { assert } from "chai";
+
+describe("ADR Token Reveal Tests", () => {
+  anchor.setProvider(anchor.AnchorProvider.env());
+
+  const program = anchor.workspace.AdrTokenMint as Program<AdrTokenMint>;
+  const [configPDA] = PublicKey.findProgramAddressSync(
+    [Buffer.from("config")],
+    program.programId
+  );
+  const [eventSequencePDA] = PublicKey.findProgramAddressSync(
+    [Buffer.from("event_sequence")],
+    program.programId
+  );
+  const wallet = program.provider.wallet;
+  const connection = anchor.getProvider().connection;
+
+  const MINT_PRICE = 10 * 10**9;
+  const PLACEHOLDER_URI = "https://test-uri.com/placeholder.json";
+  const BASE_URI = "https://test-uri.com/revealed/";
+
+  // Keypairs e contas
+  let paymentTokenMint: Keypair;
+  let collectionMint: Keypair;
+  let collectionMetadata: Keypair;
+  let payerPaymentTokenAccount: PublicKey;
+  let nftCounterPDA: PublicKey;
+  let collectionConfigPDA: PublicKey;
+  let mintRecordPDA: PublicKey;
+  let nftMetadatas: PublicKey[] = [];
+
+  // Minta o NFT do próximo índice do contador global e retorna seus metadados
+  async function mintNext() {
+    const counter = await program.account.nftCounter.fetch(nftCounterPDA);
+    const [nftMint] = PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("nft_mint"),
+        collectionMetadata.publicKey.toBuffer(),
+        counter.count.toArrayLike(Buffer, "le", 8),
+      ],
+      program.programId
+    );
+    const [nftMetadata] = PublicKey.findProgramAddressSync(
+      [Buffer.from("nft_metadata"), nftMint.toBuffer()],
+      program.programId
+    );
+
+    await program.methods
+      .mintNftWithPayment("Test NFT", "TNFT", "https://test-nft-uri.com", new anchor.BN(MINT_PRICE))
+      .accounts({
+        payer: wallet.publicKey,
+        nftCounter: nftCounterPDA,
+        nftMint,
+        nftMetadata,
+        nftTokenAccount: getAssociatedTokenAddressSync(nftMint, wallet.publicKey),
+        collectionMetadata: collectionMetadata.publicKey,
+        collectionConfig: collectionConfigPDA,
+        mintRecord: mintRecordPDA,
+        paymentTokenMint: paymentTokenMint.publicKey,
+        payerPaymentTokenAccount,
+        treasuryTokenAccount: null,
+        rewardReserveAccount: null,
+        config: configPDA,
+        eventSequence: eventSequencePDA,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        nftTokenProgram: TOKEN_PROGRAM_ID,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+        rent: SYSVAR_RENT_PUBKEY,
+      })
+      .rpc();
+    return nftMetadata;
+  }
+
+  // Contas de update_nft_metadata/lock_metadata para um NFT da coleção
+  function holderAccounts(nftMetadata: PublicKey) {
+    return {
+      authority: wallet.publicKey,
+      nftMetadata,
+      collectionConfig: collectionConfigPDA,
+      eventSequence: eventSequencePDA,
+    };
+  }
+
+  function revealNfts(metadatas: PublicKey[]) {
+    return program.methods
+      .revealNfts()
+      .accounts({
+        admin: wallet.publicKey,
+        config: configPDA,
+        collectionMetadata: collectionMetadata.publicKey,
+        collectionConfig: collectionConfigPDA,
+        eventSequence: eventSequencePDA,
+      })
+      .remainingAccounts(
+        metadatas.map((pubkey) => ({ pubkey, isWritable: true, isSigner: false }))
+      )
+      .rpc();
+  }
+
+  before(async () => {
+    paymentTokenMint = Keypair.generate();
+    collectionMint = Keypair.generate();
+    collectionMetadata = Keypair.generate();
+
+    [nftCounterPDA] = PublicKey.findProgramAddressSync(
+      [Buffer.from("nft_counter")],
+      program.programId
+    );
+    [collectionConfigPDA] = PublicKey.findProgramAddressSync(
+      [Buffer.from("collection_config"), collectionMetadata.publicKey.toBuffer()],
+      program.programId
+    );
+    [mintRecordPDA] = PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("mint_record"),
+        collectionMetadata.publicKey.toBuffer(),
+        wallet.publicKey.toBuffer(),
+      ],
+      program.programId
+    );
+
+    // Criar o token de pagamento
+    await createMint(
+      connection,
+      wallet.payer,
+      wallet.publicKey,
+      wallet.publicKey,
+      9,
+      paymentTokenMint
+    );
+    const paymentTokenAccount = await getOrCreateAssociatedTokenAccount(
+      connection,
+      wallet.payer,
+      paymentTokenMint.publicKey,
+      wallet.publicKey
+    );
+    payerPaymentTokenAccount = paymentTokenAccount.address;
+    await mintTo(
+      connection,
+      wallet.payer,
+      paymentTokenMint.publicKey,
+      payerPaymentTokenAccount,
+      wallet.publicKey,
+      1000 * 10**9
+    );
+
+    await program.methods
+      .initializeCollection("Test Collection", "TEST", "https://test-uri.com")
+      .accounts({
+        payer: wallet.publicKey,
+        collectionMint: collectionMint.publicKey,
+        collectionMetadata: collectionMetadata.publicKey,
+        collectionTokenAccount: getAssociatedTokenAddressSync(
+          collectionMint.publicKey,
+          wallet.publicKey
+        ),
+        config: configPDA,
+        eventSequence: eventSequencePDA,
+        nftCounter: nftCounterPDA,
+        collectionConfig: collectionConfigPDA,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+        rent: SYSVAR_RENT_PUBKEY,
+      })
+      .signers([collectionMint, collectionMetadata])
+      .rpc();
+
+    await program.methods
+      .setPaymentToken(paymentTokenMint.publicKey)
+      .accounts({
+        admin: wallet.publicKey,
+        config: configPDA,
+        eventSequence: eventSequencePDA,
+      })
+      .rpc();
+    await program.methods
+      .configureCollection(
+        new anchor.BN(0),
+        new anchor.BN(0),
+        new anchor.BN(0),
+        new anchor.BN(MINT_PRICE),
+        new anchor.BN(0)
+      )
+      .accounts({
+        admin: wallet.publicKey,
+        config: configPDA,
+        collectionMetadata: collectionMetadata.publicKey,
+        nftCounter: nftCounterPDA,
+        collectionConfig: collectionConfigPDA,
+        eventSequence: eventSequencePDA,
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc();
+    await program.methods
+      .setRevealPlaceholder(PLACEHOLDER_URI)
+      .accounts({
+        admin: wallet.publicKey,
+        config: configPDA,
+        collectionMetadata: collectionMetadata.publicKey,
+        collectionConfig: collectionConfigPDA,
+        eventSequence: eventSequencePDA,
+      })
+      .rpc();
+  });
+
+  it("Minta com a URI placeholder e índices próprios da coleção", async () => {
+    // Outros testes já avançaram o contador global; o índice conta só esta coleção
+    nftMetadatas.push(await mintNext());
+    nftMetadatas.push(await mintNext());
+
+    for (let index = 0; index < nftMetadatas.length; index++) {
+      const metadata = await program.account.nftMetadata.fetch(nftMetadatas[index]);
+      assert.equal(metadata.uri, PLACEHOLDER_URI);
+      assert.equal(metadata.index.toNumber(), index);
+    }
+  });
+
+  it("Rejeita update e lock dos metadados antes do reveal", async () => {
+    try {
+      await program.methods
+        .updateNftMetadata(null, null, "https://test-uri.com/early.json")
+        .accounts(holderAccounts(nftMetadatas[0]))
+        .rpc();
+      assert.fail("Deveria ter rejeitado a atualização antes do reveal");
+    } catch (e) {
+      assert(e.toString().includes("RevealPending"), "Erro diferente do esperado: " + e);
+    }
+
+    try {
+      await program.methods
+        .lockMetadata()
+        .accounts(holderAccounts(nftMetadatas[0]))
+        .rpc();
+      assert.fail("Deveria ter rejeitado o lock antes do reveal");
+    } catch (e) {
+      assert(e.toString().includes("RevealPending"), "Erro diferente do esperado: " + e);
+    }
+  });
+
+  it("Revela os NFTs com base_uri + índice da coleção", async () => {
+    await program.methods
+      .commitReveal(BASE_URI, Array(32).fill(7))
+      .accounts({
+        admin: wallet.publicKey,
+        config: configPDA,
+        collectionMetadata: collectionMetadata.publicKey,
+        collectionConfig: collectionConfigPDA,
+        eventSequence: eventSequencePDA,
+      })
+      .rpc();
+
+    await revealNfts(nftMetadatas);
+
+    for (let index = 0; index < nftMetadatas.length; index++) {
+      const metadata = await program.account.nftMetadata.fetch(nftMetadatas[index]);
+      assert.equal(metadata.uri, BASE_URI + index);
+    }
+  });
+
+  it("Ignora metadados bloqueados e revela o resto do lote", async () => {
+    // Depois do reveal o holder pode bloquear os metadados
+    await program.methods
+      .lockMetadata()
+      .accounts(holderAccounts(nftMetadatas[1]))
+      .rpc();
+
+    let revealed = -1;
+    let skipped: PublicKey[] = [];
+    const listener = program.addEventListener("nftsRevealedEvent", (event) => {
+      revealed = event.revealed.toNumber();
+      skipped = event.skipped;
+    });
+    await revealNfts(nftMetadatas);
+    await new Promise((resolve) => setTimeout(resolve, 2000));
+    await program.removeEventListener(listener);
+
+    assert.equal(revealed, nftMetadatas.length - 1);
+    assert.equal(skipped.length, 1);
+    assert(skipped[0].equals(nftMetadatas[1]), "NFT ignorado diferente do esperado");
+
+    const metadata = await program.account.nftMetadata.fetch(nftMetadatas[1]);
+    assert.isTrue(metadata.locked);
+    assert.equal(metadata.uri, BASE_URI + 1);
+  });
+});
@@ -0,0 +1,328 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Program } from "@coral-xyz/anchor";
+import {
+  Keypair,
+  LAMPORTS_PER_SOL,
+  PublicKey,
+  SystemProgram,
+  SYSVAR_RENT_PUBKEY,
+} from "@solana/web3.js";
+import {
+  TOKEN_PROGRAM_ID,
+  ASSOCIATED_TOKEN_PROGRAM_ID,
+  getAssociatedTokenAddressSync,
+} from "@solana/spl-token";
+import { keccak_256 } from "@noble/hashes/sha3";
+import { AdrTokenMint } from "../target/types/adr_token_mint";
+import { assert } from "chai";
+
+describe("ADR Token SOL Payment Tests", () => {
+  anchor.setProvider(anchor.AnchorProvider.env());
+
+  const program = anchor.workspace.AdrTokenMint as Program<AdrTokenMint>;
+  const [configPDA] = PublicKey.findProgramAddressSync(
+    [Buffer.from("config")],
+    program.programId
+  );
+  const [eventSequencePDA] = PublicKey.findProgramAddressSync(
+    [Buffer.from("event_sequence")],
+    program.programId
+  );
+  const wallet = program.provider.wallet;
+  const connection = anchor.getProvider().connection;
+
+  // Abaixo do mínimo isento de aluguel de uma conta sem dados (~890.880 lamports)
+  const MINT_PRICE = 1000;
+  const ALLOWLIST_PRICE = 500;
+  const ALLOCATION = 2;
+
+  let collectionMint: Keypair;
+  let collectionMetadata: Keypair;
+  let nftCounterPDA: PublicKey;
+  let collectionConfigPDA: PublicKey;
+  let mintRecordPDA: PublicKey;
+  let allowlistClaimPDA: PublicKey;
+  let solTreasuryPDA: PublicKey;
+
+  // Endereços do próximo NFT pelo contador global
+  async function nextNftAccounts() {
+    const counter = await program.account.nftCounter.fetch(nftCounterPDA);
+    const [nftMint] = PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("nft_mint"),
+        collectionMetadata.publicKey.toBuffer(),
+        counter.count.toArrayLike(Buffer, "le", 8),
+      ],
+      program.programId
+    );
+    const [nftMetadata] = PublicKey.findProgramAddressSync(
+      [Buffer.from("nft_metadata"), nftMint.toBuffer()],
+      program.programId
+    );
+    const nftTokenAccount = getAssociatedTokenAddressSync(nftMint, wallet.publicKey);
+    return { nftMint, nftMetadata, nftTokenAccount };
+  }
+
+  // maxPrice precisa cobrir o complemento de aluguel quando a tesouraria está vazia
+  async function mintWithSol(maxPrice = MINT_PRICE) {
+    const { nftMint, nftMetadata, nftTokenAccount } = await nextNftAccounts();
+    return program.methods
+      .mintNftWithSol("Test NFT", "TNFT", "https://test-nft-uri.com", new anchor.BN(maxPrice))
+      .accounts({
+        payer: wallet.publicKey,
+        nftCounter: nftCounterPDA,
+        nftMint,
+        nftMetadata,
+        nftTokenAccount,
+        collectionMetadata: collectionMetadata.publicKey,
+        collectionConfig: collectionConfigPDA,
+        mintRecord: mintRecordPDA,
+        solTreasury: solTreasuryPDA,
+        config: configPDA,
+        eventSequence: eventSequencePDA,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+        rent: SYSVAR_RENT_PUBKEY,
+      })
+      .rpc();
+  }
+
+  async function withdraw(amount: number, destination: PublicKey) {
+    return program.methods
+      .withdrawSolTreasury(new anchor.BN(amount))
+      .accounts({
+        admin: wallet.publicKey,
+        config: configPDA,
+        collectionMetadata: collectionMetadata.publicKey,
+        solTreasury: solTreasuryPDA,
+        destination,
+        eventSequence: eventSequencePDA,
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc();
+  }
+
+  before(async () => {
+    collectionMint = Keypair.generate();
+    collectionMetadata = Keypair.generate();
+
+    [nftCounterPDA] = PublicKey.findProgramAddressSync(
+      [Buffer.from("nft_counter")],
+      program.programId
+    );
+    [collectionConfigPDA] = PublicKey.findProgramAddressSync(
+      [Buffer.from("collection_config"), collectionMetadata.publicKey.toBuffer()],
+      program.programId
+    );
+    [mintRecordPDA] = PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("mint_record"),
+        collectionMetadata.publicKey.toBuffer(),
+        wallet.publicKey.toBuffer(),
+      ],
+      program.programId
+    );
+    [allowlistClaimPDA] = PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("allowlist_claim"),
+        collectionMetadata.publicKey.toBuffer(),
+        wallet.publicKey.toBuffer(),
+      ],
+      program.programId
+    );
+    [solTreasuryPDA] = PublicKey.findProgramAddressSync(
+      [Buffer.from("sol_treasury"), collectionMetadata.publicKey.toBuffer()],
+      program.programId
+    );
+
+    await program.methods
+      .initializeCollection("Sol Collection", "SOLC", "https://test-uri.com")
+      .accounts({
+        payer: wallet.publicKey,
+        collectionMint: collectionMint.publicKey,
+        collectionMetadata: collectionMetadata.publicKey,
+        collectionTokenAccount: getAssociatedTokenAddressSync(
+          collectionMint.publicKey,
+          wallet.publicKey
+        ),
+        config: configPDA,
+        eventSequence: eventSequencePDA,
+        nftCounter: nftCounterPDA,
+        collectionConfig: collectionConfigPDA,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+        rent: SYSVAR_RENT_PUBKEY,
+      })
+      .signers([collectionMint, collectionMetadata])
+      .rpc();
+
+    // Nenhum token de pagamento é configurado: o modo Sol não depende dele
+    await program.methods
+      .configureCollection(
+        new anchor.BN(0),
+        new anchor.BN(0),
+        new anchor.BN(0),
+        new anchor.BN(MINT_PRICE),
+        new anchor.BN(0)
+      )
+      .accounts({
+        admin: wallet.publicKey,
+        config: configPDA,
+        collectionMetadata: collectionMetadata.publicKey,
+        nftCounter: nftCounterPDA,
+        collectionConfig: collectionConfigPDA,
+        eventSequence: eventSequencePDA,
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc();
+    await program.methods
+      .setPaymentMode({ sol: {} })
+      .accounts({
+        admin: wallet.publicKey,
+        config: configPDA,
+        collectionMetadata: collectionMetadata.publicKey,
+        collectionConfig: collectionConfigPDA,
+        eventSequence: eventSequencePDA,
+      })
+      .rpc();
+  });
+
+  it("Primeiro mint em SOL deixa a tesouraria isenta de aluguel", async () => {
+    const rentExempt = await connection.getMinimumBalanceForRentExemption(0);
+    assert.isNull(await connection.getAccountInfo(solTreasuryPDA));
+
+    // O complemento de aluguel conta para o preço máximo aceito
+    try {
+      await mintWithSol();
+      assert.fail("Deveria ter rejeitado o total acima do preço máximo");
+    } catch (e) {
+      assert(e.toString().includes("PriceExceedsMaximum"), "Erro diferente do esperado: " + e);
+    }
+
+    let charged: number | null = null;
+    let curvePrice: number | null = null;
+    const listener = program.addEventListener("tokenBurnEvent", (event) => {
+      charged = event.amount.toNumber();
+      curvePrice = event.price.toNumber();
+    });
+    await mintWithSol(MINT_PRICE + rentExempt);
+    await new Promise((resolve) => setTimeout(resolve, 2000));
+    await program.removeEventListener(listener);
+
+    // O primeiro pagamento cobre o mínimo de aluguel além do preço, e o evento traz o total
+    const afterFirst = await connection.getBalance(solTreasuryPDA);
+    assert.equal(afterFirst, rentExempt + MINT_PRICE);
+    assert.equal(charged, rentExempt + MINT_PRICE);
+    assert.equal(curvePrice, MINT_PRICE);
+
+    await mintWithSol();
+    const afterSecond = await connection.getBalance(solTreasuryPDA);
+    assert.equal(afterSecond, afterFirst + MINT_PRICE);
+
+    const record = await program.account.mintRecord.fetch(mintRecordPDA);
+    assert.equal(record.count.toNumber(), 2);
+  });
+
+  it("Minta pela allowlist cobrando em SOL", async () => {
+    // Árvore com uma única folha: a raiz é a própria folha e a prova é vazia
+    const allocation = new anchor.BN(ALLOCATION);
+    const leaf = keccak_256(
+      Buffer.concat([wallet.publicKey.toBuffer(), allocation.toArrayLike(Buffer, "le", 8)])
+    );
+    await program.methods
+      .setAllowlist(Array.from(leaf), new anchor.BN(ALLOWLIST_PRICE))
+      .accounts({
+        admin: wallet.publicKey,
+        config: configPDA,
+        collectionMetadata: collectionMetadata.publicKey,
+        collectionConfig: collectionConfigPDA,
+        eventSequence: eventSequencePDA,
+      })
+      .rpc();
+
+    const treasuryBefore = await connection.getBalance(solTreasuryPDA);
+    const { nftMint, nftMetadata, nftTokenAccount } = await nextNftAccounts();
+    await program.methods
+      .mintAllowlisted("Test NFT", "TNFT", "https://test-nft-uri.com", allocation, [])
+      .accounts({
+        payer: wallet.publicKey,
+        nftCounter: nftCounterPDA,
+        nftMint,
+        nftMetadata,
+        nftTokenAccount,
+        collectionMetadata: collectionMetadata.publicKey,
+        collectionConfig: collectionConfigPDA,
+        allowlistClaim: allowlistClaimPDA,
+        paymentTokenMint: null,
+        payerPaymentTokenAccount: null,
+        treasuryTokenAccount: null,
+        rewardReserveAccount: null,
+        solTreasury: solTreasuryPDA,
+        config: configPDA,
+        eventSequence: eventSequencePDA,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+        rent: SYSVAR_RENT_PUBKEY,
+      })
+      .rpc();
+
+    const treasuryAfter = await connection.getBalance(solTreasuryPDA);
+    assert.equal(treasuryAfter, treasuryBefore + ALLOWLIST_PRICE);
+    const claim = await program.account.allowlistClaim.fetch(allowlistClaimPDA);
+    assert.equal(claim.claimed.toNumber(), 1);
+  });
+
+  it("Saca da tesouraria sem deixá-la abaixo do mínimo de aluguel", async () => {
+    const destination = Keypair.generate().publicKey;
+    const signature = await connection.requestAirdrop(destination, LAMPORTS_PER_SOL);
+    await connection.confirmTransaction(signature);
+    const rentExempt = await connection.getMinimumBalanceForRentExemption(0);
+    const balance = await connection.getBalance(solTreasuryPDA);
+
+    // Saque parcial que deixaria saldo abaixo do mínimo
+    try {
+      await withdraw(balance - 1, destination);
+      assert.fail("Deveria ter rejeitado o saque parcial");
+    } catch (e) {
+      assert(e.toString().includes("InsufficientFunds"), "Erro diferente do esperado: " + e);
+    }
+
+    // Saque acima do mínimo e depois esvaziando a tesouraria
+    await withdraw(balance - rentExempt, destination);
+    assert.equal(await connection.getBalance(solTreasuryPDA), rentExempt);
+    await withdraw(rentExempt, destination);
+    assert.equal(await connection.getBalance(solTreasuryPDA), 0);
+    assert.equal(await connection.getBalance(destination), LAMPORTS_PER_SOL + balance);
+  });
+
+  it("Rejeita saque por quem não é admin", async () => {
+    const attacker = Keypair.generate();
+    const signature = await connection.requestAirdrop(attacker.publicKey, LAMPORTS_PER_SOL);
+    await connection.confirmTransaction(signature);
+
+    // Repor a tesouraria esvaziada no teste anterior
+    await mintWithSol(MINT_PRICE + (await connection.getMinimumBalanceForRentExemption(0)));
+
+    try {
+      await program.methods
+        .withdrawSolTreasury(new anchor.BN(MINT_PRICE))
+        .accounts({
+          admin: attacker.publicKey,
+          config: configPDA,
+          collectionMetadata: collectionMetadata.publicKey,
+          solTreasury: solTreasuryPDA,
+          destination: attacker.publicKey,
+          eventSequence: eventSequencePDA,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([attacker])
+        .rpc();
+      assert.fail("Deveria ter rejeitado o saque");
+    } catch (e) {
+      assert(e.toString().includes("Unauthorized"), "Erro diferente do esperado: " + e);
+    }
+  });
+});
@@ -0,0 +1,297 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Program } from "@coral-xyz/anchor";
+import {
+  Keypair,
+  PublicKey,
+  SystemProgram,
+  SYSVAR_RENT_PUBKEY,
+  Transaction,
+} from "@solana/web3.js";
+import {
+  TOKEN_PROGRAM_ID,
+  TOKEN_2022_PROGRAM_ID,
+  ASSOCIATED_TOKEN_PROGRAM_ID,
+  getAssociatedTokenAddressSync,
+  getOrCreateAssociatedTokenAccount,
+  createMint,
+  mintTo,
+  transfer,
+} from "@solana/spl-token";
+import { AdrTokenMint } from "../target/types/adr_token_mint";
+import { assert } from "chai";
+
+describe("ADR Token Soulbound Tests", () => {
+  anchor.setProvider(anchor.AnchorProvider.env());
+
+  const program = anchor.workspace.AdrTokenMint as Program<AdrTokenMint>;
+  const [configPDA] = PublicKey.findProgramAddressSync(
+    [Buffer.from("config")],
+    program.programId
+  );
+  const [eventSequencePDA] = PublicKey.findProgramAddressSync(
+    [Buffer.from("event_sequence")],
+    program.programId
+  );
+  const wallet = program.provider.wallet;
+  const connection = anchor.getProvider().connection;
+
+  let paymentTokenMint: Keypair;
+  let collectionMint: Keypair;
+  let collectionMetadata: Keypair;
+  let payerPaymentTokenAccount: PublicKey;
+  let nftCounterPDA: PublicKey;
+  let collectionConfigPDA: PublicKey;
+  let mintRecordPDA: PublicKey;
+
+  // Endereços do próximo NFT pelo contador global
+  async function nextNftAccounts(tokenProgram: PublicKey) {
+    const counter = await program.account.nftCounter.fetch(nftCounterPDA);
+    const [nftMint] = PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("nft_mint"),
+        collectionMetadata.publicKey.toBuffer(),
+        counter.count.toArrayLike(Buffer, "le", 8),
+      ],
+      program.programId
+    );
+    const [nftMetadata] = PublicKey.findProgramAddressSync(
+      [Buffer.from("nft_metadata"), nftMint.toBuffer()],
+      program.programId
+    );
+    const nftTokenAccount = getAssociatedTokenAddressSync(
+      nftMint,
+      wallet.publicKey,
+      false,
+      tokenProgram
+    );
+    return { nftMint, nftMetadata, nftTokenAccount };
+  }
+
+  async function setSoulbound(soulbound: boolean) {
+    return program.methods
+      .setSoulbound(soulbound)
+      .accounts({
+        admin: wallet.publicKey,
+        config: configPDA,
+        collectionMetadata: collectionMetadata.publicKey,
+        collectionConfig: collectionConfigPDA,
+        eventSequence: eventSequencePDA,
+      })
+      .rpc();
+  }
+
+  // Mint pelo caminho comum, com o programa de token informado para o NFT
+  async function mintWithPayment(nftTokenProgram: PublicKey) {
+    const { nftMint, nftMetadata, nftTokenAccount } = await nextNftAccounts(nftTokenProgram);
+    await program.methods
+      .mintNftWithPayment("Badge", "BADGE", "https://test-nft-uri.com", new anchor.BN(0))
+      .accounts({
+        payer: wallet.publicKey,
+        nftCounter: nftCounterPDA,
+        nftMint,
+        nftMetadata,
+        nftTokenAccount,
+        collectionMetadata: collectionMetadata.publicKey,
+        collectionConfig: collectionConfigPDA,
+        mintRecord: mintRecordPDA,
+        paymentTokenMint: paymentTokenMint.publicKey,
+        payerPaymentTokenAccount,
+        treasuryTokenAccount: null,
+        rewardReserveAccount: null,
+        config: configPDA,
+        eventSequence: eventSequencePDA,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        nftTokenProgram,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+        rent: SYSVAR_RENT_PUBKEY,
+      })
+      .rpc();
+    return { nftMint, nftMetadata, nftTokenAccount };
+  }
+
+  before(async () => {
+    paymentTokenMint = Keypair.generate();
+    collectionMint = Keypair.generate();
+    collectionMetadata = Keypair.generate();
+
+    [nftCounterPDA] = PublicKey.findProgramAddressSync(
+      [Buffer.from("nft_counter")],
+      program.programId
+    );
+    [collectionConfigPDA] = PublicKey.findProgramAddressSync(
+      [Buffer.from("collection_config"), collectionMetadata.publicKey.toBuffer()],
+      program.programId
+    );
+    [mintRecordPDA] = PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("mint_record"),
+        collectionMetadata.publicKey.toBuffer(),
+        wallet.publicKey.toBuffer(),
+      ],
+      program.programId
+    );
+
+    // Criar o token de pagamento
+    await createMint(
+      connection,
+      wallet.payer,
+      wallet.publicKey,
+      wallet.publicKey,
+      9,
+      paymentTokenMint
+    );
+    const paymentTokenAccount = await getOrCreateAssociatedTokenAccount(
+      connection,
+      wallet.payer,
+      paymentTokenMint.publicKey,
+      wallet.publicKey
+    );
+    payerPaymentTokenAccount = paymentTokenAccount.address;
+    await mintTo(
+      connection,
+      wallet.payer,
+      paymentTokenMint.publicKey,
+      payerPaymentTokenAccount,
+      wallet.publicKey,
+      1000 * 10**9
+    );
+
+    await program.methods
+      .initializeCollection("Badge Collection", "BADGE", "https://test-uri.com")
+      .accounts({
+        payer: wallet.publicKey,
+        collectionMint: collectionMint.publicKey,
+        collectionMetadata: collectionMetadata.publicKey,
+        collectionTokenAccount: getAssociatedTokenAddressSync(
+          collectionMint.publicKey,
+          wallet.publicKey
+        ),
+        config: configPDA,
+        eventSequence: eventSequencePDA,
+        nftCounter: nftCounterPDA,
+        collectionConfig: collectionConfigPDA,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+        rent: SYSVAR_RENT_PUBKEY,
+      })
+      .signers([collectionMint, collectionMetadata])
+      .rpc();
+
+    await program.methods
+      .setPaymentToken(paymentTokenMint.publicKey)
+      .accounts({
+        admin: wallet.publicKey,
+        config: configPDA,
+        eventSequence: eventSequencePDA,
+      })
+      .rpc();
+    // Os NFTs deste teste são mintados de graça (preço fixo zero)
+    await program.methods
+      .setPricingCurve({ fixed: {} }, new anchor.BN(0), [], true)
+      .accounts({
+        admin: wallet.publicKey,
+        config: configPDA,
+        collectionMetadata: collectionMetadata.publicKey,
+        nftCounter: nftCounterPDA,
+        collectionConfig: collectionConfigPDA,
+        eventSequence: eventSequencePDA,
+      })
+      .rpc();
+
+    await setSoulbound(true);
+  });
+
+  it("Rejeita mint soulbound com o programa Token comum", async () => {
+    try {
+      await mintWithPayment(TOKEN_PROGRAM_ID);
+      assert.fail("Deveria ter exigido o Token-2022 em coleção soulbound");
+    } catch (e) {
+      assert(e.toString().includes("InvalidNftTokenProgram"), "Erro diferente do esperado: " + e);
+    }
+  });
+
+  it("Minta NFT intransferível e permite queimá-lo", async () => {
+    const { nftMint, nftMetadata, nftTokenAccount } = await mintWithPayment(TOKEN_2022_PROGRAM_ID);
+
+    const mintInfo = await connection.getAccountInfo(nftMint);
+    assert(mintInfo.owner.equals(TOKEN_2022_PROGRAM_ID));
+
+    // A transferência precisa falhar por causa da extensão NonTransferable
+    const receiver = Keypair.generate();
+    const receiverAccount = await getOrCreateAssociatedTokenAccount(
+      connection,
+      wallet.payer,
+      nftMint,
+      receiver.publicKey,
+      false,
+      undefined,
+      undefined,
+      TOKEN_2022_PROGRAM_ID
+    );
+    try {
+      await transfer(
+        connection,
+        wallet.payer,
+        nftTokenAccount,
+        receiverAccount.address,
+        wallet.publicKey,
+        1,
+        [],
+        undefined,
+        TOKEN_2022_PROGRAM_ID
+      );
+      assert.fail("Deveria ter rejeitado a transferência do NFT soulbound");
+    } catch (e) {
+      assert(!e.toString().includes("Deveria"), "Transferência não foi bloqueada");
+    }
+
+    const [redemptionAuthority] = PublicKey.findProgramAddressSync(
+      [Buffer.from("redemption_authority"), collectionMetadata.publicKey.toBuffer()],
+      program.programId
+    );
+    await program.methods
+      .burnNft()
+      .accounts({
+        holder: wallet.publicKey,
+        nftMint,
+        nftMetadata,
+        nftTokenAccount,
+        collectionMetadata: collectionMetadata.publicKey,
+        collectionConfig: collectionConfigPDA,
+        redemptionAuthority,
+        redemptionPool: null,
+        holderPaymentTokenAccount: null,
+        config: configPDA,
+        eventSequence: eventSequencePDA,
+        nftTokenProgram: TOKEN_2022_PROGRAM_ID,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .rpc();
+
+    assert.isNull(await connection.getAccountInfo(nftTokenAccount));
+    assert.isNull(await connection.getAccountInfo(nftMetadata));
+  });
+
+  it("Minta mesmo com o endereço do mint pré-financiado", async () => {
+    // Endereços dos NFTs são previsíveis: enviar lamports antes não pode bloquear o mint
+    const { nftMint } = await nextNftAccounts(TOKEN_2022_PROGRAM_ID);
+    await program.provider.sendAndConfirm(
+      new Transaction().add(
+        SystemProgram.transfer({
+          fromPubkey: wallet.publicKey,
+          toPubkey: nftMint,
+          lamports: await connection.getMinimumBalanceForRentExemption(0),
+        })
+      )
+    );
+
+    const minted = await mintWithPayment(TOKEN_2022_PROGRAM_ID);
+    assert(minted.nftMint.equals(nftMint));
+    const mintInfo = await connection.getAccountInfo(nftMint);
+    assert(mintInfo.owner.equals(TOKEN_2022_PROGRAM_ID));
+    const balance = await connection.getTokenAccountBalance(minted.nftTokenAccount);
+    assert.equal(balance.value.amount, "1");
+  });
+});
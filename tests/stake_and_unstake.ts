@@ -22,6 +22,14 @@ describe("ADR Token Staking Tests", () => {
   anchor.setProvider(anchor.AnchorProvider.env());
 
   const program = anchor.workspace.AdrTokenMint as Program<AdrTokenMint>;
+  const [configPDA] = PublicKey.findProgramAddressSync(
+    [Buffer.from("config")],
+    program.programId
+  );
+  const [eventSequencePDA] = PublicKey.findProgramAddressSync(
+    [Buffer.from("event_sequence")],
+    program.programId
+  );
   const wallet = program.provider.wallet;
   const connection = anchor.getProvider().connection;
 
@@ -31,7 +39,6 @@ describe("ADR Token Staking Tests", () => {
   const VERY_LARGE_STAKE = 10_000_000; // Valor muito grande para testar limites
 
   // Keypairs e contas
-  let configAccount: Keypair;
   let paymentTokenMint: Keypair;
   let stakeAccount: Keypair;
   let stakeAccount2: Keypair; // Para testes adicionais
@@ -44,7 +51,6 @@ describe("ADR Token Staking Tests", () => {
 
   before(async () => {
     // Gerar keypairs
-    configAccount = Keypair.generate();
     paymentTokenMint = Keypair.generate();
     stakeAccount = Keypair.generate();
     stakeAccount2 = Keypair.generate();
@@ -112,13 +118,14 @@ describe("ADR Token Staking Tests", () => {
           collectionMint: collectionMint.publicKey,
           collectionMetadata: collectionMetadata.publicKey,
           collectionTokenAccount: collectionTokenAccount,
-          config: configAccount.publicKey,
+          config: configPDA,
+          eventSequence: eventSequencePDA,
           tokenProgram: TOKEN_PROGRAM_ID,
           associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
           systemProgram: SystemProgram.programId,
           rent: SYSVAR_RENT_PUBKEY,
         })
-        .signers([collectionMint, collectionMetadata, configAccount])
+        .signers([collectionMint, collectionMetadata])
         .rpc();
 
       console.log("Coleção inicializada com sucesso!");
@@ -134,7 +141,8 @@ describe("ADR Token Staking Tests", () => {
         .setPaymentToken(paymentTokenMint.publicKey)
         .accounts({
           admin: wallet.publicKey,
-          config: configAccount.publicKey,
+          config: configPDA,
+          eventSequence: eventSequencePDA,
         })
         .rpc();
       console.log("Token de pagamento configurado com sucesso!");
@@ -150,7 +158,8 @@ describe("ADR Token Staking Tests", () => {
         .configureStaking(true, new anchor.BN(REWARD_RATE))
         .accounts({
           admin: wallet.publicKey,
-          config: configAccount.publicKey,
+          config: configPDA,
+          eventSequence: eventSequencePDA,
         })
         .rpc();
       console.log("Sistema de staking configurado com sucesso!");
@@ -166,7 +175,7 @@ describe("ADR Token Staking Tests", () => {
         .updateMaxStakeAmount(new anchor.BN(10000 * 10**9)) // 10000 tokens
         .accounts({
           admin: wallet.publicKey,
-          config: configAccount.publicKey,
+          config: configPDA,
         })
         .rpc();
       console.log("Limite máximo de stake configurado com sucesso!");
@@ -176,7 +185,7 @@ describe("ADR Token Staking Tests", () => {
   });
 
   it("Configura o sistema de staking corretamente", async () => {
-    const config = await program.account.configAccount.fetch(configAccount.publicKey);
+    const config = await program.account.configAccount.fetch(configPDA);
     assert.equal(config.stakingEnabled, true, "Staking deveria estar habilitado");
     assert.equal(config.stakingRewardRate.toNumber(), REWARD_RATE, "Taxa de recompensa incorreta");
   });
@@ -188,7 +197,8 @@ describe("ADR Token Staking Tests", () => {
     await program.methods
       .stakeTokens(
         new anchor.BN(STAKE_AMOUNT * 10**9),
-        { days7: {} }
+        { days7: {} },
+        null
       )
       .accounts({
         staker: wallet.publicKey,
@@ -197,7 +207,8 @@ describe("ADR Token Staking Tests", () => {
         stakeTokenAccount: stakeTokenAccount,
         stakeAuthority: stakeAuthorityPDA,
         stakeAccount: stakeAccount.publicKey,
-        config: configAccount.publicKey,
+        config: configPDA,
+        eventSequence: eventSequencePDA,
         tokenProgram: TOKEN_PROGRAM_ID,
         associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
         systemProgram: SystemProgram.programId,
@@ -262,7 +273,7 @@ describe("ADR Token Staking Tests", () => {
         .updateMaxStakeAmount(new anchor.BN(maxAmount))
         .accounts({
           admin: wallet.publicKey,
-          config: configAccount.publicKey,
+          config: configPDA,
         })
         .rpc();
       
@@ -273,7 +284,8 @@ describe("ADR Token Staking Tests", () => {
         await program.methods
           .stakeTokens(
             new anchor.BN(VERY_LARGE_STAKE * 10**9),
-            { days7: {} }
+            { days7: {} },
+            null
           )
           .accounts({
             staker: wallet.publicKey,
@@ -282,7 +294,8 @@ describe("ADR Token Staking Tests", () => {
             stakeTokenAccount: stakeTokenAccount,
             stakeAuthority: stakeAuthorityPDA,
             stakeAccount: stakeAccount2.publicKey,
-            config: configAccount.publicKey,
+            config: configPDA,
+            eventSequence: eventSequencePDA,
             tokenProgram: TOKEN_PROGRAM_ID,
             associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
             systemProgram: SystemProgram.programId,
@@ -319,7 +332,8 @@ describe("ADR Token Staking Tests", () => {
     await program.methods
       .stakeTokens(
         new anchor.BN(500 * 10**9), // 500 tokens
-        { days7: {} }
+        { days7: {} },
+        null
       )
       .accounts({
         staker: wallet.publicKey,
@@ -328,7 +342,8 @@ describe("ADR Token Staking Tests", () => {
         stakeTokenAccount: stakeTokenAccount,
         stakeAuthority: stakeAuthorityPDA,
         stakeAccount: unstakeTestAccount.publicKey,
-        config: configAccount.publicKey,
+        config: configPDA,
+        eventSequence: eventSequencePDA,
         tokenProgram: TOKEN_PROGRAM_ID,
         associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
         systemProgram: SystemProgram.programId,
@@ -357,7 +372,7 @@ describe("ADR Token Staking Tests", () => {
     console.log("3. Calcula as recompensas baseadas no período e taxa configurada");
     console.log("4. Transfere os tokens originais de volta para o staker");
     console.log("5. Minta as recompensas para o staker");
-    console.log("6. Marca o stake como claimed");
+    console.log("6. Fecha a conta do stake e devolve o aluguel ao staker");
     
     // 5. Em um ambiente real:
     console.log("\nEm um ambiente real, após 7 dias:");
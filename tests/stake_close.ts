@@ -0,0 +1,247 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Program } from "@coral-xyz/anchor";
+import {
+  Keypair,
+  LAMPORTS_PER_SOL,
+  PublicKey,
+  SystemProgram,
+  SYSVAR_RENT_PUBKEY,
+} from "@solana/web3.js";
+import {
+  TOKEN_PROGRAM_ID,
+  ASSOCIATED_TOKEN_PROGRAM_ID,
+  getAssociatedTokenAddressSync,
+  createMint,
+  mintTo,
+  getOrCreateAssociatedTokenAccount
+} from "@solana/spl-token";
+import { AdrTokenMint } from "../target/types/adr_token_mint";
+import { assert } from "chai";
+
+describe("ADR Token Stake Close Tests", () => {
+  anchor.setProvider(anchor.AnchorProvider.env());
+
+  const program = anchor.workspace.AdrTokenMint as Program<AdrTokenMint>;
+  const [configPDA] = PublicKey.findProgramAddressSync(
+    [Buffer.from("config")],
+    program.programId
+  );
+  const [eventSequencePDA] = PublicKey.findProgramAddressSync(
+    [Buffer.from("event_sequence")],
+    program.programId
+  );
+  const [stakeAuthorityPDA] = PublicKey.findProgramAddressSync(
+    [Buffer.from("stake_authority")],
+    program.programId
+  );
+  const wallet = program.provider.wallet;
+  const connection = anchor.getProvider().connection;
+
+  const STAKE_AMOUNT = 100 * 10**9;
+
+  // Keypairs e contas
+  let paymentTokenMint: Keypair;
+  let collectionMint: Keypair;
+  let collectionMetadata: Keypair;
+  let stakeTokenAccount: PublicKey;
+  let stakeAccountPDA: PublicKey;
+  let staker: Keypair;
+  let stakerTokenAccount: PublicKey;
+
+  function stakeTokens(amount: number) {
+    return program.methods
+      .stakeTokens(new anchor.BN(amount), { minutes1: {} }, null)
+      .accounts({
+        staker: staker.publicKey,
+        tokenMint: paymentTokenMint.publicKey,
+        stakerTokenAccount,
+        stakeAccount: stakeAccountPDA,
+        stakeTokenAccount,
+        stakeAuthority: stakeAuthorityPDA,
+        config: configPDA,
+        eventSequence: eventSequencePDA,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+        rent: SYSVAR_RENT_PUBKEY,
+      })
+      .signers([staker])
+      .rpc();
+  }
+
+  before(async () => {
+    paymentTokenMint = Keypair.generate();
+    collectionMint = Keypair.generate();
+    collectionMetadata = Keypair.generate();
+    // Staker separado: o provider paga as taxas e o saldo do staker só muda pelo aluguel
+    staker = Keypair.generate();
+
+    [stakeAccountPDA] = PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("stake_account"),
+        staker.publicKey.toBuffer(),
+        paymentTokenMint.publicKey.toBuffer(),
+      ],
+      program.programId
+    );
+    // Tokens em stake e reserva de recompensas são a mesma ATA da stake_authority
+    stakeTokenAccount = getAssociatedTokenAddressSync(
+      paymentTokenMint.publicKey,
+      stakeAuthorityPDA,
+      true
+    );
+
+    // Criar o token de pagamento
+    await createMint(
+      connection,
+      wallet.payer,
+      wallet.publicKey,
+      wallet.publicKey,
+      9,
+      paymentTokenMint
+    );
+    const signature = await connection.requestAirdrop(staker.publicKey, LAMPORTS_PER_SOL);
+    await connection.confirmTransaction(signature);
+    stakerTokenAccount = (
+      await getOrCreateAssociatedTokenAccount(
+        connection,
+        wallet.payer,
+        paymentTokenMint.publicKey,
+        staker.publicKey
+      )
+    ).address;
+    await mintTo(
+      connection,
+      wallet.payer,
+      paymentTokenMint.publicKey,
+      stakerTokenAccount,
+      wallet.publicKey,
+      1000 * 10**9
+    );
+
+    await program.methods
+      .initializeCollection("Test Collection", "TEST", "https://test-uri.com")
+      .accounts({
+        payer: wallet.publicKey,
+        collectionMint: collectionMint.publicKey,
+        collectionMetadata: collectionMetadata.publicKey,
+        collectionTokenAccount: getAssociatedTokenAddressSync(
+          collectionMint.publicKey,
+          wallet.publicKey
+        ),
+        config: configPDA,
+        eventSequence: eventSequencePDA,
+        nftCounter: PublicKey.findProgramAddressSync(
+          [Buffer.from("nft_counter")],
+          program.programId
+        )[0],
+        collectionConfig: PublicKey.findProgramAddressSync(
+          [Buffer.from("collection_config"), collectionMetadata.publicKey.toBuffer()],
+          program.programId
+        )[0],
+        tokenProgram: TOKEN_PROGRAM_ID,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+        rent: SYSVAR_RENT_PUBKEY,
+      })
+      .signers([collectionMint, collectionMetadata])
+      .rpc();
+
+    await program.methods
+      .setPaymentToken(paymentTokenMint.publicKey)
+      .accounts({
+        admin: wallet.publicKey,
+        config: configPDA,
+        eventSequence: eventSequencePDA,
+      })
+      .rpc();
+    await program.methods
+      .configureStaking(true, new anchor.BN(1000))
+      .accounts({
+        admin: wallet.publicKey,
+        config: configPDA,
+        eventSequence: eventSequencePDA,
+      })
+      .rpc();
+    await program.methods
+      .initializeRewardReserve()
+      .accounts({
+        admin: wallet.publicKey,
+        rewardReserveAccount: stakeTokenAccount,
+        tokenMint: paymentTokenMint.publicKey,
+        stakeAuthority: stakeAuthorityPDA,
+        config: configPDA,
+        eventSequence: eventSequencePDA,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+        rent: SYSVAR_RENT_PUBKEY,
+      })
+      .rpc();
+    // Saldo para as recompensas
+    await mintTo(
+      connection,
+      wallet.payer,
+      paymentTokenMint.publicKey,
+      stakeTokenAccount,
+      wallet.publicKey,
+      100 * 10**9
+    );
+  });
+
+  it("Fecha a posição no unstake, devolve o aluguel e desconta as estatísticas", async () => {
+    const initial = await program.account.configAccount.fetch(configPDA);
+    await stakeTokens(STAKE_AMOUNT);
+
+    const opened = await program.account.configAccount.fetch(configPDA);
+    assert.equal(opened.activeStakes.toNumber(), initial.activeStakes.toNumber() + 1);
+    assert.equal(opened.totalStaked.toNumber(), initial.totalStaked.toNumber() + STAKE_AMOUNT);
+
+    // Esperar o fim do período de 1 minuto
+    await new Promise((resolve) => setTimeout(resolve, 65_000));
+
+    const rent = await connection.getBalance(stakeAccountPDA);
+    const lamportsBefore = await connection.getBalance(staker.publicKey);
+    await program.methods
+      .unstakeTokens()
+      .accounts({
+        staker: staker.publicKey,
+        tokenMint: paymentTokenMint.publicKey,
+        stakerTokenAccount,
+        stakeTokenAccount,
+        rewardReserveAccount: stakeTokenAccount,
+        stakeAuthority: stakeAuthorityPDA,
+        stakeAccount: stakeAccountPDA,
+        stakeReceipt: null,
+        receiptMint: null,
+        receiptTokenAccount: null,
+        receiptMetadata: null,
+        rentRecipient: null,
+        stakeBoost: null,
+        config: configPDA,
+        eventSequence: eventSequencePDA,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([staker])
+      .rpc();
+
+    assert.isNull(await connection.getAccountInfo(stakeAccountPDA));
+    assert.equal(await connection.getBalance(staker.publicKey) - lamportsBefore, rent);
+    // A ATA da stake_authority é também a reserva e continua aberta
+    assert.isNotNull(await connection.getAccountInfo(stakeTokenAccount));
+
+    const closed = await program.account.configAccount.fetch(configPDA);
+    assert.equal(closed.activeStakes.toNumber(), initial.activeStakes.toNumber());
+    assert.equal(closed.totalStaked.toNumber(), initial.totalStaked.toNumber());
+  });
+
+  it("Reabre a posição do zero depois do unstake", async () => {
+    await stakeTokens(STAKE_AMOUNT / 2);
+
+    const position = await program.account.stakeAccount.fetch(stakeAccountPDA);
+    assert(position.owner.equals(staker.publicKey));
+    assert.equal(position.amount.toNumber(), STAKE_AMOUNT / 2);
+    assert.isFalse(position.claimed);
+  });
+});
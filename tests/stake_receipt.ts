@@ -0,0 +1,336 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Program } from "@coral-xyz/anchor";
+import {
+  Keypair,
+  LAMPORTS_PER_SOL,
+  PublicKey,
+  SystemProgram,
+  SYSVAR_RENT_PUBKEY,
+} from "@solana/web3.js";
+import {
+  TOKEN_PROGRAM_ID,
+  ASSOCIATED_TOKEN_PROGRAM_ID,
+  getAssociatedTokenAddressSync,
+  createMint,
+  getMint,
+  mintTo,
+  transfer,
+  getOrCreateAssociatedTokenAccount
+} from "@solana/spl-token";
+import { AdrTokenMint } from "../target/types/adr_token_mint";
+import { assert } from "chai";
+
+describe("ADR Token Stake Receipt Tests", () => {
+  anchor.setProvider(anchor.AnchorProvider.env());
+
+  const program = anchor.workspace.AdrTokenMint as Program<AdrTokenMint>;
+  const [configPDA] = PublicKey.findProgramAddressSync(
+    [Buffer.from("config")],
+    program.programId
+  );
+  const [eventSequencePDA] = PublicKey.findProgramAddressSync(
+    [Buffer.from("event_sequence")],
+    program.programId
+  );
+  const [stakeAuthorityPDA] = PublicKey.findProgramAddressSync(
+    [Buffer.from("stake_authority")],
+    program.programId
+  );
+  const wallet = program.provider.wallet;
+  const connection = anchor.getProvider().connection;
+
+  const STAKE_AMOUNT = 100 * 10**9;
+  const RECEIPT_URI = "https://test-uri.com/receipt.json";
+
+  // Keypairs e contas
+  let paymentTokenMint: Keypair;
+  let collectionMint: Keypair;
+  let collectionMetadata: Keypair;
+  let payerPaymentTokenAccount: PublicKey;
+  let stakeTokenAccount: PublicKey;
+  let stakeAccountPDA: PublicKey;
+  let stakeReceiptPDA: PublicKey;
+  let receiptMint: Keypair;
+  let receiptMetadata: PublicKey;
+  let holder: Keypair;
+  let holderPaymentTokenAccount: PublicKey;
+  let holderReceiptAccount: PublicKey;
+
+  // Contas do unstake feito pelo holder do recibo
+  function unstakeAccounts(rentRecipient: PublicKey) {
+    return {
+      staker: holder.publicKey,
+      tokenMint: paymentTokenMint.publicKey,
+      stakerTokenAccount: holderPaymentTokenAccount,
+      stakeTokenAccount,
+      rewardReserveAccount: stakeTokenAccount,
+      stakeAuthority: stakeAuthorityPDA,
+      stakeAccount: stakeAccountPDA,
+      stakeReceipt: stakeReceiptPDA,
+      receiptMint: receiptMint.publicKey,
+      receiptTokenAccount: holderReceiptAccount,
+      receiptMetadata,
+      rentRecipient,
+      stakeBoost: null,
+      config: configPDA,
+      eventSequence: eventSequencePDA,
+      tokenProgram: TOKEN_PROGRAM_ID,
+      systemProgram: SystemProgram.programId,
+    };
+  }
+
+  before(async () => {
+    paymentTokenMint = Keypair.generate();
+    collectionMint = Keypair.generate();
+    collectionMetadata = Keypair.generate();
+    receiptMint = Keypair.generate();
+    holder = Keypair.generate();
+
+    [stakeAccountPDA] = PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("stake_account"),
+        wallet.publicKey.toBuffer(),
+        paymentTokenMint.publicKey.toBuffer(),
+      ],
+      program.programId
+    );
+    [stakeReceiptPDA] = PublicKey.findProgramAddressSync(
+      [Buffer.from("stake_receipt"), stakeAccountPDA.toBuffer()],
+      program.programId
+    );
+    [receiptMetadata] = PublicKey.findProgramAddressSync(
+      [Buffer.from("nft_metadata"), receiptMint.publicKey.toBuffer()],
+      program.programId
+    );
+    // Tokens em stake e reserva de recompensas são a mesma ATA da stake_authority
+    stakeTokenAccount = getAssociatedTokenAddressSync(
+      paymentTokenMint.publicKey,
+      stakeAuthorityPDA,
+      true
+    );
+
+    // Criar o token de pagamento
+    await createMint(
+      connection,
+      wallet.payer,
+      wallet.publicKey,
+      wallet.publicKey,
+      9,
+      paymentTokenMint
+    );
+    const paymentTokenAccount = await getOrCreateAssociatedTokenAccount(
+      connection,
+      wallet.payer,
+      paymentTokenMint.publicKey,
+      wallet.publicKey
+    );
+    payerPaymentTokenAccount = paymentTokenAccount.address;
+    await mintTo(
+      connection,
+      wallet.payer,
+      paymentTokenMint.publicKey,
+      payerPaymentTokenAccount,
+      wallet.publicKey,
+      1000 * 10**9
+    );
+
+    // O holder paga as próprias taxas e recebe o recibo depois do stake
+    const signature = await connection.requestAirdrop(holder.publicKey, LAMPORTS_PER_SOL);
+    await connection.confirmTransaction(signature);
+    holderPaymentTokenAccount = (
+      await getOrCreateAssociatedTokenAccount(
+        connection,
+        wallet.payer,
+        paymentTokenMint.publicKey,
+        holder.publicKey
+      )
+    ).address;
+
+    await program.methods
+      .initializeCollection("Test Collection", "TEST", "https://test-uri.com")
+      .accounts({
+        payer: wallet.publicKey,
+        collectionMint: collectionMint.publicKey,
+        collectionMetadata: collectionMetadata.publicKey,
+        collectionTokenAccount: getAssociatedTokenAddressSync(
+          collectionMint.publicKey,
+          wallet.publicKey
+        ),
+        config: configPDA,
+        eventSequence: eventSequencePDA,
+        nftCounter: PublicKey.findProgramAddressSync(
+          [Buffer.from("nft_counter")],
+          program.programId
+        )[0],
+        collectionConfig: PublicKey.findProgramAddressSync(
+          [Buffer.from("collection_config"), collectionMetadata.publicKey.toBuffer()],
+          program.programId
+        )[0],
+        tokenProgram: TOKEN_PROGRAM_ID,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+        rent: SYSVAR_RENT_PUBKEY,
+      })
+      .signers([collectionMint, collectionMetadata])
+      .rpc();
+
+    await program.methods
+      .setPaymentToken(paymentTokenMint.publicKey)
+      .accounts({
+        admin: wallet.publicKey,
+        config: configPDA,
+        eventSequence: eventSequencePDA,
+      })
+      .rpc();
+    await program.methods
+      .configureStaking(true, new anchor.BN(1000))
+      .accounts({
+        admin: wallet.publicKey,
+        config: configPDA,
+        eventSequence: eventSequencePDA,
+      })
+      .rpc();
+    await program.methods
+      .initializeRewardReserve()
+      .accounts({
+        admin: wallet.publicKey,
+        rewardReserveAccount: stakeTokenAccount,
+        tokenMint: paymentTokenMint.publicKey,
+        stakeAuthority: stakeAuthorityPDA,
+        config: configPDA,
+        eventSequence: eventSequencePDA,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+        rent: SYSVAR_RENT_PUBKEY,
+      })
+      .rpc();
+    // Saldo para as recompensas
+    await mintTo(
+      connection,
+      wallet.payer,
+      paymentTokenMint.publicKey,
+      stakeTokenAccount,
+      wallet.publicKey,
+      100 * 10**9
+    );
+  });
+
+  it("Minta o recibo no stake e a posição passa a pertencer a ele", async () => {
+    await program.methods
+      .stakeTokens(new anchor.BN(STAKE_AMOUNT), { minutes1: {} }, RECEIPT_URI)
+      .accounts({
+        staker: wallet.publicKey,
+        tokenMint: paymentTokenMint.publicKey,
+        stakerTokenAccount: payerPaymentTokenAccount,
+        stakeAccount: stakeAccountPDA,
+        stakeTokenAccount,
+        stakeAuthority: stakeAuthorityPDA,
+        stakeReceipt: stakeReceiptPDA,
+        receiptMint: receiptMint.publicKey,
+        receiptMetadata,
+        receiptTokenAccount: getAssociatedTokenAddressSync(
+          receiptMint.publicKey,
+          wallet.publicKey
+        ),
+        config: configPDA,
+        eventSequence: eventSequencePDA,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+        rent: SYSVAR_RENT_PUBKEY,
+      })
+      .signers([receiptMint])
+      .rpc();
+
+    const stake = await program.account.stakeAccount.fetch(stakeAccountPDA);
+    assert(stake.owner.equals(stakeReceiptPDA));
+    const receipt = await program.account.stakeReceipt.fetch(stakeReceiptPDA);
+    assert(receipt.receiptMint.equals(receiptMint.publicKey));
+    assert(receipt.staker.equals(wallet.publicKey));
+    const metadata = await program.account.nftMetadata.fetch(receiptMetadata);
+    assert.equal(metadata.uri, RECEIPT_URI);
+    assert.isTrue(metadata.locked);
+
+    // Uma única unidade: a autoridade de mint foi revogada
+    const mint = await getMint(connection, receiptMint.publicKey);
+    assert.equal(Number(mint.supply), 1);
+    assert.isNull(mint.mintAuthority);
+  });
+
+  it("Rejeita o unstake pelo holder com o aluguel indo para ele mesmo", async () => {
+    holderReceiptAccount = (
+      await getOrCreateAssociatedTokenAccount(
+        connection,
+        wallet.payer,
+        receiptMint.publicKey,
+        holder.publicKey
+      )
+    ).address;
+    await transfer(
+      connection,
+      wallet.payer,
+      getAssociatedTokenAddressSync(receiptMint.publicKey, wallet.publicKey),
+      holderReceiptAccount,
+      wallet.publicKey,
+      1
+    );
+
+    // Esperar o fim do período de 1 minuto
+    await new Promise((resolve) => setTimeout(resolve, 65_000));
+
+    try {
+      await program.methods
+        .unstakeTokens()
+        .accounts(unstakeAccounts(holder.publicKey))
+        .signers([holder])
+        .rpc();
+      assert.fail("Deveria ter rejeitado o destinatário do aluguel");
+    } catch (e) {
+      assert(e.toString().includes("InvalidStakeReceipt"), "Erro diferente do esperado: " + e);
+    }
+  });
+
+  it("O holder resgata a posição e o aluguel volta ao staker", async () => {
+    const rent = (
+      await Promise.all(
+        [stakeAccountPDA, stakeReceiptPDA, receiptMetadata].map((account) =>
+          connection.getBalance(account)
+        )
+      )
+    ).reduce((total, lamports) => total + lamports, 0);
+    const stakerBefore = await connection.getBalance(wallet.publicKey);
+    const holderTokensBefore = await connection.getTokenAccountBalance(holderPaymentTokenAccount);
+    const config = await program.account.configAccount.fetch(configPDA);
+
+    const signature = await program.methods
+      .unstakeTokens()
+      .accounts(unstakeAccounts(wallet.publicKey))
+      .signers([holder])
+      .rpc({ commitment: "confirmed" });
+
+    // Principal e recompensa vão para o holder (Minutes1: multiplicador de 105%)
+    const reward = Math.floor(
+      Math.floor(STAKE_AMOUNT * config.stakingRewardRate.toNumber() / 10000) * 105 / 100
+    );
+    const holderTokensAfter = await connection.getTokenAccountBalance(holderPaymentTokenAccount);
+    assert.equal(
+      Number(holderTokensAfter.value.amount) - Number(holderTokensBefore.value.amount),
+      STAKE_AMOUNT + reward
+    );
+
+    // Posição, recibo e metadados fechados; o staker paga a taxa (é o fee payer do provider)
+    for (const account of [stakeAccountPDA, stakeReceiptPDA, receiptMetadata, holderReceiptAccount]) {
+      assert.isNull(await connection.getAccountInfo(account));
+    }
+    const tx = await connection.getTransaction(signature, {
+      commitment: "confirmed",
+      maxSupportedTransactionVersion: 0,
+    });
+    const stakerAfter = await connection.getBalance(wallet.publicKey);
+    assert.equal(stakerAfter - stakerBefore, rent - tx.meta.fee);
+
+    const mint = await getMint(connection, receiptMint.publicKey);
+    assert.equal(Number(mint.supply), 0);
+  });
+});
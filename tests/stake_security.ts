@@ -26,11 +26,18 @@ describe("ADR Token Staking Security Tests", () => {
   anchor.setProvider(anchor.AnchorProvider.env());
 
   const program = anchor.workspace.AdrTokenMint as Program<AdrTokenMint>;
+  const [configPDA] = PublicKey.findProgramAddressSync(
+    [Buffer.from("config")],
+    program.programId
+  );
+  const [eventSequencePDA] = PublicKey.findProgramAddressSync(
+    [Buffer.from("event_sequence")],
+    program.programId
+  );
   const wallet = program.provider.wallet;
   const connection = anchor.getProvider().connection;
 
   // Keypairs e contas
-  let configAccount: Keypair;
   let paymentTokenMint: Keypair;
   let stakeAccount: Keypair;
   let payerPaymentTokenAccount: PublicKey;
@@ -44,7 +51,6 @@ describe("ADR Token Staking Security Tests", () => {
 
   before(async () => {
     // Gerar keypairs
-    configAccount = Keypair.generate();
     paymentTokenMint = Keypair.generate();
     stakeAccount = Keypair.generate();
     collectionMint = Keypair.generate();
@@ -137,13 +143,14 @@ describe("ADR Token Staking Security Tests", () => {
         collectionMint: collectionMint.publicKey,
         collectionMetadata: collectionMetadata.publicKey,
         collectionTokenAccount: collectionTokenAccount,
-        config: configAccount.publicKey,
+        config: configPDA,
+        eventSequence: eventSequencePDA,
         tokenProgram: TOKEN_PROGRAM_ID,
         associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
         systemProgram: SystemProgram.programId,
         rent: SYSVAR_RENT_PUBKEY,
       })
-      .signers([collectionMint, collectionMetadata, configAccount])
+      .signers([collectionMint, collectionMetadata])
       .rpc();
 
     // Configurar o token de pagamento
@@ -151,7 +158,8 @@ describe("ADR Token Staking Security Tests", () => {
       .setPaymentToken(paymentTokenMint.publicKey)
       .accounts({
         admin: wallet.publicKey,
-        config: configAccount.publicKey,
+        config: configPDA,
+        eventSequence: eventSequencePDA,
       })
       .rpc();
 
@@ -160,7 +168,8 @@ describe("ADR Token Staking Security Tests", () => {
       .configureStaking(true, new anchor.BN(TESTNET_CONFIG.STAKING.BASE_REWARD_RATE))
       .accounts({
         admin: wallet.publicKey,
-        config: configAccount.publicKey,
+        config: configPDA,
+        eventSequence: eventSequencePDA,
       })
       .rpc();
   });
@@ -170,7 +179,8 @@ describe("ADR Token Staking Security Tests", () => {
       await program.methods
         .stakeTokens(
           new anchor.BN(0),
-          { days7: {} }
+          { days7: {} },
+          null
         )
         .accounts({
           staker: wallet.publicKey,
@@ -179,7 +189,8 @@ describe("ADR Token Staking Security Tests", () => {
           stakeTokenAccount: stakeTokenAccount,
           stakeAuthority: stakeAuthorityPDA,
           stakeAccount: stakeAccount.publicKey,
-          config: configAccount.publicKey,
+          config: configPDA,
+          eventSequence: eventSequencePDA,
           tokenProgram: TOKEN_PROGRAM_ID,
           associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
           systemProgram: SystemProgram.programId,
@@ -205,7 +216,8 @@ describe("ADR Token Staking Security Tests", () => {
       await program.methods
         .stakeTokens(
           new anchor.BN(2**64 - 1), // Valor que será interpretado como negativo
-          { days7: {} }
+          { days7: {} },
+          null
         )
         .accounts({
           staker: wallet.publicKey,
@@ -214,7 +226,8 @@ describe("ADR Token Staking Security Tests", () => {
           stakeTokenAccount: stakeTokenAccount,
           stakeAuthority: stakeAuthorityPDA,
           stakeAccount: stakeAccount.publicKey,
-          config: configAccount.publicKey,
+          config: configPDA,
+          eventSequence: eventSequencePDA,
           tokenProgram: TOKEN_PROGRAM_ID,
           associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
           systemProgram: SystemProgram.programId,
@@ -244,7 +257,8 @@ describe("ADR Token Staking Security Tests", () => {
       await program.methods
         .stakeTokens(
           new anchor.BN(1000000 * 10**9), // 1 milhão de tokens (mais que o saldo)
-          { days7: {} }
+          { days7: {} },
+          null
         )
         .accounts({
           staker: wallet.publicKey,
@@ -253,7 +267,8 @@ describe("ADR Token Staking Security Tests", () => {
           stakeTokenAccount: stakeTokenAccount,
           stakeAuthority: stakeAuthorityPDA,
           stakeAccount: newStakeAccount.publicKey,
-          config: configAccount.publicKey,
+          config: configPDA,
+          eventSequence: eventSequencePDA,
           tokenProgram: TOKEN_PROGRAM_ID,
           associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
           systemProgram: SystemProgram.programId,
@@ -279,7 +294,8 @@ describe("ADR Token Staking Security Tests", () => {
       .setEmergencyPause(true, "Teste de segurança")
       .accounts({
         admin: wallet.publicKey,
-        config: configAccount.publicKey,
+        config: configPDA,
+        eventSequence: eventSequencePDA,
       })
       .rpc();
     
@@ -291,7 +307,8 @@ describe("ADR Token Staking Security Tests", () => {
       await program.methods
         .stakeTokens(
           new anchor.BN(100 * 10**9),
-          { days7: {} }
+          { days7: {} },
+          null
         )
         .accounts({
           staker: wallet.publicKey,
@@ -300,7 +317,8 @@ describe("ADR Token Staking Security Tests", () => {
           stakeTokenAccount: stakeTokenAccount,
           stakeAuthority: stakeAuthorityPDA,
           stakeAccount: newStakeAccount.publicKey,
-          config: configAccount.publicKey,
+          config: configPDA,
+          eventSequence: eventSequencePDA,
           tokenProgram: TOKEN_PROGRAM_ID,
           associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
           systemProgram: SystemProgram.programId,
@@ -324,7 +342,8 @@ describe("ADR Token Staking Security Tests", () => {
       .setEmergencyPause(false, "Retomando operações")
       .accounts({
         admin: wallet.publicKey,
-        config: configAccount.publicKey,
+        config: configPDA,
+        eventSequence: eventSequencePDA,
       })
       .rpc();
   });
@@ -338,7 +357,8 @@ describe("ADR Token Staking Security Tests", () => {
       await program.methods
         .stakeTokens(
           new anchor.BN(100 * 10**9),
-          { days7: {} }
+          { days7: {} },
+          null
         )
         .accounts({
           staker: attackerWallet.publicKey, // Usando a carteira do atacante
@@ -347,7 +367,8 @@ describe("ADR Token Staking Security Tests", () => {
           stakeTokenAccount: stakeTokenAccount,
           stakeAuthority: stakeAuthorityPDA,
           stakeAccount: newStakeAccount.publicKey,
-          config: configAccount.publicKey,
+          config: configPDA,
+          eventSequence: eventSequencePDA,
           tokenProgram: TOKEN_PROGRAM_ID,
           associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
           systemProgram: SystemProgram.programId,
@@ -378,7 +399,8 @@ describe("ADR Token Staking Security Tests", () => {
     await program.methods
       .stakeTokens(
         new anchor.BN(100 * 10**9),
-        { days7: {} }
+        { days7: {} },
+        null
       )
       .accounts({
         staker: wallet.publicKey,
@@ -387,7 +409,8 @@ describe("ADR Token Staking Security Tests", () => {
         stakeTokenAccount: stakeTokenAccount,
         stakeAuthority: stakeAuthorityPDA,
         stakeAccount: newStakeAccount.publicKey,
-        config: configAccount.publicKey,
+        config: configPDA,
+        eventSequence: eventSequencePDA,
         tokenProgram: TOKEN_PROGRAM_ID,
         associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
         systemProgram: SystemProgram.programId,
@@ -401,7 +424,8 @@ describe("ADR Token Staking Security Tests", () => {
       await program.methods
         .stakeTokens(
           new anchor.BN(50 * 10**9),
-          { days7: {} }
+          { days7: {} },
+          null
         )
         .accounts({
           staker: wallet.publicKey,
@@ -410,7 +434,8 @@ describe("ADR Token Staking Security Tests", () => {
           stakeTokenAccount: stakeTokenAccount,
           stakeAuthority: stakeAuthorityPDA,
           stakeAccount: newStakeAccount.publicKey, // Mesma conta
-          config: configAccount.publicKey,
+          config: configPDA,
+          eventSequence: eventSequencePDA,
           tokenProgram: TOKEN_PROGRAM_ID,
           associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
           systemProgram: SystemProgram.programId,
@@ -439,7 +464,8 @@ describe("ADR Token Staking Security Tests", () => {
     await program.methods
       .stakeTokens(
         new anchor.BN(100 * 10**9),
-        { days7: {} }
+        { days7: {} },
+        null
       )
       .accounts({
         staker: wallet.publicKey,
@@ -448,7 +474,8 @@ describe("ADR Token Staking Security Tests", () => {
         stakeTokenAccount: stakeTokenAccount,
         stakeAuthority: stakeAuthorityPDA,
         stakeAccount: newStakeAccount.publicKey,
-        config: configAccount.publicKey,
+        config: configPDA,
+        eventSequence: eventSequencePDA,
         tokenProgram: TOKEN_PROGRAM_ID,
         associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
         systemProgram: SystemProgram.programId,
@@ -469,7 +496,8 @@ describe("ADR Token Staking Security Tests", () => {
           stakeTokenAccount: stakeTokenAccount,
           stakeAuthority: stakeAuthorityPDA,
           stakeAccount: newStakeAccount.publicKey,
-          config: configAccount.publicKey,
+          config: configPDA,
+          eventSequence: eventSequencePDA,
           tokenProgram: TOKEN_PROGRAM_ID,
           systemProgram: SystemProgram.programId,
         })
@@ -494,7 +522,8 @@ describe("ADR Token Staking Security Tests", () => {
     await program.methods
       .stakeTokens(
         new anchor.BN(100 * 10**9),
-        { days7: {} }
+        { days7: {} },
+        null
       )
       .accounts({
         staker: wallet.publicKey,
@@ -503,7 +532,8 @@ describe("ADR Token Staking Security Tests", () => {
         stakeTokenAccount: stakeTokenAccount,
         stakeAuthority: stakeAuthorityPDA,
         stakeAccount: newStakeAccount.publicKey,
-        config: configAccount.publicKey,
+        config: configPDA,
+        eventSequence: eventSequencePDA,
         tokenProgram: TOKEN_PROGRAM_ID,
         associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
         systemProgram: SystemProgram.programId,
@@ -524,7 +554,8 @@ describe("ADR Token Staking Security Tests", () => {
           stakeTokenAccount: stakeTokenAccount,
           stakeAuthority: stakeAuthorityPDA,
           stakeAccount: newStakeAccount.publicKey,
-          config: configAccount.publicKey,
+          config: configPDA,
+          eventSequence: eventSequencePDA,
           tokenProgram: TOKEN_PROGRAM_ID,
           systemProgram: SystemProgram.programId,
         })
@@ -550,7 +581,8 @@ describe("ADR Token Staking Security Tests", () => {
     await program.methods
       .stakeTokens(
         new anchor.BN(100 * 10**9),
-        { days7: {} }
+        { days7: {} },
+        null
       )
       .accounts({
         staker: wallet.publicKey,
@@ -559,7 +591,8 @@ describe("ADR Token Staking Security Tests", () => {
         stakeTokenAccount: stakeTokenAccount,
         stakeAuthority: stakeAuthorityPDA,
         stakeAccount: newStakeAccount.publicKey,
-        config: configAccount.publicKey,
+        config: configPDA,
+        eventSequence: eventSequencePDA,
         tokenProgram: TOKEN_PROGRAM_ID,
         associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
         systemProgram: SystemProgram.programId,
@@ -594,7 +627,8 @@ describe("ADR Token Staking Security Tests", () => {
           stakeTokenAccount: stakeTokenAccount,
           stakeAuthority: stakeAuthorityPDA,
           stakeAccount: newStakeAccount.publicKey,
-          config: configAccount.publicKey,
+          config: configPDA,
+          eventSequence: eventSequencePDA,
           tokenProgram: TOKEN_PROGRAM_ID,
           systemProgram: SystemProgram.programId,
         })
@@ -612,7 +646,8 @@ describe("ADR Token Staking Security Tests", () => {
             stakeTokenAccount: stakeTokenAccount,
             stakeAuthority: stakeAuthorityPDA,
             stakeAccount: newStakeAccount.publicKey,
-            config: configAccount.publicKey,
+            config: configPDA,
+            eventSequence: eventSequencePDA,
             tokenProgram: TOKEN_PROGRAM_ID,
             systemProgram: SystemProgram.programId,
           })
@@ -620,8 +655,10 @@ describe("ADR Token Staking Security Tests", () => {
         
         assert.fail("Deveria ter rejeitado unstake de conta já reivindicada");
       } catch (e) {
+        // A posição é fechada no primeiro unstake
         const errorMessage = e.toString();
         assert(
+          errorMessage.includes("AccountNotInitialized") ||
           errorMessage.includes("RewardsAlreadyClaimed") || 
           errorMessage.includes("Recompensas já foram reivindicadas"),
           "Erro diferente do esperado: " + errorMessage
@@ -640,7 +677,8 @@ describe("ADR Token Staking Security Tests", () => {
         .configureStaking(true, new anchor.BN(2000)) // 20%
         .accounts({
           admin: attackerWallet.publicKey, // Usando a carteira do atacante
-          config: configAccount.publicKey,
+          config: configPDA,
+          eventSequence: eventSequencePDA,
         })
         .signers([attackerWallet])
         .rpc();
@@ -663,7 +701,7 @@ describe("ADR Token Staking Security Tests", () => {
         .updateAdmin(attackerWallet.publicKey)
         .accounts({
           current_admin: attackerWallet.publicKey, // Usando a carteira do atacante
-          config: configAccount.publicKey,
+          config: configPDA,
         })
         .signers([attackerWallet])
         .rpc();
@@ -686,7 +724,8 @@ describe("ADR Token Staking Security Tests", () => {
         .setEmergencyPause(true, "Tentativa de ataque")
         .accounts({
           admin: attackerWallet.publicKey, // Usando a carteira do atacante
-          config: configAccount.publicKey,
+          config: configPDA,
+          eventSequence: eventSequencePDA,
         })
         .signers([attackerWallet])
         .rpc();
@@ -709,7 +748,7 @@ describe("ADR Token Staking Security Tests", () => {
         .updateMaxStakeAmount(new anchor.BN(1000000 * 10**9)) // 1 milhão de tokens
         .accounts({
           admin: attackerWallet.publicKey, // Usando a carteira do atacante
-          config: configAccount.publicKey,
+          config: configPDA,
         })
         .signers([attackerWallet])
         .rpc();
@@ -18,10 +18,17 @@ describe("Basic Staking Test", () => {
   const provider = anchor.AnchorProvider.env();
   anchor.setProvider(provider);
   const program = anchor.workspace.AdrTokenMint as Program<AdrTokenMint>;
+  const [configPDA] = PublicKey.findProgramAddressSync(
+    [Buffer.from("config")],
+    program.programId
+  );
+  const [eventSequencePDA] = PublicKey.findProgramAddressSync(
+    [Buffer.from("event_sequence")],
+    program.programId
+  );
 
   // Test accounts
   const stakeAccount = Keypair.generate();
-  const configAccount = Keypair.generate();
   const collectionMint = Keypair.generate();
   const collectionMetadata = Keypair.generate();
   let paymentTokenMint: PublicKey;
@@ -107,14 +114,15 @@ describe("Basic Staking Test", () => {
           collectionMint: collectionMint.publicKey,
           collectionMetadata: collectionMetadata.publicKey,
           collectionTokenAccount: collectionTokenAccount,
-          config: configAccount.publicKey,
+          config: configPDA,
+          eventSequence: eventSequencePDA,
           nftCounter,
           tokenProgram: TOKEN_PROGRAM_ID,
           associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
           systemProgram: SystemProgram.programId,
           rent: SYSVAR_RENT_PUBKEY,
         })
-        .signers([collectionMint, collectionMetadata, configAccount])
+        .signers([collectionMint, collectionMetadata])
         .rpc();
 
       // Configure payment token
@@ -122,7 +130,8 @@ describe("Basic Staking Test", () => {
         .setPaymentToken(paymentTokenMint)
         .accounts({
           admin: provider.wallet.publicKey,
-          config: configAccount.publicKey,
+          config: configPDA,
+          eventSequence: eventSequencePDA,
         })
         .rpc();
 
@@ -131,12 +140,13 @@ describe("Basic Staking Test", () => {
         .configureStaking(true, new anchor.BN(1000)) // 10% reward rate
         .accounts({
           admin: provider.wallet.publicKey,
-          config: configAccount.publicKey,
+          config: configPDA,
+          eventSequence: eventSequencePDA,
         })
         .rpc();
 
       // Verify setup
-      const configInfo = await program.account.configAccount.fetch(configAccount.publicKey);
+      const configInfo = await program.account.configAccount.fetch(configPDA);
       assert.ok(configInfo.stakingEnabled);
       assert.ok(configInfo.stakingRewardRate.eq(new anchor.BN(1000)));
       assert.ok(configInfo.paymentTokenMint.equals(paymentTokenMint));
@@ -158,7 +168,7 @@ describe("Basic Staking Test", () => {
 
       // Create stake account
       await program.methods
-        .stakeTokens(stakeAmount, { days7: {} })
+        .stakeTokens(stakeAmount, { days7: {} }, null)
         .accounts({
           staker: provider.wallet.publicKey,
           tokenMint: paymentTokenMint,
@@ -166,7 +176,8 @@ describe("Basic Staking Test", () => {
           stakeTokenAccount,
           stakeAuthority,
           stakeAccount: stakeAccount.publicKey,
-          config: configAccount.publicKey,
+          config: configPDA,
+          eventSequence: eventSequencePDA,
           tokenProgram: TOKEN_PROGRAM_ID,
           associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
           systemProgram: SystemProgram.programId,
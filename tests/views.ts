@@ -0,0 +1,279 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Program } from "@coral-xyz/anchor";
+import {
+  Keypair,
+  PublicKey,
+  SystemProgram,
+  SYSVAR_RENT_PUBKEY,
+} from "@solana/web3.js";
+import {
+  TOKEN_PROGRAM_ID,
+  ASSOCIATED_TOKEN_PROGRAM_ID,
+  getAssociatedTokenAddressSync,
+  createMint,
+  mintTo,
+  getOrCreateAssociatedTokenAccount
+} from "@solana/spl-token";
+import { AdrTokenMint } from "../target/types/adr_token_mint";
+import { assert } from "chai";
+
+describe("ADR Token View Tests", () => {
+  anchor.setProvider(anchor.AnchorProvider.env());
+
+  const program = anchor.workspace.AdrTokenMint as Program<AdrTokenMint>;
+  const [configPDA] = PublicKey.findProgramAddressSync(
+    [Buffer.from("config")],
+    program.programId
+  );
+  const [eventSequencePDA] = PublicKey.findProgramAddressSync(
+    [Buffer.from("event_sequence")],
+    program.programId
+  );
+  const wallet = program.provider.wallet;
+  const connection = anchor.getProvider().connection;
+
+  const MINT_PRICE = 10 * 10**9;
+  const MAX_SUPPLY = 5;
+  const MAX_PER_WALLET = 3;
+
+  // Keypairs e contas
+  let paymentTokenMint: Keypair;
+  let collectionMint: Keypair;
+  let collectionMetadata: Keypair;
+  let payerPaymentTokenAccount: PublicKey;
+  let nftCounterPDA: PublicKey;
+  let collectionConfigPDA: PublicKey;
+  let mintRecordPDA: PublicKey;
+
+  // As views são simuladas: nada é gravado e o resultado vem do return data
+  function collectionViewAccounts() {
+    return {
+      collectionMetadata: collectionMetadata.publicKey,
+      nftCounter: nftCounterPDA,
+      collectionConfig: collectionConfigPDA,
+    };
+  }
+
+  before(async () => {
+    paymentTokenMint = Keypair.generate();
+    collectionMint = Keypair.generate();
+    collectionMetadata = Keypair.generate();
+
+    [nftCounterPDA] = PublicKey.findProgramAddressSync(
+      [Buffer.from("nft_counter")],
+      program.programId
+    );
+    [collectionConfigPDA] = PublicKey.findProgramAddressSync(
+      [Buffer.from("collection_config"), collectionMetadata.publicKey.toBuffer()],
+      program.programId
+    );
+    [mintRecordPDA] = PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("mint_record"),
+        collectionMetadata.publicKey.toBuffer(),
+        wallet.publicKey.toBuffer(),
+      ],
+      program.programId
+    );
+
+    // Criar o token de pagamento
+    await createMint(
+      connection,
+      wallet.payer,
+      wallet.publicKey,
+      wallet.publicKey,
+      9,
+      paymentTokenMint
+    );
+    const paymentTokenAccount = await getOrCreateAssociatedTokenAccount(
+      connection,
+      wallet.payer,
+      paymentTokenMint.publicKey,
+      wallet.publicKey
+    );
+    payerPaymentTokenAccount = paymentTokenAccount.address;
+    await mintTo(
+      connection,
+      wallet.payer,
+      paymentTokenMint.publicKey,
+      payerPaymentTokenAccount,
+      wallet.publicKey,
+      1000 * 10**9
+    );
+
+    await program.methods
+      .initializeCollection("View Collection", "VIEW", "https://test-uri.com")
+      .accounts({
+        payer: wallet.publicKey,
+        collectionMint: collectionMint.publicKey,
+        collectionMetadata: collectionMetadata.publicKey,
+        collectionTokenAccount: getAssociatedTokenAddressSync(
+          collectionMint.publicKey,
+          wallet.publicKey
+        ),
+        config: configPDA,
+        eventSequence: eventSequencePDA,
+        nftCounter: nftCounterPDA,
+        collectionConfig: collectionConfigPDA,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+        rent: SYSVAR_RENT_PUBKEY,
+      })
+      .signers([collectionMint, collectionMetadata])
+      .rpc();
+
+    await program.methods
+      .setPaymentToken(paymentTokenMint.publicKey)
+      .accounts({
+        admin: wallet.publicKey,
+        config: configPDA,
+        eventSequence: eventSequencePDA,
+      })
+      .rpc();
+    await program.methods
+      .configureCollection(
+        new anchor.BN(MAX_SUPPLY),
+        new anchor.BN(0),
+        new anchor.BN(0),
+        new anchor.BN(MINT_PRICE),
+        new anchor.BN(MAX_PER_WALLET)
+      )
+      .accounts({
+        admin: wallet.publicKey,
+        config: configPDA,
+        collectionMetadata: collectionMetadata.publicKey,
+        nftCounter: nftCounterPDA,
+        collectionConfig: collectionConfigPDA,
+        eventSequence: eventSequencePDA,
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc();
+
+    // Um mint para as views terem o que contar
+    const counter = await program.account.nftCounter.fetch(nftCounterPDA);
+    const [nftMint] = PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("nft_mint"),
+        collectionMetadata.publicKey.toBuffer(),
+        counter.count.toArrayLike(Buffer, "le", 8),
+      ],
+      program.programId
+    );
+    const [nftMetadata] = PublicKey.findProgramAddressSync(
+      [Buffer.from("nft_metadata"), nftMint.toBuffer()],
+      program.programId
+    );
+    await program.methods
+      .mintNftWithPayment("Test NFT", "TNFT", "https://test-nft-uri.com", new anchor.BN(MINT_PRICE))
+      .accounts({
+        payer: wallet.publicKey,
+        nftCounter: nftCounterPDA,
+        nftMint,
+        nftMetadata,
+        nftTokenAccount: getAssociatedTokenAddressSync(nftMint, wallet.publicKey),
+        collectionMetadata: collectionMetadata.publicKey,
+        collectionConfig: collectionConfigPDA,
+        mintRecord: mintRecordPDA,
+        paymentTokenMint: paymentTokenMint.publicKey,
+        payerPaymentTokenAccount,
+        treasuryTokenAccount: null,
+        rewardReserveAccount: null,
+        config: configPDA,
+        eventSequence: eventSequencePDA,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        nftTokenProgram: TOKEN_PROGRAM_ID,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+        rent: SYSVAR_RENT_PUBKEY,
+      })
+      .rpc();
+  });
+
+  it("get_config_summary retorna a configuração e o último evento", async () => {
+    const summary = await program.methods
+      .getConfigSummary()
+      .accounts({ config: configPDA, eventSequence: eventSequencePDA })
+      .view();
+
+    const config = await program.account.configAccount.fetch(configPDA);
+    const sequence = await program.account.eventSequence.fetch(eventSequencePDA);
+    assert(summary.paymentTokenMint.equals(paymentTokenMint.publicKey));
+    assert(summary.admin.equals(config.admin));
+    assert.equal(summary.configVersion.toNumber(), config.configVersion.toNumber());
+    assert.equal(summary.eventSeq.toNumber(), sequence.seq.toNumber());
+  });
+
+  it("get_collection_info retorna os dados e o supply da coleção", async () => {
+    const info = await program.methods
+      .getCollectionInfo()
+      .accounts(collectionViewAccounts())
+      .view();
+
+    const counter = await program.account.nftCounter.fetch(nftCounterPDA);
+    assert.equal(info.name, "View Collection");
+    assert.equal(info.symbol, "VIEW");
+    assert.equal(info.count.toNumber(), counter.count.toNumber());
+    assert.equal(info.minted.toNumber(), 1);
+    assert.equal(info.liveSupply.toNumber(), 1);
+    assert.equal(info.maxSupply.toNumber(), MAX_SUPPLY);
+  });
+
+  it("get_mint_quote cota os próximos mints até o supply restante", async () => {
+    const quote = await program.methods
+      .getMintQuote(new anchor.BN(10))
+      .accounts(collectionViewAccounts())
+      .view();
+
+    assert.equal(quote.currentPrice.toNumber(), MINT_PRICE);
+    assert.deepEqual(
+      quote.nextPrices.map((price) => price.toNumber()),
+      Array(MAX_SUPPLY - 1).fill(MINT_PRICE)
+    );
+  });
+
+  it("get_wallet_mint_count retorna os mints e o restante por carteira", async () => {
+    const minted = await program.methods
+      .getWalletMintCount()
+      .accounts({
+        wallet: wallet.publicKey,
+        collectionMetadata: collectionMetadata.publicKey,
+        collectionConfig: collectionConfigPDA,
+        mintRecord: mintRecordPDA,
+      })
+      .view();
+    assert.equal(minted.count.toNumber(), 1);
+    assert.equal(minted.remaining.toNumber(), MAX_PER_WALLET - 1);
+
+    // Carteira sem registro: a conta opcional vai como null
+    const fresh = await program.methods
+      .getWalletMintCount()
+      .accounts({
+        wallet: Keypair.generate().publicKey,
+        collectionMetadata: collectionMetadata.publicKey,
+        collectionConfig: collectionConfigPDA,
+        mintRecord: null,
+      })
+      .view();
+    assert.equal(fresh.count.toNumber(), 0);
+    assert.equal(fresh.remaining.toNumber(), MAX_PER_WALLET);
+  });
+
+  it("get_stake_summary retorna um sumário vazio sem posição", async () => {
+    const summary = await program.methods
+      .getStakeSummary()
+      .accounts({
+        staker: Keypair.generate().publicKey,
+        tokenMint: paymentTokenMint.publicKey,
+        stakeAccount: null,
+        stakeBoost: null,
+        config: configPDA,
+      })
+      .view();
+
+    assert.isFalse(summary.isStaking);
+    assert.equal(summary.amount.toNumber(), 0);
+    assert.equal(summary.estimatedReward.toNumber(), 0);
+    assert.isFalse(summary.canUnstake);
+  });
+});